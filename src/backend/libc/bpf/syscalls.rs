@@ -0,0 +1,18 @@
+//! libc syscalls supporting `rustix::bpf`.
+
+use super::super::c;
+use super::super::conv::{syscall_ret, syscall_ret_owned_fd};
+use crate::fd::OwnedFd;
+use crate::io;
+use core::ffi::c_void;
+use linux_raw_sys::general::__NR_bpf;
+
+#[inline]
+pub(crate) unsafe fn bpf(cmd: u32, attr: *mut c_void, size: usize) -> io::Result<()> {
+    syscall_ret(c::syscall(__NR_bpf as _, cmd, attr, size))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_owned_fd(cmd: u32, attr: *mut c_void, size: usize) -> io::Result<OwnedFd> {
+    syscall_ret_owned_fd(c::syscall(__NR_bpf as _, cmd, attr, size))
+}
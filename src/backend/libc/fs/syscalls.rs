@@ -35,6 +35,8 @@ use super::super::offset::libc_posix_fadvise;
     target_os = "solaris",
 )))]
 use super::super::offset::libc_posix_fallocate;
+#[cfg(not(target_os = "wasi"))]
+use super::super::offset::libc_truncate;
 use super::super::offset::{libc_fstat, libc_fstatat, libc_ftruncate, libc_lseek, libc_off_t};
 #[cfg(not(any(
     target_os = "haiku",
@@ -76,6 +78,8 @@ use crate::fs::Access;
     target_os = "solaris",
 )))]
 use crate::fs::Advice;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::fs::DnotifyFlags;
 #[cfg(not(any(
     target_os = "aix",
     target_os = "dragonfly",
@@ -88,6 +92,8 @@ use crate::fs::Advice;
 use crate::fs::FallocateFlags;
 #[cfg(not(any(target_os = "solaris", target_os = "wasi")))]
 use crate::fs::FlockOperation;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::fs::LeaseType;
 #[cfg(any(target_os = "android", target_os = "freebsd", target_os = "linux"))]
 use crate::fs::MemfdFlags;
 #[cfg(any(
@@ -107,7 +113,7 @@ use crate::fs::SealFlags;
 )))]
 use crate::fs::StatFs;
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use crate::fs::{cwd, RenameFlags, ResolveFlags, Statx, StatxFlags};
+use crate::fs::{cwd, RenameFlags, ResolveFlags, Statx, StatxFlags, SwapFlags};
 #[cfg(not(any(
     target_os = "ios",
     target_os = "macos",
@@ -115,7 +121,7 @@ use crate::fs::{cwd, RenameFlags, ResolveFlags, Statx, StatxFlags};
     target_os = "wasi",
 )))]
 use crate::fs::{Dev, FileType};
-use crate::fs::{Mode, OFlags, Stat, Timestamps};
+use crate::fs::{Mode, OFlags, RawTimestamps, Stat};
 #[cfg(not(any(
     target_os = "haiku",
     target_os = "illumos",
@@ -450,8 +456,10 @@ fn statat_old(dirfd: BorrowedFd<'_>, path: &CStr, flags: AtFlags) -> io::Result<
 }
 
 #[cfg(not(any(
+    target_os = "android",
     target_os = "emscripten",
     target_os = "illumos",
+    target_os = "linux",
     target_os = "redox",
     target_os = "solaris",
 )))]
@@ -471,6 +479,44 @@ pub(crate) fn accessat(
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn accessat(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    access: Access,
+    flags: AtFlags,
+) -> io::Result<()> {
+    // Linux's `faccessat` doesn't have a flags parameter, so use `faccessat2`
+    // if we have flags, so that the kernel implements them. If it isn't
+    // available, fall back to libc, which may emulate them. Some seccomp
+    // sandboxes fail unknown syscalls with `EPERM` rather than `ENOSYS`, so
+    // fall back on that too; if it was a real `EPERM`, libc reports it
+    // again.
+    if !flags.is_empty() {
+        match unsafe {
+            syscall_ret(c::syscall(
+                c::SYS_faccessat2,
+                borrowed_fd(dirfd),
+                c_str(path),
+                access.bits(),
+                flags.bits(),
+            ))
+        } {
+            Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => {}
+            otherwise => return otherwise,
+        }
+    }
+
+    unsafe {
+        ret(c::faccessat(
+            borrowed_fd(dirfd),
+            c_str(path),
+            access.bits(),
+            flags.bits(),
+        ))
+    }
+}
+
 #[cfg(target_os = "emscripten")]
 pub(crate) fn accessat(
     _dirfd: BorrowedFd<'_>,
@@ -485,7 +531,7 @@ pub(crate) fn accessat(
 pub(crate) fn utimensat(
     dirfd: BorrowedFd<'_>,
     path: &CStr,
-    times: &Timestamps,
+    times: &RawTimestamps,
     flags: AtFlags,
 ) -> io::Result<()> {
     // 32-bit gnu version: libc has `utimensat` but it is not y2038 safe by
@@ -523,8 +569,8 @@ pub(crate) fn utimensat(
         )
     )))]
     unsafe {
-        // Assert that `Timestamps` has the expected layout.
-        let _ = core::mem::transmute::<Timestamps, [c::timespec; 2]>(times.clone());
+        // Assert that `RawTimestamps` has the expected layout.
+        let _ = core::mem::transmute::<RawTimestamps, [c::timespec; 2]>(times.clone());
 
         ret(c::utimensat(
             borrowed_fd(dirfd),
@@ -559,8 +605,8 @@ pub(crate) fn utimensat(
 
         // If we have `utimensat`, use it.
         if let Some(have_utimensat) = utimensat.get() {
-            // Assert that `Timestamps` has the expected layout.
-            let _ = core::mem::transmute::<Timestamps, [c::timespec; 2]>(times.clone());
+            // Assert that `RawTimestamps` has the expected layout.
+            let _ = core::mem::transmute::<RawTimestamps, [c::timespec; 2]>(times.clone());
 
             return ret(have_utimensat(
                 borrowed_fd(dirfd),
@@ -656,7 +702,7 @@ pub(crate) fn utimensat(
 unsafe fn utimensat_old(
     dirfd: BorrowedFd<'_>,
     path: &CStr,
-    times: &Timestamps,
+    times: &RawTimestamps,
     flags: AtFlags,
 ) -> io::Result<()> {
     let old_times = [
@@ -711,6 +757,24 @@ pub(crate) fn chmodat(dirfd: BorrowedFd<'_>, path: &CStr, mode: Mode) -> io::Res
     }
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn chmodat_with(
+    dirfd: BorrowedFd<'_>,
+    path: &CStr,
+    mode: Mode,
+    flags: AtFlags,
+) -> io::Result<()> {
+    // On Linux, libc emulates `AT_SYMLINK_NOFOLLOW`, if it supports it.
+    unsafe {
+        ret(c::fchmodat(
+            borrowed_fd(dirfd),
+            c_str(path),
+            mode.bits(),
+            flags.bits(),
+        ))
+    }
+}
+
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub(crate) fn fclonefileat(
     srcfd: BorrowedFd<'_>,
@@ -890,6 +954,32 @@ pub(crate) fn fcntl_add_seals(fd: BorrowedFd<'_>, seals: SealFlags) -> io::Resul
     unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_ADD_SEALS, seals.bits())) }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_getlease(fd: BorrowedFd<'_>) -> io::Result<LeaseType> {
+    let lease = unsafe { ret_c_int(c::fcntl(borrowed_fd(fd), c::F_GETLEASE))? };
+    Ok(match lease {
+        c::F_RDLCK => LeaseType::Read,
+        c::F_WRLCK => LeaseType::Write,
+        _ => LeaseType::Unlock,
+    })
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_setlease(fd: BorrowedFd<'_>, lease: LeaseType) -> io::Result<()> {
+    unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_SETLEASE, lease as c::c_int)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_dnotify(fd: BorrowedFd<'_>, flags: DnotifyFlags) -> io::Result<()> {
+    unsafe {
+        ret(c::fcntl(
+            borrowed_fd(fd),
+            c::F_NOTIFY,
+            flags.bits() as c::c_int,
+        ))
+    }
+}
+
 pub(crate) fn seek(fd: BorrowedFd<'_>, pos: SeekFrom) -> io::Result<u64> {
     let (whence, offset): (c::c_int, libc_off_t) = match pos {
         SeekFrom::Start(pos) => {
@@ -1062,7 +1152,7 @@ fn libc_statvfs_to_statvfs(from: libc_statvfs) -> StatVfs {
     }
 }
 
-pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()> {
+pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &RawTimestamps) -> io::Result<()> {
     // 32-bit gnu version: libc has `futimens` but it is not y2038 safe by default.
     #[cfg(all(
         any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
@@ -1092,8 +1182,8 @@ pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()>
         )
     )))]
     unsafe {
-        // Assert that `Timestamps` has the expected layout.
-        let _ = core::mem::transmute::<Timestamps, [c::timespec; 2]>(times.clone());
+        // Assert that `RawTimestamps` has the expected layout.
+        let _ = core::mem::transmute::<RawTimestamps, [c::timespec; 2]>(times.clone());
 
         ret(c::futimens(borrowed_fd(fd), as_ptr(times).cast()))
     }
@@ -1117,8 +1207,8 @@ pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()>
 
         // If we have `futimens`, use it.
         if let Some(have_futimens) = futimens.get() {
-            // Assert that `Timestamps` has the expected layout.
-            let _ = core::mem::transmute::<Timestamps, [c::timespec; 2]>(times.clone());
+            // Assert that `RawTimestamps` has the expected layout.
+            let _ = core::mem::transmute::<RawTimestamps, [c::timespec; 2]>(times.clone());
 
             return ret(have_futimens(borrowed_fd(fd), as_ptr(times).cast()));
         }
@@ -1140,7 +1230,7 @@ pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()>
     any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
     target_env = "gnu",
 ))]
-unsafe fn futimens_old(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()> {
+unsafe fn futimens_old(fd: BorrowedFd<'_>, times: &RawTimestamps) -> io::Result<()> {
     let old_times = [
         c::timespec {
             tv_sec: times
@@ -1247,11 +1337,27 @@ pub(crate) fn fdatasync(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(c::fdatasync(borrowed_fd(fd))) }
 }
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub(crate) fn sync() {
+    unsafe { c::sync() }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn syncfs(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(c::syncfs(borrowed_fd(fd))) }
+}
+
 pub(crate) fn ftruncate(fd: BorrowedFd<'_>, length: u64) -> io::Result<()> {
     let length = length.try_into().map_err(|_overflow_err| io::Errno::FBIG)?;
     unsafe { ret(libc_ftruncate(borrowed_fd(fd), length)) }
 }
 
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn truncate(path: &CStr, length: u64) -> io::Result<()> {
+    let length = length.try_into().map_err(|_overflow_err| io::Errno::FBIG)?;
+    unsafe { ret(libc_truncate(c_str(path), length)) }
+}
+
 #[cfg(any(target_os = "android", target_os = "freebsd", target_os = "linux"))]
 pub(crate) fn memfd_create(path: &CStr, flags: MemfdFlags) -> io::Result<OwnedFd> {
     #[cfg(target_os = "freebsd")]
@@ -1338,6 +1444,31 @@ pub(crate) fn sendfile(
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn ioctl_ficlone(fd: BorrowedFd<'_>, src_fd: BorrowedFd<'_>) -> io::Result<()> {
+    // `FICLONE` is `_IOW(0x94, 9, int)`.
+    #[cfg(not(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc",
+        target_arch = "sparc64",
+    )))]
+    const FICLONE: c::c_ulong = 0x4004_9409;
+    #[cfg(any(
+        target_arch = "mips",
+        target_arch = "mips64",
+        target_arch = "powerpc",
+        target_arch = "powerpc64",
+        target_arch = "sparc",
+        target_arch = "sparc64",
+    ))]
+    const FICLONE: c::c_ulong = 0x8004_9409;
+
+    unsafe { ret(c::ioctl(borrowed_fd(fd), FICLONE as _, borrowed_fd(src_fd))) }
+}
+
 /// Convert from a Linux `statx` value to rustix's `Stat`.
 #[cfg(all(
     any(target_os = "android", target_os = "linux"),
@@ -1717,7 +1848,7 @@ pub(crate) fn fcntl_fullfsync(fd: BorrowedFd<'_>) -> io::Result<()> {
 /// Convert `times` from a `futimens`/`utimensat` argument into `setattrlist`
 /// arguments.
 #[cfg(any(target_os = "ios", target_os = "macos"))]
-fn times_to_attrlist(times: &Timestamps) -> (c::size_t, [c::timespec; 2], Attrlist) {
+fn times_to_attrlist(times: &RawTimestamps) -> (c::size_t, [c::timespec; 2], Attrlist) {
     // ABI details.
     const ATTR_CMN_MODTIME: u32 = 0x0000_0400;
     const ATTR_CMN_ACCTIME: u32 = 0x0000_1000;
@@ -1819,6 +1950,32 @@ pub(crate) fn mount(
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn quotactl(
+    cmd: u32,
+    special: Option<&CStr>,
+    id: u32,
+    addr: *mut c::c_void,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(
+        c::SYS_quotactl,
+        cmd as c::c_int,
+        special.map_or(null(), c_str),
+        id as c::c_int,
+        addr,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn swapon(path: &CStr, flags: SwapFlags) -> io::Result<()> {
+    unsafe { ret(c::swapon(c_str(path), flags.bits() as c::c_int)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn swapoff(path: &CStr) -> io::Result<()> {
+    unsafe { ret(c::swapoff(c_str(path))) }
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub(crate) fn unmount(target: &CStr, flags: super::types::UnmountFlags) -> io::Result<()> {
     unsafe { ret(c::umount2(target.as_ptr(), flags.bits())) }
@@ -270,6 +270,19 @@ bitflags! {
             target_os = "netbsd",
         ))]
         const DIRECT = c::O_DIRECT;
+
+        /// `O_ASYNC`
+        #[cfg(any(
+            target_os = "android",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "ios",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "openbsd",
+        ))]
+        const ASYNC = c::O_ASYNC;
     }
 }
 
@@ -551,6 +564,46 @@ bitflags! {
     }
 }
 
+/// `F_*LCK` constants for use with [`fcntl_setlease`] and
+/// [`fcntl_getlease`].
+///
+/// [`fcntl_setlease`]: crate::fs::fcntl_setlease
+/// [`fcntl_getlease`]: crate::fs::fcntl_getlease
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(i32)]
+pub enum LeaseType {
+    /// `F_RDLCK`
+    Read = c::F_RDLCK,
+    /// `F_WRLCK`
+    Write = c::F_WRLCK,
+    /// `F_UNLCK`
+    Unlock = c::F_UNLCK,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `DN_*` constants for use with [`fcntl_dnotify`].
+    ///
+    /// [`fcntl_dnotify`]: crate::fs::fcntl_dnotify
+    pub struct DnotifyFlags: u32 {
+        /// `DN_ACCESS`—A file was read.
+        const ACCESS = linux_raw_sys::general::DN_ACCESS;
+        /// `DN_MODIFY`—A file was written or truncated.
+        const MODIFY = linux_raw_sys::general::DN_MODIFY;
+        /// `DN_CREATE`—A file was created.
+        const CREATE = linux_raw_sys::general::DN_CREATE;
+        /// `DN_DELETE`—A file was unlinked.
+        const DELETE = linux_raw_sys::general::DN_DELETE;
+        /// `DN_RENAME`—A file was renamed within the directory.
+        const RENAME = linux_raw_sys::general::DN_RENAME;
+        /// `DN_ATTRIB`—The attributes of a file were changed.
+        const ATTRIB = linux_raw_sys::general::DN_ATTRIB;
+        /// `DN_MULTISHOT`—Stay armed after the first notification.
+        const MULTISHOT = linux_raw_sys::general::DN_MULTISHOT;
+    }
+}
+
 #[cfg(any(
     target_os = "android",
     target_os = "freebsd",
@@ -626,6 +679,9 @@ bitflags! {
         /// `STATX_MNT_ID` (since Linux 5.8)
         const MNT_ID = c::STATX_MNT_ID;
 
+        /// `STATX_DIOALIGN` (since Linux 6.1)
+        const DIOALIGN = c::STATX_DIOALIGN;
+
         /// `STATX_ALL`
         const ALL = c::STATX_ALL;
     }
@@ -682,6 +738,9 @@ bitflags! {
         /// `STATX_MNT_ID` (since Linux 5.8)
         const MNT_ID = 0x1000;
 
+        /// `STATX_DIOALIGN` (since Linux 6.1)
+        const DIOALIGN = 0x2000;
+
         /// `STATX_ALL`
         const ALL = 0xfff;
     }
@@ -1029,7 +1088,8 @@ pub struct Statx {
     pub stx_dev_major: u32,
     pub stx_dev_minor: u32,
     pub stx_mnt_id: u64,
-    __statx_pad2: u64,
+    pub stx_dio_mem_align: u32,
+    pub stx_dio_offset_align: u32,
     __statx_pad3: [u64; 12],
 }
 
@@ -1109,6 +1169,55 @@ pub const PROC_SUPER_MAGIC: FsWord = c::PROC_SUPER_MAGIC as FsWord;
 ))]
 pub const NFS_SUPER_MAGIC: FsWord = c::NFS_SUPER_MAGIC as FsWord;
 
+/// `TMPFS_MAGIC`—The magic number for the tmpfs filesystem.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(target_env = "musl"),
+))]
+pub const TMPFS_MAGIC: FsWord = c::TMPFS_MAGIC as FsWord;
+
+/// `OVERLAYFS_SUPER_MAGIC`—The magic number for the overlayfs filesystem.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(target_env = "musl"),
+))]
+pub const OVERLAYFS_SUPER_MAGIC: FsWord = c::OVERLAYFS_SUPER_MAGIC as FsWord;
+
+/// `BTRFS_SUPER_MAGIC`—The magic number for the Btrfs filesystem.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(target_env = "musl"),
+))]
+pub const BTRFS_SUPER_MAGIC: FsWord = c::BTRFS_SUPER_MAGIC as FsWord;
+
+/// `EXT4_SUPER_MAGIC`—The magic number for the ext2, ext3, and ext4 filesystems.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(target_env = "musl"),
+))]
+pub const EXT4_SUPER_MAGIC: FsWord = c::EXT4_SUPER_MAGIC as FsWord;
+
+/// `XFS_SUPER_MAGIC`—The magic number for the XFS filesystem.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(target_env = "musl"),
+))]
+pub const XFS_SUPER_MAGIC: FsWord = c::XFS_SUPER_MAGIC as FsWord;
+
+/// `SYSFS_MAGIC`—The magic number for the sysfs filesystem.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(target_env = "musl"),
+))]
+pub const SYSFS_MAGIC: FsWord = c::SYSFS_MAGIC as FsWord;
+
+/// `CGROUP2_SUPER_MAGIC`—The magic number for the cgroup2 filesystem.
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    not(target_env = "musl"),
+))]
+pub const CGROUP2_SUPER_MAGIC: FsWord = c::CGROUP2_SUPER_MAGIC as FsWord;
+
 /// `PROC_SUPER_MAGIC`—The magic number for the procfs filesystem.
 #[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
 pub const PROC_SUPER_MAGIC: FsWord = 0x0000_9fa0;
@@ -1117,6 +1226,34 @@ pub const PROC_SUPER_MAGIC: FsWord = 0x0000_9fa0;
 #[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
 pub const NFS_SUPER_MAGIC: FsWord = 0x0000_6969;
 
+/// `TMPFS_MAGIC`—The magic number for the tmpfs filesystem.
+#[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
+pub const TMPFS_MAGIC: FsWord = 0x0102_1994;
+
+/// `OVERLAYFS_SUPER_MAGIC`—The magic number for the overlayfs filesystem.
+#[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
+pub const OVERLAYFS_SUPER_MAGIC: FsWord = 0x794c_7630;
+
+/// `BTRFS_SUPER_MAGIC`—The magic number for the Btrfs filesystem.
+#[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
+pub const BTRFS_SUPER_MAGIC: FsWord = 0x9123_683e;
+
+/// `EXT4_SUPER_MAGIC`—The magic number for the ext2, ext3, and ext4 filesystems.
+#[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
+pub const EXT4_SUPER_MAGIC: FsWord = 0x0000_ef53;
+
+/// `XFS_SUPER_MAGIC`—The magic number for the XFS filesystem.
+#[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
+pub const XFS_SUPER_MAGIC: FsWord = 0x5846_5342;
+
+/// `SYSFS_MAGIC`—The magic number for the sysfs filesystem.
+#[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
+pub const SYSFS_MAGIC: FsWord = 0x6265_6572;
+
+/// `CGROUP2_SUPER_MAGIC`—The magic number for the cgroup2 filesystem.
+#[cfg(all(any(target_os = "android", target_os = "linux"), target_env = "musl"))]
+pub const CGROUP2_SUPER_MAGIC: FsWord = 0x6367_7270;
+
 /// `copyfile_state_t`—State for use with [`fcopyfile`].
 ///
 /// [`fcopyfile`]: crate::fs::fcopyfile
@@ -25,8 +25,8 @@
 //! bind_v4(&listen_sock, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0))?;
 //! listen(&listen_sock, 1)?;
 //!
-//! // Create an epoll object. Using `Owning` here means the epoll object will
-//! // take ownership of the file descriptors registered with it.
+//! // Create an epoll object. To have it take ownership of the file
+//! // descriptors registered with it instead, use `epoll::Epoll`.
 //! let epoll = epoll::epoll_create(epoll::CreateFlags::CLOEXEC)?;
 //!
 //! // Register the socket with the epoll object.
@@ -73,6 +73,8 @@ use bitflags::bitflags;
 use core::convert::TryInto;
 use core::ptr::null_mut;
 
+pub use crate::io::owned_epoll::{Epoll, Events};
+
 bitflags! {
     /// `EPOLL_*` for use with [`Epoll::new`].
     pub struct CreateFlags: c::c_int {
@@ -6,6 +6,7 @@
 
 use super::super::c;
 use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
 use core::slice;
 
 #[derive(Copy, Clone)]
@@ -45,6 +46,15 @@ impl<'a> IoSlice<'a> {
     }
 }
 
+impl<'a> Deref for IoSlice<'a> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
 #[repr(transparent)]
 pub struct IoSliceMut<'a> {
     vec: c::iovec,
@@ -85,3 +95,19 @@ impl<'a> IoSliceMut<'a> {
         unsafe { slice::from_raw_parts_mut(self.vec.iov_base as *mut u8, self.vec.iov_len) }
     }
 }
+
+impl<'a> Deref for IoSliceMut<'a> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<'a> DerefMut for IoSliceMut<'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
@@ -1,11 +1,11 @@
 //! libc syscalls supporting `rustix::io`.
 
 use super::super::c;
-#[cfg(any(target_os = "android", target_os = "linux"))]
-use super::super::conv::syscall_ret_owned_fd;
 use super::super::conv::{
     borrowed_fd, ret, ret_c_int, ret_discarded_fd, ret_owned_fd, ret_ssize_t,
 };
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::conv::{syscall_ret, syscall_ret_owned_fd};
 use super::super::offset::{libc_pread, libc_pwrite};
 #[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "solaris")))]
 use super::super::offset::{libc_preadv, libc_pwritev};
@@ -24,7 +24,9 @@ use crate::io::DupFlags;
 use crate::io::PipeFlags;
 use crate::io::{self, FdFlags, IoSlice, IoSliceMut, PollFd};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use crate::io::{EventfdFlags, IoSliceRaw, ReadWriteFlags, SpliceFlags};
+use crate::io::{CloseRangeFlags, EventfdFlags, IoSliceRaw, Owner, ReadWriteFlags, SpliceFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::Pid;
 use core::cmp::min;
 use core::convert::TryInto;
 use core::mem::MaybeUninit;
@@ -33,7 +35,7 @@ use core::ptr;
 #[cfg(all(feature = "fs", feature = "net"))]
 use libc_errno::errno;
 
-pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     let nread = unsafe {
         ret_ssize_t(c::read(
             borrowed_fd(fd),
@@ -55,7 +57,11 @@ pub(crate) fn write(fd: BorrowedFd<'_>, buf: &[u8]) -> io::Result<usize> {
     Ok(nwritten as usize)
 }
 
-pub(crate) fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], offset: u64) -> io::Result<usize> {
+pub(crate) fn pread(
+    fd: BorrowedFd<'_>,
+    buf: &mut [MaybeUninit<u8>],
+    offset: u64,
+) -> io::Result<usize> {
     let len = min(buf.len(), READ_LIMIT);
 
     // Silently cast; we'll get `EINVAL` if the value is negative.
@@ -295,6 +301,20 @@ pub(crate) unsafe fn close(raw_fd: RawFd) {
     let _ = c::close(raw_fd as c::c_int);
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn close_range(
+    first: RawFd,
+    last: RawFd,
+    flags: CloseRangeFlags,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(
+        c::SYS_close_range,
+        first as c::c_uint,
+        last as c::c_uint,
+        flags.bits(),
+    ))
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd> {
     unsafe { syscall_ret_owned_fd(c::syscall(c::SYS_eventfd2, initval, flags.bits())) }
@@ -324,6 +344,48 @@ pub(crate) fn ioctl_blkpbszget(fd: BorrowedFd) -> io::Result<u32> {
     }
 }
 
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub(crate) fn kqueue() -> io::Result<OwnedFd> {
+    unsafe { ret_owned_fd(c::kqueue()) }
+}
+
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub(crate) unsafe fn kevent(
+    kq: BorrowedFd<'_>,
+    changelist: &[c::kevent],
+    eventlist: &mut [MaybeUninit<c::kevent>],
+    timeout: *const c::timespec,
+) -> io::Result<c::c_int> {
+    ret_c_int(c::kevent(
+        borrowed_fd(kq),
+        changelist.as_ptr(),
+        changelist
+            .len()
+            .try_into()
+            .map_err(|_| io::Errno::OVERFLOW)?,
+        eventlist.as_mut_ptr().cast(),
+        eventlist
+            .len()
+            .try_into()
+            .map_err(|_| io::Errno::OVERFLOW)?,
+        timeout,
+    ))
+}
+
 #[cfg(not(target_os = "redox"))]
 pub(crate) fn ioctl_fionread(fd: BorrowedFd<'_>) -> io::Result<u64> {
     let mut nread = MaybeUninit::<c::c_int>::uninit();
@@ -404,6 +466,72 @@ pub(crate) fn fcntl_setfd(fd: BorrowedFd<'_>, flags: FdFlags) -> io::Result<()>
     unsafe { ret(c::fcntl(borrowed_fd(fd), c::F_SETFD, flags.bits())) }
 }
 
+// The `libc` crate doesn't yet declare `F_SETOWN_EX`, `F_SETSIG`, or
+// `struct f_owner_ex`, so define them here.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_SETSIG: c::c_int = 10;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_GETSIG: c::c_int = 11;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_SETOWN_EX: c::c_int = 15;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_GETOWN_EX: c::c_int = 16;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_OWNER_TID: c::c_int = 0;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_OWNER_PID: c::c_int = 1;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const F_OWNER_PGRP: c::c_int = 2;
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[repr(C)]
+struct f_owner_ex {
+    type_: c::c_int,
+    pid: c::pid_t,
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_setown(fd: BorrowedFd<'_>, owner: Owner) -> io::Result<()> {
+    let (type_, pid) = match owner {
+        Owner::Thread(pid) => (F_OWNER_TID, pid),
+        Owner::Pid(pid) => (F_OWNER_PID, pid),
+        Owner::ProcessGroup(pid) => (F_OWNER_PGRP, pid),
+    };
+    let owner = f_owner_ex {
+        type_,
+        pid: pid.as_raw_nonzero().get(),
+    };
+    unsafe { ret(c::fcntl(borrowed_fd(fd), F_SETOWN_EX, &owner)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_getown(fd: BorrowedFd<'_>) -> io::Result<Option<Owner>> {
+    let mut owner = MaybeUninit::<f_owner_ex>::uninit();
+    let owner = unsafe {
+        ret(c::fcntl(borrowed_fd(fd), F_GETOWN_EX, owner.as_mut_ptr()))?;
+        owner.assume_init()
+    };
+    let pid = match unsafe { Pid::from_raw(owner.pid) } {
+        Some(pid) => pid,
+        None => return Ok(None),
+    };
+    Ok(Some(match owner.type_ {
+        F_OWNER_TID => Owner::Thread(pid),
+        F_OWNER_PGRP => Owner::ProcessGroup(pid),
+        _ => Owner::Pid(pid),
+    }))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_setsig(fd: BorrowedFd<'_>, sig: c::c_int) -> io::Result<()> {
+    unsafe { ret(c::fcntl(borrowed_fd(fd), F_SETSIG, sig)) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn fcntl_getsig(fd: BorrowedFd<'_>) -> io::Result<c::c_int> {
+    unsafe { ret_c_int(c::fcntl(borrowed_fd(fd), F_GETSIG)) }
+}
+
 #[cfg(not(target_os = "wasi"))]
 pub(crate) fn fcntl_dupfd_cloexec(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(c::fcntl(borrowed_fd(fd), c::F_DUPFD_CLOEXEC, min)) }
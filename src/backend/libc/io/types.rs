@@ -66,6 +66,19 @@ bitflags! {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `CLOSE_RANGE_*` constants for use with [`close_range`].
+    ///
+    /// [`close_range`]: crate::io::close_range
+    pub struct CloseRangeFlags: c::c_uint {
+        /// `CLOSE_RANGE_UNSHARE`
+        const UNSHARE = 1 << 1;
+        /// `CLOSE_RANGE_CLOEXEC`
+        const CLOEXEC = 1 << 2;
+    }
+}
+
 #[cfg(not(any(target_os = "ios", target_os = "macos", target_os = "wasi")))]
 bitflags! {
     /// `O_*` constants for use with [`pipe_with`].
@@ -0,0 +1,75 @@
+//! libc syscalls supporting `rustix::landlock`.
+
+use super::super::c;
+use super::super::conv::{borrowed_fd, syscall_ret, syscall_ret_owned_fd, syscall_ret_u32};
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::io;
+use crate::landlock::{
+    landlock_ruleset_attr, LandlockAddRuleFlags, LandlockCreateFlags, LandlockRestrictSelfFlags,
+    RuleType,
+};
+use core::mem::size_of;
+use core::ptr::null;
+use linux_raw_sys::general::{
+    __NR_landlock_add_rule, __NR_landlock_create_ruleset, __NR_landlock_restrict_self,
+};
+
+/// `LANDLOCK_CREATE_RULESET_VERSION`
+const LANDLOCK_CREATE_RULESET_VERSION: u32 = 1 << 0;
+
+#[inline]
+pub(crate) fn landlock_create_ruleset(
+    attr: &landlock_ruleset_attr,
+    flags: LandlockCreateFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            __NR_landlock_create_ruleset as _,
+            attr as *const landlock_ruleset_attr,
+            size_of::<landlock_ruleset_attr>(),
+            flags.bits(),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn landlock_abi_version() -> io::Result<u32> {
+    unsafe {
+        syscall_ret_u32(c::syscall(
+            __NR_landlock_create_ruleset as _,
+            null::<c::c_void>(),
+            0_usize,
+            LANDLOCK_CREATE_RULESET_VERSION,
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn landlock_add_rule(
+    ruleset_fd: BorrowedFd<'_>,
+    rule_type: RuleType,
+    rule_attr: *const c::c_void,
+    flags: LandlockAddRuleFlags,
+) -> io::Result<()> {
+    syscall_ret(c::syscall(
+        __NR_landlock_add_rule as _,
+        borrowed_fd(ruleset_fd),
+        rule_type as u32,
+        rule_attr,
+        flags.bits(),
+    ))
+}
+
+#[inline]
+pub(crate) fn landlock_restrict_self(
+    ruleset_fd: BorrowedFd<'_>,
+    flags: LandlockRestrictSelfFlags,
+) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            __NR_landlock_restrict_self as _,
+            borrowed_fd(ruleset_fd),
+            flags.bits(),
+        ))
+    }
+}
@@ -1,9 +1,9 @@
 //! libc syscalls supporting `rustix::mm`.
 
 use super::super::c;
-#[cfg(any(target_os = "android", target_os = "linux"))]
-use super::super::conv::syscall_ret_owned_fd;
 use super::super::conv::{borrowed_fd, no_fd, ret};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::super::conv::{syscall_ret, syscall_ret_owned_fd, syscall_ret_ssize_t};
 use super::super::offset::libc_mmap;
 #[cfg(not(target_os = "redox"))]
 use super::types::Advice;
@@ -16,6 +16,15 @@ use crate::fd::BorrowedFd;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use crate::fd::OwnedFd;
 use crate::io;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use {
+    crate::process::Pid,
+    core::mem::{size_of, MaybeUninit},
+    core::ptr::null,
+    linux_raw_sys::general::{
+        __NR_get_mempolicy, __NR_mbind, __NR_migrate_pages, __NR_move_pages, __NR_set_mempolicy,
+    },
+};
 
 #[cfg(not(target_os = "redox"))]
 pub(crate) fn madvise(addr: *mut c::c_void, len: usize, advice: Advice) -> io::Result<()> {
@@ -216,3 +225,128 @@ pub(crate) unsafe fn munlock(addr: *mut c::c_void, length: usize) -> io::Result<
 pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     syscall_ret_owned_fd(c::syscall(c::SYS_userfaultfd, flags.bits()))
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+/// Returns the `maxnode` argument for passing `nodemask` to the mempolicy
+/// syscalls, which ignore its last bit.
+#[inline]
+fn nodemask_maxnode(nodemask: &[c::c_ulong]) -> usize {
+    nodemask.len() * 8 * size_of::<c::c_ulong>() + 1
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn set_mempolicy(mode: c::c_int, nodemask: &[c::c_ulong]) -> io::Result<()> {
+    let maxnode = nodemask_maxnode(nodemask);
+    unsafe {
+        syscall_ret(c::syscall(
+            __NR_set_mempolicy as _,
+            mode,
+            nodemask.as_ptr(),
+            maxnode,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn get_mempolicy(
+    nodemask: &mut [c::c_ulong],
+    addr: *const c::c_void,
+    flags: c::c_uint,
+) -> io::Result<c::c_int> {
+    let maxnode = nodemask_maxnode(nodemask);
+    let mut mode = MaybeUninit::<c::c_int>::uninit();
+    unsafe {
+        syscall_ret(c::syscall(
+            __NR_get_mempolicy as _,
+            mode.as_mut_ptr(),
+            nodemask.as_mut_ptr(),
+            maxnode,
+            addr,
+            flags,
+        ))?;
+        Ok(mode.assume_init())
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn mbind(
+    addr: *mut c::c_void,
+    len: usize,
+    mode: c::c_int,
+    nodemask: &[c::c_ulong],
+    flags: c::c_uint,
+) -> io::Result<()> {
+    let maxnode = nodemask_maxnode(nodemask);
+    syscall_ret(c::syscall(
+        __NR_mbind as _,
+        addr,
+        len,
+        mode,
+        nodemask.as_ptr(),
+        maxnode,
+        flags,
+    ))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn migrate_pages(
+    pid: Pid,
+    old_nodes: &[c::c_ulong],
+    new_nodes: &[c::c_ulong],
+) -> io::Result<usize> {
+    debug_assert_eq!(old_nodes.len(), new_nodes.len());
+    let maxnode = nodemask_maxnode(old_nodes);
+    unsafe {
+        syscall_ret_ssize_t(c::syscall(
+            __NR_migrate_pages as _,
+            Pid::as_raw(Some(pid)) as c::pid_t,
+            maxnode,
+            old_nodes.as_ptr(),
+            new_nodes.as_ptr(),
+        ))
+        .map(|n| n as usize)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn move_pages(
+    pid: Pid,
+    pages: &[*const c::c_void],
+    nodes: Option<&[c::c_int]>,
+    status: &mut [c::c_int],
+    flags: c::c_uint,
+) -> io::Result<()> {
+    // On success, this returns the number of pages which weren't moved, which
+    // is also reflected in `status`.
+    syscall_ret_ssize_t(c::syscall(
+        __NR_move_pages as _,
+        Pid::as_raw(Some(pid)) as c::pid_t,
+        pages.len(),
+        pages.as_ptr(),
+        nodes.map_or(null(), <[c::c_int]>::as_ptr),
+        status.as_mut_ptr(),
+        flags,
+    ))?;
+    Ok(())
+}
+
+#[cfg(all(
+    any(target_os = "android", target_os = "linux"),
+    target_arch = "x86_64"
+))]
+pub(crate) unsafe fn map_shadow_stack(
+    addr: usize,
+    size: usize,
+    flags: c::c_uint,
+) -> io::Result<*mut c::c_void> {
+    // Not all versions of `libc` and `linux-raw-sys` have
+    // `SYS_map_shadow_stack` yet.
+    const SYS_MAP_SHADOW_STACK: c::c_long = 453;
+
+    let res = c::syscall(SYS_MAP_SHADOW_STACK, addr, size, flags);
+    if res == -1 {
+        Err(io::Errno::last_os_error())
+    } else {
+        Ok(res as *mut c::c_void)
+    }
+}
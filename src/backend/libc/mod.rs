@@ -55,6 +55,9 @@ pub(crate) mod c;
 #[cfg(not(windows))]
 pub(crate) use libc as c;
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "bpf")]
+pub(crate) mod bpf;
 #[cfg(not(windows))]
 #[cfg(feature = "fs")]
 pub(crate) mod fs;
@@ -62,9 +65,15 @@ pub(crate) mod io;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[cfg(feature = "io_uring")]
 pub(crate) mod io_uring;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "landlock")]
+pub(crate) mod landlock;
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[cfg(feature = "mm")]
 pub(crate) mod mm;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "mq")]
+pub(crate) mod mq;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[cfg(feature = "net")]
 pub(crate) mod net;
@@ -81,6 +90,19 @@ pub(crate) mod process;
 #[cfg(not(windows))]
 #[cfg(feature = "rand")]
 pub(crate) mod rand;
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[cfg(feature = "shm")]
+pub(crate) mod shm;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "sysvipc")]
+pub(crate) mod sysvipc;
 #[cfg(not(windows))]
 #[cfg(feature = "termios")]
 pub(crate) mod termios;
@@ -0,0 +1,196 @@
+//! libc syscalls supporting `rustix::mq`.
+
+use super::super::c;
+use super::super::conv::{
+    borrowed_fd, c_str, syscall_ret, syscall_ret_owned_fd, syscall_ret_ssize_t,
+};
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::fs::{Mode, OFlags, Timespec};
+use crate::io;
+use crate::mq::mq_attr;
+#[cfg(target_pointer_width = "32")]
+use core::convert::TryInto;
+use core::mem::MaybeUninit;
+use core::ptr::null;
+use linux_raw_sys::general::{
+    __NR_mq_getsetattr, __NR_mq_open, __NR_mq_timedreceive, __NR_mq_timedsend, __NR_mq_unlink,
+    __kernel_timespec,
+};
+#[cfg(target_pointer_width = "32")]
+use linux_raw_sys::general::{
+    __NR_mq_timedreceive_time64, __NR_mq_timedsend_time64, timespec as __kernel_old_timespec,
+};
+
+#[inline]
+pub(crate) fn mq_open(
+    name: &CStr,
+    oflags: OFlags,
+    mode: Mode,
+    attr: Option<&mq_attr>,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            __NR_mq_open as _,
+            c_str(name),
+            oflags.bits() as c::c_int,
+            mode.bits() as c::c_uint,
+            opt_ptr(attr),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn mq_unlink(name: &CStr) -> io::Result<()> {
+    unsafe { syscall_ret(c::syscall(__NR_mq_unlink as _, c_str(name))) }
+}
+
+#[inline]
+pub(crate) fn mq_timedsend(
+    mqdes: BorrowedFd<'_>,
+    msg: &[u8],
+    priority: u32,
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<()> {
+    let timeout = kernel_timespec(abs_timeout);
+
+    #[cfg(target_pointer_width = "32")]
+    {
+        let result = unsafe {
+            syscall_ret(c::syscall(
+                __NR_mq_timedsend_time64 as _,
+                borrowed_fd(mqdes),
+                msg.as_ptr(),
+                msg.len(),
+                priority as c::c_uint,
+                opt_ptr(timeout.as_ref()),
+            ))
+        };
+        // See the comments in `rustix_clock_gettime_via_syscall` about
+        // emulation.
+        if result != Err(io::Errno::NOSYS) {
+            return result;
+        }
+        let old_timeout = old_timespec(abs_timeout)?;
+        unsafe {
+            syscall_ret(c::syscall(
+                __NR_mq_timedsend as _,
+                borrowed_fd(mqdes),
+                msg.as_ptr(),
+                msg.len(),
+                priority as c::c_uint,
+                opt_ptr(old_timeout.as_ref()),
+            ))
+        }
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        syscall_ret(c::syscall(
+            __NR_mq_timedsend as _,
+            borrowed_fd(mqdes),
+            msg.as_ptr(),
+            msg.len(),
+            priority as c::c_uint,
+            opt_ptr(timeout.as_ref()),
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn mq_timedreceive(
+    mqdes: BorrowedFd<'_>,
+    buf: &mut [u8],
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<(usize, u32)> {
+    let timeout = kernel_timespec(abs_timeout);
+    let mut priority = MaybeUninit::<c::c_uint>::uninit();
+
+    #[cfg(target_pointer_width = "32")]
+    let len = {
+        let result = unsafe {
+            syscall_ret_ssize_t(c::syscall(
+                __NR_mq_timedreceive_time64 as _,
+                borrowed_fd(mqdes),
+                buf.as_mut_ptr(),
+                buf.len(),
+                priority.as_mut_ptr(),
+                opt_ptr(timeout.as_ref()),
+            ))
+        };
+        // See the comments in `rustix_clock_gettime_via_syscall` about
+        // emulation.
+        match result {
+            Err(io::Errno::NOSYS) => {
+                let old_timeout = old_timespec(abs_timeout)?;
+                unsafe {
+                    syscall_ret_ssize_t(c::syscall(
+                        __NR_mq_timedreceive as _,
+                        borrowed_fd(mqdes),
+                        buf.as_mut_ptr(),
+                        buf.len(),
+                        priority.as_mut_ptr(),
+                        opt_ptr(old_timeout.as_ref()),
+                    ))?
+                }
+            }
+            result => result?,
+        }
+    };
+
+    #[cfg(target_pointer_width = "64")]
+    let len = unsafe {
+        syscall_ret_ssize_t(c::syscall(
+            __NR_mq_timedreceive as _,
+            borrowed_fd(mqdes),
+            buf.as_mut_ptr(),
+            buf.len(),
+            priority.as_mut_ptr(),
+            opt_ptr(timeout.as_ref()),
+        ))?
+    };
+
+    // Safety: The syscall succeeded, so it wrote the priority.
+    Ok((len as usize, unsafe { priority.assume_init() }))
+}
+
+#[inline]
+pub(crate) fn mq_getsetattr(
+    mqdes: BorrowedFd<'_>,
+    new_attr: Option<&mq_attr>,
+) -> io::Result<mq_attr> {
+    let mut old_attr = mq_attr::default();
+    unsafe {
+        syscall_ret(c::syscall(
+            __NR_mq_getsetattr as _,
+            borrowed_fd(mqdes),
+            opt_ptr(new_attr),
+            &mut old_attr as *mut mq_attr,
+        ))?;
+    }
+    Ok(old_attr)
+}
+
+/// The raw syscalls take a `__kernel_timespec`, which `Timespec` may differ
+/// from on 32-bit platforms.
+fn kernel_timespec(timespec: Option<&Timespec>) -> Option<__kernel_timespec> {
+    timespec.map(|timespec| __kernel_timespec {
+        tv_sec: timespec.tv_sec.into(),
+        tv_nsec: timespec.tv_nsec.into(),
+    })
+}
+
+#[cfg(target_pointer_width = "32")]
+fn old_timespec(timespec: Option<&Timespec>) -> io::Result<Option<__kernel_old_timespec>> {
+    match timespec {
+        Some(timespec) => Ok(Some(__kernel_old_timespec {
+            tv_sec: timespec.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+            tv_nsec: timespec.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
+        })),
+        None => Ok(None),
+    }
+}
+
+fn opt_ptr<T>(t: Option<&T>) -> *const T {
+    t.map_or_else(null, |t| t as *const T)
+}
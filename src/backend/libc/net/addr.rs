@@ -104,6 +104,44 @@ impl SocketAddrUnix {
         })
     }
 
+    /// Construct a new unnamed Unix-domain address.
+    ///
+    /// On Linux, binding a socket to this address asks the kernel to
+    /// autobind it to a unique abstract name, which can be read back with
+    /// [`getsockname`].
+    ///
+    /// [`getsockname`]: crate::net::getsockname
+    #[inline]
+    pub fn new_unnamed() -> Self {
+        #[allow(unused_mut)]
+        let mut unix = Self::init();
+
+        #[cfg(any(
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "ios",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "openbsd",
+        ))]
+        {
+            unix.sun_len = offsetof_sun_path().try_into().unwrap();
+        }
+
+        Self {
+            unix,
+            #[cfg(not(any(
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "ios",
+                target_os = "macos",
+                target_os = "netbsd",
+                target_os = "openbsd",
+            )))]
+            len: offsetof_sun_path().try_into().unwrap(),
+        }
+    }
+
     fn init() -> c::sockaddr_un {
         c::sockaddr_un {
             #[cfg(any(
@@ -145,7 +183,7 @@ impl SocketAddrUnix {
     #[inline]
     pub fn path(&self) -> Option<&CStr> {
         let len = self.len();
-        if len != 0 && self.unix.sun_path[0] != b'\0' as c::c_char {
+        if len > offsetof_sun_path() && self.unix.sun_path[0] != b'\0' as c::c_char {
             let end = len as usize - offsetof_sun_path();
             let bytes = &self.unix.sun_path[..end];
             // Safety: `from_raw_parts` to convert from `&[c_char]` to `&[u8]`. And
@@ -166,7 +204,7 @@ impl SocketAddrUnix {
     #[inline]
     pub fn abstract_name(&self) -> Option<&[u8]> {
         let len = self.len();
-        if len != 0 && self.unix.sun_path[0] == b'\0' as c::c_char {
+        if len > offsetof_sun_path() && self.unix.sun_path[0] == b'\0' as c::c_char {
             let end = len as usize - offsetof_sun_path();
             let bytes = &self.unix.sun_path[1..end];
             // Safety: `from_raw_parts` to convert from `&[c_char]` to `&[u8]`.
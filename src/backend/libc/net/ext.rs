@@ -208,3 +208,7 @@ pub(crate) fn sockaddr_in6_new(
     };
     unsafe { core::mem::transmute(addr) }
 }
+
+/// `AF_VSOCK`, which musl's libc doesn't define.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) const AF_VSOCK: c::c_int = 40;
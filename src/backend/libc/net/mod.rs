@@ -1,4 +1,6 @@
 pub(crate) mod addr;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) mod cmsg;
 pub(crate) mod ext;
 pub(crate) mod read_sockaddr;
 pub(crate) mod send_recv;
@@ -6,6 +6,8 @@ use super::ext::{in6_addr_s6_addr, in_addr_s_addr, sockaddr_in6_sin6_scope_id};
 use crate::ffi::CStr;
 use crate::io;
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink, SocketAddrVsock};
 #[cfg(not(windows))]
 use alloc::vec::Vec;
 use core::mem::size_of;
@@ -138,10 +140,23 @@ pub(crate) unsafe fn read_sockaddr(
                 return Err(io::Errno::INVAL);
             }
             if len == offsetof_sun_path {
-                Ok(SocketAddrAny::Unix(SocketAddrUnix::new(&[][..]).unwrap()))
+                Ok(SocketAddrAny::Unix(SocketAddrUnix::new_unnamed()))
             } else {
                 let decode = *storage.cast::<c::sockaddr_un>();
 
+                // Abstract names start with a NUL byte and aren't
+                // NUL-terminated; their length is given by `len`.
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                if decode.sun_path[0] == 0 {
+                    return SocketAddrUnix::new_abstract_name(
+                        &decode.sun_path[1..len - offsetof_sun_path]
+                            .iter()
+                            .map(|c| *c as u8)
+                            .collect::<Vec<u8>>(),
+                    )
+                    .map(SocketAddrAny::Unix);
+                }
+
                 // Trim off unused bytes from the end of `path_bytes`.
                 let path_bytes = if cfg!(target_os = "freebsd") {
                     // FreeBSD sometimes sets the length to longer than the length
@@ -167,6 +182,36 @@ pub(crate) unsafe fn read_sockaddr(
                 ))
             }
         }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        c::AF_PACKET => {
+            if len < offsetof_sll_addr() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = *storage.cast::<c::sockaddr_ll>();
+            Ok(SocketAddrAny::Link(decode_sockaddr_ll(&decode, len)))
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        c::AF_NETLINK => {
+            if len < size_of::<c::sockaddr_nl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = *storage.cast::<c::sockaddr_nl>();
+            Ok(SocketAddrAny::Netlink(SocketAddrNetlink::new(
+                decode.nl_pid,
+                decode.nl_groups,
+            )))
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        super::ext::AF_VSOCK => {
+            if len < size_of::<c::sockaddr_vm>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = *storage.cast::<c::sockaddr_vm>();
+            Ok(SocketAddrAny::Vsock(SocketAddrVsock::new(
+                decode.svm_cid,
+                decode.svm_port,
+            )))
+        }
         _ => Err(io::Errno::INVAL),
     }
 }
@@ -229,9 +274,24 @@ unsafe fn inner_read_sockaddr_os(
         c::AF_UNIX => {
             assert!(len >= offsetof_sun_path);
             if len == offsetof_sun_path {
-                SocketAddrAny::Unix(SocketAddrUnix::new(&[][..]).unwrap())
+                SocketAddrAny::Unix(SocketAddrUnix::new_unnamed())
             } else {
                 let decode = *storage.cast::<c::sockaddr_un>();
+
+                // Abstract names start with a NUL byte and aren't
+                // NUL-terminated; their length is given by `len`.
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                if decode.sun_path[0] == 0 {
+                    return SocketAddrAny::Unix(
+                        SocketAddrUnix::new_abstract_name(
+                            &decode.sun_path[1..len - offsetof_sun_path]
+                                .iter()
+                                .map(|c| *c as u8)
+                                .collect::<Vec<u8>>(),
+                        )
+                        .unwrap(),
+                    );
+                }
                 assert_eq!(
                     decode.sun_path[len - 1 - offsetof_sun_path],
                     b'\0' as c::c_char
@@ -250,6 +310,44 @@ unsafe fn inner_read_sockaddr_os(
                 )
             }
         }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        c::AF_PACKET => {
+            assert!(len >= offsetof_sll_addr());
+            let decode = *storage.cast::<c::sockaddr_ll>();
+            SocketAddrAny::Link(decode_sockaddr_ll(&decode, len))
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        c::AF_NETLINK => {
+            assert!(len >= size_of::<c::sockaddr_nl>());
+            let decode = *storage.cast::<c::sockaddr_nl>();
+            SocketAddrAny::Netlink(SocketAddrNetlink::new(decode.nl_pid, decode.nl_groups))
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        super::ext::AF_VSOCK => {
+            assert!(len >= size_of::<c::sockaddr_vm>());
+            let decode = *storage.cast::<c::sockaddr_vm>();
+            SocketAddrAny::Vsock(SocketAddrVsock::new(decode.svm_cid, decode.svm_port))
+        }
         other => unimplemented!("{:?}", other),
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+/// The kernel only includes `sll_halen` bytes of `sll_addr` in the length of
+/// a `sockaddr_ll` that it returns.
+#[inline]
+fn offsetof_sll_addr() -> usize {
+    size_of::<c::sockaddr_ll>() - 8
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn decode_sockaddr_ll(decode: &c::sockaddr_ll, len: usize) -> SocketAddrLink {
+    let mut link = SocketAddrLink::new(u16::from_be(decode.sll_protocol), decode.sll_ifindex as _);
+    link.set_hatype(decode.sll_hatype);
+    link.set_pkttype(decode.sll_pkttype);
+    let halen = usize::from(decode.sll_halen)
+        .min(decode.sll_addr.len())
+        .min(len - offsetof_sll_addr());
+    link.set_addr(&decode.sll_addr[..halen]).unwrap();
+    link
+}
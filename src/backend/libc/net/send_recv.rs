@@ -45,6 +45,10 @@ bitflags! {
         const NOSIGNAL = c::MSG_NOSIGNAL;
         /// `MSG_OOB`
         const OOB = c::MSG_OOB;
+        /// `MSG_ZEROCOPY`
+        // Not all versions of libc define this, so define it here.
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        const ZEROCOPY = 0x400_0000;
     }
 }
 
@@ -54,6 +58,9 @@ bitflags! {
         #[cfg(not(any(windows, target_os = "haiku", target_os = "illumos", target_os = "ios", target_os = "macos", target_os = "solaris")))]
         /// `MSG_CMSG_CLOEXEC`
         const CMSG_CLOEXEC = c::MSG_CMSG_CLOEXEC;
+        /// `MSG_CTRUNC`
+        #[cfg(not(windows))]
+        const CTRUNC = c::MSG_CTRUNC;
         /// `MSG_DONTWAIT`
         #[cfg(not(windows))]
         const DONTWAIT = c::MSG_DONTWAIT;
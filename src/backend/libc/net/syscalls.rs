@@ -1,7 +1,7 @@
 //! libc syscalls supporting `rustix::net`.
 
 use super::super::c;
-use super::super::conv::{borrowed_fd, ret, ret_owned_fd, ret_send_recv, send_recv_len};
+use super::super::conv::{borrowed_fd, ret, ret_c_int, ret_owned_fd, ret_send_recv, send_recv_len};
 #[cfg(unix)]
 use super::addr::SocketAddrUnix;
 use super::ext::{in6_addr_new, in_addr_new};
@@ -13,12 +13,19 @@ use super::read_sockaddr::{maybe_read_sockaddr_os, read_sockaddr_os};
 use super::send_recv::{RecvFlags, SendFlags};
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 use super::types::{AcceptFlags, AddressFamily, Protocol, Shutdown, SocketFlags, SocketType};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use super::write_sockaddr::{encode_sockaddr_ll, encode_sockaddr_nl, encode_sockaddr_vm};
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 use super::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
 use crate::net::{SocketAddrAny, SocketAddrV4, SocketAddrV6};
 use crate::utils::as_ptr;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::{
+    io::{IoSlice, IoSliceMut},
+    net::{RecvMsgReturn, SocketAddrLink, SocketAddrNetlink, SocketAddrVsock},
+};
 use core::convert::TryInto;
 use core::mem::{size_of, MaybeUninit};
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
@@ -80,6 +87,65 @@ pub(crate) fn recvfrom(
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn recvmsg(
+    fd: BorrowedFd<'_>,
+    bufs: &mut [IoSliceMut<'_>],
+    control: &mut [u8],
+    flags: RecvFlags,
+) -> io::Result<RecvMsgReturn> {
+    unsafe {
+        let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
+        // Connection-oriented sockets don't store an address, so initialize
+        // the family field to `AF_UNSPEC` so that we can detect this case.
+        initialize_family_to_unspec(storage.as_mut_ptr());
+
+        // `msghdr` may have private padding fields, so start with zeros.
+        let mut msg: c::msghdr = core::mem::zeroed();
+        msg.msg_name = storage.as_mut_ptr().cast();
+        msg.msg_namelen = size_of::<c::sockaddr_storage>() as _;
+        msg.msg_iov = bufs.as_mut_ptr().cast();
+        msg.msg_iovlen = bufs.len() as _;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = control.len() as _;
+
+        let nread = ret_send_recv(c::recvmsg(borrowed_fd(fd), &mut msg, flags.bits()))?;
+        Ok(RecvMsgReturn {
+            bytes: nread as usize,
+            control_len: msg.msg_controllen as usize,
+            flags: RecvFlags::from_bits_truncate(msg.msg_flags),
+            address: maybe_read_sockaddr_os(storage.as_ptr(), msg.msg_namelen as usize),
+        })
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendmsg(
+    fd: BorrowedFd<'_>,
+    addr: Option<&SocketAddrAny>,
+    bufs: &[IoSlice<'_>],
+    control: &[u8],
+    flags: SendFlags,
+) -> io::Result<usize> {
+    unsafe {
+        let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
+
+        // `msghdr` may have private padding fields, so start with zeros.
+        let mut msg: c::msghdr = core::mem::zeroed();
+        if let Some(addr) = addr {
+            msg.msg_name = storage.as_mut_ptr().cast();
+            msg.msg_namelen = addr.write(storage.as_mut_ptr()) as _;
+        }
+        msg.msg_iov = bufs.as_ptr() as *mut _;
+        msg.msg_iovlen = bufs.len() as _;
+        msg.msg_control = control.as_ptr() as *mut _;
+        msg.msg_controllen = control.len() as _;
+
+        let nwritten = ret_send_recv(c::sendmsg(borrowed_fd(fd), &msg, flags.bits()))?;
+        Ok(nwritten as usize)
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn sendto_v4(
     fd: BorrowedFd<'_>,
@@ -94,7 +160,7 @@ pub(crate) fn sendto_v4(
             send_recv_len(buf.len()),
             flags.bits(),
             as_ptr(&encode_sockaddr_v4(addr)).cast::<c::sockaddr>(),
-            size_of::<SocketAddrV4>() as _,
+            size_of::<c::sockaddr_in>() as _,
         ))?
     };
     Ok(nwritten as usize)
@@ -114,7 +180,7 @@ pub(crate) fn sendto_v6(
             send_recv_len(buf.len()),
             flags.bits(),
             as_ptr(&encode_sockaddr_v6(addr)).cast::<c::sockaddr>(),
-            size_of::<SocketAddrV6>() as _,
+            size_of::<c::sockaddr_in6>() as _,
         ))?
     };
     Ok(nwritten as usize)
@@ -140,6 +206,66 @@ pub(crate) fn sendto_unix(
     Ok(nwritten as usize)
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendto_link(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrLink,
+) -> io::Result<usize> {
+    let nwritten = unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            flags.bits(),
+            as_ptr(&encode_sockaddr_ll(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_ll>() as _,
+        ))?
+    };
+    Ok(nwritten as usize)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendto_netlink(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    let nwritten = unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            flags.bits(),
+            as_ptr(&encode_sockaddr_nl(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_nl>() as _,
+        ))?
+    };
+    Ok(nwritten as usize)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn sendto_vsock(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrVsock,
+) -> io::Result<usize> {
+    let nwritten = unsafe {
+        ret_send_recv(c::sendto(
+            borrowed_fd(fd),
+            buf.as_ptr().cast(),
+            send_recv_len(buf.len()),
+            flags.bits(),
+            as_ptr(&encode_sockaddr_vm(addr)).cast::<c::sockaddr>(),
+            size_of::<c::sockaddr_vm>() as _,
+        ))?
+    };
+    Ok(nwritten as usize)
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn socket(
     domain: AddressFamily,
@@ -204,6 +330,39 @@ pub(crate) fn bind_unix(sockfd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Re
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn bind_link(sockfd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_ll(addr)).cast(),
+            size_of::<c::sockaddr_ll>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn bind_netlink(sockfd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_nl(addr)).cast(),
+            size_of::<c::sockaddr_nl>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn bind_vsock(sockfd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    unsafe {
+        ret(c::bind(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_vm(addr)).cast(),
+            size_of::<c::sockaddr_vm>() as c::socklen_t,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn connect_v4(sockfd: BorrowedFd<'_>, addr: &SocketAddrV4) -> io::Result<()> {
     unsafe {
@@ -237,6 +396,39 @@ pub(crate) fn connect_unix(sockfd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io:
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn connect_link(sockfd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_ll(addr)).cast(),
+            size_of::<c::sockaddr_ll>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn connect_netlink(sockfd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_nl(addr)).cast(),
+            size_of::<c::sockaddr_nl>() as c::socklen_t,
+        ))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) fn connect_vsock(sockfd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    unsafe {
+        ret(c::connect(
+            borrowed_fd(sockfd),
+            as_ptr(&encode_sockaddr_vm(addr)).cast(),
+            size_of::<c::sockaddr_vm>() as c::socklen_t,
+        ))
+    }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn listen(sockfd: BorrowedFd<'_>, backlog: c::c_int) -> io::Result<()> {
     unsafe { ret(c::listen(borrowed_fd(sockfd), backlog)) }
@@ -337,6 +529,16 @@ pub(crate) fn shutdown(sockfd: BorrowedFd<'_>, how: Shutdown) -> io::Result<()>
     unsafe { ret(c::shutdown(borrowed_fd(sockfd), how as c::c_int)) }
 }
 
+#[cfg(not(any(windows, target_os = "redox", target_os = "wasi")))]
+pub(crate) fn sockatmark(sockfd: BorrowedFd<'_>) -> io::Result<bool> {
+    // The libc crate doesn't declare `sockatmark`.
+    extern "C" {
+        fn sockatmark(sockfd: c::c_int) -> c::c_int;
+    }
+
+    unsafe { ret_c_int(sockatmark(borrowed_fd(sockfd))).map(|r| r != 0) }
+}
+
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) fn getsockname(sockfd: BorrowedFd<'_>) -> io::Result<SocketAddrAny> {
     unsafe {
@@ -392,8 +594,12 @@ pub(crate) fn socketpair(
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 pub(crate) mod sockopt {
     use super::{c, in6_addr_new, in_addr_new, BorrowedFd};
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    use crate::ffi::CStr;
     use crate::io;
     use crate::net::sockopt::Timeout;
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    use crate::net::sockopt::{IpMtuDiscover, TimestampingFlags};
     use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
     use crate::utils::as_mut_ptr;
     use core::convert::TryInto;
@@ -462,11 +668,100 @@ pub(crate) mod sockopt {
         }
     }
 
+    /// Like `getsockopt`, but for options with variable-length values. Returns
+    /// the length of the value.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    fn getsockopt_bytes(
+        fd: BorrowedFd<'_>,
+        level: i32,
+        optname: i32,
+        value: &mut [u8],
+    ) -> io::Result<usize> {
+        unsafe { getsockopt_raw(fd, level, optname, value.as_mut_ptr(), value.len()) }
+    }
+
+    /// Like `setsockopt`, but for options with variable-length values.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    fn setsockopt_bytes(
+        fd: BorrowedFd<'_>,
+        level: i32,
+        optname: i32,
+        value: &[u8],
+    ) -> io::Result<()> {
+        unsafe { setsockopt_raw(fd, level, optname, value.as_ptr(), value.len()) }
+    }
+
+    /// `getsockopt` into a buffer of `len` bytes at `value`. Returns the
+    /// length of the value.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be valid for writes of `len` bytes, and the option must
+    /// not have side effects which violate Rust's invariants.
+    #[inline]
+    pub(crate) unsafe fn getsockopt_raw(
+        fd: BorrowedFd<'_>,
+        level: i32,
+        optname: i32,
+        value: *mut u8,
+        len: usize,
+    ) -> io::Result<usize> {
+        use super::*;
+
+        let mut optlen = len.try_into().map_err(|_| io::Errno::INVAL)?;
+        ret(c::getsockopt(
+            borrowed_fd(fd),
+            level,
+            optname,
+            value.cast(),
+            &mut optlen,
+        ))?;
+        Ok(optlen as usize)
+    }
+
+    /// `setsockopt` from a buffer of `len` bytes at `value`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be valid for reads of `len` bytes, and the option must
+    /// not have side effects which violate Rust's invariants.
+    #[inline]
+    pub(crate) unsafe fn setsockopt_raw(
+        fd: BorrowedFd<'_>,
+        level: i32,
+        optname: i32,
+        value: *const u8,
+        len: usize,
+    ) -> io::Result<()> {
+        use super::*;
+
+        let optlen = len.try_into().map_err(|_| io::Errno::INVAL)?;
+        ret(c::setsockopt(
+            borrowed_fd(fd),
+            level,
+            optname,
+            value.cast(),
+            optlen,
+        ))
+    }
+
     #[inline]
     pub(crate) fn get_socket_type(fd: BorrowedFd<'_>) -> io::Result<SocketType> {
         getsockopt(fd, c::SOL_SOCKET as _, c::SO_TYPE)
     }
 
+    #[inline]
+    pub(crate) fn get_socket_error(fd: BorrowedFd<'_>) -> io::Result<Result<(), io::Errno>> {
+        let err: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_ERROR)?;
+        Ok(if err == 0 {
+            Ok(())
+        } else {
+            Err(io::Errno::from_raw_os_error(err))
+        })
+    }
+
     #[inline]
     pub(crate) fn set_socket_reuseaddr(fd: BorrowedFd<'_>, reuseaddr: bool) -> io::Result<()> {
         setsockopt(
@@ -477,6 +772,80 @@ pub(crate) mod sockopt {
         )
     }
 
+    #[cfg(not(any(windows, target_os = "illumos", target_os = "solaris")))]
+    #[inline]
+    pub(crate) fn set_socket_reuseport(fd: BorrowedFd<'_>, reuseport: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            c::SO_REUSEPORT,
+            from_bool(reuseport),
+        )
+    }
+
+    #[cfg(not(any(windows, target_os = "illumos", target_os = "solaris")))]
+    #[inline]
+    pub(crate) fn get_socket_reuseport(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_REUSEPORT).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_attach_reuseport_ebpf(
+        fd: BorrowedFd<'_>,
+        prog_fd: BorrowedFd<'_>,
+    ) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            linux_raw_sys::general::SO_ATTACH_REUSEPORT_EBPF as _,
+            super::borrowed_fd(prog_fd),
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_bindtodevice(
+        fd: BorrowedFd<'_>,
+        interface: Option<&CStr>,
+    ) -> io::Result<()> {
+        // An empty name removes the binding.
+        let name = interface.map_or(&[][..], CStr::to_bytes);
+        setsockopt_bytes(fd, c::SOL_SOCKET as _, c::SO_BINDTODEVICE, name)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_bindtodevice(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
+        getsockopt_bytes(fd, c::SOL_SOCKET as _, c::SO_BINDTODEVICE, buf)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_timestamp_ns(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPNS, from_bool(value))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_timestamping(
+        fd: BorrowedFd<'_>,
+        flags: TimestampingFlags,
+    ) -> io::Result<()> {
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING, flags.bits())
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_zerocopy(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            linux_raw_sys::general::SO_ZEROCOPY as _,
+            from_bool(value),
+        )
+    }
+
     #[inline]
     pub(crate) fn set_socket_broadcast(fd: BorrowedFd<'_>, broadcast: bool) -> io::Result<()> {
         setsockopt(
@@ -525,6 +894,50 @@ pub(crate) mod sockopt {
         })
     }
 
+    #[inline]
+    pub(crate) fn set_socket_recv_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUF, size)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_recv_buffer_size_force(
+        fd: BorrowedFd<'_>,
+        size: usize,
+    ) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUFFORCE, size)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_recv_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+        let size: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUF)?;
+        Ok(size as usize)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_send_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUF, size)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_send_buffer_size_force(
+        fd: BorrowedFd<'_>,
+        size: usize,
+    ) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUFFORCE, size)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_send_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+        let size: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUF)?;
+        Ok(size as usize)
+    }
+
     #[cfg(any(target_os = "android", target_os = "linux"))]
     #[inline]
     pub(crate) fn set_socket_passcred(fd: BorrowedFd<'_>, passcred: bool) -> io::Result<()> {
@@ -537,6 +950,20 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::SOL_SOCKET as _, c::SO_PASSCRED).map(to_bool)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_socket_priority(fd: BorrowedFd<'_>, priority: u32) -> io::Result<()> {
+        let priority: c::c_int = priority.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_PRIORITY, priority)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_socket_priority(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        let priority: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_PRIORITY)?;
+        Ok(priority as u32)
+    }
+
     #[inline]
     pub(crate) fn set_socket_timeout(
         fd: BorrowedFd<'_>,
@@ -555,23 +982,26 @@ pub(crate) mod sockopt {
                     return Err(io::Errno::INVAL);
                 }
 
+                // `subsec_micros` rounds down, so we use `subsec_nanos` and
+                // manually round up, carrying into the seconds if needed.
+                let mut secs = timeout.as_secs();
+                let mut micros = (timeout.subsec_nanos() + 999) / 1000;
+                if micros == 1_000_000 {
+                    secs = secs.saturating_add(1);
+                    micros = 0;
+                }
+
                 // Rust's musl libc bindings deprecated `time_t` while they
                 // transition to 64-bit `time_t`. What we want here is just
                 // "whatever type `timeval`'s `tv_sec` is", so we're ok using
                 // the deprecated type.
                 #[allow(deprecated)]
-                let tv_sec = timeout.as_secs().try_into().unwrap_or(c::time_t::MAX);
+                let tv_sec = secs.try_into().unwrap_or(c::time_t::MAX);
 
-                // `subsec_micros` rounds down, so we use `subsec_nanos` and
-                // manually round up.
-                let mut timeout = c::timeval {
+                c::timeval {
                     tv_sec,
-                    tv_usec: ((timeout.subsec_nanos() + 999) / 1000) as _,
-                };
-                if timeout.tv_sec == 0 && timeout.tv_usec == 0 {
-                    timeout.tv_usec = 1;
+                    tv_usec: micros as _,
                 }
-                timeout
             }
             None => c::timeval {
                 tv_sec: 0,
@@ -670,6 +1100,19 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IP as _, c::IP_TTL)
     }
 
+    #[cfg(not(windows))]
+    #[inline]
+    pub(crate) fn set_ip_tos(fd: BorrowedFd<'_>, tos: u8) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_TOS, c::c_int::from(tos))
+    }
+
+    #[cfg(not(windows))]
+    #[inline]
+    pub(crate) fn get_ip_tos(fd: BorrowedFd<'_>) -> io::Result<u8> {
+        let tos: c::c_int = getsockopt(fd, c::IPPROTO_IP as _, c::IP_TOS)?;
+        Ok(tos as u8)
+    }
+
     #[inline]
     pub(crate) fn set_ipv6_v6only(fd: BorrowedFd<'_>, only_v6: bool) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY, from_bool(only_v6))
@@ -680,6 +1123,44 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY).map(to_bool)
     }
 
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "illumos",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+    ))]
+    #[inline]
+    pub(crate) fn set_ipv6_tclass(fd: BorrowedFd<'_>, tclass: u32) -> io::Result<()> {
+        let tclass: c::c_int = tclass.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_TCLASS, tclass)
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "illumos",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+        target_os = "solaris",
+    ))]
+    #[inline]
+    pub(crate) fn get_ipv6_tclass(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        let tclass: c::c_int = getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_TCLASS)?;
+        Ok(tclass as u32)
+    }
+
     #[inline]
     pub(crate) fn set_ip_multicast_loop(
         fd: BorrowedFd<'_>,
@@ -838,6 +1319,72 @@ pub(crate) mod sockopt {
         setsockopt(fd, c::IPPROTO_IPV6 as _, IPV6_DROP_MEMBERSHIP, mreq)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ip_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_MTU)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ip_mtu_discover(fd: BorrowedFd<'_>, value: IpMtuDiscover) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IP as _,
+            c::IP_MTU_DISCOVER,
+            value as c::c_int,
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ipv6_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_MTU)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ipv6_mtu_discover(
+        fd: BorrowedFd<'_>,
+        value: IpMtuDiscover,
+    ) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            c::IPV6_MTU_DISCOVER,
+            value as c::c_int,
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ip_pktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_PKTINFO, from_bool(value))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ip_pktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, c::IP_PKTINFO).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_ipv6_recvpktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            c::IPV6_RECVPKTINFO,
+            from_bool(value),
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_ipv6_recvpktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_RECVPKTINFO).map(to_bool)
+    }
+
     #[inline]
     pub(crate) fn set_tcp_nodelay(fd: BorrowedFd<'_>, nodelay: bool) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_NODELAY, from_bool(nodelay))
@@ -848,6 +1395,143 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_NODELAY).map(to_bool)
     }
 
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_tcp_cork(fd: BorrowedFd<'_>, cork: bool) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_CORK, from_bool(cork))
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_tcp_cork(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_CORK).map(to_bool)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn set_tcp_quickack(fd: BorrowedFd<'_>, quickack: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            c::TCP_QUICKACK,
+            from_bool(quickack),
+        )
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[inline]
+    pub(crate) fn get_tcp_quickack(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_QUICKACK).map(to_bool)
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+    ))]
+    #[inline]
+    pub(crate) fn set_tcp_keepidle(fd: BorrowedFd<'_>, duration: Duration) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            c::TCP_KEEPIDLE,
+            duration_to_secs(duration)?,
+        )
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+    ))]
+    #[inline]
+    pub(crate) fn get_tcp_keepidle(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let secs: c::c_uint = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPIDLE)?;
+        Ok(Duration::from_secs(secs.into()))
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+    ))]
+    #[inline]
+    pub(crate) fn set_tcp_keepintvl(fd: BorrowedFd<'_>, duration: Duration) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            c::TCP_KEEPINTVL,
+            duration_to_secs(duration)?,
+        )
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+    ))]
+    #[inline]
+    pub(crate) fn get_tcp_keepintvl(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let secs: c::c_uint = getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPINTVL)?;
+        Ok(Duration::from_secs(secs.into()))
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+    ))]
+    #[inline]
+    pub(crate) fn set_tcp_keepcnt(fd: BorrowedFd<'_>, count: u32) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPCNT, count)
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+    ))]
+    #[inline]
+    pub(crate) fn get_tcp_keepcnt(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_KEEPCNT)
+    }
+
+    /// Convert `duration` to a number of seconds, rounding up.
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+    ))]
+    #[inline]
+    fn duration_to_secs(duration: Duration) -> io::Result<c::c_uint> {
+        let mut secs = duration.as_secs();
+        if duration.subsec_nanos() != 0 {
+            secs = secs.checked_add(1).ok_or(io::Errno::INVAL)?;
+        }
+        secs.try_into().map_err(|_e| io::Errno::INVAL)
+    }
+
     #[inline]
     fn to_imr(multiaddr: &Ipv4Addr, interface: &Ipv4Addr) -> c::ip_mreq {
         c::ip_mreq {
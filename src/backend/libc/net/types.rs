@@ -423,6 +423,9 @@ impl AddressFamily {
         target_os = "solaris",
     )))]
     pub const IEEE802154: Self = Self(c::AF_IEEE802154 as _);
+    /// `AF_VSOCK`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    pub const VSOCK: Self = Self(super::ext::AF_VSOCK as _);
 
     /// Constructs a `AddressFamily` from a raw integer.
     #[inline]
@@ -626,6 +629,60 @@ impl Protocol {
     }
 }
 
+/// `NETLINK_*` constants for use with [`socket`] with
+/// [`AddressFamily::NETLINK`].
+///
+/// [`socket`]: crate::net::socket
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct NetlinkFamily(pub(crate) RawProtocol);
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[rustfmt::skip]
+impl NetlinkFamily {
+    /// `NETLINK_ROUTE`
+    pub const ROUTE: Self = Self(c::NETLINK_ROUTE as _);
+    /// `NETLINK_USERSOCK`
+    pub const USERSOCK: Self = Self(c::NETLINK_USERSOCK as _);
+    /// `NETLINK_SOCK_DIAG`
+    pub const SOCK_DIAG: Self = Self(c::NETLINK_SOCK_DIAG as _);
+    /// `NETLINK_XFRM`
+    pub const XFRM: Self = Self(c::NETLINK_XFRM as _);
+    /// `NETLINK_AUDIT`
+    pub const AUDIT: Self = Self(c::NETLINK_AUDIT as _);
+    /// `NETLINK_CONNECTOR`
+    pub const CONNECTOR: Self = Self(c::NETLINK_CONNECTOR as _);
+    /// `NETLINK_NETFILTER`
+    pub const NETFILTER: Self = Self(c::NETLINK_NETFILTER as _);
+    /// `NETLINK_KOBJECT_UEVENT`
+    pub const KOBJECT_UEVENT: Self = Self(c::NETLINK_KOBJECT_UEVENT as _);
+    /// `NETLINK_GENERIC`
+    pub const GENERIC: Self = Self(c::NETLINK_GENERIC as _);
+    /// `NETLINK_CRYPTO`
+    pub const CRYPTO: Self = Self(c::NETLINK_CRYPTO as _);
+
+    /// Constructs a `NetlinkFamily` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: RawProtocol) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `NetlinkFamily`.
+    #[inline]
+    pub const fn as_raw(self) -> RawProtocol {
+        self.0
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl From<NetlinkFamily> for Protocol {
+    #[inline]
+    fn from(family: NetlinkFamily) -> Self {
+        Self(family.0)
+    }
+}
+
 /// `SHUT_*` constants for use with [`shutdown`].
 ///
 /// [`shutdown`]: crate::net::shutdown
@@ -657,9 +714,11 @@ bitflags! {
 }
 
 bitflags! {
-    /// `SOCK_*` constants for use with [`socket`].
+    /// `SOCK_*` constants for use with [`socket_with`] and
+    /// [`socketpair_with`].
     ///
-    /// [`socket`]: crate::net::socket
+    /// [`socket_with`]: crate::net::socket_with
+    /// [`socketpair_with`]: crate::net::socketpair_with
     pub struct SocketFlags: c::c_int {
         /// `SOCK_NONBLOCK`
         #[cfg(not(any(windows, target_os = "haiku", target_os = "ios", target_os = "macos")))]
@@ -685,3 +744,65 @@ pub enum Timeout {
     /// `SO_SNDTIMEO`—Timeout for sending.
     Send = c::SO_SNDTIMEO,
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `SOF_TIMESTAMPING_*` constants for use with [`set_socket_timestamping`].
+    ///
+    /// [`set_socket_timestamping`]: crate::net::sockopt::set_socket_timestamping
+    pub struct TimestampingFlags: c::c_uint {
+        /// `SOF_TIMESTAMPING_TX_HARDWARE`
+        const TX_HARDWARE = c::SOF_TIMESTAMPING_TX_HARDWARE;
+        /// `SOF_TIMESTAMPING_TX_SOFTWARE`
+        const TX_SOFTWARE = c::SOF_TIMESTAMPING_TX_SOFTWARE;
+        /// `SOF_TIMESTAMPING_RX_HARDWARE`
+        const RX_HARDWARE = c::SOF_TIMESTAMPING_RX_HARDWARE;
+        /// `SOF_TIMESTAMPING_RX_SOFTWARE`
+        const RX_SOFTWARE = c::SOF_TIMESTAMPING_RX_SOFTWARE;
+        /// `SOF_TIMESTAMPING_SOFTWARE`
+        const SOFTWARE = c::SOF_TIMESTAMPING_SOFTWARE;
+        /// `SOF_TIMESTAMPING_SYS_HARDWARE`
+        const SYS_HARDWARE = c::SOF_TIMESTAMPING_SYS_HARDWARE;
+        /// `SOF_TIMESTAMPING_RAW_HARDWARE`
+        const RAW_HARDWARE = c::SOF_TIMESTAMPING_RAW_HARDWARE;
+        /// `SOF_TIMESTAMPING_OPT_ID`
+        const OPT_ID = c::SOF_TIMESTAMPING_OPT_ID;
+        /// `SOF_TIMESTAMPING_TX_SCHED`
+        const TX_SCHED = c::SOF_TIMESTAMPING_TX_SCHED;
+        /// `SOF_TIMESTAMPING_TX_ACK`
+        const TX_ACK = c::SOF_TIMESTAMPING_TX_ACK;
+        /// `SOF_TIMESTAMPING_OPT_CMSG`
+        const OPT_CMSG = c::SOF_TIMESTAMPING_OPT_CMSG;
+        /// `SOF_TIMESTAMPING_OPT_TSONLY`
+        const OPT_TSONLY = c::SOF_TIMESTAMPING_OPT_TSONLY;
+        /// `SOF_TIMESTAMPING_OPT_STATS`
+        const OPT_STATS = c::SOF_TIMESTAMPING_OPT_STATS;
+        /// `SOF_TIMESTAMPING_OPT_PKTINFO`
+        const OPT_PKTINFO = c::SOF_TIMESTAMPING_OPT_PKTINFO;
+        /// `SOF_TIMESTAMPING_OPT_TX_SWHW`
+        const OPT_TX_SWHW = c::SOF_TIMESTAMPING_OPT_TX_SWHW;
+    }
+}
+
+/// `IP_PMTUDISC_*` constants for use with [`set_ip_mtu_discover`] and
+/// [`set_ipv6_mtu_discover`].
+///
+/// [`set_ip_mtu_discover`]: crate::net::sockopt::set_ip_mtu_discover
+/// [`set_ipv6_mtu_discover`]: crate::net::sockopt::set_ipv6_mtu_discover
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum IpMtuDiscover {
+    /// `IP_PMTUDISC_DONT`—Never send packets with the "Don't Fragment" flag.
+    Dont = c::IP_PMTUDISC_DONT,
+
+    /// `IP_PMTUDISC_WANT`—Use per-route path MTU discovery hints.
+    Want = c::IP_PMTUDISC_WANT,
+
+    /// `IP_PMTUDISC_DO`—Always send packets with the "Don't Fragment" flag.
+    Do = c::IP_PMTUDISC_DO,
+
+    /// `IP_PMTUDISC_PROBE`—Set the "Don't Fragment" flag, but ignore the
+    /// path MTU.
+    Probe = c::IP_PMTUDISC_PROBE,
+}
@@ -7,6 +7,8 @@ use super::addr::SocketAddrStorage;
 use super::addr::SocketAddrUnix;
 use super::ext::{in6_addr_new, in_addr_new, sockaddr_in6_new};
 use crate::net::{SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink, SocketAddrVsock};
 use core::mem::size_of;
 
 pub(crate) unsafe fn write_sockaddr(
@@ -18,6 +20,12 @@ pub(crate) unsafe fn write_sockaddr(
         SocketAddrAny::V6(v6) => write_sockaddr_v6(v6, storage),
         #[cfg(unix)]
         SocketAddrAny::Unix(unix) => write_sockaddr_unix(unix, storage),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Link(link) => write_sockaddr_ll(link, storage),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Netlink(netlink) => write_sockaddr_nl(netlink, storage),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Vsock(vsock) => write_sockaddr_vm(vsock, storage),
     }
 }
 
@@ -100,3 +108,59 @@ unsafe fn write_sockaddr_unix(unix: &SocketAddrUnix, storage: *mut SocketAddrSto
     core::ptr::write(storage.cast(), unix.unix);
     unix.len()
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn encode_sockaddr_ll(link: &SocketAddrLink) -> c::sockaddr_ll {
+    let mut sll_addr = [0_u8; 8];
+    sll_addr[..link.addr().len()].copy_from_slice(link.addr());
+    c::sockaddr_ll {
+        sll_family: c::AF_PACKET as _,
+        sll_protocol: u16::to_be(link.protocol()),
+        sll_ifindex: link.ifindex() as _,
+        sll_hatype: link.hatype(),
+        sll_pkttype: link.pkttype(),
+        sll_halen: link.addr().len() as _,
+        sll_addr,
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+unsafe fn write_sockaddr_ll(link: &SocketAddrLink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_ll(link);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_ll>()
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn encode_sockaddr_nl(netlink: &SocketAddrNetlink) -> c::sockaddr_nl {
+    // `sockaddr_nl` has a private padding field, so start with zeros.
+    let mut encoded: c::sockaddr_nl = core::mem::zeroed();
+    encoded.nl_family = c::AF_NETLINK as _;
+    encoded.nl_pid = netlink.pid();
+    encoded.nl_groups = netlink.groups();
+    encoded
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+unsafe fn write_sockaddr_nl(netlink: &SocketAddrNetlink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_nl(netlink);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_nl>()
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) unsafe fn encode_sockaddr_vm(vsock: &SocketAddrVsock) -> c::sockaddr_vm {
+    // `sockaddr_vm` may have private padding fields, so start with zeros.
+    let mut encoded: c::sockaddr_vm = core::mem::zeroed();
+    encoded.svm_family = super::ext::AF_VSOCK as _;
+    encoded.svm_port = vsock.port();
+    encoded.svm_cid = vsock.cid();
+    encoded
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+unsafe fn write_sockaddr_vm(vsock: &SocketAddrVsock, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_vm(vsock);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_vm>()
+}
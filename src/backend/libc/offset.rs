@@ -28,6 +28,26 @@ pub(super) use c::{
     lseek64 as libc_lseek, off64_t as libc_off_t,
 };
 
+#[cfg(not(any(
+    windows,
+    target_os = "android",
+    target_os = "emscripten",
+    target_os = "l4re",
+    target_os = "linux",
+    target_os = "wasi",
+)))]
+#[cfg(feature = "fs")]
+pub(super) use c::truncate as libc_truncate;
+
+#[cfg(any(
+    target_os = "android",
+    target_os = "emscripten",
+    target_os = "l4re",
+    target_os = "linux",
+))]
+#[cfg(feature = "fs")]
+pub(super) use c::truncate64 as libc_truncate;
+
 #[cfg(any(
     target_os = "android",
     target_os = "emscripten",
@@ -4,6 +4,11 @@ use super::super::c;
     target_os = "linux",
 ))]
 use crate::ffi::CStr;
+#[cfg(any(
+    all(target_os = "android", target_pointer_width = "64"),
+    target_os = "linux",
+))]
+use crate::param::AuxvKey;
 
 // `getauxval` wasn't supported in glibc until 2.16.
 #[cfg(any(
@@ -52,3 +57,18 @@ pub(crate) fn linux_execfn() -> &'static CStr {
         cstr!("")
     }
 }
+
+#[cfg(any(
+    all(target_os = "android", target_pointer_width = "64"),
+    target_os = "linux",
+))]
+#[inline]
+pub(crate) fn auxv(key: AuxvKey) -> Option<usize> {
+    let libc_getauxval = getauxval.get()?;
+    let value = unsafe { libc_getauxval(key as _) } as usize;
+    if value == 0 && key != AuxvKey::Secure {
+        None
+    } else {
+        Some(value)
+    }
+}
@@ -0,0 +1,36 @@
+use super::super::c;
+use super::types::RawSigSet;
+use crate::process::Signal;
+
+#[inline]
+pub(crate) fn sigemptyset(set: &mut RawSigSet) {
+    unsafe {
+        c::sigemptyset(set);
+    }
+}
+
+#[inline]
+pub(crate) fn sigfillset(set: &mut RawSigSet) {
+    unsafe {
+        c::sigfillset(set);
+    }
+}
+
+#[inline]
+pub(crate) fn sigaddset(set: &mut RawSigSet, sig: Signal) {
+    unsafe {
+        c::sigaddset(set, sig as c::c_int);
+    }
+}
+
+#[inline]
+pub(crate) fn sigdelset(set: &mut RawSigSet, sig: Signal) {
+    unsafe {
+        c::sigdelset(set, sig as c::c_int);
+    }
+}
+
+#[inline]
+pub(crate) fn sigismember(set: &RawSigSet, sig: Signal) -> bool {
+    unsafe { c::sigismember(set, sig as c::c_int) == 1 }
+}
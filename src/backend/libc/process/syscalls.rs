@@ -27,9 +27,36 @@ use {
     crate::process::{Resource, Rlimit},
     core::convert::TryInto,
 };
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use {
+    super::super::conv::syscall_ret_owned_fd,
+    crate::fd::AsRawFd,
+    crate::process::{
+        perf_event_attr, seccomp_notif_sizes, PerfEventFlags, PerfIocFlags, PidfdFlags,
+        ProcessMreleaseFlags, SeccompFilterFlags, SockFprog,
+    },
+    core::ptr::null,
+    linux_raw_sys::general::{
+        __NR_kcmp, __NR_perf_event_open, __NR_pidfd_open, __NR_process_mrelease, __NR_ptrace,
+        __NR_seccomp,
+    },
+};
+
+/// `SECCOMP_SET_MODE_*`
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SECCOMP_SET_MODE_STRICT: c::c_uint = 0;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SECCOMP_SET_MODE_FILTER: c::c_uint = 1;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+const SECCOMP_GET_NOTIF_SIZES: c::c_uint = 3;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use {
     super::super::offset::libc_prlimit,
+    crate::fd::{FromRawFd, OwnedFd},
     crate::process::{Cpuid, MembarrierCommand, MembarrierQuery},
 };
 #[cfg(not(target_os = "wasi"))]
@@ -157,6 +184,12 @@ pub(crate) fn getpgid(pid: Option<Pid>) -> io::Result<Pid> {
     }
 }
 
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    unsafe { ret(c::setpgid(Pid::as_raw(pid) as _, Pid::as_raw(pgid) as _)) }
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 #[must_use]
@@ -408,6 +441,240 @@ pub(crate) fn exit_group(code: c::c_int) -> ! {
     }
 }
 
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) unsafe fn fork() -> io::Result<Option<Pid>> {
+    let pid = ret_pid_t(c::fork())?;
+    Ok(Pid::from_raw(pid))
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn fork_pidfd() -> io::Result<Option<(Pid, OwnedFd)>> {
+    // libc doesn't have a `clone` wrapper that returns twice, so use
+    // `syscall`. With `CLONE_PIDFD`, the kernel stores the pidfd through the
+    // `parent_tid` pointer.
+    let mut pidfd: c::c_int = -1;
+    #[cfg(not(target_arch = "s390x"))]
+    let pid = syscall_ret_u32(c::syscall(
+        c::SYS_clone,
+        c::CLONE_PIDFD | c::SIGCHLD,
+        0,
+        &mut pidfd,
+        0,
+        0,
+    ))?;
+    // On s390x, the stack and flags arguments are swapped.
+    #[cfg(target_arch = "s390x")]
+    let pid = syscall_ret_u32(c::syscall(
+        c::SYS_clone,
+        0,
+        c::CLONE_PIDFD | c::SIGCHLD,
+        &mut pidfd,
+        0,
+        0,
+    ))?;
+    Ok(Pid::from_raw(pid as RawPid).map(|pid| (pid, OwnedFd::from_raw_fd(pidfd))))
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            __NR_pidfd_open as _,
+            Pid::as_raw(Some(pid)) as c::pid_t,
+            flags.bits() as c::c_uint,
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn process_mrelease(
+    pidfd: BorrowedFd<'_>,
+    flags: ProcessMreleaseFlags,
+) -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            __NR_process_mrelease as _,
+            borrowed_fd(pidfd),
+            flags.bits() as c::c_uint,
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn seccomp_set_mode_strict() -> io::Result<()> {
+    unsafe {
+        syscall_ret(c::syscall(
+            __NR_seccomp as _,
+            SECCOMP_SET_MODE_STRICT,
+            0 as c::c_uint,
+            null::<c::c_void>(),
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn seccomp_set_mode_filter(
+    prog: &SockFprog<'_>,
+    flags: SeccompFilterFlags,
+) -> io::Result<u32> {
+    unsafe {
+        syscall_ret_u32(c::syscall(
+            __NR_seccomp as _,
+            SECCOMP_SET_MODE_FILTER,
+            flags.bits(),
+            prog as *const SockFprog<'_>,
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn seccomp_set_mode_filter_listener(
+    prog: &SockFprog<'_>,
+    flags: SeccompFilterFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            __NR_seccomp as _,
+            SECCOMP_SET_MODE_FILTER,
+            flags.bits(),
+            prog as *const SockFprog<'_>,
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn seccomp_get_notif_sizes() -> io::Result<seccomp_notif_sizes> {
+    let mut sizes = seccomp_notif_sizes::default();
+    unsafe {
+        syscall_ret(c::syscall(
+            __NR_seccomp as _,
+            SECCOMP_GET_NOTIF_SIZES,
+            0 as c::c_uint,
+            &mut sizes as *mut seccomp_notif_sizes,
+        ))?;
+    }
+    Ok(sizes)
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn seccomp_notif_ioctl(
+    listener_fd: BorrowedFd<'_>,
+    request: u32,
+    arg: *mut c::c_void,
+) -> io::Result<()> {
+    ret(c::ioctl(borrowed_fd(listener_fd), request as _, arg))
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn ptrace(
+    request: c::c_uint,
+    pid: Option<Pid>,
+    addr: usize,
+    data: usize,
+) -> io::Result<()> {
+    // Use the raw syscall rather than `c::ptrace`, which returns the result
+    // of `PTRACE_PEEK*` in-band and takes its arguments variadically.
+    syscall_ret(c::syscall(
+        __NR_ptrace as _,
+        request as c::c_long,
+        Pid::as_raw(pid) as c::c_long,
+        addr,
+        data,
+    ))
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn kcmp(
+    pid1: Pid,
+    pid2: Pid,
+    type_: c::c_uint,
+    idx1: usize,
+    idx2: usize,
+) -> io::Result<c::c_uint> {
+    syscall_ret_u32(c::syscall(
+        __NR_kcmp as _,
+        Pid::as_raw(Some(pid1)) as c::pid_t,
+        Pid::as_raw(Some(pid2)) as c::pid_t,
+        type_,
+        idx1 as c::c_ulong,
+        idx2 as c::c_ulong,
+    ))
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn perf_event_open(
+    attr: &perf_event_attr,
+    pid: c::c_int,
+    cpu: c::c_int,
+    group_fd: Option<BorrowedFd<'_>>,
+    flags: PerfEventFlags,
+) -> io::Result<OwnedFd> {
+    // The kernel writes the size it supports back to `attr` on `E2BIG`.
+    let mut attr = *attr;
+    let group_fd = group_fd.map_or(-1, |fd| fd.as_raw_fd());
+    unsafe {
+        syscall_ret_owned_fd(c::syscall(
+            __NR_perf_event_open as _,
+            &mut attr as *mut perf_event_attr,
+            pid,
+            cpu,
+            group_fd,
+            c::c_ulong::from(flags.bits()),
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn perf_event_ioctl(
+    fd: BorrowedFd<'_>,
+    request: u32,
+    flags: PerfIocFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(c::ioctl(
+            borrowed_fd(fd),
+            request as _,
+            c::c_ulong::from(flags.bits()),
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) unsafe fn execve(
+    path: &CStr,
+    args: *const *const u8,
+    env_vars: *const *const u8,
+) -> io::Errno {
+    let _ = c::execve(c_str(path), args.cast(), env_vars.cast());
+    io::Errno::last_os_error()
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 pub(crate) fn setsid() -> io::Result<Pid> {
@@ -418,12 +685,28 @@ pub(crate) fn setsid() -> io::Result<Pid> {
     }
 }
 
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn getsid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let sid = ret_pid_t(c::getsid(Pid::as_raw(pid) as _))?;
+        debug_assert_ne!(sid, 0);
+        Ok(Pid::from_raw_nonzero(RawNonZeroPid::new_unchecked(sid)))
+    }
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 pub(crate) fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
     unsafe { ret(c::kill(pid.as_raw_nonzero().get(), sig as i32)) }
 }
 
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn test_kill_process(pid: Pid) -> io::Result<()> {
+    unsafe { ret(c::kill(pid.as_raw_nonzero().get(), 0)) }
+}
+
 #[cfg(not(target_os = "wasi"))]
 #[inline]
 pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
@@ -463,3 +746,33 @@ pub(crate) unsafe fn procctl(
 ) -> io::Result<()> {
     ret(c::procctl(idtype, id, option, data))
 }
+
+/// Returns the resident size, peak resident size, and virtual size of the
+/// current task, in bytes.
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+pub(crate) fn task_basic_info() -> io::Result<(u64, u64, u64)> {
+    let mut info = MaybeUninit::<c::mach_task_basic_info>::uninit();
+    let mut count = c::MACH_TASK_BASIC_INFO_COUNT;
+    unsafe {
+        // `mach_task_self` is deprecated in the `libc` crate in favor of the
+        // `mach2` crate, but it's the same function.
+        #[allow(deprecated)]
+        let task = c::mach_task_self();
+        if c::task_info(
+            task,
+            c::MACH_TASK_BASIC_INFO,
+            info.as_mut_ptr().cast(),
+            &mut count,
+        ) != c::KERN_SUCCESS
+        {
+            return Err(io::Errno::INVAL);
+        }
+        let info = info.assume_init();
+        Ok((
+            info.resident_size,
+            info.resident_size_max,
+            info.virtual_size,
+        ))
+    }
+}
@@ -408,6 +408,35 @@ pub(crate) fn raw_cpu_set_new() -> RawCpuSet {
     set
 }
 
+#[cfg(not(target_os = "wasi"))]
+pub(crate) type RawSigSet = c::sigset_t;
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn raw_sig_set_new() -> RawSigSet {
+    let mut set = unsafe { core::mem::zeroed() };
+    super::sigset::sigemptyset(&mut set);
+    set
+}
+
+/// `SIG_*` constants for use with [`sigprocmask`].
+///
+/// [`sigprocmask`]: crate::thread::sigprocmask
+#[cfg(not(target_os = "wasi"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(i32)]
+pub enum SigmaskHow {
+    /// `SIG_BLOCK`
+    #[doc(alias = "SIG_BLOCK")]
+    Block = c::SIG_BLOCK,
+    /// `SIG_UNBLOCK`
+    #[doc(alias = "SIG_UNBLOCK")]
+    Unblock = c::SIG_UNBLOCK,
+    /// `SIG_SETMASK`
+    #[doc(alias = "SIG_SETMASK")]
+    SetMask = c::SIG_SETMASK,
+}
+
 #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
 pub(crate) const CPU_SETSIZE: usize = c::CPU_SETSIZE as usize;
 #[cfg(target_os = "dragonfly")]
@@ -1,7 +1,14 @@
 //! libc syscalls supporting `rustix::rand`.
 
+#[cfg(not(target_os = "wasi"))]
+use {
+    super::super::c,
+    super::super::conv::{c_str, ret_owned_fd},
+    crate::fd::OwnedFd,
+    crate::io,
+};
 #[cfg(target_os = "linux")]
-use {super::super::c, super::super::conv::ret_ssize_t, crate::io, crate::rand::GetRandomFlags};
+use {super::super::conv::ret_ssize_t, crate::rand::GetRandomFlags};
 
 #[cfg(target_os = "linux")]
 pub(crate) fn getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usize> {
@@ -14,3 +21,33 @@ pub(crate) fn getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usi
         unsafe { ret_ssize_t(getrandom(buf.as_mut_ptr().cast(), buf.len(), flags.bits()))? };
     Ok(nread as usize)
 }
+
+/// glibc 2.41 and newer use the vDSO in `getrandom` on their own, with
+/// per-thread state they manage.
+#[cfg(target_os = "linux")]
+#[inline]
+pub(crate) fn vdso_getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usize> {
+    getrandom(buf, flags)
+}
+
+#[cfg(not(target_os = "wasi"))]
+pub(crate) fn open_dev_urandom() -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(c::open(
+            c_str(cstr!("/dev/urandom")),
+            c::O_RDONLY | c::O_CLOEXEC,
+        ))
+    }
+}
+
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub(crate) fn arc4random_buf(buf: &mut [u8]) {
+    unsafe { c::arc4random_buf(buf.as_mut_ptr().cast(), buf.len()) }
+}
@@ -0,0 +1,24 @@
+//! libc syscalls supporting `rustix::shm`.
+
+use super::super::c;
+use super::super::conv::{c_str, ret, ret_owned_fd};
+use crate::fd::OwnedFd;
+use crate::ffi::CStr;
+use crate::fs::{Mode, OFlags};
+use crate::io;
+
+#[inline]
+pub(crate) fn shm_open(name: &CStr, oflags: OFlags, mode: Mode) -> io::Result<OwnedFd> {
+    // On Apple platforms, `shm_open` is declared as a variadic function, so
+    // pass `mode` as a `c_uint`, as in `openat`.
+    #[cfg(any(target_os = "ios", target_os = "macos"))]
+    let mode = c::c_uint::from(mode.bits());
+    #[cfg(not(any(target_os = "ios", target_os = "macos")))]
+    let mode = mode.bits();
+    unsafe { ret_owned_fd(c::shm_open(c_str(name), oflags.bits(), mode)) }
+}
+
+#[inline]
+pub(crate) fn shm_unlink(name: &CStr) -> io::Result<()> {
+    unsafe { ret(c::shm_unlink(c_str(name))) }
+}
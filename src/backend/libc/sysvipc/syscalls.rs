@@ -0,0 +1,94 @@
+//! libc syscalls supporting `rustix::sysvipc`.
+
+use super::super::c;
+use super::super::conv::{ret, ret_c_int, ret_ssize_t};
+use crate::io;
+use crate::sysvipc::Sembuf;
+
+#[inline]
+pub(crate) fn shmget(key: c::c_int, size: usize, flags: u32) -> io::Result<c::c_int> {
+    unsafe { ret_c_int(c::shmget(key, size, flags as c::c_int)) }
+}
+
+#[inline]
+pub(crate) unsafe fn shmat(
+    id: c::c_int,
+    addr: *const c::c_void,
+    flags: u32,
+) -> io::Result<*mut c::c_void> {
+    let res = c::shmat(id, addr, flags as c::c_int);
+    if res as isize == -1 {
+        Err(io::Errno::last_os_error())
+    } else {
+        Ok(res)
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn shmdt(addr: *const c::c_void) -> io::Result<()> {
+    ret(c::shmdt(addr))
+}
+
+#[inline]
+pub(crate) unsafe fn shmctl(id: c::c_int, cmd: c::c_int, buf: *mut c::c_void) -> io::Result<()> {
+    ret(c::shmctl(id, cmd, buf.cast()))
+}
+
+#[inline]
+pub(crate) fn semget(key: c::c_int, nsems: c::c_int, flags: u32) -> io::Result<c::c_int> {
+    unsafe { ret_c_int(c::semget(key, nsems, flags as c::c_int)) }
+}
+
+#[inline]
+pub(crate) fn semop(id: c::c_int, sops: &[Sembuf]) -> io::Result<()> {
+    // `semop` doesn't write to `sops`, despite its signature.
+    unsafe { ret(c::semop(id, sops.as_ptr() as *mut c::sembuf, sops.len())) }
+}
+
+#[inline]
+pub(crate) unsafe fn semctl(
+    id: c::c_int,
+    semnum: c::c_int,
+    cmd: c::c_int,
+    arg: usize,
+) -> io::Result<c::c_int> {
+    ret_c_int(c::semctl(id, semnum, cmd, arg))
+}
+
+#[inline]
+pub(crate) fn msgget(key: c::c_int, flags: u32) -> io::Result<c::c_int> {
+    unsafe { ret_c_int(c::msgget(key, flags as c::c_int)) }
+}
+
+#[inline]
+pub(crate) unsafe fn msgsnd(
+    id: c::c_int,
+    msgp: *const c::c_void,
+    msgsz: usize,
+    flags: u32,
+) -> io::Result<()> {
+    ret(c::msgsnd(id, msgp, msgsz, flags as c::c_int))
+}
+
+#[inline]
+pub(crate) unsafe fn msgrcv(
+    id: c::c_int,
+    msgp: *mut c::c_void,
+    msgsz: usize,
+    msgtyp: isize,
+    flags: u32,
+) -> io::Result<usize> {
+    let len = ret_ssize_t(c::msgrcv(
+        id,
+        msgp,
+        msgsz,
+        msgtyp as c::c_long,
+        flags as c::c_int,
+    ))?;
+    Ok(len as usize)
+}
+
+#[inline]
+pub(crate) unsafe fn msgctl(id: c::c_int, cmd: c::c_int, buf: *mut c::c_void) -> io::Result<()> {
+    ret(c::msgctl(id, cmd, buf.cast()))
+}
@@ -7,15 +7,16 @@
 use super::super::c;
 use super::super::conv::{borrowed_fd, ret, ret_pid_t};
 use crate::fd::BorrowedFd;
-#[cfg(feature = "procfs")]
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
-use crate::ffi::CStr;
+use crate::ffi::{CStr, CString};
 #[cfg(not(target_os = "wasi"))]
 use crate::io;
 #[cfg(not(target_os = "wasi"))]
 use crate::process::{Pid, RawNonZeroPid};
 #[cfg(not(target_os = "wasi"))]
 use crate::termios::{Action, OptionalActions, QueueSelector, Speed, Termios, Winsize};
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+use alloc::borrow::ToOwned;
 use core::mem::MaybeUninit;
 
 #[cfg(not(target_os = "wasi"))]
@@ -161,3 +162,18 @@ pub(crate) fn ttyname(dirfd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize
         }
     }
 }
+
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+pub(crate) fn ctermid() -> CString {
+    // The libc crate doesn't declare `ctermid` on all platforms.
+    extern "C" {
+        fn ctermid(s: *mut c::c_char) -> *mut c::c_char;
+    }
+
+    // This is at least `L_ctermid` on all the platforms we support.
+    let mut buf = [0 as c::c_char; 1024];
+    unsafe {
+        ctermid(buf.as_mut_ptr());
+        CStr::from_ptr(buf.as_ptr()).to_owned()
+    }
+}
@@ -4,12 +4,14 @@ use super::super::c;
 use super::super::conv::ret;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use super::super::conv::{borrowed_fd, ret_c_int, syscall_ret};
+#[cfg(not(target_os = "wasi"))]
+use super::super::process::types::{RawSigSet, SigmaskHow};
 use super::super::time::types::LibcTimespec;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use crate::fd::BorrowedFd;
 use crate::io;
 #[cfg(any(target_os = "android", target_os = "linux"))]
-use crate::process::{Pid, RawNonZeroPid};
+use crate::process::{Pid, RawNonZeroPid, Signal};
 #[cfg(not(target_os = "redox"))]
 use crate::thread::{NanosleepRelativeResult, Timespec};
 use core::mem::MaybeUninit;
@@ -288,6 +290,20 @@ pub(crate) fn gettid() -> Pid {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub(crate) fn tgkill(tgid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    // `tgkill` wasn't supported in glibc until 2.30, so use `syscall`.
+    unsafe {
+        syscall_ret(c::syscall(
+            c::SYS_tgkill,
+            tgid.as_raw_nonzero().get(),
+            tid.as_raw_nonzero().get(),
+            sig as c::c_int,
+        ))
+    }
+}
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 #[inline]
 pub(crate) fn setns(fd: BorrowedFd, nstype: c::c_int) -> io::Result<c::c_int> {
@@ -333,3 +349,27 @@ pub(crate) fn setuid_thread(uid: crate::process::Uid) -> io::Result<()> {
 pub(crate) fn setgid_thread(gid: crate::process::Gid) -> io::Result<()> {
     unsafe { syscall_ret(c::syscall(c::SYS_setgid, gid.as_raw())) }
 }
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn sigprocmask(how: SigmaskHow, set: &RawSigSet) -> io::Result<RawSigSet> {
+    let mut old = MaybeUninit::<RawSigSet>::uninit();
+    let err = unsafe { c::pthread_sigmask(how as c::c_int, set, old.as_mut_ptr()) };
+
+    // `pthread_sigmask` returns its error status rather than using `errno`.
+    if err == 0 {
+        Ok(unsafe { old.assume_init() })
+    } else {
+        Err(io::Errno(err))
+    }
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub(crate) fn sigpending() -> io::Result<RawSigSet> {
+    let mut set = MaybeUninit::<RawSigSet>::uninit();
+    unsafe {
+        ret(c::sigpending(set.as_mut_ptr()))?;
+        Ok(set.assume_init())
+    }
+}
@@ -11,6 +11,12 @@ use super::types::Timespec;
 use super::types::{ClockId, DynamicClockId};
 use crate::io;
 use core::mem::MaybeUninit;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+use {
+    super::super::conv::ret_c_int,
+    crate::time::{Sigevent, TimerId, TimerSettimeFlags},
+};
 #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
 #[cfg(feature = "time")]
 use {
@@ -41,6 +47,18 @@ weak!(fn __timerfd_gettime64(c::c_int, *mut LibcItimerspec) -> c::c_int);
 ))]
 #[cfg(feature = "time")]
 weak!(fn __timerfd_settime64(c::c_int, c::c_int, *const LibcItimerspec, *mut LibcItimerspec) -> c::c_int);
+#[cfg(all(
+    any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+    target_env = "gnu",
+))]
+#[cfg(feature = "time")]
+weak!(fn __timer_gettime64(c::timer_t, *mut LibcItimerspec) -> c::c_int);
+#[cfg(all(
+    any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+    target_env = "gnu",
+))]
+#[cfg(feature = "time")]
+weak!(fn __timer_settime64(c::timer_t, c::c_int, *const LibcItimerspec, *mut LibcItimerspec) -> c::c_int);
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[inline]
@@ -412,3 +430,197 @@ unsafe fn timerfd_gettime_old(fd: BorrowedFd<'_>) -> io::Result<Itimerspec> {
         },
     })
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+pub(crate) fn timer_create(id: ClockId, sevp: &Sigevent) -> io::Result<TimerId> {
+    // Safety: `sigevent` is a plain C struct, and all zeros is a valid value
+    // of it. This also zeros the padding, which the kernel expects.
+    let mut sev: c::sigevent = unsafe { core::mem::zeroed() };
+    match *sevp {
+        Sigevent::None => sev.sigev_notify = c::SIGEV_NONE,
+        Sigevent::Signal { signal, value } => {
+            sev.sigev_notify = c::SIGEV_SIGNAL;
+            sev.sigev_signo = signal as _;
+            sev.sigev_value = c::sigval {
+                sival_ptr: value as _,
+            };
+        }
+        Sigevent::ThreadId { signal, value, tid } => {
+            sev.sigev_notify = c::SIGEV_THREAD_ID;
+            sev.sigev_signo = signal as _;
+            sev.sigev_value = c::sigval {
+                sival_ptr: value as _,
+            };
+            sev.sigev_notify_thread_id = tid.as_raw_nonzero().get();
+        }
+    }
+
+    let mut timerid = MaybeUninit::<c::timer_t>::uninit();
+    unsafe {
+        ret(c::timer_create(
+            id as c::clockid_t,
+            &mut sev,
+            timerid.as_mut_ptr(),
+        ))?;
+        Ok(TimerId(timerid.assume_init()))
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+pub(crate) fn timer_settime(
+    timerid: TimerId,
+    flags: TimerSettimeFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    let mut result = MaybeUninit::<LibcItimerspec>::uninit();
+
+    #[cfg(all(
+        any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+        target_env = "gnu",
+    ))]
+    unsafe {
+        if let Some(libc_timer_settime) = __timer_settime64.get() {
+            ret(libc_timer_settime(
+                timerid.0,
+                flags.bits(),
+                &new_value.clone().into(),
+                result.as_mut_ptr(),
+            ))?;
+            Ok(result.assume_init().into())
+        } else {
+            let mut old_result = MaybeUninit::<c::itimerspec>::uninit();
+            ret(c::timer_settime(
+                timerid.0,
+                flags.bits(),
+                &itimerspec_to_old(new_value)?,
+                old_result.as_mut_ptr(),
+            ))?;
+            itimerspec_from_old(old_result.assume_init())
+        }
+    }
+
+    #[cfg(not(all(
+        any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+        target_env = "gnu",
+    )))]
+    unsafe {
+        ret(c::timer_settime(
+            timerid.0,
+            flags.bits(),
+            new_value,
+            result.as_mut_ptr(),
+        ))?;
+        Ok(result.assume_init())
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+pub(crate) fn timer_gettime(timerid: TimerId) -> io::Result<Itimerspec> {
+    let mut result = MaybeUninit::<LibcItimerspec>::uninit();
+
+    #[cfg(all(
+        any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+        target_env = "gnu",
+    ))]
+    unsafe {
+        if let Some(libc_timer_gettime) = __timer_gettime64.get() {
+            ret(libc_timer_gettime(timerid.0, result.as_mut_ptr()))?;
+            Ok(result.assume_init().into())
+        } else {
+            let mut old_result = MaybeUninit::<c::itimerspec>::uninit();
+            ret(c::timer_gettime(timerid.0, old_result.as_mut_ptr()))?;
+            itimerspec_from_old(old_result.assume_init())
+        }
+    }
+
+    #[cfg(not(all(
+        any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+        target_env = "gnu",
+    )))]
+    unsafe {
+        ret(c::timer_gettime(timerid.0, result.as_mut_ptr()))?;
+        Ok(result.assume_init())
+    }
+}
+
+/// Convert an `Itimerspec` to the old `itimerspec` format, for 32-bit glibc
+/// versions which lack the `time64` functions.
+#[cfg(all(
+    any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+    target_env = "gnu",
+))]
+#[cfg(feature = "time")]
+fn itimerspec_to_old(value: &Itimerspec) -> io::Result<c::itimerspec> {
+    use core::convert::TryInto;
+
+    Ok(c::itimerspec {
+        it_interval: c::timespec {
+            tv_sec: value
+                .it_interval
+                .tv_sec
+                .try_into()
+                .map_err(|_| io::Errno::OVERFLOW)?,
+            tv_nsec: value
+                .it_interval
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Errno::INVAL)?,
+        },
+        it_value: c::timespec {
+            tv_sec: value
+                .it_value
+                .tv_sec
+                .try_into()
+                .map_err(|_| io::Errno::OVERFLOW)?,
+            tv_nsec: value
+                .it_value
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Errno::INVAL)?,
+        },
+    })
+}
+
+/// Convert an old `itimerspec` to an `Itimerspec`.
+#[cfg(all(
+    any(target_arch = "arm", target_arch = "mips", target_arch = "x86"),
+    target_env = "gnu",
+))]
+#[cfg(feature = "time")]
+fn itimerspec_from_old(old: c::itimerspec) -> io::Result<Itimerspec> {
+    use core::convert::TryInto;
+
+    Ok(Itimerspec {
+        it_interval: Timespec {
+            tv_sec: old.it_interval.tv_sec.into(),
+            tv_nsec: old
+                .it_interval
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Errno::OVERFLOW)?,
+        },
+        it_value: Timespec {
+            tv_sec: old.it_value.tv_sec.into(),
+            tv_nsec: old
+                .it_value
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Errno::OVERFLOW)?,
+        },
+    })
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+pub(crate) fn timer_getoverrun(timerid: TimerId) -> io::Result<u32> {
+    unsafe { ret_c_int(c::timer_getoverrun(timerid.0)).map(|overrun| overrun as u32) }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+pub(crate) fn timer_delete(timerid: TimerId) -> io::Result<()> {
+    unsafe { ret(c::timer_delete(timerid.0)) }
+}
@@ -316,6 +316,38 @@ bitflags! {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+bitflags! {
+    /// `TIMER_*` flags for use with [`timer_settime`].
+    ///
+    /// [`timer_settime`]: crate::time::timer_settime
+    pub struct TimerSettimeFlags: c::c_int {
+        /// `TIMER_ABSTIME`
+        const ABSTIME = c::TIMER_ABSTIME;
+    }
+}
+
+/// `timer_t`—A POSIX per-process timer, as returned by [`timer_create`].
+///
+/// Timers aren't file descriptors, so they aren't closed automatically;
+/// delete them with [`timer_delete`].
+///
+/// [`timer_create`]: crate::time::timer_create
+/// [`timer_delete`]: crate::time::timer_delete
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct TimerId(pub(crate) c::timer_t);
+
+// Safety: A `timer_t` is just an identifier for a timer, which any thread in
+// the process may use.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[allow(unsafe_code)]
+unsafe impl Send for TimerId {}
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[allow(unsafe_code)]
+unsafe impl Sync for TimerId {}
+
 /// `CLOCK_*` constants for use with [`timerfd_create`].
 ///
 /// [`timerfd_create`]: crate::time::timerfd_create
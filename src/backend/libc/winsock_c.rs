@@ -18,9 +18,12 @@ pub(crate) const AF_INET: i32 = WinSock::AF_INET as _;
 pub(crate) const AF_INET6: i32 = WinSock::AF_INET6 as _;
 pub(crate) const AF_UNSPEC: i32 = WinSock::AF_UNSPEC as _;
 pub(crate) const SO_TYPE: i32 = WinSock::SO_TYPE as _;
+pub(crate) const SO_ERROR: i32 = WinSock::SO_ERROR as _;
 pub(crate) const SO_REUSEADDR: i32 = WinSock::SO_REUSEADDR as _;
 pub(crate) const SO_BROADCAST: i32 = WinSock::SO_BROADCAST as _;
 pub(crate) const SO_LINGER: i32 = WinSock::SO_LINGER as _;
+pub(crate) const SO_RCVBUF: i32 = WinSock::SO_RCVBUF as _;
+pub(crate) const SO_SNDBUF: i32 = WinSock::SO_SNDBUF as _;
 pub(crate) const SOL_SOCKET: i32 = WinSock::SOL_SOCKET as _;
 pub(crate) const SO_RCVTIMEO: i32 = WinSock::SO_RCVTIMEO as _;
 pub(crate) const SO_SNDTIMEO: i32 = WinSock::SO_SNDTIMEO as _;
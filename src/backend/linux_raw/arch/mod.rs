@@ -211,7 +211,7 @@ macro_rules! syscall_readonly {
     };
 }
 
-#[cfg(feature = "runtime")]
+#[cfg(any(feature = "process", feature = "runtime"))]
 macro_rules! syscall_noreturn {
     ($nr:ident, $a0:expr) => {
         $crate::backend::arch::choose::syscall1_noreturn(
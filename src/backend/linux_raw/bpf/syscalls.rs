@@ -0,0 +1,22 @@
+//! linux_raw syscalls supporting `rustix::bpf`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend::syscalls` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use super::super::conv::{c_uint, pass_usize, ret, ret_owned_fd};
+use crate::fd::OwnedFd;
+use crate::io;
+use core::ffi::c_void;
+
+#[inline]
+pub(crate) unsafe fn bpf(cmd: u32, attr: *mut c_void, size: usize) -> io::Result<()> {
+    ret(syscall!(__NR_bpf, c_uint(cmd), attr, pass_usize(size)))
+}
+
+#[inline]
+pub(crate) unsafe fn bpf_owned_fd(cmd: u32, attr: *mut c_void, size: usize) -> io::Result<OwnedFd> {
+    ret_owned_fd(syscall!(__NR_bpf, c_uint(cmd), attr, pass_usize(size)))
+}
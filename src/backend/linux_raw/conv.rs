@@ -17,13 +17,11 @@ use super::c;
 use super::fd::{AsRawFd, BorrowedFd, FromRawFd, RawFd};
 #[cfg(not(debug_assertions))]
 use super::io::errno::decode_usize_infallible;
-#[cfg(feature = "runtime")]
-use super::io::errno::try_decode_error;
 #[cfg(target_pointer_width = "64")]
 use super::io::errno::try_decode_u64;
 use super::io::errno::{
-    try_decode_c_int, try_decode_c_uint, try_decode_raw_fd, try_decode_usize, try_decode_void,
-    try_decode_void_star,
+    try_decode_c_int, try_decode_c_uint, try_decode_error, try_decode_raw_fd, try_decode_usize,
+    try_decode_void, try_decode_void_star,
 };
 use super::reg::{raw_arg, ArgNumber, ArgReg, RetReg, R0};
 #[cfg(any(feature = "thread", feature = "time", target_arch = "x86"))]
@@ -228,7 +226,12 @@ pub(super) fn opt_mut<T: Sized, Num: ArgNumber>(t: Option<&mut T>) -> ArgReg<Num
 
 /// Convert an optional immutable reference into a `usize` for passing to a
 /// syscall.
-#[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "riscv64",
+    feature = "mq",
+    feature = "runtime"
+))]
 #[inline]
 pub(super) fn opt_ref<T: Sized, Num: ArgNumber>(t: Option<&T>) -> ArgReg<Num> {
     // This optimizes into the equivalent of `transmute(t)`, and has the
@@ -367,6 +370,13 @@ impl<'a, Num: ArgNumber> From<crate::io::PipeFlags> for ArgReg<'a, Num> {
     }
 }
 
+impl<'a, Num: ArgNumber> From<crate::io::CloseRangeFlags> for ArgReg<'a, Num> {
+    #[inline]
+    fn from(flags: crate::io::CloseRangeFlags) -> Self {
+        c_uint(flags.bits())
+    }
+}
+
 impl<'a, Num: ArgNumber> From<crate::io::DupFlags> for ArgReg<'a, Num> {
     #[inline]
     fn from(flags: crate::io::DupFlags) -> Self {
@@ -590,6 +600,14 @@ impl<'a, Num: ArgNumber> From<crate::time::TimerfdTimerFlags> for ArgReg<'a, Num
     }
 }
 
+#[cfg(feature = "time")]
+impl<'a, Num: ArgNumber> From<crate::time::TimerSettimeFlags> for ArgReg<'a, Num> {
+    #[inline]
+    fn from(flags: crate::time::TimerSettimeFlags) -> Self {
+        c_uint(flags.bits())
+    }
+}
+
 #[cfg(feature = "rand")]
 impl<'a, Num: ArgNumber> From<crate::rand::GetRandomFlags> for ArgReg<'a, Num> {
     #[inline]
@@ -731,7 +749,6 @@ pub(super) unsafe fn ret(raw: RetReg<R0>) -> io::Result<()> {
 ///
 /// The caller must ensure that this is the return value of a syscall which
 /// doesn't return on success.
-#[cfg(feature = "runtime")]
 #[inline]
 pub(super) unsafe fn ret_error(raw: RetReg<R0>) -> io::Errno {
     try_decode_error(raw)
@@ -10,7 +10,7 @@
 use super::super::c;
 use super::super::conv::{
     by_ref, c_int, c_uint, dev_t, oflags_for_open_how, opt_mut, pass_usize, raw_fd, ret, ret_c_int,
-    ret_c_uint, ret_owned_fd, ret_usize, size_of, slice_mut, zero,
+    ret_c_uint, ret_infallible, ret_owned_fd, ret_usize, size_of, slice_mut, zero,
 };
 #[cfg(target_pointer_width = "64")]
 use super::super::conv::{loff_t, loff_t_from_u64, ret_u64};
@@ -25,9 +25,9 @@ use crate::fd::{BorrowedFd, OwnedFd};
 use crate::ffi::CStr;
 use crate::fs::inotify;
 use crate::fs::{
-    Access, Advice, AtFlags, FallocateFlags, FileType, FlockOperation, MemfdFlags, Mode, OFlags,
-    RenameFlags, ResolveFlags, SealFlags, Stat, StatFs, StatVfs, StatVfsMountFlags, StatxFlags,
-    Timestamps,
+    Access, Advice, AtFlags, DnotifyFlags, FallocateFlags, FileType, FlockOperation, LeaseType,
+    MemfdFlags, Mode, OFlags, RawTimestamps, RenameFlags, ResolveFlags, SealFlags, Stat, StatFs,
+    StatVfs, StatVfsMountFlags, StatxFlags, SwapFlags,
 };
 use crate::io::{self, SeekFrom};
 use crate::process::{Gid, Uid};
@@ -38,10 +38,11 @@ use core::mem::MaybeUninit;
 use linux_raw_sys::general::stat as linux_stat64;
 use linux_raw_sys::general::{
     __kernel_fsid_t, __kernel_timespec, open_how, statx, AT_EACCESS, AT_FDCWD, AT_REMOVEDIR,
-    AT_SYMLINK_NOFOLLOW, F_ADD_SEALS, F_GETFL, F_GETLEASE, F_GETOWN, F_GETPIPE_SZ, F_GETSIG,
-    F_GET_SEALS, F_SETFL, F_SETPIPE_SZ, SEEK_CUR, SEEK_DATA, SEEK_END, SEEK_HOLE, SEEK_SET,
-    STATX__RESERVED,
+    AT_SYMLINK_NOFOLLOW, F_ADD_SEALS, F_GETFL, F_GETLEASE, F_GETPIPE_SZ, F_GET_SEALS, F_NOTIFY,
+    F_RDLCK, F_SETFL, F_SETLEASE, F_SETPIPE_SZ, F_WRLCK, SEEK_CUR, SEEK_DATA, SEEK_END, SEEK_HOLE,
+    SEEK_SET, STATX__RESERVED,
 };
+use linux_raw_sys::ioctl::FICLONE;
 #[cfg(target_pointer_width = "32")]
 use {
     super::super::conv::{hi, lo, slice_just_addr},
@@ -143,6 +144,47 @@ pub(crate) fn chmodat(dirfd: BorrowedFd<'_>, filename: &CStr, mode: Mode) -> io:
     unsafe { ret(syscall_readonly!(__NR_fchmodat, dirfd, filename, mode)) }
 }
 
+pub(crate) fn chmodat_with(
+    dirfd: BorrowedFd<'_>,
+    filename: &CStr,
+    mode: Mode,
+    flags: AtFlags,
+) -> io::Result<()> {
+    if flags.is_empty() {
+        return chmodat(dirfd, filename, mode);
+    }
+    if flags != AtFlags::SYMLINK_NOFOLLOW {
+        return Err(io::Errno::INVAL);
+    }
+
+    // Linux's `fchmodat` has no flags argument, so emulate
+    // `AT_SYMLINK_NOFOLLOW` the way libc does: open the file itself with
+    // `O_PATH`, and change its mode through its `/proc/self/fd` entry.
+    #[cfg(feature = "procfs")]
+    {
+        use crate::fd::AsFd;
+
+        let fd = openat(
+            dirfd,
+            filename,
+            OFlags::PATH | OFlags::NOFOLLOW | OFlags::CLOEXEC,
+            Mode::empty(),
+        )?;
+        if FileType::from_raw_mode(fstat(fd.as_fd())?.st_mode) == FileType::Symlink {
+            // Linux doesn't support changing the mode of a symlink.
+            return Err(io::Errno::OPNOTSUPP);
+        }
+        chmodat(
+            io::proc_self_fd()?,
+            crate::path::DecInt::from_fd(fd.as_fd()).as_c_str(),
+            mode,
+        )
+    }
+
+    #[cfg(not(feature = "procfs"))]
+    Err(io::Errno::OPNOTSUPP)
+}
+
 #[inline]
 pub(crate) fn fchmod(fd: BorrowedFd<'_>, mode: Mode) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_fchmod, fd, mode)) }
@@ -306,6 +348,44 @@ pub(crate) fn ftruncate(fd: BorrowedFd<'_>, length: u64) -> io::Result<()> {
     }
 }
 
+#[inline]
+pub(crate) fn truncate(filename: &CStr, length: u64) -> io::Result<()> {
+    // <https://github.com/torvalds/linux/blob/fcadab740480e0e0e9fa9bd272acd409884d431a/arch/arm64/kernel/sys32.c>
+    #[cfg(all(
+        target_pointer_width = "32",
+        any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc"),
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_truncate64,
+            filename,
+            zero(),
+            hi(length),
+            lo(length)
+        ))
+    }
+    #[cfg(all(
+        target_pointer_width = "32",
+        not(any(target_arch = "arm", target_arch = "mips", target_arch = "powerpc")),
+    ))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_truncate64,
+            filename,
+            hi(length),
+            lo(length)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_truncate,
+            filename,
+            loff_t_from_u64(length)
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn fallocate(
     fd: BorrowedFd<'_>,
@@ -419,6 +499,16 @@ pub(crate) fn fdatasync(fd: BorrowedFd<'_>) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_fdatasync, fd)) }
 }
 
+#[inline]
+pub(crate) fn sync() {
+    unsafe { ret_infallible(syscall_readonly!(__NR_sync)) }
+}
+
+#[inline]
+pub(crate) fn syncfs(fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_syncfs, fd)) }
+}
+
 #[inline]
 pub(crate) fn flock(fd: BorrowedFd<'_>, operation: FlockOperation) -> io::Result<()> {
     unsafe { ret(syscall!(__NR_flock, fd, c_uint(operation as c::c_uint))) }
@@ -928,38 +1018,60 @@ pub(crate) fn fcntl_setfl(fd: BorrowedFd<'_>, flags: OFlags) -> io::Result<()> {
 }
 
 #[inline]
-pub(crate) fn fcntl_getlease(fd: BorrowedFd<'_>) -> io::Result<c::c_int> {
+pub(crate) fn fcntl_getlease(fd: BorrowedFd<'_>) -> io::Result<LeaseType> {
     #[cfg(target_pointer_width = "32")]
-    unsafe {
-        ret_c_int(syscall_readonly!(__NR_fcntl64, fd, c_uint(F_GETLEASE)))
-    }
+    let lease = unsafe { ret_c_int(syscall_readonly!(__NR_fcntl64, fd, c_uint(F_GETLEASE)))? };
     #[cfg(target_pointer_width = "64")]
-    unsafe {
-        ret_c_int(syscall_readonly!(__NR_fcntl, fd, c_uint(F_GETLEASE)))
-    }
+    let lease = unsafe { ret_c_int(syscall_readonly!(__NR_fcntl, fd, c_uint(F_GETLEASE)))? };
+
+    Ok(match lease as u32 {
+        F_RDLCK => LeaseType::Read,
+        F_WRLCK => LeaseType::Write,
+        _ => LeaseType::Unlock,
+    })
 }
 
 #[inline]
-pub(crate) fn fcntl_getown(fd: BorrowedFd<'_>) -> io::Result<c::c_int> {
+pub(crate) fn fcntl_setlease(fd: BorrowedFd<'_>, lease: LeaseType) -> io::Result<()> {
     #[cfg(target_pointer_width = "32")]
     unsafe {
-        ret_c_int(syscall_readonly!(__NR_fcntl64, fd, c_uint(F_GETOWN)))
+        ret(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(F_SETLEASE),
+            c_uint(lease as u32)
+        ))
     }
     #[cfg(target_pointer_width = "64")]
     unsafe {
-        ret_c_int(syscall_readonly!(__NR_fcntl, fd, c_uint(F_GETOWN)))
+        ret(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(F_SETLEASE),
+            c_uint(lease as u32)
+        ))
     }
 }
 
 #[inline]
-pub(crate) fn fcntl_getsig(fd: BorrowedFd<'_>) -> io::Result<c::c_int> {
+pub(crate) fn fcntl_dnotify(fd: BorrowedFd<'_>, flags: DnotifyFlags) -> io::Result<()> {
     #[cfg(target_pointer_width = "32")]
     unsafe {
-        ret_c_int(syscall_readonly!(__NR_fcntl64, fd, c_uint(F_GETSIG)))
+        ret(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(F_NOTIFY),
+            c_uint(flags.bits())
+        ))
     }
     #[cfg(target_pointer_width = "64")]
     unsafe {
-        ret_c_int(syscall_readonly!(__NR_fcntl, fd, c_uint(F_GETSIG)))
+        ret(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(F_NOTIFY),
+            c_uint(flags.bits())
+        ))
     }
 }
 
@@ -1226,7 +1338,7 @@ pub(crate) fn getdents_uninit(
 pub(crate) fn utimensat(
     dirfd: BorrowedFd<'_>,
     pathname: &CStr,
-    times: &Timestamps,
+    times: &RawTimestamps,
     flags: AtFlags,
 ) -> io::Result<()> {
     _utimensat(dirfd, Some(pathname), times, flags)
@@ -1236,11 +1348,11 @@ pub(crate) fn utimensat(
 fn _utimensat(
     dirfd: BorrowedFd<'_>,
     pathname: Option<&CStr>,
-    times: &Timestamps,
+    times: &RawTimestamps,
     flags: AtFlags,
 ) -> io::Result<()> {
-    // Assert that `Timestamps` has the expected layout.
-    let _ = unsafe { core::mem::transmute::<Timestamps, [__kernel_timespec; 2]>(times.clone()) };
+    // Assert that `RawTimestamps` has the expected layout.
+    let _ = unsafe { core::mem::transmute::<RawTimestamps, [__kernel_timespec; 2]>(times.clone()) };
 
     #[cfg(target_pointer_width = "32")]
     unsafe {
@@ -1271,7 +1383,7 @@ fn _utimensat(
 unsafe fn _utimensat_old(
     dirfd: BorrowedFd<'_>,
     pathname: Option<&CStr>,
-    times: &Timestamps,
+    times: &RawTimestamps,
     flags: AtFlags,
 ) -> io::Result<()> {
     // See the comments in `rustix_clock_gettime_via_syscall` about
@@ -1314,7 +1426,7 @@ unsafe fn _utimensat_old(
 }
 
 #[inline]
-pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &Timestamps) -> io::Result<()> {
+pub(crate) fn futimens(fd: BorrowedFd<'_>, times: &RawTimestamps) -> io::Result<()> {
     _utimensat(fd, None, times, AtFlags::empty())
 }
 
@@ -1324,21 +1436,45 @@ pub(crate) fn accessat(
     access: Access,
     flags: AtFlags,
 ) -> io::Result<()> {
-    // Linux's `faccessat` doesn't have a flags parameter. If we have
-    // `AT_EACCESS` and we're not setuid or setgid, we can emulate it.
-    if flags.is_empty()
-        || (flags.bits() == AT_EACCESS
-            && crate::process::getuid() == crate::process::geteuid()
-            && crate::process::getgid() == crate::process::getegid())
+    // Linux's `faccessat` doesn't have a flags parameter.
+    if flags.is_empty() {
+        return unsafe { ret(syscall_readonly!(__NR_faccessat, dirfd, path, access)) };
+    }
+
+    // If we have flags, use `faccessat2`, so that the kernel implements them.
+    // Some seccomp sandboxes fail unknown syscalls with `EPERM` rather than
+    // `ENOSYS`, so try emulating on that too.
+    let err = match unsafe {
+        ret(syscall_readonly!(
+            __NR_faccessat2,
+            dirfd,
+            path,
+            access,
+            flags
+        ))
+    } {
+        Err(err @ io::Errno::NOSYS) | Err(err @ io::Errno::PERM) => err,
+        otherwise => return otherwise,
+    };
+
+    // On older kernels, if we have `AT_EACCESS` and we're not setuid or
+    // setgid, we can emulate it.
+    if flags.bits() == AT_EACCESS
+        && crate::process::getuid() == crate::process::geteuid()
+        && crate::process::getgid() == crate::process::getegid()
     {
         return unsafe { ret(syscall_readonly!(__NR_faccessat, dirfd, path, access)) };
     }
 
+    // If we can't emulate, an `EPERM` may be real, so report it as is.
+    if err == io::Errno::PERM {
+        return Err(err);
+    }
+
     if flags.bits() != AT_EACCESS {
         return Err(io::Errno::INVAL);
     }
 
-    // TODO: Use faccessat2 in newer Linux versions.
     Err(io::Errno::NOSYS)
 }
 
@@ -1363,6 +1499,11 @@ pub(crate) fn copy_file_range(
     }
 }
 
+#[inline]
+pub(crate) fn ioctl_ficlone(fd: BorrowedFd<'_>, src_fd: BorrowedFd<'_>) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_ioctl, fd, c_uint(FICLONE), src_fd)) }
+}
+
 #[inline]
 pub(crate) fn memfd_create(name: &CStr, flags: MemfdFlags) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall_readonly!(__NR_memfd_create, name, flags)) }
@@ -1418,6 +1559,32 @@ pub(crate) fn mount(
     }
 }
 
+#[inline]
+pub(crate) unsafe fn quotactl(
+    cmd: u32,
+    special: Option<&CStr>,
+    id: u32,
+    addr: *mut c::c_void,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_quotactl,
+        c_uint(cmd),
+        special,
+        c_uint(id),
+        addr
+    ))
+}
+
+#[inline]
+pub(crate) fn swapon(path: &CStr, flags: SwapFlags) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_swapon, path, c_uint(flags.bits()))) }
+}
+
+#[inline]
+pub(crate) fn swapoff(path: &CStr) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_swapoff, path)) }
+}
+
 #[inline]
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub(crate) fn unmount(target: &CStr, flags: super::types::UnmountFlags) -> io::Result<()> {
@@ -201,6 +201,9 @@ bitflags! {
 
         /// `O_DIRECT`
         const DIRECT = linux_raw_sys::general::O_DIRECT;
+
+        /// `O_ASYNC`
+        const ASYNC = linux_raw_sys::general::FASYNC;
     }
 }
 
@@ -459,6 +462,9 @@ bitflags! {
         /// `STATX_MNT_ID` (since Linux 5.8)
         const MNT_ID = linux_raw_sys::general::STATX_MNT_ID;
 
+        /// `STATX_DIOALIGN` (since Linux 6.1)
+        const DIOALIGN = linux_raw_sys::general::STATX_DIOALIGN;
+
         /// `STATX_ALL`
         const ALL = linux_raw_sys::general::STATX_ALL;
     }
@@ -538,6 +544,44 @@ pub enum FlockOperation {
     NonBlockingUnlock = linux_raw_sys::general::LOCK_UN | linux_raw_sys::general::LOCK_NB,
 }
 
+/// `F_*LCK` constants for use with [`fcntl_setlease`] and
+/// [`fcntl_getlease`].
+///
+/// [`fcntl_setlease`]: crate::fs::fcntl_setlease
+/// [`fcntl_getlease`]: crate::fs::fcntl_getlease
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[repr(u32)]
+pub enum LeaseType {
+    /// `F_RDLCK`
+    Read = linux_raw_sys::general::F_RDLCK,
+    /// `F_WRLCK`
+    Write = linux_raw_sys::general::F_WRLCK,
+    /// `F_UNLCK`
+    Unlock = linux_raw_sys::general::F_UNLCK,
+}
+
+bitflags! {
+    /// `DN_*` constants for use with [`fcntl_dnotify`].
+    ///
+    /// [`fcntl_dnotify`]: crate::fs::fcntl_dnotify
+    pub struct DnotifyFlags: u32 {
+        /// `DN_ACCESS`—A file was read.
+        const ACCESS = linux_raw_sys::general::DN_ACCESS;
+        /// `DN_MODIFY`—A file was written or truncated.
+        const MODIFY = linux_raw_sys::general::DN_MODIFY;
+        /// `DN_CREATE`—A file was created.
+        const CREATE = linux_raw_sys::general::DN_CREATE;
+        /// `DN_DELETE`—A file was unlinked.
+        const DELETE = linux_raw_sys::general::DN_DELETE;
+        /// `DN_RENAME`—A file was renamed within the directory.
+        const RENAME = linux_raw_sys::general::DN_RENAME;
+        /// `DN_ATTRIB`—The attributes of a file were changed.
+        const ATTRIB = linux_raw_sys::general::DN_ATTRIB;
+        /// `DN_MULTISHOT`—Stay armed after the first notification.
+        const MULTISHOT = linux_raw_sys::general::DN_MULTISHOT;
+    }
+}
+
 /// `struct stat` for use with [`statat`] and [`fstat`].
 ///
 /// [`statat`]: crate::fs::statat
@@ -648,6 +692,27 @@ pub const PROC_SUPER_MAGIC: FsWord = linux_raw_sys::general::PROC_SUPER_MAGIC as
 /// `NFS_SUPER_MAGIC`—The magic number for the NFS filesystem.
 pub const NFS_SUPER_MAGIC: FsWord = linux_raw_sys::general::NFS_SUPER_MAGIC as FsWord;
 
+/// `TMPFS_MAGIC`—The magic number for the tmpfs filesystem.
+pub const TMPFS_MAGIC: FsWord = linux_raw_sys::general::TMPFS_MAGIC as FsWord;
+
+/// `OVERLAYFS_SUPER_MAGIC`—The magic number for the overlayfs filesystem.
+pub const OVERLAYFS_SUPER_MAGIC: FsWord = linux_raw_sys::general::OVERLAYFS_SUPER_MAGIC as FsWord;
+
+/// `BTRFS_SUPER_MAGIC`—The magic number for the Btrfs filesystem.
+pub const BTRFS_SUPER_MAGIC: FsWord = linux_raw_sys::general::BTRFS_SUPER_MAGIC as FsWord;
+
+/// `EXT4_SUPER_MAGIC`—The magic number for the ext2, ext3, and ext4 filesystems.
+pub const EXT4_SUPER_MAGIC: FsWord = linux_raw_sys::general::EXT4_SUPER_MAGIC as FsWord;
+
+/// `XFS_SUPER_MAGIC`—The magic number for the XFS filesystem.
+pub const XFS_SUPER_MAGIC: FsWord = linux_raw_sys::general::XFS_SUPER_MAGIC as FsWord;
+
+/// `SYSFS_MAGIC`—The magic number for the sysfs filesystem.
+pub const SYSFS_MAGIC: FsWord = linux_raw_sys::general::SYSFS_MAGIC as FsWord;
+
+/// `CGROUP2_SUPER_MAGIC`—The magic number for the cgroup2 filesystem.
+pub const CGROUP2_SUPER_MAGIC: FsWord = linux_raw_sys::general::CGROUP2_SUPER_MAGIC as FsWord;
+
 #[cfg(any(target_os = "android", target_os = "linux"))]
 bitflags! {
     /// `MS_*` constants for use with [`mount`][crate::fs::mount].
@@ -5,6 +5,7 @@
 #![allow(unsafe_code)]
 use super::super::c;
 use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
 use core::slice;
 use linux_raw_sys::general::__kernel_size_t;
 
@@ -49,6 +50,15 @@ impl<'a> IoSlice<'a> {
     }
 }
 
+impl<'a> Deref for IoSlice<'a> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
 /// <https://doc.rust-lang.org/nightly/std/io/struct.IoSliceMut.html>
 #[repr(transparent)]
 pub struct IoSliceMut<'a> {
@@ -96,3 +106,19 @@ impl<'a> IoSliceMut<'a> {
         }
     }
 }
+
+impl<'a> Deref for IoSliceMut<'a> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<'a> DerefMut for IoSliceMut<'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
@@ -10,8 +10,8 @@ use super::super::c;
 #[cfg(target_pointer_width = "64")]
 use super::super::conv::loff_t_from_u64;
 use super::super::conv::{
-    by_ref, c_int, c_uint, opt_mut, pass_usize, raw_fd, ret, ret_c_uint, ret_discarded_fd,
-    ret_owned_fd, ret_usize, slice, slice_mut, zero,
+    by_ref, c_int, c_uint, opt_mut, pass_usize, raw_fd, ret, ret_c_int, ret_c_uint,
+    ret_discarded_fd, ret_owned_fd, ret_usize, slice, slice_mut, zero,
 };
 #[cfg(target_pointer_width = "32")]
 use super::super::conv::{hi, lo};
@@ -19,17 +19,19 @@ use crate::fd::{AsFd, BorrowedFd, OwnedFd, RawFd};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 use crate::io::SpliceFlags;
 use crate::io::{
-    self, epoll, DupFlags, EventfdFlags, FdFlags, IoSlice, IoSliceMut, IoSliceRaw, PipeFlags,
-    PollFd, ReadWriteFlags,
+    self, epoll, CloseRangeFlags, DupFlags, EventfdFlags, FdFlags, IoSlice, IoSliceMut, IoSliceRaw,
+    Owner, PipeFlags, PollFd, ReadWriteFlags,
 };
 #[cfg(all(feature = "fs", feature = "net"))]
 use crate::net::{RecvFlags, SendFlags};
+use crate::process::Pid;
 use core::cmp;
 use core::mem::MaybeUninit;
 #[cfg(target_os = "espidf")]
 use linux_raw_sys::general::F_DUPFD;
 use linux_raw_sys::general::{
-    epoll_event, EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD, F_DUPFD_CLOEXEC, F_GETFD, F_SETFD,
+    epoll_event, f_owner_ex, EPOLL_CTL_ADD, EPOLL_CTL_DEL, EPOLL_CTL_MOD, F_DUPFD_CLOEXEC, F_GETFD,
+    F_GETOWN_EX, F_GETSIG, F_OWNER_PGRP, F_OWNER_PID, F_OWNER_TID, F_SETFD, F_SETOWN_EX, F_SETSIG,
     UIO_MAXIOV,
 };
 use linux_raw_sys::ioctl::{BLKPBSZGET, BLKSSZGET, FIONBIO, FIONREAD, TIOCEXCL, TIOCNXCL};
@@ -40,14 +42,18 @@ use {
 };
 
 #[inline]
-pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
+pub(crate) fn read(fd: BorrowedFd<'_>, buf: &mut [MaybeUninit<u8>]) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
 
     unsafe { ret_usize(syscall!(__NR_read, fd, buf_addr_mut, buf_len)) }
 }
 
 #[inline]
-pub(crate) fn pread(fd: BorrowedFd<'_>, buf: &mut [u8], pos: u64) -> io::Result<usize> {
+pub(crate) fn pread(
+    fd: BorrowedFd<'_>,
+    buf: &mut [MaybeUninit<u8>],
+    pos: u64,
+) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
 
     // <https://github.com/torvalds/linux/blob/fcadab740480e0e0e9fa9bd272acd409884d431a/arch/arm64/kernel/sys32.c#L75>
@@ -297,6 +303,20 @@ pub(crate) unsafe fn close(fd: RawFd) {
     syscall_readonly!(__NR_close, raw_fd(fd)).decode_void();
 }
 
+#[inline]
+pub(crate) unsafe fn close_range(
+    first: RawFd,
+    last: RawFd,
+    flags: CloseRangeFlags,
+) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_close_range,
+        c_uint(first as u32),
+        c_uint(last as u32),
+        flags
+    ))
+}
+
 #[inline]
 pub(crate) fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd> {
     unsafe { ret_owned_fd(syscall_readonly!(__NR_eventfd2, c_uint(initval), flags)) }
@@ -447,6 +467,92 @@ pub(crate) fn fcntl_setfd(fd: BorrowedFd<'_>, flags: FdFlags) -> io::Result<()>
     }
 }
 
+#[inline]
+pub(crate) fn fcntl_setown(fd: BorrowedFd<'_>, owner: Owner) -> io::Result<()> {
+    let (type_, pid) = match owner {
+        Owner::Thread(pid) => (F_OWNER_TID, pid),
+        Owner::Pid(pid) => (F_OWNER_PID, pid),
+        Owner::ProcessGroup(pid) => (F_OWNER_PGRP, pid),
+    };
+    let owner = f_owner_ex {
+        type_: type_ as _,
+        pid: pid.as_raw_nonzero().get() as _,
+    };
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(F_SETOWN_EX),
+            by_ref(&owner)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(F_SETOWN_EX),
+            by_ref(&owner)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fcntl_getown(fd: BorrowedFd<'_>) -> io::Result<Option<Owner>> {
+    let mut owner = MaybeUninit::<f_owner_ex>::uninit();
+    let owner = unsafe {
+        #[cfg(target_pointer_width = "32")]
+        ret(syscall!(__NR_fcntl64, fd, c_uint(F_GETOWN_EX), &mut owner))?;
+        #[cfg(target_pointer_width = "64")]
+        ret(syscall!(__NR_fcntl, fd, c_uint(F_GETOWN_EX), &mut owner))?;
+        owner.assume_init()
+    };
+    let pid = match unsafe { Pid::from_raw(owner.pid as _) } {
+        Some(pid) => pid,
+        None => return Ok(None),
+    };
+    Ok(Some(match owner.type_ as u32 {
+        F_OWNER_TID => Owner::Thread(pid),
+        F_OWNER_PGRP => Owner::ProcessGroup(pid),
+        _ => Owner::Pid(pid),
+    }))
+}
+
+#[inline]
+pub(crate) fn fcntl_setsig(fd: BorrowedFd<'_>, sig: c::c_int) -> io::Result<()> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl64,
+            fd,
+            c_uint(F_SETSIG),
+            c_int(sig)
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_fcntl,
+            fd,
+            c_uint(F_SETSIG),
+            c_int(sig)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn fcntl_getsig(fd: BorrowedFd<'_>) -> io::Result<c::c_int> {
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret_c_int(syscall_readonly!(__NR_fcntl64, fd, c_uint(F_GETSIG)))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret_c_int(syscall_readonly!(__NR_fcntl, fd, c_uint(F_GETSIG)))
+    }
+}
+
 #[cfg(target_os = "espidf")]
 #[inline]
 pub(crate) fn fcntl_dupfd(fd: BorrowedFd<'_>, min: RawFd) -> io::Result<OwnedFd> {
@@ -0,0 +1,76 @@
+//! linux_raw syscalls supporting `rustix::landlock`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend::syscalls` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use super::super::conv::{by_ref, c_uint, ret, ret_c_uint, ret_owned_fd, size_of, zero};
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::io;
+use crate::landlock::{
+    landlock_ruleset_attr, LandlockAddRuleFlags, LandlockCreateFlags, LandlockRestrictSelfFlags,
+    RuleType,
+};
+use core::ffi::c_void;
+
+/// `LANDLOCK_CREATE_RULESET_VERSION`
+const LANDLOCK_CREATE_RULESET_VERSION: u32 = 1 << 0;
+
+#[inline]
+pub(crate) fn landlock_create_ruleset(
+    attr: &landlock_ruleset_attr,
+    flags: LandlockCreateFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_landlock_create_ruleset,
+            by_ref(attr),
+            size_of::<landlock_ruleset_attr, _>(),
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn landlock_abi_version() -> io::Result<u32> {
+    unsafe {
+        ret_c_uint(syscall_readonly!(
+            __NR_landlock_create_ruleset,
+            zero(),
+            zero(),
+            c_uint(LANDLOCK_CREATE_RULESET_VERSION)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn landlock_add_rule(
+    ruleset_fd: BorrowedFd<'_>,
+    rule_type: RuleType,
+    rule_attr: *const c_void,
+    flags: LandlockAddRuleFlags,
+) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_landlock_add_rule,
+        ruleset_fd,
+        c_uint(rule_type as u32),
+        rule_attr,
+        c_uint(flags.bits())
+    ))
+}
+
+#[inline]
+pub(crate) fn landlock_restrict_self(
+    ruleset_fd: BorrowedFd<'_>,
+    flags: LandlockRestrictSelfFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_landlock_restrict_self,
+            ruleset_fd,
+            c_uint(flags.bits())
+        ))
+    }
+}
@@ -9,15 +9,22 @@
 use super::super::c;
 #[cfg(target_pointer_width = "64")]
 use super::super::conv::loff_t_from_u64;
-use super::super::conv::{c_uint, no_fd, pass_usize, ret, ret_owned_fd, ret_void_star};
+use super::super::conv::{
+    c_int, c_uint, no_fd, pass_usize, ret, ret_owned_fd, ret_usize, ret_void_star,
+};
+#[cfg(target_arch = "x86_64")]
+use super::super::{arch::choose, reg::nr};
 use super::types::{
     Advice, MapFlags, MlockFlags, MprotectFlags, MremapFlags, MsyncFlags, ProtFlags,
     UserfaultfdFlags,
 };
 use crate::fd::{BorrowedFd, OwnedFd};
 use crate::io;
+use crate::process::Pid;
 #[cfg(target_pointer_width = "32")]
 use core::convert::TryInto;
+use core::mem::{size_of, MaybeUninit};
+use core::ptr::null;
 use linux_raw_sys::general::MAP_ANONYMOUS;
 
 #[inline]
@@ -212,3 +219,124 @@ pub(crate) unsafe fn munlock(addr: *mut c::c_void, length: usize) -> io::Result<
 pub(crate) unsafe fn userfaultfd(flags: UserfaultfdFlags) -> io::Result<OwnedFd> {
     ret_owned_fd(syscall_readonly!(__NR_userfaultfd, flags))
 }
+
+/// Returns the `maxnode` argument for passing `nodemask` to the mempolicy
+/// syscalls, which ignore its last bit.
+#[inline]
+fn nodemask_maxnode(nodemask: &[c::c_ulong]) -> usize {
+    nodemask.len() * 8 * size_of::<c::c_ulong>() + 1
+}
+
+#[inline]
+pub(crate) fn set_mempolicy(mode: c::c_int, nodemask: &[c::c_ulong]) -> io::Result<()> {
+    let maxnode = nodemask_maxnode(nodemask);
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_set_mempolicy,
+            c_int(mode),
+            nodemask.as_ptr(),
+            pass_usize(maxnode)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn get_mempolicy(
+    nodemask: &mut [c::c_ulong],
+    addr: *const c::c_void,
+    flags: c::c_uint,
+) -> io::Result<c::c_int> {
+    let maxnode = nodemask_maxnode(nodemask);
+    let mut mode = MaybeUninit::<c::c_int>::uninit();
+    unsafe {
+        ret(syscall!(
+            __NR_get_mempolicy,
+            &mut mode,
+            nodemask.as_mut_ptr(),
+            pass_usize(maxnode),
+            addr,
+            c_uint(flags)
+        ))?;
+        Ok(mode.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn mbind(
+    addr: *mut c::c_void,
+    len: usize,
+    mode: c::c_int,
+    nodemask: &[c::c_ulong],
+    flags: c::c_uint,
+) -> io::Result<()> {
+    let maxnode = nodemask_maxnode(nodemask);
+    ret(syscall!(
+        __NR_mbind,
+        addr,
+        pass_usize(len),
+        c_int(mode),
+        nodemask.as_ptr(),
+        pass_usize(maxnode),
+        c_uint(flags)
+    ))
+}
+
+#[inline]
+pub(crate) fn migrate_pages(
+    pid: Pid,
+    old_nodes: &[c::c_ulong],
+    new_nodes: &[c::c_ulong],
+) -> io::Result<usize> {
+    debug_assert_eq!(old_nodes.len(), new_nodes.len());
+    let maxnode = nodemask_maxnode(old_nodes);
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_migrate_pages,
+            pid,
+            pass_usize(maxnode),
+            old_nodes.as_ptr(),
+            new_nodes.as_ptr()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn move_pages(
+    pid: Pid,
+    pages: &[*const c::c_void],
+    nodes: Option<&[c::c_int]>,
+    status: &mut [c::c_int],
+    flags: c::c_uint,
+) -> io::Result<()> {
+    // On success, this returns the number of pages which weren't moved, which
+    // is also reflected in `status`.
+    ret_usize(syscall!(
+        __NR_move_pages,
+        pid,
+        pass_usize(pages.len()),
+        pages.as_ptr(),
+        nodes.map_or(null(), <[c::c_int]>::as_ptr),
+        status.as_mut_ptr(),
+        c_uint(flags)
+    ))?;
+    Ok(())
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline]
+pub(crate) unsafe fn map_shadow_stack(
+    addr: usize,
+    size: usize,
+    flags: c::c_uint,
+) -> io::Result<*mut c::c_void> {
+    // `linux-raw-sys` doesn't have `__NR_map_shadow_stack` yet, so we can't
+    // use the `syscall!` macro.
+    const NR_MAP_SHADOW_STACK: u32 = 453;
+
+    ret_void_star(choose::syscall3(
+        nr(NR_MAP_SHADOW_STACK),
+        pass_usize(addr),
+        pass_usize(size),
+        c_uint(flags),
+    ))
+}
@@ -24,22 +24,29 @@ mod arch;
 mod conv;
 mod elf;
 mod reg;
-#[cfg(any(feature = "time", target_arch = "x86"))]
+#[cfg(any(feature = "rand", feature = "time", target_arch = "x86"))]
 mod vdso;
-#[cfg(any(feature = "time", target_arch = "x86"))]
+#[cfg(any(feature = "rand", feature = "time", target_arch = "x86"))]
 mod vdso_wrappers;
 
+#[cfg(feature = "bpf")]
+pub(crate) mod bpf;
 #[cfg(feature = "fs")]
 pub(crate) mod fs;
 pub(crate) mod io;
 #[cfg(feature = "io_uring")]
 pub(crate) mod io_uring;
+#[cfg(feature = "landlock")]
+pub(crate) mod landlock;
 #[cfg(feature = "mm")]
 pub(crate) mod mm;
+#[cfg(feature = "mq")]
+pub(crate) mod mq;
 #[cfg(feature = "net")]
 pub(crate) mod net;
 #[cfg(any(
     feature = "param",
+    feature = "rand",
     feature = "runtime",
     feature = "time",
     target_arch = "x86",
@@ -50,6 +57,8 @@ pub(crate) mod process;
 pub(crate) mod rand;
 #[cfg(feature = "runtime")]
 pub(crate) mod runtime;
+#[cfg(feature = "sysvipc")]
+pub(crate) mod sysvipc;
 #[cfg(feature = "termios")]
 pub(crate) mod termios;
 #[cfg(feature = "thread")]
@@ -0,0 +1,198 @@
+//! linux_raw syscalls supporting `rustix::mq`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend::syscalls` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use super::super::conv::{by_mut, c_uint, opt_ref, ret, ret_owned_fd, ret_usize, slice, slice_mut};
+use crate::fd::{BorrowedFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::fs::{Mode, OFlags, Timespec};
+use crate::io;
+use crate::mq::mq_attr;
+use core::mem::MaybeUninit;
+#[cfg(target_pointer_width = "32")]
+use {core::convert::TryInto, linux_raw_sys::general::timespec as __kernel_old_timespec};
+
+#[inline]
+pub(crate) fn mq_open(
+    name: &CStr,
+    oflags: OFlags,
+    mode: Mode,
+    attr: Option<&mq_attr>,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_mq_open,
+            name,
+            oflags,
+            mode,
+            opt_ref(attr)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn mq_unlink(name: &CStr) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_mq_unlink, name)) }
+}
+
+#[inline]
+pub(crate) fn mq_timedsend(
+    mqdes: BorrowedFd<'_>,
+    msg: &[u8],
+    priority: u32,
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<()> {
+    let (msg_addr, msg_len) = slice(msg);
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_mq_timedsend_time64,
+            mqdes,
+            msg_addr,
+            msg_len,
+            c_uint(priority),
+            opt_ref(abs_timeout)
+        ))
+        .or_else(|err| {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Errno::NOSYS {
+                mq_timedsend_old(mqdes, msg, priority, abs_timeout)
+            } else {
+                Err(err)
+            }
+        })
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_mq_timedsend,
+            mqdes,
+            msg_addr,
+            msg_len,
+            c_uint(priority),
+            opt_ref(abs_timeout)
+        ))
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn mq_timedsend_old(
+    mqdes: BorrowedFd<'_>,
+    msg: &[u8],
+    priority: u32,
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<()> {
+    let old_timeout = match abs_timeout {
+        Some(abs_timeout) => Some(old_timespec(abs_timeout)?),
+        None => None,
+    };
+    let (msg_addr, msg_len) = slice(msg);
+    ret(syscall_readonly!(
+        __NR_mq_timedsend,
+        mqdes,
+        msg_addr,
+        msg_len,
+        c_uint(priority),
+        opt_ref(old_timeout.as_ref())
+    ))
+}
+
+#[inline]
+pub(crate) fn mq_timedreceive(
+    mqdes: BorrowedFd<'_>,
+    buf: &mut [u8],
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<(usize, u32)> {
+    let mut priority = MaybeUninit::<u32>::uninit();
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        let (buf_addr, buf_len) = slice_mut(buf);
+        let len = ret_usize(syscall!(
+            __NR_mq_timedreceive_time64,
+            mqdes,
+            buf_addr,
+            buf_len,
+            &mut priority,
+            opt_ref(abs_timeout)
+        ))
+        .or_else(|err| {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Errno::NOSYS {
+                mq_timedreceive_old(mqdes, buf, &mut priority, abs_timeout)
+            } else {
+                Err(err)
+            }
+        })?;
+        Ok((len, priority.assume_init()))
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        let (buf_addr, buf_len) = slice_mut(buf);
+        let len = ret_usize(syscall!(
+            __NR_mq_timedreceive,
+            mqdes,
+            buf_addr,
+            buf_len,
+            &mut priority,
+            opt_ref(abs_timeout)
+        ))?;
+        Ok((len, priority.assume_init()))
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn mq_timedreceive_old(
+    mqdes: BorrowedFd<'_>,
+    buf: &mut [u8],
+    priority: &mut MaybeUninit<u32>,
+    abs_timeout: Option<&Timespec>,
+) -> io::Result<usize> {
+    let old_timeout = match abs_timeout {
+        Some(abs_timeout) => Some(old_timespec(abs_timeout)?),
+        None => None,
+    };
+    let (buf_addr, buf_len) = slice_mut(buf);
+    ret_usize(syscall!(
+        __NR_mq_timedreceive,
+        mqdes,
+        buf_addr,
+        buf_len,
+        priority,
+        opt_ref(old_timeout.as_ref())
+    ))
+}
+
+#[cfg(target_pointer_width = "32")]
+fn old_timespec(timespec: &Timespec) -> io::Result<__kernel_old_timespec> {
+    Ok(__kernel_old_timespec {
+        tv_sec: timespec.tv_sec.try_into().map_err(|_| io::Errno::INVAL)?,
+        tv_nsec: timespec.tv_nsec.try_into().map_err(|_| io::Errno::INVAL)?,
+    })
+}
+
+#[inline]
+pub(crate) fn mq_getsetattr(
+    mqdes: BorrowedFd<'_>,
+    new_attr: Option<&mq_attr>,
+) -> io::Result<mq_attr> {
+    let mut old_attr = mq_attr::default();
+    unsafe {
+        ret(syscall!(
+            __NR_mq_getsetattr,
+            mqdes,
+            opt_ref(new_attr),
+            by_mut(&mut old_attr)
+        ))?;
+    }
+    Ok(old_attr)
+}
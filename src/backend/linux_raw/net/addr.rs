@@ -57,6 +57,20 @@ impl SocketAddrUnix {
         Ok(Self { unix, len })
     }
 
+    /// Construct a new unnamed Unix-domain address.
+    ///
+    /// Binding a socket to this address asks the kernel to autobind it to a
+    /// unique abstract name, which can be read back with [`getsockname`].
+    ///
+    /// [`getsockname`]: crate::net::getsockname
+    #[inline]
+    pub fn new_unnamed() -> Self {
+        Self {
+            unix: Self::init(),
+            len: offsetof_sun_path().try_into().unwrap(),
+        }
+    }
+
     fn init() -> c::sockaddr_un {
         c::sockaddr_un {
             sun_family: c::AF_UNIX as _,
@@ -68,7 +82,7 @@ impl SocketAddrUnix {
     #[inline]
     pub fn path(&self) -> Option<&CStr> {
         let len = self.len();
-        if len != 0 && self.unix.sun_path[0] != b'\0' as c::c_char {
+        if len > offsetof_sun_path() && self.unix.sun_path[0] != b'\0' as c::c_char {
             let end = len as usize - offsetof_sun_path();
             let bytes = &self.unix.sun_path[..end];
             // Safety: `from_raw_parts` to convert from `&[c_char]` to `&[u8]`. And
@@ -88,7 +102,7 @@ impl SocketAddrUnix {
     #[inline]
     pub fn abstract_name(&self) -> Option<&[u8]> {
         let len = self.len();
-        if len != 0 && self.unix.sun_path[0] == b'\0' as c::c_char {
+        if len > offsetof_sun_path() && self.unix.sun_path[0] == b'\0' as c::c_char {
             let end = len as usize - offsetof_sun_path();
             let bytes = &self.unix.sun_path[1..end];
             // Safety: `from_raw_parts` to convert from `&[c_char]` to `&[u8]`.
@@ -0,0 +1,220 @@
+//! Decoding of control messages received with `recvmsg`, and encoding of
+//! those sent with `sendmsg`.
+#![allow(unsafe_code)]
+
+use super::super::c;
+use super::super::time::types::Timespec;
+use crate::net::{
+    Ipv4Addr, Ipv4PacketInfo, Ipv6Addr, Ipv6PacketInfo, UnixCredentials, ZerocopyCompletion,
+};
+use crate::process::{Gid, Pid, Uid};
+use core::mem::size_of;
+use linux_raw_sys::general::{
+    __kernel_old_timespec, in6_addr__bindgen_ty_1, in6_pktinfo, in_pktinfo, ucred, IPV6_PKTINFO,
+    IPV6_RECVERR, IP_PKTINFO, IP_RECVERR, SCM_CREDENTIALS, SCM_RIGHTS,
+};
+
+// These aren't in linux-raw-sys, so define them here.
+const SO_EE_ORIGIN_ZEROCOPY: u8 = 5;
+const SO_EE_CODE_ZEROCOPY_COPIED: u8 = 1;
+
+/// `struct sock_extended_err`
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct sock_extended_err {
+    ee_errno: u32,
+    ee_origin: u8,
+    ee_type: u8,
+    ee_code: u8,
+    ee_pad: u8,
+    ee_info: u32,
+    ee_data: u32,
+}
+
+/// The `cmsg_level` and `cmsg_type` of a `SCM_RIGHTS` message.
+pub(crate) const RIGHTS: (i32, i32) = (c::SOL_SOCKET as i32, SCM_RIGHTS as i32);
+
+/// The `cmsg_level` and `cmsg_type` of a `SCM_CREDENTIALS` message.
+pub(crate) const CREDENTIALS: (i32, i32) = (c::SOL_SOCKET as i32, SCM_CREDENTIALS as i32);
+
+/// The length of the payload of a `SCM_CREDENTIALS` message.
+pub(crate) const CREDENTIALS_LEN: usize = size_of::<ucred>();
+
+/// Test whether a control message is a `SCM_RIGHTS` message.
+#[inline]
+pub(crate) fn is_rights(level: i32, type_: i32) -> bool {
+    (level, type_) == RIGHTS
+}
+
+/// Decode a `SCM_CREDENTIALS` control message.
+pub(crate) fn credentials(level: i32, type_: i32, data: &[u8]) -> Option<UnixCredentials> {
+    if (level, type_) != CREDENTIALS {
+        return None;
+    }
+    let ucred = read::<ucred>(data)?;
+    // Safety: The kernel filled in these IDs.
+    unsafe {
+        Some(UnixCredentials {
+            pid: Pid::from_raw(ucred.pid as _),
+            uid: Uid::from_raw(ucred.uid),
+            gid: Gid::from_raw(ucred.gid),
+        })
+    }
+}
+
+/// Encode the payload of a `SCM_CREDENTIALS` control message into `data`,
+/// which must be at least `CREDENTIALS_LEN` bytes long.
+pub(crate) fn write_credentials(creds: UnixCredentials, data: &mut [u8]) {
+    let ucred = ucred {
+        pid: Pid::as_raw(creds.pid) as _,
+        uid: creds.uid.as_raw(),
+        gid: creds.gid.as_raw(),
+    };
+    write(ucred, data);
+}
+
+/// The `cmsg_level` and `cmsg_type` of an `IP_PKTINFO` message.
+pub(crate) const IPV4_PACKET_INFO: (i32, i32) = (c::IPPROTO_IP as i32, IP_PKTINFO as i32);
+
+/// The `cmsg_level` and `cmsg_type` of an `IPV6_PKTINFO` message.
+pub(crate) const IPV6_PACKET_INFO: (i32, i32) = (c::IPPROTO_IPV6 as i32, IPV6_PKTINFO as i32);
+
+/// The length of the payload of an `IP_PKTINFO` message.
+pub(crate) const IPV4_PACKET_INFO_LEN: usize = size_of::<in_pktinfo>();
+
+/// The length of the payload of an `IPV6_PKTINFO` message.
+pub(crate) const IPV6_PACKET_INFO_LEN: usize = size_of::<in6_pktinfo>();
+
+/// Decode an `IP_PKTINFO` control message.
+pub(crate) fn ipv4_packet_info(level: i32, type_: i32, data: &[u8]) -> Option<Ipv4PacketInfo> {
+    if (level, type_) != IPV4_PACKET_INFO {
+        return None;
+    }
+    let info = read::<in_pktinfo>(data)?;
+    Some(Ipv4PacketInfo {
+        ifindex: info.ipi_ifindex as u32,
+        local_addr: Ipv4Addr::from(u32::from_be(info.ipi_spec_dst.s_addr)),
+        dest_addr: Ipv4Addr::from(u32::from_be(info.ipi_addr.s_addr)),
+    })
+}
+
+/// Decode an `IPV6_PKTINFO` control message.
+pub(crate) fn ipv6_packet_info(level: i32, type_: i32, data: &[u8]) -> Option<Ipv6PacketInfo> {
+    if (level, type_) != IPV6_PACKET_INFO {
+        return None;
+    }
+    let info = read::<in6_pktinfo>(data)?;
+    // Safety: Every bit pattern is a valid `[u8; 16]`.
+    let octets = unsafe { info.ipi6_addr.in6_u.u6_addr8 };
+    Some(Ipv6PacketInfo {
+        ifindex: info.ipi6_ifindex as u32,
+        addr: Ipv6Addr::from(octets),
+    })
+}
+
+/// Encode the payload of an `IP_PKTINFO` control message into `data`, which
+/// must be at least `IPV4_PACKET_INFO_LEN` bytes long.
+pub(crate) fn write_ipv4_packet_info(info: Ipv4PacketInfo, data: &mut [u8]) {
+    let info = in_pktinfo {
+        ipi_ifindex: info.ifindex as _,
+        ipi_spec_dst: c::in_addr {
+            s_addr: u32::from_ne_bytes(info.local_addr.octets()),
+        },
+        ipi_addr: c::in_addr {
+            s_addr: u32::from_ne_bytes(info.dest_addr.octets()),
+        },
+    };
+    write(info, data);
+}
+
+/// Encode the payload of an `IPV6_PKTINFO` control message into `data`,
+/// which must be at least `IPV6_PACKET_INFO_LEN` bytes long.
+pub(crate) fn write_ipv6_packet_info(info: Ipv6PacketInfo, data: &mut [u8]) {
+    let info = in6_pktinfo {
+        ipi6_addr: c::in6_addr {
+            in6_u: in6_addr__bindgen_ty_1 {
+                u6_addr8: info.addr.octets(),
+            },
+        },
+        ipi6_ifindex: info.ifindex as _,
+    };
+    write(info, data);
+}
+
+/// Decode a `SCM_TIMESTAMPNS` control message.
+pub(crate) fn timestamp_ns(level: i32, type_: i32, data: &[u8]) -> Option<Timespec> {
+    if level != c::SOL_SOCKET as i32 {
+        return None;
+    }
+    match type_ as u32 {
+        c::SO_TIMESTAMPNS_NEW => read::<Timespec>(data),
+        c::SO_TIMESTAMPNS_OLD => read::<__kernel_old_timespec>(data).map(from_old),
+        _ => None,
+    }
+}
+
+/// Decode a `SCM_TIMESTAMPING` control message.
+pub(crate) fn timestamping(level: i32, type_: i32, data: &[u8]) -> Option<[Timespec; 3]> {
+    if level != c::SOL_SOCKET as i32 {
+        return None;
+    }
+    match type_ as u32 {
+        c::SO_TIMESTAMPING_NEW => read::<[Timespec; 3]>(data),
+        c::SO_TIMESTAMPING_OLD => read::<[__kernel_old_timespec; 3]>(data)
+            .map(|[a, b, c]| [from_old(a), from_old(b), from_old(c)]),
+        _ => None,
+    }
+}
+
+/// Decode a `IP_RECVERR` or `IPV6_RECVERR` control message holding a
+/// `MSG_ZEROCOPY` completion notification.
+pub(crate) fn zerocopy_completion(
+    level: i32,
+    type_: i32,
+    data: &[u8],
+) -> Option<ZerocopyCompletion> {
+    let is_recverr = (level == c::IPPROTO_IP as i32 && type_ == IP_RECVERR as i32)
+        || (level == c::IPPROTO_IPV6 as i32 && type_ == IPV6_RECVERR as i32);
+    if !is_recverr {
+        return None;
+    }
+    let err = read::<sock_extended_err>(data)?;
+    if err.ee_errno != 0 || err.ee_origin != SO_EE_ORIGIN_ZEROCOPY {
+        return None;
+    }
+    Some(ZerocopyCompletion {
+        lo: err.ee_info,
+        hi: err.ee_data,
+        copied: err.ee_code & SO_EE_CODE_ZEROCOPY_COPIED != 0,
+    })
+}
+
+/// Convert a timestamp delivered by an `_OLD` option to a `Timespec`.
+#[inline]
+fn from_old(old: __kernel_old_timespec) -> Timespec {
+    Timespec {
+        tv_sec: old.tv_sec as _,
+        tv_nsec: old.tv_nsec as _,
+    }
+}
+
+/// Read a `T` from the start of `data`, which need not be aligned.
+#[inline]
+fn read<T: Copy>(data: &[u8]) -> Option<T> {
+    if data.len() < size_of::<T>() {
+        return None;
+    }
+    // Safety: We checked that `data` is long enough, and every `T` we read
+    // is plain old data.
+    Some(unsafe { data.as_ptr().cast::<T>().read_unaligned() })
+}
+
+/// Write a `T` to the start of `data`, which need not be aligned.
+#[inline]
+fn write<T: Copy>(value: T, data: &mut [u8]) {
+    assert!(data.len() >= size_of::<T>());
+    // Safety: We checked that `data` is long enough, and `write_unaligned`
+    // doesn't require it to be aligned.
+    unsafe { data.as_mut_ptr().cast::<T>().write_unaligned(value) };
+}
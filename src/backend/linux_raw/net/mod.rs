@@ -1,4 +1,5 @@
 pub(crate) mod addr;
+pub(crate) mod cmsg;
 pub(crate) mod read_sockaddr;
 pub(crate) mod send_recv;
 pub(crate) mod syscalls;
@@ -4,7 +4,10 @@
 
 use super::super::c;
 use crate::io;
-use crate::net::{Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    Ipv4Addr, Ipv6Addr, SocketAddrAny, SocketAddrLink, SocketAddrNetlink, SocketAddrUnix,
+    SocketAddrV4, SocketAddrV6, SocketAddrVsock,
+};
 use alloc::vec::Vec;
 use core::mem::size_of;
 
@@ -86,9 +89,21 @@ pub(crate) unsafe fn read_sockaddr(
                 return Err(io::Errno::INVAL);
             }
             if len == offsetof_sun_path {
-                Ok(SocketAddrAny::Unix(SocketAddrUnix::new(&[][..])?))
+                Ok(SocketAddrAny::Unix(SocketAddrUnix::new_unnamed()))
             } else {
                 let decode = *storage.cast::<c::sockaddr_un>();
+
+                // Abstract names start with a NUL byte and aren't
+                // NUL-terminated; their length is given by `len`.
+                if decode.sun_path[0] == 0 {
+                    return SocketAddrUnix::new_abstract_name(
+                        &decode.sun_path[1..len - offsetof_sun_path]
+                            .iter()
+                            .map(|c| *c as u8)
+                            .collect::<Vec<u8>>(),
+                    )
+                    .map(SocketAddrAny::Unix);
+                }
                 assert_eq!(
                     decode.sun_path[len - 1 - offsetof_sun_path],
                     b'\0' as c::c_char
@@ -101,6 +116,33 @@ pub(crate) unsafe fn read_sockaddr(
                 )?))
             }
         }
+        c::AF_PACKET => {
+            if len < offsetof_sll_addr() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = *storage.cast::<c::sockaddr_ll>();
+            Ok(SocketAddrAny::Link(decode_sockaddr_ll(&decode, len)))
+        }
+        c::AF_NETLINK => {
+            if len < size_of::<c::sockaddr_nl>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = *storage.cast::<c::sockaddr_nl>();
+            Ok(SocketAddrAny::Netlink(SocketAddrNetlink::new(
+                decode.nl_pid,
+                decode.nl_groups,
+            )))
+        }
+        c::AF_VSOCK => {
+            if len < size_of::<c::sockaddr_vm>() {
+                return Err(io::Errno::INVAL);
+            }
+            let decode = *storage.cast::<c::sockaddr_vm>();
+            Ok(SocketAddrAny::Vsock(SocketAddrVsock::new(
+                decode.svm_cid,
+                decode.svm_port,
+            )))
+        }
         _ => Err(io::Errno::NOTSUP),
     }
 }
@@ -152,9 +194,23 @@ pub(crate) unsafe fn read_sockaddr_os(storage: *const c::sockaddr, len: usize) -
         c::AF_UNIX => {
             assert!(len >= offsetof_sun_path);
             if len == offsetof_sun_path {
-                SocketAddrAny::Unix(SocketAddrUnix::new(&[][..]).unwrap())
+                SocketAddrAny::Unix(SocketAddrUnix::new_unnamed())
             } else {
                 let decode = *storage.cast::<c::sockaddr_un>();
+
+                // Abstract names start with a NUL byte and aren't
+                // NUL-terminated; their length is given by `len`.
+                if decode.sun_path[0] == 0 {
+                    return SocketAddrAny::Unix(
+                        SocketAddrUnix::new_abstract_name(
+                            &decode.sun_path[1..len - offsetof_sun_path]
+                                .iter()
+                                .map(|c| *c as u8)
+                                .collect::<Vec<u8>>(),
+                        )
+                        .unwrap(),
+                    );
+                }
                 assert_eq!(
                     decode.sun_path[len - 1 - offsetof_sun_path],
                     b'\0' as c::c_char
@@ -170,6 +226,39 @@ pub(crate) unsafe fn read_sockaddr_os(storage: *const c::sockaddr, len: usize) -
                 )
             }
         }
+        c::AF_PACKET => {
+            assert!(len >= offsetof_sll_addr());
+            let decode = *storage.cast::<c::sockaddr_ll>();
+            SocketAddrAny::Link(decode_sockaddr_ll(&decode, len))
+        }
+        c::AF_NETLINK => {
+            assert!(len >= size_of::<c::sockaddr_nl>());
+            let decode = *storage.cast::<c::sockaddr_nl>();
+            SocketAddrAny::Netlink(SocketAddrNetlink::new(decode.nl_pid, decode.nl_groups))
+        }
+        c::AF_VSOCK => {
+            assert!(len >= size_of::<c::sockaddr_vm>());
+            let decode = *storage.cast::<c::sockaddr_vm>();
+            SocketAddrAny::Vsock(SocketAddrVsock::new(decode.svm_cid, decode.svm_port))
+        }
         other => unimplemented!("{:?}", other),
     }
 }
+
+/// The kernel only includes `sll_halen` bytes of `sll_addr` in the length of
+/// a `sockaddr_ll` that it returns.
+#[inline]
+fn offsetof_sll_addr() -> usize {
+    size_of::<c::sockaddr_ll>() - 8
+}
+
+fn decode_sockaddr_ll(decode: &c::sockaddr_ll, len: usize) -> SocketAddrLink {
+    let mut link = SocketAddrLink::new(u16::from_be(decode.sll_protocol), decode.sll_ifindex as _);
+    link.set_hatype(decode.sll_hatype);
+    link.set_pkttype(decode.sll_pkttype);
+    let halen = usize::from(decode.sll_halen)
+        .min(decode.sll_addr.len())
+        .min(len - offsetof_sll_addr());
+    link.set_addr(&decode.sll_addr[..halen]).unwrap();
+    link
+}
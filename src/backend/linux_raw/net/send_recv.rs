@@ -18,6 +18,9 @@ bitflags! {
         const NOSIGNAL = c::MSG_NOSIGNAL;
         /// `MSG_OOB`
         const OOB = c::MSG_OOB;
+        /// `MSG_ZEROCOPY`
+        // This isn't in the kernel's UAPI headers, so define it here.
+        const ZEROCOPY = 0x400_0000;
     }
 }
 
@@ -26,6 +29,8 @@ bitflags! {
     pub struct RecvFlags: u32 {
         /// `MSG_CMSG_CLOEXEC`
         const CMSG_CLOEXEC = c::MSG_CMSG_CLOEXEC;
+        /// `MSG_CTRUNC`
+        const CTRUNC = c::MSG_CTRUNC;
         /// `MSG_DONTWAIT`
         const DONTWAIT = c::MSG_DONTWAIT;
         /// `MSG_ERRQUEUE`
@@ -14,21 +14,29 @@ use super::super::conv::{
 use super::read_sockaddr::{initialize_family_to_unspec, maybe_read_sockaddr_os, read_sockaddr_os};
 use super::send_recv::{RecvFlags, SendFlags};
 use super::types::{AcceptFlags, AddressFamily, Protocol, Shutdown, SocketFlags, SocketType};
-use super::write_sockaddr::{encode_sockaddr_v4, encode_sockaddr_v6};
+use super::write_sockaddr::{
+    encode_sockaddr_ll, encode_sockaddr_nl, encode_sockaddr_v4, encode_sockaddr_v6,
+    encode_sockaddr_vm,
+};
 use crate::fd::{BorrowedFd, OwnedFd};
-use crate::io;
-use crate::net::{SocketAddrAny, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+use crate::io::{self, IoSlice, IoSliceMut};
+use crate::net::{
+    RecvMsgReturn, SocketAddrAny, SocketAddrLink, SocketAddrNetlink, SocketAddrUnix, SocketAddrV4,
+    SocketAddrV6, SocketAddrVsock,
+};
 use c::{sockaddr, sockaddr_in, sockaddr_in6, socklen_t};
 use core::convert::TryInto;
 use core::mem::MaybeUninit;
+use core::ptr::null_mut;
+use linux_raw_sys::ioctl::SIOCATMARK;
 #[cfg(target_arch = "x86")]
 use {
     super::super::conv::{slice_just_addr, x86_sys},
     super::super::reg::{ArgReg, SocketArg},
     linux_raw_sys::general::{
         SYS_ACCEPT, SYS_ACCEPT4, SYS_BIND, SYS_CONNECT, SYS_GETPEERNAME, SYS_GETSOCKNAME,
-        SYS_GETSOCKOPT, SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_SEND, SYS_SENDTO, SYS_SETSOCKOPT,
-        SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
+        SYS_GETSOCKOPT, SYS_LISTEN, SYS_RECV, SYS_RECVFROM, SYS_RECVMSG, SYS_SEND, SYS_SENDMSG,
+        SYS_SENDTO, SYS_SETSOCKOPT, SYS_SHUTDOWN, SYS_SOCKET, SYS_SOCKETPAIR,
     },
 };
 
@@ -259,6 +267,15 @@ pub(crate) fn shutdown(fd: BorrowedFd<'_>, how: Shutdown) -> io::Result<()> {
     }
 }
 
+#[inline]
+pub(crate) fn sockatmark(fd: BorrowedFd<'_>) -> io::Result<bool> {
+    unsafe {
+        let mut result = MaybeUninit::<c::c_int>::uninit();
+        ret(syscall!(__NR_ioctl, fd, c_uint(SIOCATMARK), &mut result))?;
+        Ok(result.assume_init() != 0)
+    }
+}
+
 #[inline]
 pub(crate) fn send(fd: BorrowedFd<'_>, buf: &[u8], flags: SendFlags) -> io::Result<usize> {
     let (buf_addr, buf_len) = slice(buf);
@@ -414,6 +431,120 @@ pub(crate) fn sendto_unix(
     }
 }
 
+#[inline]
+pub(crate) fn sendto_link(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrLink,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_ll(addr)),
+            size_of::<c::sockaddr_ll, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_ll(addr)),
+                size_of::<c::sockaddr_ll, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sendto_netlink(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_nl(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_nl(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn sendto_vsock(
+    fd: BorrowedFd<'_>,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrVsock,
+) -> io::Result<usize> {
+    let (buf_addr, buf_len) = slice(buf);
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_sendto,
+            fd,
+            buf_addr,
+            buf_len,
+            flags,
+            by_ref(&encode_sockaddr_vm(addr)),
+            size_of::<c::sockaddr_vm, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDTO),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                buf_addr,
+                buf_len,
+                flags.into(),
+                by_ref(&encode_sockaddr_vm(addr)),
+                size_of::<c::sockaddr_vm, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn recv(fd: BorrowedFd<'_>, buf: &mut [u8], flags: RecvFlags) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
@@ -508,6 +639,90 @@ pub(crate) fn recvfrom(
     }
 }
 
+#[inline]
+pub(crate) fn recvmsg(
+    fd: BorrowedFd<'_>,
+    bufs: &mut [IoSliceMut<'_>],
+    control: &mut [u8],
+    flags: RecvFlags,
+) -> io::Result<RecvMsgReturn> {
+    let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
+    // Connection-oriented sockets don't store an address, so initialize the
+    // family field to `AF_UNSPEC` so that we can detect this case.
+    unsafe { initialize_family_to_unspec(storage.as_mut_ptr().cast()) };
+
+    let mut msg = c::msghdr {
+        msg_name: storage.as_mut_ptr().cast(),
+        msg_namelen: core::mem::size_of::<c::sockaddr_storage>() as _,
+        msg_iov: bufs.as_mut_ptr().cast(),
+        msg_iovlen: bufs.len() as _,
+        msg_control: control.as_mut_ptr().cast(),
+        msg_controllen: control.len() as _,
+        msg_flags: 0,
+    };
+
+    #[cfg(not(target_arch = "x86"))]
+    let nread = unsafe { ret_usize(syscall!(__NR_recvmsg, fd, by_mut(&mut msg), flags))? };
+    #[cfg(target_arch = "x86")]
+    let nread = unsafe {
+        ret_usize(syscall!(
+            __NR_socketcall,
+            x86_sys(SYS_RECVMSG),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[fd.into(), by_mut(&mut msg), flags.into(),])
+        ))?
+    };
+
+    Ok(RecvMsgReturn {
+        bytes: nread,
+        control_len: msg.msg_controllen as usize,
+        flags: RecvFlags::from_bits_truncate(msg.msg_flags),
+        address: unsafe {
+            maybe_read_sockaddr_os(storage.as_ptr().cast(), msg.msg_namelen as usize)
+        },
+    })
+}
+
+#[inline]
+pub(crate) fn sendmsg(
+    fd: BorrowedFd<'_>,
+    addr: Option<&SocketAddrAny>,
+    bufs: &[IoSlice<'_>],
+    control: &[u8],
+    flags: SendFlags,
+) -> io::Result<usize> {
+    let mut storage = MaybeUninit::<c::sockaddr_storage>::uninit();
+    let (msg_name, msg_namelen) = match addr {
+        Some(addr) => unsafe {
+            let len = addr.write(storage.as_mut_ptr().cast());
+            (storage.as_mut_ptr().cast(), len as _)
+        },
+        None => (null_mut(), 0),
+    };
+
+    let msg = c::msghdr {
+        msg_name,
+        msg_namelen,
+        msg_iov: bufs.as_ptr() as *mut _,
+        msg_iovlen: bufs.len() as _,
+        msg_control: control.as_ptr() as *mut _,
+        msg_controllen: control.len() as _,
+        msg_flags: 0,
+    };
+
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret_usize(syscall_readonly!(__NR_sendmsg, fd, by_ref(&msg), flags))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret_usize(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_SENDMSG),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[fd.into(), by_ref(&msg), flags.into(),])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn getpeername(fd: BorrowedFd<'_>) -> io::Result<Option<SocketAddrAny>> {
     #[cfg(not(target_arch = "x86"))]
@@ -657,6 +872,81 @@ pub(crate) fn bind_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Result
     }
 }
 
+#[inline]
+pub(crate) fn bind_link(fd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_ll(addr)),
+            size_of::<c::sockaddr_ll, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_ll(addr)),
+                size_of::<c::sockaddr_ll, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn bind_netlink(fd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_nl(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_nl(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn bind_vsock(fd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_bind,
+            fd,
+            by_ref(&encode_sockaddr_vm(addr)),
+            size_of::<c::sockaddr_vm, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_BIND),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_vm(addr)),
+                size_of::<c::sockaddr_vm, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn connect_v4(fd: BorrowedFd<'_>, addr: &SocketAddrV4) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -732,6 +1022,81 @@ pub(crate) fn connect_unix(fd: BorrowedFd<'_>, addr: &SocketAddrUnix) -> io::Res
     }
 }
 
+#[inline]
+pub(crate) fn connect_link(fd: BorrowedFd<'_>, addr: &SocketAddrLink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_connect,
+            fd,
+            by_ref(&encode_sockaddr_ll(addr)),
+            size_of::<c::sockaddr_ll, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_ll(addr)),
+                size_of::<c::sockaddr_ll, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn connect_netlink(fd: BorrowedFd<'_>, addr: &SocketAddrNetlink) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_connect,
+            fd,
+            by_ref(&encode_sockaddr_nl(addr)),
+            size_of::<c::sockaddr_nl, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_nl(addr)),
+                size_of::<c::sockaddr_nl, _>(),
+            ])
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn connect_vsock(fd: BorrowedFd<'_>, addr: &SocketAddrVsock) -> io::Result<()> {
+    #[cfg(not(target_arch = "x86"))]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_connect,
+            fd,
+            by_ref(&encode_sockaddr_vm(addr)),
+            size_of::<c::sockaddr_vm, _>()
+        ))
+    }
+    #[cfg(target_arch = "x86")]
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_socketcall,
+            x86_sys(SYS_CONNECT),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                by_ref(&encode_sockaddr_vm(addr)),
+                size_of::<c::sockaddr_vm, _>(),
+            ])
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn listen(fd: BorrowedFd<'_>, backlog: c::c_int) -> io::Result<()> {
     #[cfg(not(target_arch = "x86"))]
@@ -750,13 +1115,15 @@ pub(crate) fn listen(fd: BorrowedFd<'_>, backlog: c::c_int) -> io::Result<()> {
 
 pub(crate) mod sockopt {
     use super::{c, BorrowedFd};
+    use crate::fd::AsRawFd;
+    use crate::ffi::CStr;
     use crate::io;
-    use crate::net::sockopt::Timeout;
+    use crate::net::sockopt::{IpMtuDiscover, Timeout, TimestampingFlags};
     use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
     use c::{SO_RCVTIMEO_NEW, SO_RCVTIMEO_OLD, SO_SNDTIMEO_NEW, SO_SNDTIMEO_OLD};
     use core::convert::TryInto;
     use core::time::Duration;
-    use linux_raw_sys::general::{__kernel_timespec, timeval};
+    use linux_raw_sys::general::{__kernel_sock_timeval, timeval};
 
     // TODO: With Rust 1.53 we can use `Duration::ZERO` instead.
     const DURATION_ZERO: Duration = Duration::from_secs(0);
@@ -855,14 +1222,135 @@ pub(crate) mod sockopt {
         }
     }
 
+    /// Like `getsockopt`, but for options with variable-length values. Returns
+    /// the length of the value.
     #[inline]
-    pub(crate) fn get_socket_type(fd: BorrowedFd<'_>) -> io::Result<SocketType> {
-        getsockopt(fd, c::SOL_SOCKET as _, c::SO_TYPE)
+    fn getsockopt_bytes(
+        fd: BorrowedFd<'_>,
+        level: u32,
+        optname: u32,
+        value: &mut [u8],
+    ) -> io::Result<usize> {
+        unsafe { getsockopt_raw(fd, level, optname, value.as_mut_ptr(), value.len()) }
     }
 
+    /// Like `setsockopt`, but for options with variable-length values.
     #[inline]
-    pub(crate) fn set_socket_reuseaddr(fd: BorrowedFd<'_>, reuseaddr: bool) -> io::Result<()> {
-        setsockopt(
+    fn setsockopt_bytes(
+        fd: BorrowedFd<'_>,
+        level: u32,
+        optname: u32,
+        value: &[u8],
+    ) -> io::Result<()> {
+        unsafe { setsockopt_raw(fd, level, optname, value.as_ptr(), value.len()) }
+    }
+
+    /// `getsockopt` into a buffer of `len` bytes at `value`. Returns the
+    /// length of the value.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be valid for writes of `len` bytes, and the option must
+    /// not have side effects which violate Rust's invariants.
+    #[inline]
+    pub(crate) unsafe fn getsockopt_raw(
+        fd: BorrowedFd<'_>,
+        level: u32,
+        optname: u32,
+        value: *mut u8,
+        len: usize,
+    ) -> io::Result<usize> {
+        use super::*;
+
+        let mut optlen: c::socklen_t = len.try_into().map_err(|_| io::Errno::INVAL)?;
+
+        #[cfg(not(target_arch = "x86"))]
+        ret(syscall!(
+            __NR_getsockopt,
+            fd,
+            c_uint(level),
+            c_uint(optname),
+            value,
+            by_mut(&mut optlen)
+        ))?;
+        #[cfg(target_arch = "x86")]
+        ret(syscall!(
+            __NR_socketcall,
+            x86_sys(SYS_GETSOCKOPT),
+            slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                fd.into(),
+                c_uint(level),
+                c_uint(optname),
+                value.into(),
+                by_mut(&mut optlen),
+            ])
+        ))?;
+        Ok(optlen as usize)
+    }
+
+    /// `setsockopt` from a buffer of `len` bytes at `value`.
+    ///
+    /// # Safety
+    ///
+    /// `value` must be valid for reads of `len` bytes, and the option must
+    /// not have side effects which violate Rust's invariants.
+    #[inline]
+    pub(crate) unsafe fn setsockopt_raw(
+        fd: BorrowedFd<'_>,
+        level: u32,
+        optname: u32,
+        value: *const u8,
+        len: usize,
+    ) -> io::Result<()> {
+        use super::*;
+
+        let optlen = len.try_into().map_err(|_| io::Errno::INVAL)?;
+
+        #[cfg(not(target_arch = "x86"))]
+        {
+            ret(syscall_readonly!(
+                __NR_setsockopt,
+                fd,
+                c_uint(level),
+                c_uint(optname),
+                value,
+                socklen_t(optlen)
+            ))
+        }
+        #[cfg(target_arch = "x86")]
+        {
+            ret(syscall_readonly!(
+                __NR_socketcall,
+                x86_sys(SYS_SETSOCKOPT),
+                slice_just_addr::<ArgReg<SocketArg>, _>(&[
+                    fd.into(),
+                    c_uint(level),
+                    c_uint(optname),
+                    value.into(),
+                    socklen_t(optlen),
+                ])
+            ))
+        }
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_type(fd: BorrowedFd<'_>) -> io::Result<SocketType> {
+        getsockopt(fd, c::SOL_SOCKET as _, c::SO_TYPE)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_error(fd: BorrowedFd<'_>) -> io::Result<Result<(), io::Errno>> {
+        let err: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_ERROR)?;
+        Ok(if err == 0 {
+            Ok(())
+        } else {
+            Err(io::Errno::from_raw_os_error(err))
+        })
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_reuseaddr(fd: BorrowedFd<'_>, reuseaddr: bool) -> io::Result<()> {
+        setsockopt(
             fd,
             c::SOL_SOCKET as _,
             c::SO_REUSEADDR,
@@ -870,6 +1358,103 @@ pub(crate) mod sockopt {
         )
     }
 
+    #[inline]
+    pub(crate) fn set_socket_reuseport(fd: BorrowedFd<'_>, reuseport: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            linux_raw_sys::general::SO_REUSEPORT,
+            from_bool(reuseport),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_reuseport(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::SOL_SOCKET as _, linux_raw_sys::general::SO_REUSEPORT).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_attach_reuseport_ebpf(
+        fd: BorrowedFd<'_>,
+        prog_fd: BorrowedFd<'_>,
+    ) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            linux_raw_sys::general::SO_ATTACH_REUSEPORT_EBPF,
+            prog_fd.as_raw_fd() as c::c_int,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_bindtodevice(
+        fd: BorrowedFd<'_>,
+        interface: Option<&CStr>,
+    ) -> io::Result<()> {
+        // An empty name removes the binding.
+        let name = interface.map_or(&[][..], CStr::to_bytes);
+        setsockopt_bytes(
+            fd,
+            c::SOL_SOCKET as _,
+            linux_raw_sys::general::SO_BINDTODEVICE,
+            name,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_bindtodevice(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
+        getsockopt_bytes(
+            fd,
+            c::SOL_SOCKET as _,
+            linux_raw_sys::general::SO_BINDTODEVICE,
+            buf,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_timestamp_ns(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        // Prefer `_NEW`, which delivers 64-bit timestamps on all platforms,
+        // and fall back to `_OLD` on kernels older than 5.1.
+        match setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            c::SO_TIMESTAMPNS_NEW,
+            from_bool(value),
+        ) {
+            Err(io::Errno::NOPROTOOPT) => setsockopt(
+                fd,
+                c::SOL_SOCKET as _,
+                c::SO_TIMESTAMPNS_OLD,
+                from_bool(value),
+            ),
+            otherwise => otherwise,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_timestamping(
+        fd: BorrowedFd<'_>,
+        flags: TimestampingFlags,
+    ) -> io::Result<()> {
+        // As with `set_socket_timestamp_ns`, prefer `_NEW`.
+        match setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_NEW, flags.bits()) {
+            Err(io::Errno::NOPROTOOPT) => {
+                setsockopt(fd, c::SOL_SOCKET as _, c::SO_TIMESTAMPING_OLD, flags.bits())
+            }
+            otherwise => otherwise,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_zerocopy(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::SOL_SOCKET as _,
+            linux_raw_sys::general::SO_ZEROCOPY,
+            from_bool(value),
+        )
+    }
+
     #[inline]
     pub(crate) fn set_socket_broadcast(fd: BorrowedFd<'_>, broadcast: bool) -> io::Result<()> {
         setsockopt(
@@ -918,6 +1503,48 @@ pub(crate) mod sockopt {
         })
     }
 
+    #[inline]
+    pub(crate) fn set_socket_recv_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUF, size)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_recv_buffer_size_force(
+        fd: BorrowedFd<'_>,
+        size: usize,
+    ) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUFFORCE, size)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_recv_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+        let size: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_RCVBUF)?;
+        Ok(size as usize)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_send_buffer_size(fd: BorrowedFd<'_>, size: usize) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUF, size)
+    }
+
+    #[inline]
+    pub(crate) fn set_socket_send_buffer_size_force(
+        fd: BorrowedFd<'_>,
+        size: usize,
+    ) -> io::Result<()> {
+        let size: c::c_int = size.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUFFORCE, size)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_send_buffer_size(fd: BorrowedFd<'_>) -> io::Result<usize> {
+        let size: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_SNDBUF)?;
+        Ok(size as usize)
+    }
+
     #[inline]
     pub(crate) fn set_socket_passcred(fd: BorrowedFd<'_>, passcred: bool) -> io::Result<()> {
         setsockopt(fd, c::SOL_SOCKET as _, c::SO_PASSCRED, from_bool(passcred))
@@ -928,6 +1555,18 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::SOL_SOCKET as _, c::SO_PASSCRED).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_socket_priority(fd: BorrowedFd<'_>, priority: u32) -> io::Result<()> {
+        let priority: c::c_int = priority.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::SOL_SOCKET as _, c::SO_PRIORITY, priority)
+    }
+
+    #[inline]
+    pub(crate) fn get_socket_priority(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        let priority: c::c_int = getsockopt(fd, c::SOL_SOCKET as _, c::SO_PRIORITY)?;
+        Ok(priority as u32)
+    }
+
     #[inline]
     pub(crate) fn set_socket_timeout(
         fd: BorrowedFd<'_>,
@@ -948,7 +1587,7 @@ pub(crate) mod sockopt {
     }
 
     /// Same as `set_socket_timeout` but uses `timeval` instead of
-    /// `__kernel_timespec` and `_OLD` constants instead of `_NEW`.
+    /// `__kernel_sock_timeval` and `_OLD` constants instead of `_NEW`.
     fn set_socket_timeout_old(
         fd: BorrowedFd<'_>,
         id: Timeout,
@@ -971,7 +1610,7 @@ pub(crate) mod sockopt {
             Timeout::Recv => SO_RCVTIMEO_NEW,
             Timeout::Send => SO_SNDTIMEO_NEW,
         };
-        let time: __kernel_timespec = match getsockopt(fd, c::SOL_SOCKET, optname) {
+        let time: __kernel_sock_timeval = match getsockopt(fd, c::SOL_SOCKET, optname) {
             Err(io::Errno::NOPROTOOPT) if SO_RCVTIMEO_NEW != SO_RCVTIMEO_OLD => {
                 return get_socket_timeout_old(fd, id)
             }
@@ -981,7 +1620,7 @@ pub(crate) mod sockopt {
     }
 
     /// Same as `get_socket_timeout` but uses `timeval` instead of
-    /// `__kernel_timespec` and `_OLD` constants instead of `_NEW`.
+    /// `__kernel_sock_timeval` and `_OLD` constants instead of `_NEW`.
     fn get_socket_timeout_old(fd: BorrowedFd<'_>, id: Timeout) -> io::Result<Option<Duration>> {
         let optname = match id {
             Timeout::Recv => SO_RCVTIMEO_OLD,
@@ -991,14 +1630,15 @@ pub(crate) mod sockopt {
         Ok(duration_from_linux_old(time))
     }
 
-    /// Convert a C `timespec` to a Rust `Option<Duration>`.
+    /// Convert a C `__kernel_sock_timeval` to a Rust `Option<Duration>`.
     #[inline]
-    fn duration_from_linux(time: __kernel_timespec) -> Option<Duration> {
-        if time.tv_sec == 0 && time.tv_nsec == 0 {
+    fn duration_from_linux(time: __kernel_sock_timeval) -> Option<Duration> {
+        if time.tv_sec == 0 && time.tv_usec == 0 {
             None
         } else {
             Some(
-                Duration::from_secs(time.tv_sec as u64) + Duration::from_nanos(time.tv_nsec as u64),
+                Duration::from_secs(time.tv_sec as u64)
+                    + Duration::from_micros(time.tv_usec as u64),
             )
         }
     }
@@ -1015,26 +1655,20 @@ pub(crate) mod sockopt {
         }
     }
 
-    /// Convert a Rust `Option<Duration>` to a C `timespec`.
+    /// Convert a Rust `Option<Duration>` to a C `__kernel_sock_timeval`.
     #[inline]
-    fn duration_to_linux(timeout: Option<Duration>) -> io::Result<__kernel_timespec> {
+    fn duration_to_linux(timeout: Option<Duration>) -> io::Result<__kernel_sock_timeval> {
         Ok(match timeout {
             Some(timeout) => {
-                if timeout == DURATION_ZERO {
-                    return Err(io::Errno::INVAL);
-                }
-                let mut timeout = __kernel_timespec {
-                    tv_sec: timeout.as_secs().try_into().unwrap_or(i64::MAX),
-                    tv_nsec: timeout.subsec_nanos().into(),
-                };
-                if timeout.tv_sec == 0 && timeout.tv_nsec == 0 {
-                    timeout.tv_nsec = 1;
+                let (tv_sec, tv_usec) = duration_to_secs_micros(timeout)?;
+                __kernel_sock_timeval {
+                    tv_sec: tv_sec.try_into().unwrap_or(i64::MAX),
+                    tv_usec: tv_usec.into(),
                 }
-                timeout
             }
-            None => __kernel_timespec {
+            None => __kernel_sock_timeval {
                 tv_sec: 0,
-                tv_nsec: 0,
+                tv_usec: 0,
             },
         })
     }
@@ -1043,20 +1677,11 @@ pub(crate) mod sockopt {
     fn duration_to_linux_old(timeout: Option<Duration>) -> io::Result<timeval> {
         Ok(match timeout {
             Some(timeout) => {
-                if timeout == DURATION_ZERO {
-                    return Err(io::Errno::INVAL);
+                let (tv_sec, tv_usec) = duration_to_secs_micros(timeout)?;
+                timeval {
+                    tv_sec: tv_sec.try_into().unwrap_or(c::c_long::MAX),
+                    tv_usec: tv_usec as _,
                 }
-
-                // `subsec_micros` rounds down, so we use `subsec_nanos` and
-                // manually round up.
-                let mut timeout = timeval {
-                    tv_sec: timeout.as_secs().try_into().unwrap_or(c::c_long::MAX),
-                    tv_usec: ((timeout.subsec_nanos() + 999) / 1000) as _,
-                };
-                if timeout.tv_sec == 0 && timeout.tv_usec == 0 {
-                    timeout.tv_usec = 1;
-                }
-                timeout
             }
             None => timeval {
                 tv_sec: 0,
@@ -1065,6 +1690,25 @@ pub(crate) mod sockopt {
         })
     }
 
+    /// Split a non-zero `Duration` into seconds and microseconds, rounding
+    /// up, so that small durations don't become zero, which means "no
+    /// timeout".
+    fn duration_to_secs_micros(timeout: Duration) -> io::Result<(u64, u32)> {
+        if timeout == DURATION_ZERO {
+            return Err(io::Errno::INVAL);
+        }
+
+        // `subsec_micros` rounds down, so we use `subsec_nanos` and manually
+        // round up, carrying into the seconds if needed.
+        let mut secs = timeout.as_secs();
+        let mut micros = (timeout.subsec_nanos() + 999) / 1000;
+        if micros == 1_000_000 {
+            secs = secs.saturating_add(1);
+            micros = 0;
+        }
+        Ok((secs, micros))
+    }
+
     #[inline]
     pub(crate) fn set_ip_ttl(fd: BorrowedFd<'_>, ttl: u32) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_IP as _, c::IP_TTL, ttl)
@@ -1075,6 +1719,17 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IP as _, c::IP_TTL)
     }
 
+    #[inline]
+    pub(crate) fn set_ip_tos(fd: BorrowedFd<'_>, tos: u8) -> io::Result<()> {
+        setsockopt(fd, c::IPPROTO_IP as _, c::IP_TOS, c::c_int::from(tos))
+    }
+
+    #[inline]
+    pub(crate) fn get_ip_tos(fd: BorrowedFd<'_>) -> io::Result<u8> {
+        let tos: c::c_int = getsockopt(fd, c::IPPROTO_IP as _, c::IP_TOS)?;
+        Ok(tos as u8)
+    }
+
     #[inline]
     pub(crate) fn set_ipv6_v6only(fd: BorrowedFd<'_>, only_v6: bool) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY, from_bool(only_v6))
@@ -1085,6 +1740,18 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_V6ONLY).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_ipv6_tclass(fd: BorrowedFd<'_>, tclass: u32) -> io::Result<()> {
+        let tclass: c::c_int = tclass.try_into().map_err(|_| io::Errno::INVAL)?;
+        setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_TCLASS, tclass)
+    }
+
+    #[inline]
+    pub(crate) fn get_ipv6_tclass(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        let tclass: c::c_int = getsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_TCLASS)?;
+        Ok(tclass as u32)
+    }
+
     #[inline]
     pub(crate) fn set_ip_multicast_loop(
         fd: BorrowedFd<'_>,
@@ -1189,6 +1856,74 @@ pub(crate) mod sockopt {
         setsockopt(fd, c::IPPROTO_IPV6 as _, c::IPV6_DROP_MEMBERSHIP, mreq)
     }
 
+    #[inline]
+    pub(crate) fn get_ip_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        getsockopt(fd, c::IPPROTO_IP as _, linux_raw_sys::general::IP_MTU)
+    }
+
+    #[inline]
+    pub(crate) fn set_ip_mtu_discover(fd: BorrowedFd<'_>, value: IpMtuDiscover) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IP as _,
+            linux_raw_sys::general::IP_MTU_DISCOVER,
+            value as c::c_int,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_ipv6_mtu(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        getsockopt(fd, c::IPPROTO_IPV6 as _, linux_raw_sys::general::IPV6_MTU)
+    }
+
+    #[inline]
+    pub(crate) fn set_ipv6_mtu_discover(
+        fd: BorrowedFd<'_>,
+        value: IpMtuDiscover,
+    ) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            linux_raw_sys::general::IPV6_MTU_DISCOVER,
+            value as c::c_int,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn set_ip_pktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IP as _,
+            linux_raw_sys::general::IP_PKTINFO,
+            from_bool(value),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_ip_pktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_IP as _, linux_raw_sys::general::IP_PKTINFO).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_ipv6_recvpktinfo(fd: BorrowedFd<'_>, value: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            linux_raw_sys::general::IPV6_RECVPKTINFO,
+            from_bool(value),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_ipv6_recvpktinfo(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(
+            fd,
+            c::IPPROTO_IPV6 as _,
+            linux_raw_sys::general::IPV6_RECVPKTINFO,
+        )
+        .map(to_bool)
+    }
+
     #[inline]
     pub(crate) fn set_tcp_nodelay(fd: BorrowedFd<'_>, nodelay: bool) -> io::Result<()> {
         setsockopt(fd, c::IPPROTO_TCP as _, c::TCP_NODELAY, from_bool(nodelay))
@@ -1199,6 +1934,106 @@ pub(crate) mod sockopt {
         getsockopt(fd, c::IPPROTO_TCP as _, c::TCP_NODELAY).map(to_bool)
     }
 
+    #[inline]
+    pub(crate) fn set_tcp_cork(fd: BorrowedFd<'_>, cork: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            linux_raw_sys::general::TCP_CORK,
+            from_bool(cork),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_cork(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(fd, c::IPPROTO_TCP as _, linux_raw_sys::general::TCP_CORK).map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_tcp_quickack(fd: BorrowedFd<'_>, quickack: bool) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            linux_raw_sys::general::TCP_QUICKACK,
+            from_bool(quickack),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_quickack(fd: BorrowedFd<'_>) -> io::Result<bool> {
+        getsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            linux_raw_sys::general::TCP_QUICKACK,
+        )
+        .map(to_bool)
+    }
+
+    #[inline]
+    pub(crate) fn set_tcp_keepidle(fd: BorrowedFd<'_>, duration: Duration) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            linux_raw_sys::general::TCP_KEEPIDLE,
+            duration_to_secs(duration)?,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_keepidle(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let secs: c::c_uint = getsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            linux_raw_sys::general::TCP_KEEPIDLE,
+        )?;
+        Ok(Duration::from_secs(secs.into()))
+    }
+
+    #[inline]
+    pub(crate) fn set_tcp_keepintvl(fd: BorrowedFd<'_>, duration: Duration) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            linux_raw_sys::general::TCP_KEEPINTVL,
+            duration_to_secs(duration)?,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_keepintvl(fd: BorrowedFd<'_>) -> io::Result<Duration> {
+        let secs: c::c_uint = getsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            linux_raw_sys::general::TCP_KEEPINTVL,
+        )?;
+        Ok(Duration::from_secs(secs.into()))
+    }
+
+    #[inline]
+    pub(crate) fn set_tcp_keepcnt(fd: BorrowedFd<'_>, count: u32) -> io::Result<()> {
+        setsockopt(
+            fd,
+            c::IPPROTO_TCP as _,
+            linux_raw_sys::general::TCP_KEEPCNT,
+            count,
+        )
+    }
+
+    #[inline]
+    pub(crate) fn get_tcp_keepcnt(fd: BorrowedFd<'_>) -> io::Result<u32> {
+        getsockopt(fd, c::IPPROTO_TCP as _, linux_raw_sys::general::TCP_KEEPCNT)
+    }
+
+    /// Convert `duration` to a number of seconds, rounding up.
+    #[inline]
+    fn duration_to_secs(duration: Duration) -> io::Result<c::c_uint> {
+        let mut secs = duration.as_secs();
+        if duration.subsec_nanos() != 0 {
+            secs = secs.checked_add(1).ok_or(io::Errno::INVAL)?;
+        }
+        secs.try_into().map_err(|_e| io::Errno::INVAL)
+    }
+
     #[inline]
     fn to_imr(multiaddr: &Ipv4Addr, interface: &Ipv4Addr) -> c::ip_mreq {
         c::ip_mreq {
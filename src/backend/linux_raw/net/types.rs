@@ -125,6 +125,8 @@ impl AddressFamily {
     pub const PHONET: Self = Self(c::AF_PHONET as _);
     /// `AF_IEEE802154`
     pub const IEEE802154: Self = Self(c::AF_IEEE802154 as _);
+    /// `AF_VSOCK`
+    pub const VSOCK: Self = Self(c::AF_VSOCK as _);
 
     /// Constructs a `AddressFamily` from a raw integer.
     #[inline]
@@ -226,13 +228,64 @@ impl Protocol {
     }
 }
 
+/// `NETLINK_*` constants for use with [`socket`] with
+/// [`AddressFamily::NETLINK`].
+///
+/// [`socket`]: crate::net::socket
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct NetlinkFamily(pub(crate) RawProtocol);
+
+#[rustfmt::skip]
+impl NetlinkFamily {
+    /// `NETLINK_ROUTE`
+    pub const ROUTE: Self = Self(linux_raw_sys::netlink::NETLINK_ROUTE as _);
+    /// `NETLINK_USERSOCK`
+    pub const USERSOCK: Self = Self(linux_raw_sys::netlink::NETLINK_USERSOCK as _);
+    /// `NETLINK_SOCK_DIAG`
+    pub const SOCK_DIAG: Self = Self(linux_raw_sys::netlink::NETLINK_SOCK_DIAG as _);
+    /// `NETLINK_XFRM`
+    pub const XFRM: Self = Self(linux_raw_sys::netlink::NETLINK_XFRM as _);
+    /// `NETLINK_AUDIT`
+    pub const AUDIT: Self = Self(linux_raw_sys::netlink::NETLINK_AUDIT as _);
+    /// `NETLINK_CONNECTOR`
+    pub const CONNECTOR: Self = Self(linux_raw_sys::netlink::NETLINK_CONNECTOR as _);
+    /// `NETLINK_NETFILTER`
+    pub const NETFILTER: Self = Self(linux_raw_sys::netlink::NETLINK_NETFILTER as _);
+    /// `NETLINK_KOBJECT_UEVENT`
+    pub const KOBJECT_UEVENT: Self = Self(linux_raw_sys::netlink::NETLINK_KOBJECT_UEVENT as _);
+    /// `NETLINK_GENERIC`
+    pub const GENERIC: Self = Self(linux_raw_sys::netlink::NETLINK_GENERIC as _);
+    /// `NETLINK_CRYPTO`
+    pub const CRYPTO: Self = Self(linux_raw_sys::netlink::NETLINK_CRYPTO as _);
+
+    /// Constructs a `NetlinkFamily` from a raw integer.
+    #[inline]
+    pub const fn from_raw(raw: RawProtocol) -> Self {
+        Self(raw)
+    }
+
+    /// Returns the raw integer for this `NetlinkFamily`.
+    #[inline]
+    pub const fn as_raw(self) -> RawProtocol {
+        self.0
+    }
+}
+
+impl From<NetlinkFamily> for Protocol {
+    #[inline]
+    fn from(family: NetlinkFamily) -> Self {
+        Self(family.0)
+    }
+}
+
 /// `SHUT_*` constants for use with [`shutdown`].
 ///
 /// [`shutdown`]: crate::net::shutdown
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 #[repr(u32)]
 pub enum Shutdown {
-    /// `SHUT_WR`—Disable further read operations.
+    /// `SHUT_RD`—Disable further read operations.
     Read = c::SHUT_RD,
     /// `SHUT_WR`—Disable further write operations.
     Write = c::SHUT_WR,
@@ -254,9 +307,11 @@ bitflags! {
 }
 
 bitflags! {
-    /// `SOCK_*` constants for use with [`socket`].
+    /// `SOCK_*` constants for use with [`socket_with`] and
+    /// [`socketpair_with`].
     ///
-    /// [`socket`]: crate::net::socket
+    /// [`socket_with`]: crate::net::socket_with
+    /// [`socketpair_with`]: crate::net::socketpair_with
     pub struct SocketFlags: c::c_uint {
         /// `SOCK_NONBLOCK`
         const NONBLOCK = c::O_NONBLOCK;
@@ -280,3 +335,63 @@ pub enum Timeout {
     /// `SO_SNDTIMEO`—Timeout for sending.
     Send = c::SO_SNDTIMEO_NEW,
 }
+
+bitflags! {
+    /// `SOF_TIMESTAMPING_*` constants for use with [`set_socket_timestamping`].
+    ///
+    /// [`set_socket_timestamping`]: crate::net::sockopt::set_socket_timestamping
+    pub struct TimestampingFlags: c::c_uint {
+        /// `SOF_TIMESTAMPING_TX_HARDWARE`
+        const TX_HARDWARE = 1 << 0;
+        /// `SOF_TIMESTAMPING_TX_SOFTWARE`
+        const TX_SOFTWARE = 1 << 1;
+        /// `SOF_TIMESTAMPING_RX_HARDWARE`
+        const RX_HARDWARE = 1 << 2;
+        /// `SOF_TIMESTAMPING_RX_SOFTWARE`
+        const RX_SOFTWARE = 1 << 3;
+        /// `SOF_TIMESTAMPING_SOFTWARE`
+        const SOFTWARE = 1 << 4;
+        /// `SOF_TIMESTAMPING_SYS_HARDWARE`
+        const SYS_HARDWARE = 1 << 5;
+        /// `SOF_TIMESTAMPING_RAW_HARDWARE`
+        const RAW_HARDWARE = 1 << 6;
+        /// `SOF_TIMESTAMPING_OPT_ID`
+        const OPT_ID = 1 << 7;
+        /// `SOF_TIMESTAMPING_TX_SCHED`
+        const TX_SCHED = 1 << 8;
+        /// `SOF_TIMESTAMPING_TX_ACK`
+        const TX_ACK = 1 << 9;
+        /// `SOF_TIMESTAMPING_OPT_CMSG`
+        const OPT_CMSG = 1 << 10;
+        /// `SOF_TIMESTAMPING_OPT_TSONLY`
+        const OPT_TSONLY = 1 << 11;
+        /// `SOF_TIMESTAMPING_OPT_STATS`
+        const OPT_STATS = 1 << 12;
+        /// `SOF_TIMESTAMPING_OPT_PKTINFO`
+        const OPT_PKTINFO = 1 << 13;
+        /// `SOF_TIMESTAMPING_OPT_TX_SWHW`
+        const OPT_TX_SWHW = 1 << 14;
+    }
+}
+
+/// `IP_PMTUDISC_*` constants for use with [`set_ip_mtu_discover`] and
+/// [`set_ipv6_mtu_discover`].
+///
+/// [`set_ip_mtu_discover`]: crate::net::sockopt::set_ip_mtu_discover
+/// [`set_ipv6_mtu_discover`]: crate::net::sockopt::set_ipv6_mtu_discover
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum IpMtuDiscover {
+    /// `IP_PMTUDISC_DONT`—Never send packets with the "Don't Fragment" flag.
+    Dont = linux_raw_sys::general::IP_PMTUDISC_DONT,
+
+    /// `IP_PMTUDISC_WANT`—Use per-route path MTU discovery hints.
+    Want = linux_raw_sys::general::IP_PMTUDISC_WANT,
+
+    /// `IP_PMTUDISC_DO`—Always send packets with the "Don't Fragment" flag.
+    Do = linux_raw_sys::general::IP_PMTUDISC_DO,
+
+    /// `IP_PMTUDISC_PROBE`—Set the "Don't Fragment" flag, but ignore the
+    /// path MTU.
+    Probe = linux_raw_sys::general::IP_PMTUDISC_PROBE,
+}
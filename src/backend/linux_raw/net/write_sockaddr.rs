@@ -3,7 +3,10 @@
 #![allow(unsafe_code)]
 
 use super::super::c;
-use crate::net::{SocketAddrAny, SocketAddrStorage, SocketAddrUnix, SocketAddrV4, SocketAddrV6};
+use crate::net::{
+    SocketAddrAny, SocketAddrLink, SocketAddrNetlink, SocketAddrStorage, SocketAddrUnix,
+    SocketAddrV4, SocketAddrV6, SocketAddrVsock,
+};
 use core::mem::size_of;
 
 pub(crate) unsafe fn write_sockaddr(
@@ -14,6 +17,9 @@ pub(crate) unsafe fn write_sockaddr(
         SocketAddrAny::V4(v4) => write_sockaddr_v4(v4, storage),
         SocketAddrAny::V6(v6) => write_sockaddr_v6(v6, storage),
         SocketAddrAny::Unix(unix) => write_sockaddr_unix(unix, storage),
+        SocketAddrAny::Link(link) => write_sockaddr_ll(link, storage),
+        SocketAddrAny::Netlink(netlink) => write_sockaddr_nl(netlink, storage),
+        SocketAddrAny::Vsock(vsock) => write_sockaddr_vm(vsock, storage),
     }
 }
 
@@ -58,3 +64,54 @@ unsafe fn write_sockaddr_unix(unix: &SocketAddrUnix, storage: *mut SocketAddrSto
     core::ptr::write(storage.cast(), unix.unix);
     unix.len()
 }
+
+pub(crate) unsafe fn encode_sockaddr_ll(link: &SocketAddrLink) -> c::sockaddr_ll {
+    let mut sll_addr = [0_u8; 8];
+    sll_addr[..link.addr().len()].copy_from_slice(link.addr());
+    c::sockaddr_ll {
+        sll_family: c::AF_PACKET as _,
+        sll_protocol: u16::to_be(link.protocol()),
+        sll_ifindex: link.ifindex() as _,
+        sll_hatype: link.hatype(),
+        sll_pkttype: link.pkttype(),
+        sll_halen: link.addr().len() as _,
+        sll_addr,
+    }
+}
+
+unsafe fn write_sockaddr_ll(link: &SocketAddrLink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_ll(link);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_ll>()
+}
+
+pub(crate) unsafe fn encode_sockaddr_nl(netlink: &SocketAddrNetlink) -> c::sockaddr_nl {
+    c::sockaddr_nl {
+        nl_family: c::AF_NETLINK as _,
+        nl_pad: 0,
+        nl_pid: netlink.pid(),
+        nl_groups: netlink.groups(),
+    }
+}
+
+unsafe fn write_sockaddr_nl(netlink: &SocketAddrNetlink, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_nl(netlink);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_nl>()
+}
+
+pub(crate) unsafe fn encode_sockaddr_vm(vsock: &SocketAddrVsock) -> c::sockaddr_vm {
+    c::sockaddr_vm {
+        svm_family: c::AF_VSOCK as _,
+        svm_reserved1: 0,
+        svm_port: vsock.port(),
+        svm_cid: vsock.cid(),
+        svm_zero: [0; 4],
+    }
+}
+
+unsafe fn write_sockaddr_vm(vsock: &SocketAddrVsock, storage: *mut SocketAddrStorage) -> usize {
+    let encoded = encode_sockaddr_vm(vsock);
+    core::ptr::write(storage.cast(), encoded);
+    size_of::<c::sockaddr_vm>()
+}
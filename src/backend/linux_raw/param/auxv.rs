@@ -12,6 +12,8 @@ use crate::fd::OwnedFd;
 use crate::ffi::CStr;
 #[cfg(not(target_vendor = "mustang"))]
 use crate::fs::{Mode, OFlags};
+#[cfg(feature = "param")]
+use crate::param::AuxvKey;
 use crate::utils::{as_ptr, check_raw_pointer};
 use alloc::vec::Vec;
 use core::ffi::c_void;
@@ -23,7 +25,7 @@ use core::sync::atomic::Ordering::Relaxed;
 use core::sync::atomic::{AtomicPtr, AtomicUsize};
 use linux_raw_sys::general::{
     AT_BASE, AT_CLKTCK, AT_EXECFN, AT_HWCAP, AT_HWCAP2, AT_NULL, AT_PAGESZ, AT_PHDR, AT_PHENT,
-    AT_PHNUM, AT_SYSINFO_EHDR,
+    AT_PHNUM, AT_RANDOM, AT_SECURE, AT_SYSINFO_EHDR,
 };
 
 #[cfg(feature = "param")]
@@ -82,6 +84,60 @@ pub(crate) fn linux_execfn() -> &'static CStr {
     unsafe { CStr::from_ptr(execfn.cast()) }
 }
 
+#[cfg(feature = "param")]
+#[inline]
+pub(crate) fn auxv(key: AuxvKey) -> Option<usize> {
+    let value = match key {
+        AuxvKey::Hwcap => linux_hwcap().0,
+        AuxvKey::Hwcap2 => linux_hwcap().1,
+        AuxvKey::Pagesz => page_size(),
+        AuxvKey::Clktck => clock_ticks_per_second() as usize,
+        AuxvKey::Secure => {
+            // `SECURE` starts out as `usize::MAX`, because zero is a
+            // meaningful value.
+            let mut secure = SECURE.load(Relaxed);
+
+            if secure == usize::MAX {
+                init_from_proc_self_auxv();
+                secure = SECURE.load(Relaxed);
+            }
+
+            return if secure == usize::MAX {
+                None
+            } else {
+                Some(secure)
+            };
+        }
+        AuxvKey::Random => {
+            let mut random = RANDOM.load(Relaxed);
+
+            if random.is_null() {
+                init_from_proc_self_auxv();
+                random = RANDOM.load(Relaxed);
+            }
+
+            random as usize
+        }
+        AuxvKey::Execfn => {
+            let mut execfn = EXECFN.load(Relaxed);
+
+            if execfn.is_null() {
+                init_from_proc_self_auxv();
+                execfn = EXECFN.load(Relaxed);
+            }
+
+            execfn as usize
+        }
+        AuxvKey::SysinfoEhdr => sysinfo_ehdr() as usize,
+    };
+
+    if value == 0 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
 #[cfg(feature = "runtime")]
 #[inline]
 pub(crate) fn exe_phdrs() -> (*const c::c_void, usize) {
@@ -129,6 +185,8 @@ static SYSINFO_EHDR: AtomicPtr<Elf_Ehdr> = AtomicPtr::new(null_mut());
 static PHDR: AtomicPtr<Elf_Phdr> = AtomicPtr::new(null_mut());
 static PHNUM: AtomicUsize = AtomicUsize::new(0);
 static EXECFN: AtomicPtr<c::c_char> = AtomicPtr::new(null_mut());
+static SECURE: AtomicUsize = AtomicUsize::new(usize::MAX);
+static RANDOM: AtomicPtr<u8> = AtomicPtr::new(null_mut());
 
 /// On non-Mustang platforms, we read the aux vector from /proc/self/auxv.
 #[cfg(not(target_vendor = "mustang"))]
@@ -199,6 +257,8 @@ unsafe fn init_from_auxp(mut auxp: *const Elf_auxv_t) -> Option<()> {
     let mut execfn = null_mut();
     let mut sysinfo_ehdr = null_mut();
     let mut phent = 0;
+    let mut secure = 0;
+    let mut random = null_mut();
 
     loop {
         let Elf_auxv_t { a_type, a_val } = read_unaligned(auxp);
@@ -214,6 +274,8 @@ unsafe fn init_from_auxp(mut auxp: *const Elf_auxv_t) -> Option<()> {
             AT_EXECFN => execfn = check_raw_pointer::<c::c_char>(a_val as *mut _)?.as_ptr(),
             AT_BASE => check_interpreter_base(a_val.cast())?,
             AT_SYSINFO_EHDR => sysinfo_ehdr = check_vdso_base(a_val as *mut _)?.as_ptr(),
+            AT_SECURE => secure = a_val as usize,
+            AT_RANDOM => random = check_raw_pointer::<u8>(a_val as *mut _)?.as_ptr(),
             AT_NULL => break,
             _ => (),
         }
@@ -232,6 +294,8 @@ unsafe fn init_from_auxp(mut auxp: *const Elf_auxv_t) -> Option<()> {
     PHNUM.store(phnum, Relaxed);
     EXECFN.store(execfn, Relaxed);
     SYSINFO_EHDR.store(sysinfo_ehdr, Relaxed);
+    SECURE.store(secure, Relaxed);
+    RANDOM.store(random, Relaxed);
 
     Some(())
 }
@@ -8,6 +8,8 @@
 use super::super::elf::*;
 #[cfg(feature = "param")]
 use crate::ffi::CStr;
+#[cfg(feature = "param")]
+use crate::param::AuxvKey;
 #[cfg(feature = "runtime")]
 use core::slice;
 
@@ -50,6 +52,18 @@ pub(crate) fn linux_execfn() -> &'static CStr {
     }
 }
 
+#[cfg(feature = "param")]
+#[inline]
+pub(crate) fn auxv(key: AuxvKey) -> Option<usize> {
+    let libc_getauxval = getauxval.get()?;
+    let value = unsafe { libc_getauxval(key as _) } as usize;
+    if value == 0 && key != AuxvKey::Secure {
+        None
+    } else {
+        Some(value)
+    }
+}
+
 #[cfg(feature = "runtime")]
 #[inline]
 pub(crate) fn exe_phdrs() -> (*const libc::c_void, usize) {
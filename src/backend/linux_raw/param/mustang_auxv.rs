@@ -9,6 +9,8 @@ use super::super::c;
 use super::super::elf::*;
 #[cfg(feature = "param")]
 use crate::ffi::CStr;
+#[cfg(feature = "param")]
+use crate::param::AuxvKey;
 use core::ffi::c_void;
 use core::mem::size_of;
 use core::ptr::{null, read};
@@ -16,7 +18,7 @@ use core::ptr::{null, read};
 use core::slice;
 use linux_raw_sys::general::{
     AT_CLKTCK, AT_EXECFN, AT_HWCAP, AT_HWCAP2, AT_NULL, AT_PAGESZ, AT_PHDR, AT_PHENT, AT_PHNUM,
-    AT_SYSINFO_EHDR,
+    AT_RANDOM, AT_SECURE, AT_SYSINFO_EHDR,
 };
 
 #[cfg(feature = "param")]
@@ -48,6 +50,30 @@ pub(crate) fn linux_execfn() -> &'static CStr {
     unsafe { CStr::from_ptr(EXECFN.0.cast()) }
 }
 
+#[cfg(feature = "param")]
+#[inline]
+pub(crate) fn auxv(key: AuxvKey) -> Option<usize> {
+    // Safety: This is initialized during program startup.
+    let value = unsafe {
+        match key {
+            AuxvKey::Hwcap => HWCAP,
+            AuxvKey::Hwcap2 => HWCAP2,
+            AuxvKey::Pagesz => PAGE_SIZE,
+            AuxvKey::Clktck => CLOCK_TICKS_PER_SECOND,
+            AuxvKey::Secure => return Some(SECURE),
+            AuxvKey::Random => RANDOM.0 as usize,
+            AuxvKey::Execfn => EXECFN.0 as usize,
+            AuxvKey::SysinfoEhdr => SYSINFO_EHDR.0 as usize,
+        }
+    };
+
+    if value == 0 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
 #[cfg(feature = "runtime")]
 #[inline]
 pub(crate) fn exe_phdrs() -> (*const c_void, usize) {
@@ -97,6 +123,8 @@ static mut SYSINFO_EHDR: SyncConstPtr<Elf_Ehdr> = unsafe { SyncConstPtr::new(nul
 static mut PHDR: SyncConstPtr<Elf_Phdr> = unsafe { SyncConstPtr::new(null()) };
 static mut PHNUM: usize = 0;
 static mut EXECFN: SyncConstPtr<c::c_char> = unsafe { SyncConstPtr::new(null()) };
+static mut SECURE: usize = 0;
+static mut RANDOM: SyncConstPtr<u8> = unsafe { SyncConstPtr::new(null()) };
 
 /// On mustang, we export a function to be called during initialization, and
 /// passed a pointer to the original environment variable block set up by the
@@ -138,6 +166,8 @@ unsafe fn init_from_auxp(mut auxp: *const Elf_auxv_t) {
             AT_PHENT => assert_eq!(a_val as usize, size_of::<Elf_Phdr>()),
             AT_EXECFN => EXECFN = SyncConstPtr::new(a_val.cast::<c::c_char>()),
             AT_SYSINFO_EHDR => SYSINFO_EHDR = SyncConstPtr::new(a_val.cast::<Elf_Ehdr>()),
+            AT_SECURE => SECURE = a_val as usize,
+            AT_RANDOM => RANDOM = SyncConstPtr::new(a_val.cast::<u8>()),
             AT_NULL => break,
             _ => (),
         }
@@ -0,0 +1,41 @@
+use super::types::RawSigSet;
+use crate::process::Signal;
+use core::mem::size_of_val;
+
+#[inline]
+pub(crate) fn sigemptyset(set: &mut RawSigSet) {
+    for element in &mut set.bits {
+        *element = 0;
+    }
+}
+
+#[inline]
+pub(crate) fn sigfillset(set: &mut RawSigSet) {
+    for element in &mut set.bits {
+        *element = !0;
+    }
+}
+
+#[inline]
+pub(crate) fn sigaddset(set: &mut RawSigSet, sig: Signal) {
+    let size_in_bits = 8 * size_of_val(&set.bits[0]);
+    let bit = sig as usize - 1;
+    let (idx, offset) = (bit / size_in_bits, bit % size_in_bits);
+    set.bits[idx] |= 1 << offset
+}
+
+#[inline]
+pub(crate) fn sigdelset(set: &mut RawSigSet, sig: Signal) {
+    let size_in_bits = 8 * size_of_val(&set.bits[0]);
+    let bit = sig as usize - 1;
+    let (idx, offset) = (bit / size_in_bits, bit % size_in_bits);
+    set.bits[idx] &= !(1 << offset)
+}
+
+#[inline]
+pub(crate) fn sigismember(set: &RawSigSet, sig: Signal) -> bool {
+    let size_in_bits = 8 * size_of_val(&set.bits[0]);
+    let bit = sig as usize - 1;
+    let (idx, offset) = (bit / size_in_bits, bit % size_in_bits);
+    (set.bits[idx] & (1 << offset)) != 0
+}
@@ -8,11 +8,11 @@
 
 use super::super::c;
 use super::super::conv::{
-    by_mut, by_ref, c_int, c_uint, negative_pid, pass_usize, ret, ret_c_int, ret_c_uint,
+    by_mut, by_ref, c_int, c_uint, negative_pid, pass_usize, ret, ret_c_int, ret_c_uint, ret_error,
     ret_infallible, ret_usize, ret_usize_infallible, size_of, slice_just_addr, slice_mut, zero,
 };
 use super::types::{RawCpuSet, RawUname};
-use crate::fd::BorrowedFd;
+use crate::fd::{BorrowedFd, FromRawFd, OwnedFd};
 use crate::ffi::CStr;
 use crate::io;
 use crate::process::{
@@ -25,8 +25,25 @@ use core::num::NonZeroU32;
 use core::ptr::{null, null_mut};
 use linux_raw_sys::general::{
     __kernel_gid_t, __kernel_pid_t, __kernel_uid_t, membarrier_cmd, membarrier_cmd_flag, rlimit,
-    rlimit64, PRIO_PGRP, PRIO_PROCESS, PRIO_USER, RLIM64_INFINITY, RLIM_INFINITY,
+    rlimit64, CLONE_PIDFD, PRIO_PGRP, PRIO_PROCESS, PRIO_USER, RLIM64_INFINITY, RLIM_INFINITY,
+    SIGCHLD,
 };
+#[cfg(feature = "process")]
+use {
+    super::super::conv::{no_fd, ret_owned_fd},
+    crate::process::{
+        perf_event_attr, seccomp_notif_sizes, PerfEventFlags, PerfIocFlags, PidfdFlags,
+        ProcessMreleaseFlags, SeccompFilterFlags, SockFprog,
+    },
+};
+
+/// `SECCOMP_SET_MODE_*`
+#[cfg(feature = "process")]
+const SECCOMP_SET_MODE_STRICT: u32 = 0;
+#[cfg(feature = "process")]
+const SECCOMP_SET_MODE_FILTER: u32 = 1;
+#[cfg(feature = "process")]
+const SECCOMP_GET_NOTIF_SIZES: u32 = 3;
 
 #[inline]
 pub(crate) fn chdir(filename: &CStr) -> io::Result<()> {
@@ -111,6 +128,17 @@ pub(crate) fn getpgid(pid: Option<Pid>) -> io::Result<Pid> {
     }
 }
 
+#[inline]
+pub(crate) fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_setpgid,
+            c_uint(Pid::as_raw(pid)),
+            c_uint(Pid::as_raw(pgid))
+        ))
+    }
+}
+
 #[inline]
 pub(crate) fn getpgrp() -> Pid {
     // Use the `getpgrp` syscall if available.
@@ -516,12 +544,234 @@ pub(crate) fn _waitpid(
     }
 }
 
-#[cfg(feature = "runtime")]
+#[cfg(any(feature = "process", feature = "runtime"))]
 #[inline]
 pub(crate) fn exit_group(code: c::c_int) -> ! {
     unsafe { syscall_noreturn!(__NR_exit_group, c_int(code)) }
 }
 
+#[inline]
+pub(crate) unsafe fn fork() -> io::Result<Option<Pid>> {
+    let pid = ret_c_uint(syscall_readonly!(
+        __NR_clone,
+        c_uint(SIGCHLD),
+        zero(),
+        zero(),
+        zero(),
+        zero()
+    ))?;
+    Ok(Pid::from_raw(pid))
+}
+
+#[inline]
+pub(crate) unsafe fn fork_pidfd() -> io::Result<Option<(Pid, OwnedFd)>> {
+    // With `CLONE_PIDFD`, the kernel stores the pidfd through the
+    // `parent_tid` pointer, which is the third argument on all the
+    // architectures we support.
+    let mut pidfd = MaybeUninit::<c::c_int>::uninit();
+    let pid = ret_c_uint(syscall!(
+        __NR_clone,
+        c_uint(CLONE_PIDFD | SIGCHLD),
+        zero(),
+        &mut pidfd,
+        zero(),
+        zero()
+    ))?;
+    Ok(Pid::from_raw(pid).map(|pid| (pid, OwnedFd::from_raw_fd(pidfd.assume_init()))))
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_pidfd_open,
+            pid,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn process_mrelease(
+    pidfd: BorrowedFd<'_>,
+    flags: ProcessMreleaseFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_process_mrelease,
+            pidfd,
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn seccomp_set_mode_strict() -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_seccomp,
+            c_uint(SECCOMP_SET_MODE_STRICT),
+            c_uint(0),
+            zero()
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn seccomp_set_mode_filter(
+    prog: &SockFprog<'_>,
+    flags: SeccompFilterFlags,
+) -> io::Result<c::c_uint> {
+    unsafe {
+        ret_c_uint(syscall_readonly!(
+            __NR_seccomp,
+            c_uint(SECCOMP_SET_MODE_FILTER),
+            c_uint(flags.bits()),
+            by_ref(prog)
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn seccomp_set_mode_filter_listener(
+    prog: &SockFprog<'_>,
+    flags: SeccompFilterFlags,
+) -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_seccomp,
+            c_uint(SECCOMP_SET_MODE_FILTER),
+            c_uint(flags.bits()),
+            by_ref(prog)
+        ))
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn seccomp_get_notif_sizes() -> io::Result<seccomp_notif_sizes> {
+    let mut sizes = seccomp_notif_sizes::default();
+    unsafe {
+        ret(syscall!(
+            __NR_seccomp,
+            c_uint(SECCOMP_GET_NOTIF_SIZES),
+            c_uint(0),
+            by_mut(&mut sizes)
+        ))?;
+    }
+    Ok(sizes)
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) unsafe fn seccomp_notif_ioctl(
+    listener_fd: BorrowedFd<'_>,
+    request: u32,
+    arg: *mut c::c_void,
+) -> io::Result<()> {
+    ret(syscall!(__NR_ioctl, listener_fd, c_uint(request), arg))
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) unsafe fn ptrace(
+    request: u32,
+    pid: Option<Pid>,
+    addr: usize,
+    data: usize,
+) -> io::Result<()> {
+    ret(syscall!(
+        __NR_ptrace,
+        c_uint(request),
+        c_uint(Pid::as_raw(pid)),
+        pass_usize(addr),
+        pass_usize(data)
+    ))
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) unsafe fn kcmp(
+    pid1: Pid,
+    pid2: Pid,
+    type_: u32,
+    idx1: usize,
+    idx2: usize,
+) -> io::Result<c::c_uint> {
+    ret_c_uint(syscall_readonly!(
+        __NR_kcmp,
+        pid1,
+        pid2,
+        c_uint(type_),
+        pass_usize(idx1),
+        pass_usize(idx2)
+    ))
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn perf_event_open(
+    attr: &perf_event_attr,
+    pid: c::c_int,
+    cpu: c::c_int,
+    group_fd: Option<BorrowedFd<'_>>,
+    flags: PerfEventFlags,
+) -> io::Result<OwnedFd> {
+    // The kernel writes the size it supports back to `attr` on `E2BIG`.
+    let mut attr = *attr;
+    unsafe {
+        match group_fd {
+            Some(group_fd) => ret_owned_fd(syscall!(
+                __NR_perf_event_open,
+                by_mut(&mut attr),
+                c_int(pid),
+                c_int(cpu),
+                group_fd,
+                c_uint(flags.bits())
+            )),
+            None => ret_owned_fd(syscall!(
+                __NR_perf_event_open,
+                by_mut(&mut attr),
+                c_int(pid),
+                c_int(cpu),
+                no_fd(),
+                c_uint(flags.bits())
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "process")]
+#[inline]
+pub(crate) fn perf_event_ioctl(
+    fd: BorrowedFd<'_>,
+    request: u32,
+    flags: PerfIocFlags,
+) -> io::Result<()> {
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_ioctl,
+            fd,
+            c_uint(request),
+            c_uint(flags.bits())
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn execve(
+    path: &CStr,
+    args: *const *const u8,
+    env_vars: *const *const u8,
+) -> io::Errno {
+    ret_error(syscall_readonly!(__NR_execve, path, args, env_vars))
+}
+
 #[inline]
 pub(crate) fn setsid() -> io::Result<Pid> {
     unsafe {
@@ -533,11 +783,27 @@ pub(crate) fn setsid() -> io::Result<Pid> {
     }
 }
 
+#[inline]
+pub(crate) fn getsid(pid: Option<Pid>) -> io::Result<Pid> {
+    unsafe {
+        let sid: i32 =
+            ret_usize(syscall_readonly!(__NR_getsid, c_uint(Pid::as_raw(pid))))? as __kernel_pid_t;
+        Ok(Pid::from_raw_nonzero(RawNonZeroPid::new_unchecked(
+            sid as u32,
+        )))
+    }
+}
+
 #[inline]
 pub(crate) fn kill_process(pid: Pid, sig: Signal) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_kill, pid, sig)) }
 }
 
+#[inline]
+pub(crate) fn test_kill_process(pid: Pid) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_kill, pid, pass_usize(0))) }
+}
+
 #[inline]
 pub(crate) fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
     unsafe { ret(syscall_readonly!(__NR_kill, negative_pid(pid), sig)) }
@@ -244,3 +244,46 @@ pub(crate) fn raw_cpu_set_new() -> RawCpuSet {
 }
 
 pub(crate) const CPU_SETSIZE: usize = 8 * core::mem::size_of::<RawCpuSet>();
+
+/// The number of signals in the kernel's `sigset_t`.
+#[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+const NSIG: usize = 128;
+/// The number of signals in the kernel's `sigset_t`.
+#[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+const NSIG: usize = 64;
+
+const SIGSET_WORDS: usize = NSIG / (8 * core::mem::size_of::<c::c_ulong>());
+
+/// The kernel's `sigset_t`, as used by the `rt_sig*` system calls.
+///
+/// This differs from the `sigset_t` in `linux_raw_sys`, which is the older
+/// `sigset_t` on some architectures.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct RawSigSet {
+    pub(crate) bits: [c::c_ulong; SIGSET_WORDS],
+}
+
+#[inline]
+pub(crate) fn raw_sig_set_new() -> RawSigSet {
+    RawSigSet {
+        bits: [0; SIGSET_WORDS],
+    }
+}
+
+/// `SIG_*` constants for use with [`sigprocmask`].
+///
+/// [`sigprocmask`]: crate::thread::sigprocmask
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum SigmaskHow {
+    /// `SIG_BLOCK`
+    #[doc(alias = "SIG_BLOCK")]
+    Block = linux_raw_sys::general::SIG_BLOCK,
+    /// `SIG_UNBLOCK`
+    #[doc(alias = "SIG_UNBLOCK")]
+    Unblock = linux_raw_sys::general::SIG_UNBLOCK,
+    /// `SIG_SETMASK`
+    #[doc(alias = "SIG_SETMASK")]
+    SetMask = linux_raw_sys::general::SIG_SETMASK,
+}
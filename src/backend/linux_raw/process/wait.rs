@@ -28,6 +28,11 @@ pub(crate) fn WTERMSIG(status: u32) -> u32 {
     status & 0x7f
 }
 
+#[inline]
+pub(crate) fn WCOREDUMP(status: u32) -> bool {
+    (status & 0x80) != 0
+}
+
 #[inline]
 pub(crate) fn WIFEXITED(status: u32) -> bool {
     (status & 0x7f) == 0
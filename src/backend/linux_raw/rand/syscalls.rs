@@ -6,12 +6,155 @@
 #![allow(unsafe_code)]
 #![allow(clippy::undocumented_unsafe_blocks)]
 
-use super::super::conv::{ret_usize, slice_mut};
+use super::super::conv::{c_uint, raw_fd, ret_owned_fd, ret_usize, slice_mut};
+use crate::fd::OwnedFd;
 use crate::io;
 use crate::rand::GetRandomFlags;
+use linux_raw_sys::general::{AT_FDCWD, O_CLOEXEC, O_RDONLY};
+#[cfg(feature = "std")]
+use {
+    super::super::c,
+    super::super::conv::{no_fd, pass_usize, ret, ret_void_star, zero},
+    super::super::vdso_wrappers::{vgetrandom, VgetrandomOpaqueParams, VgetrandomType},
+    core::cell::RefCell,
+    core::ptr::null_mut,
+};
 
 #[inline]
 pub(crate) fn getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usize> {
     let (buf_addr_mut, buf_len) = slice_mut(buf);
     unsafe { ret_usize(syscall!(__NR_getrandom, buf_addr_mut, buf_len, flags)) }
 }
+
+/// `/dev/urandom` is the fallback for kernels older than 3.17, which don't
+/// have `getrandom`.
+#[inline]
+pub(crate) fn open_dev_urandom() -> io::Result<OwnedFd> {
+    unsafe {
+        ret_owned_fd(syscall_readonly!(
+            __NR_openat,
+            raw_fd(AT_FDCWD),
+            cstr!("/dev/urandom"),
+            c_uint(O_RDONLY | O_CLOEXEC)
+        ))
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn vdso_getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usize> {
+    let vgetrandom = match vgetrandom() {
+        Some(vgetrandom) => vgetrandom,
+        None => return getrandom(buf, flags),
+    };
+
+    // If the thread is exiting and its state is gone, or if we're reentering
+    // from a signal handler while the state is in use, use the syscall.
+    let result = VGETRANDOM_STATE.try_with(|state| {
+        let mut state = state.try_borrow_mut().ok()?;
+        if state.is_none() {
+            *state = VgetrandomState::new(vgetrandom);
+        }
+        let state = state.as_ref()?;
+        Some(state.getrandom(vgetrandom, buf, flags))
+    });
+    match result {
+        Ok(Some(result)) => result,
+        Ok(None) | Err(_) => getrandom(buf, flags),
+    }
+}
+
+/// Without `std`, there's no thread-local storage to hold the vDSO's state,
+/// so always use the syscall.
+#[cfg(not(feature = "std"))]
+#[inline]
+pub(crate) fn vdso_getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usize> {
+    getrandom(buf, flags)
+}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static VGETRANDOM_STATE: RefCell<Option<VgetrandomState>> = RefCell::new(None);
+}
+
+/// A thread's opaque state for the vDSO `getrandom`, allocated as the vDSO
+/// asks.
+#[cfg(feature = "std")]
+struct VgetrandomState {
+    ptr: *mut c::c_void,
+    len: usize,
+}
+
+#[cfg(feature = "std")]
+impl VgetrandomState {
+    fn new(vgetrandom: VgetrandomType) -> Option<Self> {
+        let mut params = VgetrandomOpaqueParams::default();
+        unsafe {
+            // Passing a null buffer and an opaque length of `!0` asks the
+            // vDSO for the size of the state and how to `mmap` it.
+            let params_ptr = (&mut params as *mut VgetrandomOpaqueParams).cast();
+            if vgetrandom(null_mut(), 0, 0, params_ptr, !0) != 0 {
+                return None;
+            }
+            let len = params.size_of_opaque_state as usize;
+
+            // The state must not straddle a page boundary, which a mapping
+            // of its own guarantees.
+            #[cfg(target_pointer_width = "32")]
+            let ptr = ret_void_star(syscall!(
+                __NR_mmap2,
+                zero(),
+                pass_usize(len),
+                c_uint(params.mmap_prot),
+                c_uint(params.mmap_flags),
+                no_fd(),
+                zero()
+            ));
+            #[cfg(target_pointer_width = "64")]
+            let ptr = ret_void_star(syscall!(
+                __NR_mmap,
+                zero(),
+                pass_usize(len),
+                c_uint(params.mmap_prot),
+                c_uint(params.mmap_flags),
+                no_fd(),
+                zero()
+            ));
+
+            Some(Self {
+                ptr: ptr.ok()?,
+                len,
+            })
+        }
+    }
+
+    fn getrandom(
+        &self,
+        vgetrandom: VgetrandomType,
+        buf: &mut [u8],
+        flags: GetRandomFlags,
+    ) -> io::Result<usize> {
+        let r = unsafe {
+            vgetrandom(
+                buf.as_mut_ptr().cast(),
+                buf.len(),
+                flags.bits(),
+                self.ptr,
+                self.len,
+            )
+        };
+        if r < 0 {
+            Err(io::Errno::from_raw_os_error(-r as i32))
+        } else {
+            Ok(r as usize)
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for VgetrandomState {
+    fn drop(&mut self) {
+        unsafe {
+            ret(syscall!(__NR_munmap, self.ptr, pass_usize(self.len))).ok();
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub(crate) mod syscalls;
 pub(crate) mod tls;
+pub(crate) mod types;
@@ -9,31 +9,26 @@
 use super::super::c;
 #[cfg(target_arch = "x86")]
 use super::super::conv::by_mut;
-use super::super::conv::{c_int, c_uint, ret, ret_c_uint, ret_error, ret_usize_infallible, zero};
+#[cfg(feature = "fs")]
+use super::super::conv::ret_error;
+use super::super::conv::{
+    by_ref, c_int, c_uint, opt_ref, ret, ret_c_int, ret_usize_infallible, size_of,
+};
+use super::super::process::types::RawSigSet;
+use super::super::time::types::Timespec;
+use super::types::{KernelSigaction, SigHandler, Sigaction, Siginfo, Stack};
 #[cfg(feature = "fs")]
 use crate::fd::BorrowedFd;
 use crate::ffi::CStr;
 #[cfg(feature = "fs")]
 use crate::fs::AtFlags;
 use crate::io;
-use crate::process::{Pid, RawNonZeroPid};
-use linux_raw_sys::general::{__kernel_pid_t, PR_SET_NAME, SIGCHLD};
+use crate::process::{Pid, RawNonZeroPid, SigSet, Signal};
+use core::mem::MaybeUninit;
+use linux_raw_sys::general::{__kernel_pid_t, PR_SET_NAME};
 #[cfg(target_arch = "x86_64")]
 use {super::super::conv::ret_infallible, linux_raw_sys::general::ARCH_SET_FS};
 
-#[inline]
-pub(crate) unsafe fn fork() -> io::Result<Option<Pid>> {
-    let pid = ret_c_uint(syscall_readonly!(
-        __NR_clone,
-        c_uint(SIGCHLD),
-        zero(),
-        zero(),
-        zero(),
-        zero()
-    ))?;
-    Ok(Pid::from_raw(pid))
-}
-
 #[cfg(feature = "fs")]
 pub(crate) unsafe fn execveat(
     dirfd: BorrowedFd<'_>,
@@ -52,12 +47,145 @@ pub(crate) unsafe fn execveat(
     ))
 }
 
-pub(crate) unsafe fn execve(
-    path: &CStr,
-    args: *const *const u8,
-    env_vars: *const *const u8,
-) -> io::Errno {
-    ret_error(syscall_readonly!(__NR_execve, path, args, env_vars))
+// On x86-64, the kernel requires signal handlers to have a restorer, which
+// calls `rt_sigreturn` when the handler returns.
+#[cfg(all(asm, target_arch = "x86_64"))]
+core::arch::global_asm!(
+    ".pushsection .text.rustix_x86_64_sigreturn,\"ax\",@progbits",
+    ".p2align 4",
+    ".weak rustix_x86_64_sigreturn",
+    ".hidden rustix_x86_64_sigreturn",
+    ".type rustix_x86_64_sigreturn, @function",
+    "rustix_x86_64_sigreturn:",
+    "mov eax, 15", // `__NR_rt_sigreturn`
+    "syscall",
+    "ud2",
+    ".size rustix_x86_64_sigreturn, .-rustix_x86_64_sigreturn",
+    ".popsection",
+);
+
+#[cfg(all(asm, target_arch = "x86_64"))]
+extern "C" {
+    fn rustix_x86_64_sigreturn();
+}
+
+#[inline]
+pub(crate) unsafe fn sigaction(sig: Signal, new: Option<&Sigaction>) -> io::Result<Sigaction> {
+    let new = match new {
+        Some(new) => {
+            let mut kernel = KernelSigaction::new(new);
+            if !matches!(new.handler, SigHandler::Default | SigHandler::Ignore) {
+                set_restorer(&mut kernel)?;
+            }
+            Some(kernel)
+        }
+        None => None,
+    };
+    let mut old = MaybeUninit::<KernelSigaction>::uninit();
+    ret(syscall!(
+        __NR_rt_sigaction,
+        sig,
+        opt_ref(new.as_ref()),
+        &mut old,
+        size_of::<RawSigSet, _>()
+    ))?;
+    Ok(old.assume_init().to_sigaction())
+}
+
+#[inline]
+pub(crate) unsafe fn sigaltstack(new: Option<Stack>) -> io::Result<Stack> {
+    let new = new.map(Stack::to_raw);
+    let mut old = MaybeUninit::<linux_raw_sys::general::stack_t>::uninit();
+    ret(syscall!(__NR_sigaltstack, opt_ref(new.as_ref()), &mut old))?;
+    Ok(Stack::from_raw(old.assume_init()))
+}
+
+#[inline]
+pub(crate) fn sigtimedwait(set: &SigSet, timeout: Option<Timespec>) -> io::Result<Siginfo> {
+    let mut info = MaybeUninit::<linux_raw_sys::general::siginfo_t>::uninit();
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        let _signum = ret_c_int(syscall!(
+            __NR_rt_sigtimedwait,
+            by_ref(set.as_raw()),
+            &mut info,
+            opt_ref(timeout.as_ref()),
+            size_of::<RawSigSet, _>()
+        ))?;
+        Ok(Siginfo(info.assume_init()))
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        match ret_c_int(syscall!(
+            __NR_rt_sigtimedwait_time64,
+            by_ref(set.as_raw()),
+            &mut info,
+            opt_ref(timeout.as_ref()),
+            size_of::<RawSigSet, _>()
+        )) {
+            Ok(_signum) => (),
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            Err(io::Errno::NOSYS) => sigtimedwait_old(set, timeout, &mut info)?,
+            Err(err) => return Err(err),
+        }
+        Ok(Siginfo(info.assume_init()))
+    }
+}
+
+#[cfg(target_pointer_width = "32")]
+unsafe fn sigtimedwait_old(
+    set: &SigSet,
+    timeout: Option<Timespec>,
+    info: &mut MaybeUninit<linux_raw_sys::general::siginfo_t>,
+) -> io::Result<()> {
+    use core::convert::TryInto;
+    use linux_raw_sys::general::timespec as __kernel_old_timespec;
+
+    let old_timeout = match timeout {
+        Some(timeout) => Some(__kernel_old_timespec {
+            tv_sec: timeout.tv_sec.try_into().map_err(|_| io::Errno::OVERFLOW)?,
+            tv_nsec: timeout.tv_nsec as _,
+        }),
+        None => None,
+    };
+
+    let _signum = ret_c_int(syscall!(
+        __NR_rt_sigtimedwait,
+        by_ref(set.as_raw()),
+        info,
+        opt_ref(old_timeout.as_ref()),
+        size_of::<RawSigSet, _>()
+    ))?;
+    Ok(())
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn set_restorer(kernel: &mut KernelSigaction) -> io::Result<()> {
+    #[cfg(asm)]
+    {
+        kernel.sa_flags |= c::c_ulong::from(linux_raw_sys::general::SA_RESTORER);
+        kernel.sa_restorer = rustix_x86_64_sigreturn as unsafe extern "C" fn() as usize;
+        Ok(())
+    }
+
+    // Without `asm`, we have no way to define a restorer.
+    #[cfg(not(asm))]
+    {
+        let _ = kernel;
+        Err(io::Errno::NOSYS)
+    }
+}
+
+// On other architectures, the kernel provides a default restorer in the vDSO
+// or in a signal page.
+#[cfg(not(target_arch = "x86_64"))]
+#[inline]
+fn set_restorer(_kernel: &mut KernelSigaction) -> io::Result<()> {
+    Ok(())
 }
 
 pub(crate) mod tls {
@@ -0,0 +1,314 @@
+#![allow(unsafe_code)]
+
+use super::super::c;
+use super::super::process::types::RawSigSet;
+use crate::process::{Pid, SigSet, Uid};
+use bitflags::bitflags;
+use core::fmt;
+use core::mem::transmute;
+
+bitflags! {
+    /// `SA_*` constants for use with [`sigaction`].
+    ///
+    /// [`sigaction`]: crate::runtime::sigaction
+    pub struct SigactionFlags: c::c_uint {
+        /// `SA_NOCLDSTOP`
+        const NOCLDSTOP = linux_raw_sys::general::SA_NOCLDSTOP;
+        /// `SA_NOCLDWAIT`
+        const NOCLDWAIT = linux_raw_sys::general::SA_NOCLDWAIT;
+        /// `SA_NODEFER`
+        const NODEFER = linux_raw_sys::general::SA_NODEFER;
+        /// `SA_ONSTACK`
+        const ONSTACK = linux_raw_sys::general::SA_ONSTACK;
+        /// `SA_RESETHAND`
+        const RESETHAND = linux_raw_sys::general::SA_RESETHAND;
+        /// `SA_RESTART`
+        const RESTART = linux_raw_sys::general::SA_RESTART;
+        /// `SA_SIGINFO`
+        const SIGINFO = linux_raw_sys::general::SA_SIGINFO;
+    }
+}
+
+/// A signal disposition, for use with [`sigaction`].
+///
+/// [`sigaction`]: crate::runtime::sigaction
+#[derive(Copy, Clone, Debug)]
+pub enum SigHandler {
+    /// `SIG_DFL`
+    #[doc(alias = "SIG_DFL")]
+    Default,
+
+    /// `SIG_IGN`
+    #[doc(alias = "SIG_IGN")]
+    Ignore,
+
+    /// A handler function, which is passed the signal number.
+    Handler(unsafe extern "C" fn(i32)),
+
+    /// A handler function for use with [`SigactionFlags::SIGINFO`], which is
+    /// passed the signal number, a pointer to a `siginfo_t`, and a pointer
+    /// to a `ucontext_t`.
+    SigInfo(unsafe extern "C" fn(i32, *mut c::c_void, *mut c::c_void)),
+}
+
+/// A signal action, for use with [`sigaction`].
+///
+/// [`sigaction`]: crate::runtime::sigaction
+#[derive(Copy, Clone, Debug)]
+pub struct Sigaction {
+    /// The disposition of the signal.
+    pub handler: SigHandler,
+
+    /// Additional signals to block while the handler runs.
+    pub mask: SigSet,
+
+    /// Flags modifying the behavior of the signal.
+    pub flags: SigactionFlags,
+}
+
+/// The kernel's `struct sigaction`, as used by `rt_sigaction`.
+///
+/// This differs from the `sigaction` in `linux_raw_sys`, which is the older
+/// `struct sigaction` on some architectures.
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub(crate) struct KernelSigaction {
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    pub(crate) sa_handler: usize,
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64")))]
+    pub(crate) sa_flags: c::c_ulong,
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    pub(crate) sa_flags: c::c_uint,
+    #[cfg(any(target_arch = "mips", target_arch = "mips64"))]
+    pub(crate) sa_handler: usize,
+    #[cfg(not(any(target_arch = "mips", target_arch = "mips64", target_arch = "riscv64")))]
+    pub(crate) sa_restorer: usize,
+    pub(crate) sa_mask: RawSigSet,
+}
+
+const SIG_DFL: usize = 0;
+const SIG_IGN: usize = 1;
+
+impl KernelSigaction {
+    pub(crate) fn new(action: &Sigaction) -> Self {
+        let sa_handler = match action.handler {
+            SigHandler::Default => SIG_DFL,
+            SigHandler::Ignore => SIG_IGN,
+            SigHandler::Handler(handler) => handler as usize,
+            SigHandler::SigInfo(handler) => handler as usize,
+        };
+        Self {
+            sa_handler,
+            sa_flags: action.flags.bits() as _,
+            #[cfg(not(any(
+                target_arch = "mips",
+                target_arch = "mips64",
+                target_arch = "riscv64"
+            )))]
+            sa_restorer: 0,
+            sa_mask: *action.mask.as_raw(),
+        }
+    }
+
+    pub(crate) fn to_sigaction(self) -> Sigaction {
+        let flags = SigactionFlags::from_bits_truncate(self.sa_flags as _);
+        let handler = match self.sa_handler {
+            SIG_DFL => SigHandler::Default,
+            SIG_IGN => SigHandler::Ignore,
+            // Safety: The kernel only gives us handlers that were previously
+            // installed, which are non-null function pointers with the
+            // signature indicated by `SA_SIGINFO`.
+            handler if flags.contains(SigactionFlags::SIGINFO) => unsafe {
+                SigHandler::SigInfo(transmute::<
+                    usize,
+                    unsafe extern "C" fn(i32, *mut c::c_void, *mut c::c_void),
+                >(handler))
+            },
+            handler => unsafe {
+                SigHandler::Handler(transmute::<usize, unsafe extern "C" fn(i32)>(handler))
+            },
+        };
+        Sigaction {
+            handler,
+            mask: SigSet::from_raw(self.sa_mask),
+            flags,
+        }
+    }
+}
+
+bitflags! {
+    /// `SS_*` constants for use with [`sigaltstack`].
+    ///
+    /// [`sigaltstack`]: crate::runtime::sigaltstack
+    pub struct StackFlags: c::c_uint {
+        /// `SS_ONSTACK`, which is set in the current stack returned by
+        /// [`sigaltstack`] if the thread is currently running on it.
+        ///
+        /// [`sigaltstack`]: crate::runtime::sigaltstack
+        const ONSTACK = linux_raw_sys::general::SS_ONSTACK;
+        /// `SS_DISABLE`
+        const DISABLE = linux_raw_sys::general::SS_DISABLE;
+        /// `SS_AUTODISARM` (since Linux 4.7)
+        const AUTODISARM = linux_raw_sys::general::SS_AUTODISARM;
+    }
+}
+
+/// An alternate signal stack, for use with [`sigaltstack`].
+///
+/// [`sigaltstack`]: crate::runtime::sigaltstack
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Stack {
+    /// The base address of the stack.
+    pub sp: *mut c::c_void,
+
+    /// Flags describing the stack.
+    pub flags: StackFlags,
+
+    /// The size of the stack, in bytes.
+    pub size: usize,
+}
+
+impl Stack {
+    pub(crate) fn to_raw(self) -> linux_raw_sys::general::stack_t {
+        linux_raw_sys::general::stack_t {
+            ss_sp: self.sp,
+            ss_flags: self.flags.bits() as _,
+            ss_size: self.size as _,
+        }
+    }
+
+    pub(crate) fn from_raw(raw: linux_raw_sys::general::stack_t) -> Self {
+        Self {
+            sp: raw.ss_sp,
+            flags: StackFlags::from_bits_truncate(raw.ss_flags as _),
+            size: raw.ss_size as _,
+        }
+    }
+}
+
+/// `MINSIGSTKSZ`—The minimum size of an alternate signal stack.
+pub const MINSIGSTKSZ: usize = linux_raw_sys::general::MINSIGSTKSZ as usize;
+
+/// `SIGSTKSZ`—The recommended size of an alternate signal stack.
+pub const SIGSTKSZ: usize = linux_raw_sys::general::SIGSTKSZ as usize;
+
+/// `siginfo_t`—Information about a signal, as returned by [`sigwaitinfo`]
+/// and [`sigtimedwait`].
+///
+/// Which fields are meaningful depends on the signal and on [`code`], which
+/// says where it came from. The sender's [`pid`] and [`uid`] are set for
+/// signals sent with `kill`, `tgkill`, or `sigqueue`, and for `SIGCHLD`.
+/// The [`value`] is set for signals sent with `sigqueue` and by POSIX
+/// timers.
+///
+/// [`sigwaitinfo`]: crate::runtime::sigwaitinfo
+/// [`sigtimedwait`]: crate::runtime::sigtimedwait
+/// [`code`]: Self::code
+/// [`pid`]: Self::pid
+/// [`uid`]: Self::uid
+/// [`value`]: Self::value
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct Siginfo(pub(crate) linux_raw_sys::general::siginfo_t);
+
+impl Siginfo {
+    /// The signal number, `si_signo`.
+    #[inline]
+    pub fn signo(&self) -> i32 {
+        // Safety: Every variant of the union starts with these fields.
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.si_signo }
+    }
+
+    /// Where the signal came from, `si_code`, such as `SI_USER` or
+    /// `SI_TIMER`.
+    #[inline]
+    pub fn code(&self) -> i32 {
+        // Safety: Every variant of the union starts with these fields.
+        unsafe { self.0.__bindgen_anon_1.__bindgen_anon_1.si_code }
+    }
+
+    /// The process ID of the sender, `si_pid`.
+    ///
+    /// Returns `None` if `si_code` says the signal wasn't sent by a process,
+    /// such as for a timer expiring or a fault.
+    #[inline]
+    pub fn pid(&self) -> Option<Pid> {
+        if !self.has_sender() {
+            return None;
+        }
+        // Safety: `has_sender` checked that this is one of `_kill`, `_rt`,
+        // or `_sigchld`, which all start with the sender's `pid` and `uid`.
+        unsafe {
+            Pid::from_raw(
+                self.0
+                    .__bindgen_anon_1
+                    .__bindgen_anon_1
+                    ._sifields
+                    ._kill
+                    ._pid as _,
+            )
+        }
+    }
+
+    /// The real user ID of the sender, `si_uid`.
+    ///
+    /// Returns `None` if `si_code` says the signal wasn't sent by a process.
+    #[inline]
+    pub fn uid(&self) -> Option<Uid> {
+        if !self.has_sender() {
+            return None;
+        }
+        // Safety: See `pid`.
+        unsafe {
+            Some(Uid::from_raw(
+                self.0
+                    .__bindgen_anon_1
+                    .__bindgen_anon_1
+                    ._sifields
+                    ._kill
+                    ._uid,
+            ))
+        }
+    }
+
+    /// Whether `si_code` is one which fills in `si_pid` and `si_uid`: a signal
+    /// sent by `kill`, `sigqueue`, `tgkill`, or a message queue notification,
+    /// or a `SIGCHLD` reporting a change in a child's state.
+    fn has_sender(&self) -> bool {
+        use linux_raw_sys::general::{SIGCHLD, SI_MESGQ, SI_QUEUE, SI_TKILL, SI_USER};
+
+        match self.code() {
+            code if code == SI_USER as i32 => true,
+            SI_QUEUE | SI_MESGQ | SI_TKILL => true,
+            // The `CLD_*` codes are all positive.
+            code => code > 0 && self.signo() == SIGCHLD as i32,
+        }
+    }
+
+    /// The value passed by the sender with the signal, `si_value`.
+    #[inline]
+    pub fn value(&self) -> isize {
+        // Safety: `_rt` and `_timer` both have the value at the same offset.
+        unsafe {
+            self.0
+                .__bindgen_anon_1
+                .__bindgen_anon_1
+                ._sifields
+                ._rt
+                ._sigval
+                .sival_ptr as isize
+        }
+    }
+}
+
+impl fmt::Debug for Siginfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Siginfo")
+            .field("signo", &self.signo())
+            .field("code", &self.code())
+            .field("pid", &self.pid())
+            .field("uid", &self.uid())
+            .field("value", &self.value())
+            .finish()
+    }
+}
@@ -0,0 +1,146 @@
+//! linux_raw syscalls supporting `rustix::sysvipc`.
+//!
+//! # Safety
+//!
+//! See the `rustix::backend::syscalls` module documentation for details.
+#![allow(unsafe_code)]
+#![allow(clippy::undocumented_unsafe_blocks)]
+
+use super::super::c;
+use super::super::conv::{
+    c_int, c_uint, pass_usize, ret, ret_c_int, ret_usize, ret_void_star, slice, zero,
+};
+use crate::io;
+use crate::sysvipc::Sembuf;
+
+#[inline]
+pub(crate) fn shmget(key: c::c_int, size: usize, flags: u32) -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_shmget,
+            c_int(key),
+            pass_usize(size),
+            c_uint(flags)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn shmat(
+    id: c::c_int,
+    addr: *const c::c_void,
+    flags: u32,
+) -> io::Result<*mut c::c_void> {
+    ret_void_star(syscall!(__NR_shmat, c_int(id), addr, c_uint(flags)))
+}
+
+#[inline]
+pub(crate) unsafe fn shmdt(addr: *const c::c_void) -> io::Result<()> {
+    ret(syscall!(__NR_shmdt, addr))
+}
+
+#[inline]
+pub(crate) unsafe fn shmctl(id: c::c_int, cmd: c::c_int, buf: *mut c::c_void) -> io::Result<()> {
+    ret(syscall!(__NR_shmctl, c_int(id), c_int(cmd), buf))
+}
+
+#[inline]
+pub(crate) fn semget(key: c::c_int, nsems: c::c_int, flags: u32) -> io::Result<c::c_int> {
+    unsafe {
+        ret_c_int(syscall_readonly!(
+            __NR_semget,
+            c_int(key),
+            c_int(nsems),
+            c_uint(flags)
+        ))
+    }
+}
+
+#[inline]
+pub(crate) fn semop(id: c::c_int, sops: &[Sembuf]) -> io::Result<()> {
+    let (sops_addr, sops_len) = slice(sops);
+
+    // There's no `semop` syscall on some architectures, and no `semtimedop`
+    // with a 32-bit `time_t` on others, but `semtimedop` with a null timeout
+    // is equivalent.
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall!(
+            __NR_semtimedop_time64,
+            c_int(id),
+            sops_addr,
+            sops_len,
+            zero()
+        ))
+    }
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall!(
+            __NR_semtimedop,
+            c_int(id),
+            sops_addr,
+            sops_len,
+            zero()
+        ))
+    }
+}
+
+#[inline]
+pub(crate) unsafe fn semctl(
+    id: c::c_int,
+    semnum: c::c_int,
+    cmd: c::c_int,
+    arg: usize,
+) -> io::Result<c::c_int> {
+    ret_c_int(syscall!(
+        __NR_semctl,
+        c_int(id),
+        c_int(semnum),
+        c_int(cmd),
+        pass_usize(arg)
+    ))
+}
+
+#[inline]
+pub(crate) fn msgget(key: c::c_int, flags: u32) -> io::Result<c::c_int> {
+    unsafe { ret_c_int(syscall_readonly!(__NR_msgget, c_int(key), c_uint(flags))) }
+}
+
+#[inline]
+pub(crate) unsafe fn msgsnd(
+    id: c::c_int,
+    msgp: *const c::c_void,
+    msgsz: usize,
+    flags: u32,
+) -> io::Result<()> {
+    ret(syscall_readonly!(
+        __NR_msgsnd,
+        c_int(id),
+        msgp,
+        pass_usize(msgsz),
+        c_uint(flags)
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn msgrcv(
+    id: c::c_int,
+    msgp: *mut c::c_void,
+    msgsz: usize,
+    msgtyp: isize,
+    flags: u32,
+) -> io::Result<usize> {
+    ret_usize(syscall!(
+        __NR_msgrcv,
+        c_int(id),
+        msgp,
+        pass_usize(msgsz),
+        pass_usize(msgtyp as usize),
+        c_uint(flags)
+    ))
+}
+
+#[inline]
+pub(crate) unsafe fn msgctl(id: c::c_int, cmd: c::c_int, buf: *mut c::c_void) -> io::Result<()> {
+    ret(syscall!(__NR_msgctl, c_int(id), c_int(cmd), buf))
+}
@@ -8,6 +8,7 @@
 
 use super::super::conv::{by_ref, c_uint, ret};
 use crate::fd::BorrowedFd;
+use crate::ffi::CString;
 use crate::io;
 use crate::process::{Pid, RawNonZeroPid};
 use crate::termios::{
@@ -17,6 +18,7 @@ use crate::termios::{
 };
 #[cfg(feature = "procfs")]
 use crate::{ffi::CStr, fs::FileType, path::DecInt};
+use alloc::borrow::ToOwned;
 use core::mem::MaybeUninit;
 use linux_raw_sys::general::__kernel_pid_t;
 use linux_raw_sys::ioctl::{
@@ -138,7 +140,16 @@ pub(crate) fn tcsetwinsize(fd: BorrowedFd, winsize: Winsize) -> io::Result<()> {
 
 #[inline]
 pub(crate) fn tcsetpgrp(fd: BorrowedFd<'_>, pid: Pid) -> io::Result<()> {
-    unsafe { ret(syscall!(__NR_ioctl, fd, c_uint(TIOCSPGRP), pid)) }
+    // `TIOCSPGRP` takes a pointer to the process group ID.
+    let pid = pid.as_raw_nonzero().get() as __kernel_pid_t;
+    unsafe {
+        ret(syscall_readonly!(
+            __NR_ioctl,
+            fd,
+            c_uint(TIOCSPGRP),
+            by_ref(&pid)
+        ))
+    }
 }
 
 #[inline]
@@ -250,3 +261,10 @@ pub(crate) fn ttyname(fd: BorrowedFd<'_>, buf: &mut [u8]) -> io::Result<usize> {
 
     Ok(r)
 }
+
+#[inline]
+pub(crate) fn ctermid() -> CString {
+    // Linux has no other name for the controlling terminal of the current
+    // process.
+    cstr!("/dev/tty").to_owned()
+}
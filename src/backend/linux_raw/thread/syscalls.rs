@@ -8,11 +8,12 @@
 
 use super::super::c;
 use super::super::conv::{
-    by_ref, c_int, c_uint, ret, ret_c_int, ret_usize, ret_usize_infallible, zero,
+    by_ref, c_int, c_uint, ret, ret_c_int, ret_usize, ret_usize_infallible, size_of, zero,
 };
+use super::super::process::types::{RawSigSet, SigmaskHow};
 use crate::fd::BorrowedFd;
 use crate::io;
-use crate::process::{Pid, RawNonZeroPid};
+use crate::process::{Pid, RawNonZeroPid, Signal};
 use crate::thread::{ClockId, FutexFlags, FutexOperation, NanosleepRelativeResult, Timespec};
 use core::mem::MaybeUninit;
 use linux_raw_sys::general::{__kernel_pid_t, __kernel_timespec, TIMER_ABSTIME};
@@ -214,6 +215,39 @@ pub(crate) fn gettid() -> Pid {
     }
 }
 
+#[inline]
+pub(crate) fn tgkill(tgid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_tgkill, tgid, tid, sig)) }
+}
+
+#[inline]
+pub(crate) fn sigprocmask(how: SigmaskHow, set: &RawSigSet) -> io::Result<RawSigSet> {
+    unsafe {
+        let mut old = MaybeUninit::<RawSigSet>::uninit();
+        ret(syscall!(
+            __NR_rt_sigprocmask,
+            c_uint(how as u32),
+            by_ref(set),
+            &mut old,
+            size_of::<RawSigSet, _>()
+        ))
+        .map(|()| old.assume_init())
+    }
+}
+
+#[inline]
+pub(crate) fn sigpending() -> io::Result<RawSigSet> {
+    unsafe {
+        let mut set = MaybeUninit::<RawSigSet>::uninit();
+        ret(syscall!(
+            __NR_rt_sigpending,
+            &mut set,
+            size_of::<RawSigSet, _>()
+        ))
+        .map(|()| set.assume_init())
+    }
+}
+
 // TODO: This could be de-multiplexed.
 #[inline]
 pub(crate) unsafe fn futex(
@@ -6,9 +6,12 @@
 #![allow(unsafe_code)]
 #![allow(clippy::undocumented_unsafe_blocks)]
 
+use super::super::conv::ret;
+#[cfg(target_pointer_width = "32")]
+use super::super::conv::ret_infallible;
 #[cfg(feature = "time")]
-use super::super::conv::{by_ref, ret_owned_fd};
-use super::super::conv::{ret, ret_infallible};
+use super::super::conv::{by_ref, c_int, ret_c_uint, ret_owned_fd};
+#[cfg(any(feature = "time", target_pointer_width = "32"))]
 use super::types::ClockId;
 #[cfg(feature = "time")]
 use crate::fd::BorrowedFd;
@@ -16,8 +19,12 @@ use crate::fd::BorrowedFd;
 use crate::fd::OwnedFd;
 use crate::io;
 #[cfg(feature = "time")]
-use crate::time::{Itimerspec, TimerfdClockId, TimerfdFlags, TimerfdTimerFlags};
+use crate::time::{
+    Itimerspec, Sigevent, TimerId, TimerSettimeFlags, TimerfdClockId, TimerfdFlags,
+    TimerfdTimerFlags,
+};
 use core::mem::MaybeUninit;
+#[cfg(target_pointer_width = "32")]
 use linux_raw_sys::general::__kernel_timespec;
 #[cfg(feature = "time")]
 #[cfg(target_pointer_width = "32")]
@@ -29,9 +36,14 @@ use {core::ptr, linux_raw_sys::general::timespec as __kernel_old_timespec};
 #[cfg(feature = "time")]
 pub(crate) use super::super::vdso_wrappers::{clock_gettime, clock_gettime_dynamic};
 
+// On 64-bit platforms, so does `clock_getres`.
+#[cfg(feature = "time")]
+#[cfg(target_pointer_width = "64")]
+pub(crate) use super::super::vdso_wrappers::clock_getres;
+
 #[inline]
+#[cfg(target_pointer_width = "32")]
 pub(crate) fn clock_getres(which_clock: ClockId) -> __kernel_timespec {
-    #[cfg(target_pointer_width = "32")]
     unsafe {
         let mut result = MaybeUninit::<__kernel_timespec>::uninit();
         if let Err(err) = ret(syscall!(__NR_clock_getres_time64, which_clock, &mut result)) {
@@ -42,12 +54,6 @@ pub(crate) fn clock_getres(which_clock: ClockId) -> __kernel_timespec {
         }
         result.assume_init()
     }
-    #[cfg(target_pointer_width = "64")]
-    unsafe {
-        let mut result = MaybeUninit::<__kernel_timespec>::uninit();
-        ret_infallible(syscall!(__NR_clock_getres, which_clock, &mut result));
-        result.assume_init()
-    }
 }
 
 #[cfg(target_pointer_width = "32")]
@@ -227,3 +233,221 @@ unsafe fn timerfd_gettime_old(
     );
     Ok(())
 }
+
+#[cfg(feature = "time")]
+pub(crate) fn timer_create(clockid: ClockId, sevp: &Sigevent) -> io::Result<TimerId> {
+    use linux_raw_sys::general::{
+        __kernel_timer_t, sigevent, sigval, SIGEV_NONE, SIGEV_SIGNAL, SIGEV_THREAD_ID,
+    };
+
+    // Safety: `sigevent` is a plain C struct, and all zeros is a valid value
+    // of it. This also zeros the padding, which the kernel expects.
+    let mut sev: sigevent = unsafe { core::mem::zeroed() };
+    match *sevp {
+        Sigevent::None => sev.sigev_notify = SIGEV_NONE as _,
+        Sigevent::Signal { signal, value } => {
+            sev.sigev_notify = SIGEV_SIGNAL as _;
+            sev.sigev_signo = signal as _;
+            sev.sigev_value = sigval {
+                sival_ptr: value as _,
+            };
+        }
+        Sigevent::ThreadId { signal, value, tid } => {
+            sev.sigev_notify = SIGEV_THREAD_ID as _;
+            sev.sigev_signo = signal as _;
+            sev.sigev_value = sigval {
+                sival_ptr: value as _,
+            };
+            sev._sigev_un._tid = tid.as_raw_nonzero().get() as _;
+        }
+    }
+
+    let mut timerid = MaybeUninit::<__kernel_timer_t>::uninit();
+    unsafe {
+        ret(syscall!(
+            __NR_timer_create,
+            clockid,
+            by_ref(&sev),
+            &mut timerid
+        ))?;
+        Ok(TimerId(timerid.assume_init()))
+    }
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn timer_settime(
+    timerid: TimerId,
+    flags: TimerSettimeFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    let mut result = MaybeUninit::<Itimerspec>::uninit();
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall!(
+            __NR_timer_settime,
+            c_int(timerid.0),
+            flags,
+            by_ref(new_value),
+            &mut result
+        ))?;
+        Ok(result.assume_init())
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall!(
+            __NR_timer_settime64,
+            c_int(timerid.0),
+            flags,
+            by_ref(new_value),
+            &mut result
+        ))
+        .or_else(|err| {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Errno::NOSYS {
+                timer_settime_old(timerid, flags, new_value, &mut result)
+            } else {
+                Err(err)
+            }
+        })?;
+        Ok(result.assume_init())
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg(target_pointer_width = "32")]
+unsafe fn timer_settime_old(
+    timerid: TimerId,
+    flags: TimerSettimeFlags,
+    new_value: &Itimerspec,
+    result: &mut MaybeUninit<Itimerspec>,
+) -> io::Result<()> {
+    let mut old_result = MaybeUninit::<__kernel_old_itimerspec>::uninit();
+
+    // Convert `new_value` to the old `__kernel_old_itimerspec` format.
+    let old_new_value = __kernel_old_itimerspec {
+        it_interval: __kernel_old_timespec {
+            tv_sec: new_value
+                .it_interval
+                .tv_sec
+                .try_into()
+                .map_err(|_| io::Errno::OVERFLOW)?,
+            tv_nsec: new_value
+                .it_interval
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Errno::INVAL)?,
+        },
+        it_value: __kernel_old_timespec {
+            tv_sec: new_value
+                .it_value
+                .tv_sec
+                .try_into()
+                .map_err(|_| io::Errno::OVERFLOW)?,
+            tv_nsec: new_value
+                .it_value
+                .tv_nsec
+                .try_into()
+                .map_err(|_| io::Errno::INVAL)?,
+        },
+    };
+    ret(syscall!(
+        __NR_timer_settime,
+        c_int(timerid.0),
+        flags,
+        by_ref(&old_new_value),
+        &mut old_result
+    ))?;
+    let old_result = old_result.assume_init();
+    // TODO: With Rust 1.55, we can use MaybeUninit::write here.
+    ptr::write(
+        result.as_mut_ptr(),
+        Itimerspec {
+            it_interval: __kernel_timespec {
+                tv_sec: old_result.it_interval.tv_sec.into(),
+                tv_nsec: old_result.it_interval.tv_nsec.into(),
+            },
+            it_value: __kernel_timespec {
+                tv_sec: old_result.it_value.tv_sec.into(),
+                tv_nsec: old_result.it_value.tv_nsec.into(),
+            },
+        },
+    );
+    Ok(())
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn timer_gettime(timerid: TimerId) -> io::Result<Itimerspec> {
+    let mut result = MaybeUninit::<Itimerspec>::uninit();
+
+    #[cfg(target_pointer_width = "64")]
+    unsafe {
+        ret(syscall!(__NR_timer_gettime, c_int(timerid.0), &mut result))?;
+        Ok(result.assume_init())
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    unsafe {
+        ret(syscall!(
+            __NR_timer_gettime64,
+            c_int(timerid.0),
+            &mut result
+        ))
+        .or_else(|err| {
+            // See the comments in `rustix_clock_gettime_via_syscall` about
+            // emulation.
+            if err == io::Errno::NOSYS {
+                timer_gettime_old(timerid, &mut result)
+            } else {
+                Err(err)
+            }
+        })?;
+        Ok(result.assume_init())
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg(target_pointer_width = "32")]
+unsafe fn timer_gettime_old(
+    timerid: TimerId,
+    result: &mut MaybeUninit<Itimerspec>,
+) -> io::Result<()> {
+    let mut old_result = MaybeUninit::<__kernel_old_itimerspec>::uninit();
+    ret(syscall!(
+        __NR_timer_gettime,
+        c_int(timerid.0),
+        &mut old_result
+    ))?;
+    let old_result = old_result.assume_init();
+    // TODO: With Rust 1.55, we can use MaybeUninit::write here.
+    ptr::write(
+        result.as_mut_ptr(),
+        Itimerspec {
+            it_interval: __kernel_timespec {
+                tv_sec: old_result.it_interval.tv_sec.into(),
+                tv_nsec: old_result.it_interval.tv_nsec.into(),
+            },
+            it_value: __kernel_timespec {
+                tv_sec: old_result.it_value.tv_sec.into(),
+                tv_nsec: old_result.it_value.tv_nsec.into(),
+            },
+        },
+    );
+    Ok(())
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn timer_getoverrun(timerid: TimerId) -> io::Result<u32> {
+    unsafe { ret_c_uint(syscall_readonly!(__NR_timer_getoverrun, c_int(timerid.0))) }
+}
+
+#[cfg(feature = "time")]
+#[inline]
+pub(crate) fn timer_delete(timerid: TimerId) -> io::Result<()> {
+    unsafe { ret(syscall_readonly!(__NR_timer_delete, c_int(timerid.0))) }
+}
@@ -107,6 +107,27 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// `TIMER_*` flags for use with [`timer_settime`].
+    ///
+    /// [`timer_settime`]: crate::time::timer_settime
+    pub struct TimerSettimeFlags: c::c_uint {
+        /// `TIMER_ABSTIME`
+        const ABSTIME = linux_raw_sys::general::TIMER_ABSTIME;
+    }
+}
+
+/// `timer_t`—A POSIX per-process timer, as returned by [`timer_create`].
+///
+/// Timers aren't file descriptors, so they aren't closed automatically;
+/// delete them with [`timer_delete`].
+///
+/// [`timer_create`]: crate::time::timer_create
+/// [`timer_delete`]: crate::time::timer_delete
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct TimerId(pub(crate) linux_raw_sys::general::__kernel_timer_t);
+
 /// `CLOCK_*` constants for use with [`timerfd_create`].
 ///
 /// [`timerfd_create`]: crate::time::timerfd_create
@@ -10,6 +10,8 @@
 #![allow(unsafe_code)]
 
 use super::conv::{c_int, ret};
+#[cfg(feature = "rand")]
+use super::conv::{c_uint, pass_usize, ret_usize};
 #[cfg(target_arch = "x86")]
 use super::reg::{ArgReg, RetReg, SyscallNumber, A0, A1, A2, A3, A4, A5, R0};
 use super::time::types::{ClockId, DynamicClockId, Timespec};
@@ -42,6 +44,27 @@ pub(crate) fn clock_gettime(which_clock: ClockId) -> __kernel_timespec {
     }
 }
 
+/// On 64-bit platforms, `clock_getres` is in the vDSO too. On 32-bit
+/// platforms, the vDSO only has a version with a 32-bit `time_t`, so we
+/// always use the syscall.
+#[cfg(target_pointer_width = "64")]
+#[inline]
+pub(crate) fn clock_getres(which_clock: ClockId) -> __kernel_timespec {
+    // Safety: `CLOCK_GETRES` contains either null or the address of a
+    // function with an ABI like libc `clock_getres`, and calling it has
+    // the side effect of writing to the result buffer, and no others.
+    unsafe {
+        let mut result = MaybeUninit::<__kernel_timespec>::uninit();
+        let callee = match transmute(CLOCK_GETRES.load(Relaxed)) {
+            Some(callee) => callee,
+            None => init_clock_getres(),
+        };
+        let r0 = callee(which_clock as c::c_int, result.as_mut_ptr());
+        assert_eq!(r0, 0);
+        result.assume_init()
+    }
+}
+
 #[inline]
 pub(crate) fn clock_gettime_dynamic(which_clock: DynamicClockId<'_>) -> io::Result<Timespec> {
     let id = match which_clock {
@@ -83,6 +106,40 @@ pub(crate) fn clock_gettime_dynamic(which_clock: DynamicClockId<'_>) -> io::Resu
     }
 }
 
+/// `struct vgetrandom_opaque_params`—How to allocate the opaque state for
+/// the vDSO `getrandom`.
+#[cfg(feature = "rand")]
+#[repr(C)]
+#[derive(Default)]
+pub(crate) struct VgetrandomOpaqueParams {
+    pub(crate) size_of_opaque_state: u32,
+    pub(crate) mmap_prot: u32,
+    pub(crate) mmap_flags: u32,
+    reserved: [u32; 13],
+}
+
+/// Returns the vDSO `getrandom` function, or `None` if the vDSO doesn't
+/// provide one.
+#[cfg(feature = "rand")]
+#[inline]
+pub(crate) fn vgetrandom() -> Option<VgetrandomType> {
+    // Safety: `GETRANDOM` contains either null, the address of
+    // `rustix_getrandom_via_syscall`, or the address of a function with the
+    // ABI of the vDSO `getrandom`.
+    unsafe {
+        let mut ptr = GETRANDOM.load(Relaxed);
+        if ptr.is_null() {
+            init();
+            ptr = GETRANDOM.load(Relaxed);
+        }
+        if ptr == rustix_getrandom_via_syscall as *mut Function {
+            None
+        } else {
+            Some(transmute::<*mut Function, VgetrandomType>(ptr))
+        }
+    }
+}
+
 #[cfg(target_arch = "x86")]
 pub(super) mod x86_via_vdso {
     use super::{transmute, ArgReg, Relaxed, RetReg, SyscallNumber, A0, A1, A2, A3, A4, A5, R0};
@@ -207,6 +264,11 @@ pub(super) mod x86_via_vdso {
 }
 
 type ClockGettimeType = unsafe extern "C" fn(c::c_int, *mut Timespec) -> c::c_int;
+#[cfg(target_pointer_width = "64")]
+type ClockGetresType = ClockGettimeType;
+#[cfg(feature = "rand")]
+pub(crate) type VgetrandomType =
+    unsafe extern "C" fn(*mut c::c_void, usize, c::c_uint, *mut c::c_void, usize) -> isize;
 
 /// The underlying syscall functions are only called from asm, using the
 /// special syscall calling convention to pass arguments and return values,
@@ -222,6 +284,15 @@ fn init_clock_gettime() -> ClockGettimeType {
     unsafe { transmute(CLOCK_GETTIME.load(Relaxed)) }
 }
 
+/// Initialize `CLOCK_GETRES` and return its value.
+#[cfg(target_pointer_width = "64")]
+fn init_clock_getres() -> ClockGetresType {
+    init();
+    // Safety: Load the function address from static storage that we
+    // just initialized.
+    unsafe { transmute(CLOCK_GETRES.load(Relaxed)) }
+}
+
 /// Initialize `SYSCALL` and return its value.
 #[cfg(target_arch = "x86")]
 fn init_syscall() -> SyscallType {
@@ -235,8 +306,12 @@ fn init_syscall() -> SyscallType {
 /// placeholder type, and cast it as needed.
 struct Function;
 static mut CLOCK_GETTIME: AtomicPtr<Function> = AtomicPtr::new(null_mut());
+#[cfg(target_pointer_width = "64")]
+static mut CLOCK_GETRES: AtomicPtr<Function> = AtomicPtr::new(null_mut());
 #[cfg(target_arch = "x86")]
 static mut SYSCALL: AtomicPtr<Function> = AtomicPtr::new(null_mut());
+#[cfg(feature = "rand")]
+static GETRANDOM: AtomicPtr<Function> = AtomicPtr::new(null_mut());
 
 unsafe extern "C" fn rustix_clock_gettime_via_syscall(
     clockid: c::c_int,
@@ -293,6 +368,38 @@ unsafe fn _rustix_clock_gettime_via_syscall(
     ret(syscall!(__NR_clock_gettime, c_int(clockid), res))
 }
 
+#[cfg(target_pointer_width = "64")]
+unsafe extern "C" fn rustix_clock_getres_via_syscall(
+    clockid: c::c_int,
+    res: *mut Timespec,
+) -> c::c_int {
+    match ret(syscall!(__NR_clock_getres, c_int(clockid), res)) {
+        Ok(()) => 0,
+        Err(err) => err.raw_os_error().wrapping_neg(),
+    }
+}
+
+/// The placeholder for the vDSO `getrandom`, which ignores the opaque state
+/// and makes the syscall.
+#[cfg(feature = "rand")]
+unsafe extern "C" fn rustix_getrandom_via_syscall(
+    buf: *mut c::c_void,
+    len: usize,
+    flags: c::c_uint,
+    _opaque_state: *mut c::c_void,
+    _opaque_len: usize,
+) -> isize {
+    match ret_usize(syscall!(
+        __NR_getrandom,
+        buf,
+        pass_usize(len),
+        c_uint(flags)
+    )) {
+        Ok(n) => n as isize,
+        Err(err) => (err.raw_os_error() as isize).wrapping_neg(),
+    }
+}
+
 /// A symbol pointing to an `int 0x80` instruction. This "function" is only
 /// called from assembly, and only with the x86 syscall calling convention,
 /// so its signature here is not its true signature.
@@ -322,6 +429,28 @@ fn minimal_init() {
                 Relaxed,
             )
             .ok();
+        #[cfg(target_pointer_width = "64")]
+        {
+            CLOCK_GETRES
+                .compare_exchange(
+                    null_mut(),
+                    rustix_clock_getres_via_syscall as *mut Function,
+                    Relaxed,
+                    Relaxed,
+                )
+                .ok();
+        }
+        #[cfg(feature = "rand")]
+        {
+            GETRANDOM
+                .compare_exchange(
+                    null_mut(),
+                    rustix_getrandom_via_syscall as *mut Function,
+                    Relaxed,
+                    Relaxed,
+                )
+                .ok();
+        }
         #[cfg(target_arch = "x86")]
         {
             SYSCALL
@@ -383,6 +512,52 @@ fn init() {
             }
         }
 
+        // On 64-bit platforms, also look up `clock_getres`. Not all kernel
+        // versions provide it, so fall back to the syscall if it's missing.
+        #[cfg(target_pointer_width = "64")]
+        {
+            #[cfg(target_arch = "x86_64")]
+            let ptr = vdso.sym(cstr!("LINUX_2.6"), cstr!("__vdso_clock_getres"));
+            #[cfg(target_arch = "aarch64")]
+            let ptr = vdso.sym(cstr!("LINUX_2.6.39"), cstr!("__kernel_clock_getres"));
+            #[cfg(target_arch = "riscv64")]
+            let ptr = vdso.sym(cstr!("LINUX_4.15"), cstr!("__vdso_clock_getres"));
+            #[cfg(target_arch = "powerpc64")]
+            let ptr = vdso.sym(cstr!("LINUX_2.6.15"), cstr!("__kernel_clock_getres"));
+            #[cfg(target_arch = "mips64")]
+            let ptr = vdso.sym(cstr!("LINUX_2.6"), cstr!("__vdso_clock_getres"));
+
+            if !ptr.is_null() {
+                // Safety: As above, store the computed function addresses in
+                // static storage.
+                unsafe {
+                    CLOCK_GETRES.store(ptr.cast(), Relaxed);
+                }
+            }
+        }
+
+        // Linux 6.11 and newer provide `getrandom` on some platforms. If it's
+        // missing, keep the syscall placeholder.
+        #[cfg(feature = "rand")]
+        {
+            #[cfg(target_arch = "x86_64")]
+            let ptr = vdso.sym(cstr!("LINUX_2.6"), cstr!("__vdso_getrandom"));
+            #[cfg(target_arch = "aarch64")]
+            let ptr = vdso.sym(cstr!("LINUX_2.6.39"), cstr!("__kernel_getrandom"));
+            #[cfg(target_arch = "powerpc64")]
+            let ptr = vdso.sym(cstr!("LINUX_2.6.15"), cstr!("__kernel_getrandom"));
+            #[cfg(not(any(
+                target_arch = "x86_64",
+                target_arch = "aarch64",
+                target_arch = "powerpc64"
+            )))]
+            let ptr: *mut c::c_void = null_mut();
+
+            if !ptr.is_null() {
+                GETRANDOM.store(ptr.cast(), Relaxed);
+            }
+        }
+
         // On x86, also look up the vsyscall entry point.
         #[cfg(target_arch = "x86")]
         {
@@ -0,0 +1,476 @@
+//! Linux [eBPF] programs and maps.
+//!
+//! This is a thin layer over the `bpf` system call. The kernel's
+//! `union bpf_attr` has a different layout for each command; each function
+//! here passes just the prefix of it which its command uses, which the
+//! kernel zero-extends.
+//!
+//! Most operations require the `CAP_BPF` or `CAP_SYS_ADMIN` capability,
+//! unless the `kernel.unprivileged_bpf_disabled` sysctl is zero.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+//! [eBPF]: https://docs.kernel.org/bpf/
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::{backend, io};
+use bitflags::bitflags;
+use core::convert::TryInto;
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::ptr::null_mut;
+
+// `enum bpf_cmd`
+const BPF_MAP_CREATE: u32 = 0;
+const BPF_MAP_LOOKUP_ELEM: u32 = 1;
+const BPF_MAP_UPDATE_ELEM: u32 = 2;
+const BPF_MAP_DELETE_ELEM: u32 = 3;
+const BPF_PROG_LOAD: u32 = 5;
+
+/// `BPF_MAP_TYPE_*` constants for use with [`MapCreateAttr`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum MapType {
+    /// `BPF_MAP_TYPE_HASH`
+    Hash = 1,
+    /// `BPF_MAP_TYPE_ARRAY`
+    Array = 2,
+    /// `BPF_MAP_TYPE_PROG_ARRAY`
+    ProgArray = 3,
+    /// `BPF_MAP_TYPE_PERF_EVENT_ARRAY`
+    PerfEventArray = 4,
+    /// `BPF_MAP_TYPE_PERCPU_HASH`
+    PercpuHash = 5,
+    /// `BPF_MAP_TYPE_PERCPU_ARRAY`
+    PercpuArray = 6,
+    /// `BPF_MAP_TYPE_STACK_TRACE`
+    StackTrace = 7,
+    /// `BPF_MAP_TYPE_CGROUP_ARRAY`
+    CgroupArray = 8,
+    /// `BPF_MAP_TYPE_LRU_HASH`
+    LruHash = 9,
+    /// `BPF_MAP_TYPE_LRU_PERCPU_HASH`
+    LruPercpuHash = 10,
+    /// `BPF_MAP_TYPE_LPM_TRIE`
+    LpmTrie = 11,
+    /// `BPF_MAP_TYPE_ARRAY_OF_MAPS`
+    ArrayOfMaps = 12,
+    /// `BPF_MAP_TYPE_HASH_OF_MAPS`
+    HashOfMaps = 13,
+    /// `BPF_MAP_TYPE_QUEUE`
+    Queue = 22,
+    /// `BPF_MAP_TYPE_STACK`
+    Stack = 23,
+    /// `BPF_MAP_TYPE_RINGBUF`
+    Ringbuf = 27,
+}
+
+/// `BPF_PROG_TYPE_*` constants for use with [`ProgLoadAttr`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum ProgType {
+    /// `BPF_PROG_TYPE_SOCKET_FILTER`
+    SocketFilter = 1,
+    /// `BPF_PROG_TYPE_KPROBE`
+    Kprobe = 2,
+    /// `BPF_PROG_TYPE_SCHED_CLS`
+    SchedCls = 3,
+    /// `BPF_PROG_TYPE_SCHED_ACT`
+    SchedAct = 4,
+    /// `BPF_PROG_TYPE_TRACEPOINT`
+    Tracepoint = 5,
+    /// `BPF_PROG_TYPE_XDP`
+    Xdp = 6,
+    /// `BPF_PROG_TYPE_PERF_EVENT`
+    PerfEvent = 7,
+    /// `BPF_PROG_TYPE_CGROUP_SKB`
+    CgroupSkb = 8,
+    /// `BPF_PROG_TYPE_CGROUP_SOCK`
+    CgroupSock = 9,
+    /// `BPF_PROG_TYPE_SOCK_OPS`
+    SockOps = 13,
+    /// `BPF_PROG_TYPE_CGROUP_DEVICE`
+    CgroupDevice = 15,
+    /// `BPF_PROG_TYPE_RAW_TRACEPOINT`
+    RawTracepoint = 17,
+}
+
+bitflags! {
+    /// `BPF_F_*` flags for use with [`MapCreateAttr`].
+    #[derive(Default)]
+    pub struct MapFlags: u32 {
+        /// `BPF_F_NO_PREALLOC`
+        const NO_PREALLOC = 1 << 0;
+        /// `BPF_F_NO_COMMON_LRU`
+        const NO_COMMON_LRU = 1 << 1;
+        /// `BPF_F_RDONLY`
+        const RDONLY = 1 << 3;
+        /// `BPF_F_WRONLY`
+        const WRONLY = 1 << 4;
+        /// `BPF_F_RDONLY_PROG`
+        const RDONLY_PROG = 1 << 7;
+        /// `BPF_F_WRONLY_PROG`
+        const WRONLY_PROG = 1 << 8;
+        /// `BPF_F_MMAPABLE`
+        const MMAPABLE = 1 << 10;
+    }
+}
+
+bitflags! {
+    /// `BPF_*` flags for use with [`map_update_elem`].
+    ///
+    /// The empty set is `BPF_ANY`, which creates an element or updates an
+    /// existing one.
+    pub struct UpdateFlags: u64 {
+        /// `BPF_NOEXIST`—Only create a new element.
+        const NOEXIST = 1;
+        /// `BPF_EXIST`—Only update an existing element.
+        const EXIST = 2;
+        /// `BPF_F_LOCK`—Update a spin-locked element.
+        const LOCK = 4;
+    }
+}
+
+/// The parameters of a map, for use with [`map_create`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MapCreateAttr {
+    /// `map_type`
+    pub map_type: MapType,
+    /// `key_size`—The size of a key, in bytes.
+    pub key_size: u32,
+    /// `value_size`—The size of a value, in bytes.
+    pub value_size: u32,
+    /// `max_entries`—The maximum number of elements.
+    pub max_entries: u32,
+    /// `map_flags`
+    pub map_flags: MapFlags,
+}
+
+/// `struct bpf_insn`—An eBPF instruction.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct BpfInsn {
+    code: u8,
+    regs: u8,
+    off: i16,
+    imm: i32,
+}
+
+impl BpfInsn {
+    /// Constructs an instruction from its opcode, registers, offset, and
+    /// immediate operand.
+    ///
+    /// Only the low 4 bits of each register number are used.
+    #[inline]
+    pub const fn new(code: u8, dst_reg: u8, src_reg: u8, off: i16, imm: i32) -> Self {
+        // `dst_reg` and `src_reg` are 4-bit bitfields, which C lays out
+        // starting from the low bits on little-endian platforms, and the
+        // high bits on big-endian ones.
+        #[cfg(target_endian = "little")]
+        let regs = (dst_reg & 0xf) | (src_reg << 4);
+        #[cfg(target_endian = "big")]
+        let regs = (dst_reg << 4) | (src_reg & 0xf);
+        Self {
+            code,
+            regs,
+            off,
+            imm,
+        }
+    }
+
+    /// Returns the opcode.
+    #[inline]
+    pub const fn code(&self) -> u8 {
+        self.code
+    }
+
+    /// Returns the destination register.
+    #[inline]
+    pub const fn dst_reg(&self) -> u8 {
+        #[cfg(target_endian = "little")]
+        let reg = self.regs & 0xf;
+        #[cfg(target_endian = "big")]
+        let reg = self.regs >> 4;
+        reg
+    }
+
+    /// Returns the source register.
+    #[inline]
+    pub const fn src_reg(&self) -> u8 {
+        #[cfg(target_endian = "little")]
+        let reg = self.regs >> 4;
+        #[cfg(target_endian = "big")]
+        let reg = self.regs & 0xf;
+        reg
+    }
+
+    /// Returns the offset.
+    #[inline]
+    pub const fn off(&self) -> i16 {
+        self.off
+    }
+
+    /// Returns the immediate operand.
+    #[inline]
+    pub const fn imm(&self) -> i32 {
+        self.imm
+    }
+}
+
+/// The parameters of a program, for use with [`prog_load`].
+#[derive(Debug, Copy, Clone)]
+pub struct ProgLoadAttr<'a> {
+    /// `prog_type`
+    pub prog_type: ProgType,
+    /// `insns` and `insn_cnt`—The instructions of the program.
+    pub insns: &'a [BpfInsn],
+    /// `license`—The program's license, such as `"GPL"`, which determines
+    /// which kernel helpers it may call.
+    pub license: &'a CStr,
+    /// `log_level`—How much the verifier logs to the buffer passed to
+    /// [`prog_load`]. This is ignored if there's no buffer.
+    pub log_level: u32,
+    /// `kern_version`—The kernel version, which older kernels required for
+    /// kprobe programs.
+    pub kern_version: u32,
+    /// `prog_flags`—`BPF_F_*` flags for loading programs.
+    pub prog_flags: u32,
+}
+
+/// The prefix of `union bpf_attr` for `BPF_MAP_CREATE`.
+#[repr(C)]
+struct MapCreate {
+    map_type: u32,
+    key_size: u32,
+    value_size: u32,
+    max_entries: u32,
+    map_flags: u32,
+}
+
+/// The prefix of `union bpf_attr` for `BPF_MAP_*_ELEM`.
+#[repr(C)]
+struct MapElem {
+    map_fd: u32,
+    _pad: u32,
+    key: u64,
+    value: u64,
+    flags: u64,
+}
+
+/// The prefix of `union bpf_attr` for `BPF_PROG_LOAD`.
+#[repr(C)]
+struct ProgLoad {
+    prog_type: u32,
+    insn_cnt: u32,
+    insns: u64,
+    license: u64,
+    log_level: u32,
+    log_size: u32,
+    log_buf: u64,
+    kern_version: u32,
+    prog_flags: u32,
+}
+
+/// `bpf(BPF_MAP_CREATE, attr, size)`—Creates a map.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[doc(alias = "BPF_MAP_CREATE")]
+pub fn map_create(attr: &MapCreateAttr) -> io::Result<OwnedFd> {
+    let mut raw = MapCreate {
+        map_type: attr.map_type as u32,
+        key_size: attr.key_size,
+        value_size: attr.value_size,
+        max_entries: attr.max_entries,
+        map_flags: attr.map_flags.bits(),
+    };
+
+    // Safety: `raw` is the prefix of `union bpf_attr` for this command.
+    unsafe {
+        backend::bpf::syscalls::bpf_owned_fd(
+            BPF_MAP_CREATE,
+            (&mut raw as *mut MapCreate).cast(),
+            size_of::<MapCreate>(),
+        )
+    }
+}
+
+/// `bpf(BPF_MAP_LOOKUP_ELEM, attr, size)`—Copies the value of the element
+/// with the given key into `value`.
+///
+/// If there's no such element, this fails with [`io::Errno::NOENT`].
+///
+/// # Safety
+///
+/// The kernel reads the map's key size from `key` and writes its value size
+/// to `value`, which is multiplied by the number of possible CPUs for
+/// per-CPU maps, so they must be at least that big.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[doc(alias = "BPF_MAP_LOOKUP_ELEM")]
+pub unsafe fn map_lookup_elem<Fd: AsFd>(
+    map_fd: Fd,
+    key: &[u8],
+    value: &mut [u8],
+) -> io::Result<()> {
+    let mut raw = MapElem {
+        map_fd: map_fd.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.as_ptr() as usize as u64,
+        value: value.as_mut_ptr() as usize as u64,
+        flags: 0,
+    };
+    backend::bpf::syscalls::bpf(
+        BPF_MAP_LOOKUP_ELEM,
+        (&mut raw as *mut MapElem).cast(),
+        size_of::<MapElem>(),
+    )
+}
+
+/// `bpf(BPF_MAP_UPDATE_ELEM, attr, size)`—Creates or updates the element
+/// with the given key.
+///
+/// # Safety
+///
+/// The kernel reads the map's key size from `key` and its value size from
+/// `value`, which is multiplied by the number of possible CPUs for per-CPU
+/// maps, so they must be at least that big.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[doc(alias = "BPF_MAP_UPDATE_ELEM")]
+pub unsafe fn map_update_elem<Fd: AsFd>(
+    map_fd: Fd,
+    key: &[u8],
+    value: &[u8],
+    flags: UpdateFlags,
+) -> io::Result<()> {
+    let mut raw = MapElem {
+        map_fd: map_fd.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.as_ptr() as usize as u64,
+        value: value.as_ptr() as usize as u64,
+        flags: flags.bits(),
+    };
+    backend::bpf::syscalls::bpf(
+        BPF_MAP_UPDATE_ELEM,
+        (&mut raw as *mut MapElem).cast(),
+        size_of::<MapElem>(),
+    )
+}
+
+/// `bpf(BPF_MAP_DELETE_ELEM, attr, size)`—Deletes the element with the
+/// given key.
+///
+/// Elements of arrays can't be deleted, so for those this fails with
+/// [`io::Errno::INVAL`].
+///
+/// # Safety
+///
+/// The kernel reads the map's key size from `key`, so it must be at least
+/// that big.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[doc(alias = "BPF_MAP_DELETE_ELEM")]
+pub unsafe fn map_delete_elem<Fd: AsFd>(map_fd: Fd, key: &[u8]) -> io::Result<()> {
+    let mut raw = MapElem {
+        map_fd: map_fd.as_fd().as_raw_fd() as u32,
+        _pad: 0,
+        key: key.as_ptr() as usize as u64,
+        value: 0,
+        flags: 0,
+    };
+    backend::bpf::syscalls::bpf(
+        BPF_MAP_DELETE_ELEM,
+        (&mut raw as *mut MapElem).cast(),
+        size_of::<MapElem>(),
+    )
+}
+
+/// `bpf(BPF_PROG_LOAD, attr, size)`—Verifies and loads a program.
+///
+/// If `log_buf` is given, the verifier writes its log there, as a
+/// NUL-terminated string, at the level in [`ProgLoadAttr::log_level`],
+/// which must then be non-zero. When verification fails, the log explains
+/// why. If the log doesn't fit, this fails with [`io::Errno::NOSPC`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/bpf.2.html
+#[doc(alias = "BPF_PROG_LOAD")]
+pub fn prog_load(attr: &ProgLoadAttr<'_>, log_buf: Option<&mut [u8]>) -> io::Result<OwnedFd> {
+    let insn_cnt = attr.insns.len().try_into().map_err(|_| io::Errno::INVAL)?;
+    let (log_level, log_size, log_buf) = match log_buf {
+        Some(log_buf) => (
+            attr.log_level,
+            log_buf.len().try_into().map_err(|_| io::Errno::INVAL)?,
+            log_buf.as_mut_ptr(),
+        ),
+        None => (0, 0, null_mut()),
+    };
+    let mut raw = ProgLoad {
+        prog_type: attr.prog_type as u32,
+        insn_cnt,
+        insns: attr.insns.as_ptr() as usize as u64,
+        license: attr.license.as_ptr() as usize as u64,
+        log_level,
+        log_size,
+        log_buf: log_buf as usize as u64,
+        kern_version: attr.kern_version,
+        prog_flags: attr.prog_flags,
+    };
+
+    // Safety: `raw` is the prefix of `union bpf_attr` for this command, and
+    // its pointers come from slices of the sizes it says.
+    unsafe {
+        backend::bpf::syscalls::bpf_owned_fd(
+            BPF_PROG_LOAD,
+            (&mut raw as *mut ProgLoad).cast::<c_void>(),
+            size_of::<ProgLoad>(),
+        )
+    }
+}
+
+/// Check that our structs match the layouts of the kernel's.
+#[test]
+fn bpf_layouts() {
+    use memoffset::offset_of;
+
+    assert_eq!(size_of::<BpfInsn>(), 8);
+    assert_eq!(size_of::<MapCreate>(), 20);
+    assert_eq!(size_of::<MapElem>(), 32);
+    assert_eq!(offset_of!(MapElem, key), 8);
+    assert_eq!(offset_of!(MapElem, flags), 24);
+    assert_eq!(size_of::<ProgLoad>(), 48);
+    assert_eq!(offset_of!(ProgLoad, log_buf), 32);
+    assert_eq!(offset_of!(ProgLoad, prog_flags), 44);
+
+    let insn = BpfInsn::new(0xb7, 1, 10, -2, 3);
+    assert_eq!(
+        (
+            insn.code(),
+            insn.dst_reg(),
+            insn.src_reg(),
+            insn.off(),
+            insn.imm()
+        ),
+        (0xb7, 1, 10, -2, 3)
+    );
+}
@@ -16,10 +16,9 @@ use crate::fs::StatFs;
     target_os = "solaris",
     target_os = "wasi",
 )))]
-use {
-    crate::fs::StatVfs,
-    crate::{backend, io, path},
-};
+use crate::fs::StatVfs;
+#[cfg(not(target_os = "wasi"))]
+use crate::{backend, io, path};
 
 /// `statfs`—Queries filesystem metadata.
 ///
@@ -67,3 +66,21 @@ pub fn statfs<P: path::Arg>(path: P) -> io::Result<StatFs> {
 pub fn statvfs<P: path::Arg>(path: P) -> io::Result<StatVfs> {
     path.into_with_c_str(backend::fs::syscalls::statvfs)
 }
+
+/// `truncate(path, length)`—Sets the length of a file.
+///
+/// The length is always 64 bits, even on 32-bit platforms. Extending a file
+/// past its current size fills the new space with zeros, which most
+/// filesystems store as a sparse hole.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/truncate.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/truncate.2.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub fn truncate<P: path::Arg>(path: P, length: u64) -> io::Result<()> {
+    path.into_with_c_str(|path| backend::fs::syscalls::truncate(path, length))
+}
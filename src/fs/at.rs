@@ -68,6 +68,11 @@ pub fn openat<P: path::Arg, Fd: AsFd>(
 ///
 /// If `reuse` is non-empty, reuse its buffer to store the result if possible.
 ///
+/// `readlinkat` doesn't report truncation, so this grows the buffer and
+/// retries until the result is shorter than the buffer. It doesn't rely on
+/// the size reported by `lstat`, which is unreliable for magic symlinks such
+/// as those in `/proc/self/fd`.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -222,6 +227,114 @@ pub fn renameat_with<P: path::Arg, Q: path::Arg, PFd: AsFd, QFd: AsFd>(
     })
 }
 
+/// `renameat2(old_dirfd, old_path, new_dirfd, new_path, RENAME_NOREPLACE)`—Renames
+/// a file or directory, failing if the destination exists.
+///
+/// If `new_path` exists, this fails with [`io::Errno::EXIST`], and checking
+/// for it and renaming happen atomically.
+///
+/// If the kernel or the filesystem doesn't support `RENAME_NOREPLACE`, this
+/// falls back to creating `new_path` as a hard link to `old_path` and then
+/// unlinking `old_path`, which is atomic with respect to the destination,
+/// but not to the source. The fallback doesn't work for directories, or on
+/// filesystems without hard links; then this fails with
+/// [`io::Errno::INVAL`], as `renameat2` did.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/renameat2.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[doc(alias = "RENAME_NOREPLACE")]
+pub fn rename_noreplace<P: path::Arg, Q: path::Arg, PFd: AsFd, QFd: AsFd>(
+    old_dirfd: PFd,
+    old_path: P,
+    new_dirfd: QFd,
+    new_path: Q,
+) -> io::Result<()> {
+    let (old_dirfd, new_dirfd) = (old_dirfd.as_fd(), new_dirfd.as_fd());
+    old_path.into_with_c_str(|old_path| {
+        new_path.into_with_c_str(|new_path| {
+            match backend::fs::syscalls::renameat2(
+                old_dirfd,
+                old_path,
+                new_dirfd,
+                new_path,
+                RenameFlags::NOREPLACE,
+            ) {
+                // The kernel lacks `renameat2`.
+                Err(io::Errno::NOSYS) => {
+                    rename_noreplace_by_linking(old_dirfd, old_path, new_dirfd, new_path)
+                }
+                // The filesystem may lack `RENAME_NOREPLACE`.
+                Err(io::Errno::INVAL) => {
+                    rename_noreplace_after_inval(old_dirfd, old_path, new_dirfd, new_path)
+                }
+                otherwise => otherwise,
+            }
+        })
+    })
+}
+
+/// Emulate `RENAME_NOREPLACE` with `linkat` and `unlinkat`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn rename_noreplace_by_linking(
+    old_dirfd: BorrowedFd<'_>,
+    old_path: &CStr,
+    new_dirfd: BorrowedFd<'_>,
+    new_path: &CStr,
+) -> io::Result<()> {
+    backend::fs::syscalls::linkat(old_dirfd, old_path, new_dirfd, new_path, AtFlags::empty())?;
+    unlink_after_linking(old_dirfd, old_path, new_dirfd, new_path)
+}
+
+/// Emulate `RENAME_NOREPLACE` after `renameat2` failed with `EINVAL`.
+///
+/// `EINVAL` also means other things, such as moving a directory into
+/// itself. Directories can't be hard-linked anyway, so only emulate for
+/// other files. If `linkat` can't link on this filesystem, report the
+/// original error, but pass on anything else, such as `EEXIST`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn rename_noreplace_after_inval(
+    old_dirfd: BorrowedFd<'_>,
+    old_path: &CStr,
+    new_dirfd: BorrowedFd<'_>,
+    new_path: &CStr,
+) -> io::Result<()> {
+    let stat = backend::fs::syscalls::statat(old_dirfd, old_path, AtFlags::SYMLINK_NOFOLLOW)?;
+    if FileType::from_raw_mode(stat.st_mode) == FileType::Directory {
+        return Err(io::Errno::INVAL);
+    }
+    match backend::fs::syscalls::linkat(old_dirfd, old_path, new_dirfd, new_path, AtFlags::empty())
+    {
+        Ok(()) => unlink_after_linking(old_dirfd, old_path, new_dirfd, new_path),
+        Err(io::Errno::PERM)
+        | Err(io::Errno::XDEV)
+        | Err(io::Errno::MLINK)
+        | Err(io::Errno::OPNOTSUPP) => Err(io::Errno::INVAL),
+        Err(err) => Err(err),
+    }
+}
+
+/// Finish emulating `RENAME_NOREPLACE` once `old_path` has been linked to
+/// `new_path`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn unlink_after_linking(
+    old_dirfd: BorrowedFd<'_>,
+    old_path: &CStr,
+    new_dirfd: BorrowedFd<'_>,
+    new_path: &CStr,
+) -> io::Result<()> {
+    match backend::fs::syscalls::unlinkat(old_dirfd, old_path, AtFlags::empty()) {
+        Ok(()) => Ok(()),
+        Err(err) => {
+            // Undo the link, so that a failure leaves things as they were.
+            let _ = backend::fs::syscalls::unlinkat(new_dirfd, new_path, AtFlags::empty());
+            Err(err)
+        }
+    }
+}
+
 /// `symlinkat(old_path, new_dirfd, new_path)`—Creates a symlink.
 ///
 /// # References
@@ -265,6 +378,11 @@ pub fn statat<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, flags: AtFlags) -> io:
 /// `faccessat(dirfd, path, access, flags)`—Tests permissions for a file or
 /// directory.
 ///
+/// On Linux, when `flags` is non-empty, this uses `faccessat2`, so that
+/// `AT_EACCESS` and `AT_SYMLINK_NOFOLLOW` are implemented by the kernel. On
+/// older kernels without `faccessat2`, or in sandboxes which reject it with
+/// `EPERM`, these flags are emulated where possible.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -283,6 +401,41 @@ pub fn accessat<P: path::Arg, Fd: AsFd>(
     path.into_with_c_str(|path| backend::fs::syscalls::accessat(dirfd.as_fd(), path, access, flags))
 }
 
+/// `faccessat(dirfd, path, access, AT_EACCESS)`—Tests whether the current
+/// process may access a file or directory.
+///
+/// This checks `access` using the effective user and group IDs, including
+/// the supplementary groups, as opening the file would. It returns
+/// `Ok(false)` if access is denied, and other failures such as
+/// [`io::Errno::NOENT`] as errors.
+///
+/// As with any access check, the result may be out of date by the time it's
+/// used, so it shouldn't be used to decide whether an operation is safe to
+/// perform.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/faccessat.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/faccessat.2.html
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "illumos",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "wasi",
+    all(libc, target_os = "android"),
+)))]
+#[doc(alias = "AT_EACCESS")]
+pub fn can_access<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, access: Access) -> io::Result<bool> {
+    match accessat(dirfd, path, access, AtFlags::EACCESS) {
+        Ok(()) => Ok(true),
+        Err(io::Errno::ACCESS) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
 /// `utimensat(dirfd, path, times, flags)`—Sets file or directory timestamps.
 ///
 /// # References
@@ -298,13 +451,16 @@ pub fn utimensat<P: path::Arg, Fd: AsFd>(
     times: &Timestamps,
     flags: AtFlags,
 ) -> io::Result<()> {
-    path.into_with_c_str(|path| backend::fs::syscalls::utimensat(dirfd.as_fd(), path, times, flags))
+    path.into_with_c_str(|path| {
+        backend::fs::syscalls::utimensat(dirfd.as_fd(), path, &times.to_raw(), flags)
+    })
 }
 
 /// `fchmodat(dirfd, path, mode, 0)`—Sets file or directory permissions.
 ///
 /// The flags argument is fixed to 0, so `AT_SYMLINK_NOFOLLOW` is not
 /// supported. <details>Platform support for this flag varies widely.</details>
+/// See [`chmodat_with`] for a version that supports it.
 ///
 /// This implementation does not support `O_PATH` file descriptors, even on
 /// platforms where the host libc emulates it.
@@ -322,6 +478,38 @@ pub fn chmodat<P: path::Arg, Fd: AsFd>(dirfd: Fd, path: P, mode: Mode) -> io::Re
     path.into_with_c_str(|path| backend::fs::syscalls::chmodat(dirfd.as_fd(), path, mode))
 }
 
+/// `fchmodat(dirfd, path, mode, flags)`—Sets file or directory permissions,
+/// with flags.
+///
+/// The only supported flag is [`AtFlags::SYMLINK_NOFOLLOW`], which changes
+/// the mode of a symlink itself rather than the file it refers to. Linux's
+/// `fchmodat` has no flags argument, so on Linux it's emulated by opening
+/// `path` with `O_PATH | O_NOFOLLOW` and changing the mode through
+/// `/proc/self/fd`, which requires the `procfs` feature with the linux_raw
+/// backend. Linux doesn't support modes on symlinks, so if `path` is a
+/// symlink, this fails with [`io::Errno::OPNOTSUPP`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fchmodat.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fchmodat.2.html
+/// [`io::Errno::OPNOTSUPP`]: crate::io::Errno::OPNOTSUPP
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+#[doc(alias = "fchmodat")]
+pub fn chmodat_with<P: path::Arg, Fd: AsFd>(
+    dirfd: Fd,
+    path: P,
+    mode: Mode,
+    flags: AtFlags,
+) -> io::Result<()> {
+    path.into_with_c_str(|path| {
+        backend::fs::syscalls::chmodat_with(dirfd.as_fd(), path, mode, flags)
+    })
+}
+
 /// `fclonefileat(src, dst_dir, dst, flags)`—Efficiently copies between files.
 ///
 /// # References
@@ -366,6 +554,10 @@ pub fn mknodat<P: path::Arg, Fd: AsFd>(
 /// `fchownat(dirfd, path, owner, group, flags)`—Sets file or directory
 /// ownership.
 ///
+/// With [`AtFlags::SYMLINK_NOFOLLOW`], if `path` is a symlink, this changes
+/// the ownership of the symlink itself, like `lchown`, rather than the file
+/// it refers to.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -386,3 +578,76 @@ pub fn chownat<P: path::Arg, Fd: AsFd>(
         backend::fs::syscalls::chownat(dirfd.as_fd(), path, owner, group, flags)
     })
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_rename_noreplace_by_linking() {
+    use crate::fs::{cwd, statat};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    let _ = openat(&dir, "a", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    let _ = openat(&dir, "b", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    let a = statat(&dir, "a", AtFlags::empty()).unwrap();
+
+    let (a_path, b_path, c_path) = (crate::cstr!("a"), crate::cstr!("b"), crate::cstr!("c"));
+    assert_eq!(
+        rename_noreplace_by_linking(dir.as_fd(), a_path, dir.as_fd(), b_path),
+        Err(io::Errno::EXIST)
+    );
+    rename_noreplace_by_linking(dir.as_fd(), a_path, dir.as_fd(), c_path).unwrap();
+    assert_eq!(
+        statat(&dir, "a", AtFlags::empty()).unwrap_err(),
+        io::Errno::NOENT
+    );
+    let c = statat(&dir, "c", AtFlags::empty()).unwrap();
+    assert_eq!((a.st_dev, a.st_ino), (c.st_dev, c.st_ino));
+    assert_eq!(c.st_nlink, 1);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_rename_noreplace_after_inval() {
+    use crate::fs::{cwd, mkdirat, statat};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    let _ = openat(&dir, "a", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    let _ = openat(&dir, "b", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    mkdirat(&dir, "d", Mode::RWXU).unwrap();
+
+    let (a_path, b_path, c_path, d_path) = (
+        crate::cstr!("a"),
+        crate::cstr!("b"),
+        crate::cstr!("c"),
+        crate::cstr!("d"),
+    );
+    // An existing destination is reported as such, not as the `EINVAL`
+    // from `renameat2`.
+    assert_eq!(
+        rename_noreplace_after_inval(dir.as_fd(), a_path, dir.as_fd(), b_path),
+        Err(io::Errno::EXIST)
+    );
+    assert_eq!(
+        rename_noreplace_after_inval(dir.as_fd(), d_path, dir.as_fd(), c_path),
+        Err(io::Errno::INVAL)
+    );
+    rename_noreplace_after_inval(dir.as_fd(), a_path, dir.as_fd(), c_path).unwrap();
+    assert_eq!(
+        statat(&dir, "a", AtFlags::empty()).unwrap_err(),
+        io::Errno::NOENT
+    );
+    statat(&dir, "c", AtFlags::empty()).unwrap();
+}
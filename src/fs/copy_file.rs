@@ -0,0 +1,250 @@
+use crate::fd::{AsFd, BorrowedFd};
+use crate::fs::{copy_file_range, fstat, ftruncate, ioctl_ficlone, seek};
+use crate::io::{self, pread, pwrite, SeekFrom};
+use core::convert::TryInto;
+
+/// Copy the contents of one file to another, using the fastest mechanism
+/// available.
+///
+/// This replaces the contents of `dst_fd` with those of `src_fd`, from the
+/// start of both files, and returns the number of bytes copied. It tries, in
+/// order:
+///
+///  - `ioctl(dst_fd, FICLONE, src_fd)`, which shares the data of the files
+///    without copying it, if the filesystem supports reflinks,
+///  - `copy_file_range`, which copies the data within the kernel and may
+///    offload it to the filesystem or storage,
+///  - `sendfile`, which copies the data within the kernel, and
+///  - a `pread`/`pwrite` loop, which skips the holes in `src_fd` using
+///    `SEEK_DATA` and `SEEK_HOLE` so that they stay holes in `dst_fd`.
+///
+/// Each mechanism is abandoned for the next when it fails with
+/// [`io::Errno::OPNOTSUPP`], [`io::Errno::XDEV`], [`io::Errno::INVAL`], or
+/// [`io::Errno::NOSYS`]; other errors are returned.
+///
+/// The file offset of `dst_fd` is unspecified afterwards, and that of
+/// `src_fd` may be changed.
+///
+/// # References
+///  - [Linux `ioctl_ficlone`]
+///  - [Linux `copy_file_range`]
+///  - [Linux `sendfile`]
+///
+/// [Linux `ioctl_ficlone`]: https://man7.org/linux/man-pages/man2/ioctl_ficlone.2.html
+/// [Linux `copy_file_range`]: https://man7.org/linux/man-pages/man2/copy_file_range.2.html
+/// [Linux `sendfile`]: https://man7.org/linux/man-pages/man2/sendfile.2.html
+pub fn copy_file<SrcFd: AsFd, DstFd: AsFd>(src_fd: SrcFd, dst_fd: DstFd) -> io::Result<u64> {
+    copy_file_with(Method::Clone, src_fd.as_fd(), dst_fd.as_fd())
+}
+
+/// The mechanisms `copy_file` tries, in order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+enum Method {
+    Clone,
+    CopyFileRange,
+    #[cfg(target_os = "linux")]
+    Sendfile,
+}
+
+/// Copy `src` to `dst`, starting with `first` and falling back from there.
+fn copy_file_with(first: Method, src: BorrowedFd<'_>, dst: BorrowedFd<'_>) -> io::Result<u64> {
+    let len = fstat(src)?.st_size as u64;
+
+    if first <= Method::Clone {
+        match ioctl_ficlone(dst, src) {
+            Err(err) if falls_back(err) => (),
+            result => return result.map(|()| len),
+        }
+    }
+
+    // Clear out `dst` so that nothing it contained before remains in the
+    // holes we don't write to.
+    ftruncate(dst, 0)?;
+
+    if first <= Method::CopyFileRange {
+        match copy_with_copy_file_range(src, dst, len) {
+            Err(err) if falls_back(err) => (),
+            result => return result,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    if first <= Method::Sendfile {
+        match copy_with_sendfile(src, dst, len) {
+            Err(err) if falls_back(err) => (),
+            result => return result,
+        }
+    }
+
+    // A mechanism above may have written part of `dst` before giving up, and
+    // the loop below leaves holes unwritten, so clear it out again.
+    ftruncate(dst, 0)?;
+    copy_with_read_write(src, dst, len)
+}
+
+/// Test whether `err` means that a copy mechanism isn't supported for the
+/// files given to it, so the next one should be tried.
+fn falls_back(err: io::Errno) -> bool {
+    matches!(
+        err,
+        io::Errno::OPNOTSUPP | io::Errno::XDEV | io::Errno::INVAL | io::Errno::NOSYS
+    )
+}
+
+/// The most we pass to `copy_file_range` or `sendfile` in one call, which is
+/// what Linux transfers at most.
+const MAX_CHUNK: u64 = 0x7fff_f000;
+
+fn copy_with_copy_file_range(
+    src: BorrowedFd<'_>,
+    dst: BorrowedFd<'_>,
+    len: u64,
+) -> io::Result<u64> {
+    let mut off_in = 0;
+    let mut off_out = 0;
+    while off_in < len {
+        let chunk = (len - off_in).min(MAX_CHUNK) as usize;
+        let n = copy_file_range(src, Some(&mut off_in), dst, Some(&mut off_out), chunk)?;
+        if n == 0 {
+            // `src` was truncated while we were copying it.
+            break;
+        }
+    }
+    Ok(off_out)
+}
+
+#[cfg(target_os = "linux")]
+fn copy_with_sendfile(src: BorrowedFd<'_>, dst: BorrowedFd<'_>, len: u64) -> io::Result<u64> {
+    // `sendfile` writes at the file offset of `dst`.
+    seek(dst, SeekFrom::Start(0))?;
+
+    let mut offset = 0;
+    let mut copied = 0;
+    while offset < len {
+        let chunk = (len - offset).min(MAX_CHUNK) as usize;
+        let n = crate::fs::sendfile(dst, src, Some(&mut offset), chunk)?;
+        if n == 0 {
+            break;
+        }
+        copied += n as u64;
+    }
+    Ok(copied)
+}
+
+fn copy_with_read_write(src: BorrowedFd<'_>, dst: BorrowedFd<'_>, len: u64) -> io::Result<u64> {
+    let mut pos = 0;
+    while pos < len {
+        let start = pos.try_into().map_err(|_| io::Errno::OVERFLOW)?;
+        let data = match seek(src, SeekFrom::Data(start)) {
+            Ok(data) => data,
+            // Only a hole remains.
+            Err(io::Errno::NXIO) => break,
+            // `SEEK_DATA` isn't supported, so copy everything.
+            Err(io::Errno::INVAL) => pos,
+            Err(err) => return Err(err),
+        };
+        if data >= len {
+            break;
+        }
+        let hole = match seek(src, SeekFrom::Hole(data as i64)) {
+            Ok(hole) => hole.min(len),
+            Err(io::Errno::INVAL) => len,
+            Err(err) => return Err(err),
+        };
+        pos = copy_range(src, dst, data, hole)?;
+        if pos < hole {
+            // `src` was truncated while we were copying it.
+            ftruncate(dst, pos)?;
+            return Ok(pos);
+        }
+    }
+
+    // Extend `dst` over any hole at the end.
+    ftruncate(dst, len)?;
+    Ok(len)
+}
+
+/// Copy the bytes from `start` to `end` with `pread` and `pwrite`, and
+/// return the offset copying stopped at.
+fn copy_range(src: BorrowedFd<'_>, dst: BorrowedFd<'_>, start: u64, end: u64) -> io::Result<u64> {
+    let mut buf = [0_u8; 8192];
+    let mut pos = start;
+    while pos < end {
+        let chunk = (end - pos).min(buf.len() as u64) as usize;
+        let n = pread(src, &mut buf[..chunk], pos)?;
+        if n == 0 {
+            break;
+        }
+        let mut written = 0;
+        while written < n {
+            written += pwrite(dst, &buf[written..n], pos + written as u64)?;
+        }
+        pos += n as u64;
+    }
+    Ok(pos)
+}
+
+#[test]
+fn test_copy_file_fallbacks() {
+    use crate::fs::{cwd, openat, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let src = openat(
+        cwd(),
+        tmp.path().join("src"),
+        OFlags::CREATE | OFlags::RDWR,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    // Data, a hole, more data, and a hole at the end.
+    pwrite(&src, b"start", 0).unwrap();
+    pwrite(&src, b"middle", 1 << 20).unwrap();
+    ftruncate(&src, 3 << 20).unwrap();
+
+    // `None` stands for the `pread`/`pwrite` loop.
+    let mut methods = vec![Some(Method::CopyFileRange), None];
+    #[cfg(target_os = "linux")]
+    methods.push(Some(Method::Sendfile));
+
+    for method in methods {
+        let dst = openat(
+            cwd(),
+            tmp.path().join(format!("{:?}", method)),
+            OFlags::CREATE | OFlags::RDWR,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap();
+        pwrite(&dst, b"old contents", 4 << 20).unwrap();
+
+        let copied = match method {
+            Some(method) => copy_file_with(method, src.as_fd(), dst.as_fd()),
+            None => {
+                ftruncate(&dst, 0).unwrap();
+                copy_with_read_write(src.as_fd(), dst.as_fd(), 3 << 20)
+            }
+        };
+        assert_eq!(copied, Ok(3 << 20));
+        assert_eq!(fstat(&dst).unwrap().st_size, 3 << 20);
+
+        let mut buf = vec![0_u8; 3 << 20];
+        let mut pos = 0;
+        while pos < buf.len() {
+            pos += pread(&dst, &mut buf[pos..], pos as u64).unwrap();
+        }
+        assert_eq!(&buf[..5], b"start");
+        assert_eq!(&buf[1 << 20..(1 << 20) + 6], b"middle");
+        assert!(buf[5..1 << 20].iter().all(|b| *b == 0));
+        assert!(buf[(1 << 20) + 6..].iter().all(|b| *b == 0));
+
+        if method.is_none() {
+            // The holes are preserved, if the filesystem supports them.
+            if let Ok(data) = seek(&dst, SeekFrom::Data(0)) {
+                assert_eq!(data, 0);
+                let hole = seek(&dst, SeekFrom::Hole(0)).unwrap();
+                assert!(hole < 1 << 20, "{}", hole);
+                assert_eq!(seek(&dst, SeekFrom::Data(2 << 20)), Err(io::Errno::NXIO));
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Alignment-checked `O_DIRECT` I/O.
+
+use crate::fd::AsFd;
+use crate::fs::{fstat, statx, AtFlags, StatxFlags};
+use crate::io;
+use core::fmt;
+
+/// The alignment requirements of `O_DIRECT` I/O on a file, as returned by
+/// [`dio_alignment`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DioAlignment {
+    /// `stx_dio_mem_align`—The alignment, in bytes, required of the
+    /// addresses of user memory buffers.
+    pub mem_align: u32,
+    /// `stx_dio_offset_align`—The alignment, in bytes, required of file
+    /// offsets and I/O lengths.
+    pub offset_align: u32,
+}
+
+impl DioAlignment {
+    /// Check that a buffer at `addr` of `len` bytes, at file offset `offset`,
+    /// meets these requirements.
+    pub fn check(&self, addr: *const u8, len: usize, offset: u64) -> Result<(), DirectIoError> {
+        if addr as usize % self.mem_align as usize != 0 {
+            Err(DirectIoError::MisalignedBuffer)
+        } else if len % self.offset_align as usize != 0 {
+            Err(DirectIoError::MisalignedLength)
+        } else if offset % u64::from(self.offset_align) != 0 {
+            Err(DirectIoError::MisalignedOffset)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// `statx(fd, "", AT_EMPTY_PATH, STATX_DIOALIGN)`—Returns the alignment
+/// requirements of `O_DIRECT` I/O on a file.
+///
+/// On Linux before 6.1, which doesn't report `STATX_DIOALIGN`, this falls
+/// back to `ioctl(fd, BLKSSZGET)` for block devices, which gives the logical
+/// block size, and otherwise to the file's `st_blksize`, which is at least
+/// as strict as the actual requirements on common filesystems.
+///
+/// This fails with [`io::Errno::INVAL`] if the file doesn't support
+/// `O_DIRECT` I/O.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/statx.2.html
+#[doc(alias = "STATX_DIOALIGN")]
+pub fn dio_alignment<Fd: AsFd>(fd: Fd) -> io::Result<DioAlignment> {
+    let fd = fd.as_fd();
+
+    match statx(fd, "", AtFlags::EMPTY_PATH, StatxFlags::DIOALIGN) {
+        Ok(x) if StatxFlags::from_bits_truncate(x.stx_mask).contains(StatxFlags::DIOALIGN) => {
+            // Both are zero if `O_DIRECT` isn't supported.
+            if x.stx_dio_mem_align == 0 || x.stx_dio_offset_align == 0 {
+                return Err(io::Errno::INVAL);
+            }
+            return Ok(DioAlignment {
+                mem_align: x.stx_dio_mem_align,
+                offset_align: x.stx_dio_offset_align,
+            });
+        }
+        Ok(_) | Err(io::Errno::NOSYS) => (),
+        Err(err) => return Err(err),
+    }
+
+    let align = match io::ioctl_blksszget(fd) {
+        Ok(size) => size,
+        Err(io::Errno::NOTTY) => fstat(fd)?.st_blksize as u32,
+        Err(err) => return Err(err),
+    };
+    Ok(DioAlignment {
+        mem_align: align,
+        offset_align: align,
+    })
+}
+
+/// A file opened with `O_DIRECT`, whose reads and writes are checked against
+/// its alignment requirements before they're issued.
+///
+/// Misaligned `O_DIRECT` I/O fails with a bare [`io::Errno::INVAL`], which is
+/// also used for many other problems. `DirectIo` catches misalignment itself
+/// and reports which part of the request is misaligned.
+#[derive(Debug)]
+pub struct DirectIo<Fd> {
+    fd: Fd,
+    alignment: DioAlignment,
+}
+
+impl<Fd: AsFd> DirectIo<Fd> {
+    /// Wrap `fd`, which should be opened with [`OFlags::DIRECT`], querying
+    /// its alignment requirements with [`dio_alignment`].
+    ///
+    /// [`OFlags::DIRECT`]: crate::fs::OFlags::DIRECT
+    pub fn new(fd: Fd) -> io::Result<Self> {
+        let alignment = dio_alignment(&fd)?;
+        Ok(Self { fd, alignment })
+    }
+
+    /// Return the alignment requirements of the file.
+    #[inline]
+    pub fn alignment(&self) -> DioAlignment {
+        self.alignment
+    }
+
+    /// Return a reference to the wrapped file descriptor.
+    #[inline]
+    pub fn get_ref(&self) -> &Fd {
+        &self.fd
+    }
+
+    /// Unwrap the file descriptor.
+    #[inline]
+    pub fn into_inner(self) -> Fd {
+        self.fd
+    }
+
+    /// `pread(fd, buf, offset)`, after checking that `buf` and `offset` are
+    /// aligned.
+    pub fn pread(&self, buf: &mut [u8], offset: u64) -> Result<usize, DirectIoError> {
+        self.alignment.check(buf.as_ptr(), buf.len(), offset)?;
+        Ok(io::pread(&self.fd, buf, offset)?)
+    }
+
+    /// `pwrite(fd, buf, offset)`, after checking that `buf` and `offset` are
+    /// aligned.
+    pub fn pwrite(&self, buf: &[u8], offset: u64) -> Result<usize, DirectIoError> {
+        self.alignment.check(buf.as_ptr(), buf.len(), offset)?;
+        Ok(io::pwrite(&self.fd, buf, offset)?)
+    }
+}
+
+/// An error from [`DirectIo`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DirectIoError {
+    /// The address of the buffer isn't a multiple of
+    /// [`DioAlignment::mem_align`].
+    MisalignedBuffer,
+    /// The length of the buffer isn't a multiple of
+    /// [`DioAlignment::offset_align`].
+    MisalignedLength,
+    /// The file offset isn't a multiple of [`DioAlignment::offset_align`].
+    MisalignedOffset,
+    /// The I/O itself failed.
+    Io(io::Errno),
+}
+
+impl From<io::Errno> for DirectIoError {
+    #[inline]
+    fn from(err: io::Errno) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl fmt::Display for DirectIoError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MisalignedBuffer => fmt.write_str("direct I/O buffer address is misaligned"),
+            Self::MisalignedLength => fmt.write_str("direct I/O length is misaligned"),
+            Self::MisalignedOffset => fmt.write_str("direct I/O file offset is misaligned"),
+            Self::Io(err) => fmt::Display::fmt(err, fmt),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DirectIoError {}
+
+#[cfg(feature = "std")]
+impl From<DirectIoError> for std::io::Error {
+    fn from(err: DirectIoError) -> Self {
+        match err {
+            DirectIoError::Io(err) => err.into(),
+            misaligned => Self::new(std::io::ErrorKind::InvalidInput, misaligned),
+        }
+    }
+}
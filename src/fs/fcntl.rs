@@ -30,6 +30,11 @@ pub fn fcntl_getfl<Fd: AsFd>(fd: Fd) -> io::Result<OFlags> {
 
 /// `fcntl(fd, F_SETFL, flags)`—Sets a file descriptor's status.
 ///
+/// Only some status flags can be changed this way, such as
+/// [`OFlags::NONBLOCK`], [`OFlags::APPEND`], and, on some platforms,
+/// `OFlags::DIRECT` and `OFlags::ASYNC`. Others, including the access mode and
+/// `O_CLOEXEC`, are ignored. To change `O_CLOEXEC`, use [`fcntl_setfd`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -42,6 +47,102 @@ pub fn fcntl_setfl<Fd: AsFd>(fd: Fd, flags: OFlags) -> io::Result<()> {
     backend::fs::syscalls::fcntl_setfl(fd.as_fd(), flags)
 }
 
+/// Enables or disables nonblocking mode on a file descriptor.
+///
+/// This reads the status flags with [`fcntl_getfl`], sets or clears
+/// [`OFlags::NONBLOCK`], and writes them back with [`fcntl_setfl`]. It isn't
+/// atomic with respect to other threads changing the status flags of the
+/// same file description.
+///
+/// See also [`ioctl_fionbio`], which sets nonblocking mode in a single call.
+///
+/// [`ioctl_fionbio`]: crate::io::ioctl_fionbio
+#[inline]
+pub fn set_nonblocking<Fd: AsFd>(fd: Fd, nonblocking: bool) -> io::Result<()> {
+    let fd = fd.as_fd();
+    let flags = fcntl_getfl(fd)?;
+    let new_flags = if nonblocking {
+        flags | OFlags::NONBLOCK
+    } else {
+        flags - OFlags::NONBLOCK
+    };
+    if new_flags != flags {
+        fcntl_setfl(fd, new_flags)?;
+    }
+    Ok(())
+}
+
+/// `fcntl(fd, F_GETLEASE)`—Returns the type of lease held on a file.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_GETLEASE")]
+pub fn fcntl_getlease<Fd: AsFd>(fd: Fd) -> io::Result<LeaseType> {
+    backend::fs::syscalls::fcntl_getlease(fd.as_fd())
+}
+
+/// `fcntl(fd, F_SETLEASE, lease)`—Takes or releases a lease on a file.
+///
+/// A lease notifies the holder when another process opens the file in a
+/// conflicting way, or truncates it. A read lease conflicts with opens for
+/// writing, and a write lease conflicts with any open. By default the
+/// notification is a `SIGIO` signal, which terminates the process unless it's
+/// handled; this can be changed with `F_SETSIG`. The holder should then clean
+/// up and release the lease with [`LeaseType::Unlock`] before the
+/// `/proc/sys/fs/lease-break-time` timeout expires.
+///
+/// Leases can only be taken on regular files. Taking a lease requires the
+/// file to be owned by the process' filesystem user ID, or `CAP_LEASE`. A
+/// read lease requires `fd` to be opened read-only, and a write lease
+/// requires there to be no other open file descriptors for the file.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_SETLEASE")]
+pub fn fcntl_setlease<Fd: AsFd>(fd: Fd, lease: LeaseType) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_setlease(fd.as_fd(), lease)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use backend::fs::types::LeaseType;
+
+/// `fcntl(fd, F_NOTIFY, flags)`—Requests a signal when a directory changes.
+///
+/// This is dnotify, which sends a signal to the process when one of the
+/// events in `flags` happens to a file in the directory `fd`. By default the
+/// signal is `SIGIO`, which terminates the process unless it's handled; this
+/// can be changed with `F_SETSIG`, and with a realtime signal, the
+/// `si_fd` field of its `siginfo_t` is set to `fd`. Unless
+/// [`DnotifyFlags::MULTISHOT`] is set, the notification happens only once.
+/// Passing empty `flags` disarms it.
+///
+/// dnotify only works on directories, and only reports that something
+/// changed, not what. [`inotify`] is generally preferred.
+///
+/// # References
+///  - [Linux]
+///
+/// [`inotify`]: crate::fs::inotify
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_NOTIFY")]
+#[doc(alias = "dnotify")]
+pub fn fcntl_dnotify<Fd: AsFd>(fd: Fd, flags: DnotifyFlags) -> io::Result<()> {
+    backend::fs::syscalls::fcntl_dnotify(fd.as_fd(), flags)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use backend::fs::types::DnotifyFlags;
+
 /// `fcntl(fd, F_GET_SEALS)`
 ///
 /// # References
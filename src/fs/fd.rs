@@ -50,16 +50,72 @@ pub use backend::fs::types::FsWord;
 ///
 /// [`utimensat`]: crate::fs::utimensat
 /// [`futimens`]: crate::fs::futimens
-// This is `repr(C)` and specifically laid out to match the representation used
-// by `utimensat` and `futimens`, which expect 2-element arrays of timestamps.
-#[repr(C)]
 #[derive(Clone, Debug)]
 pub struct Timestamps {
     /// The timestamp of the last access to a filesystem object.
-    pub last_access: crate::fs::Timespec,
+    pub last_access: Timestamp,
 
     /// The timestamp of the last modification of a filesystem object.
-    pub last_modification: crate::fs::Timespec,
+    pub last_modification: Timestamp,
+}
+
+/// A timestamp to set with [`utimensat`] or [`futimens`].
+///
+/// [`utimensat`]: crate::fs::utimensat
+/// [`futimens`]: crate::fs::futimens
+#[derive(Clone, Copy, Debug)]
+pub enum Timestamp {
+    /// Set the timestamp to the given time.
+    SetTo(crate::fs::Timespec),
+
+    /// Set the timestamp to the current time, like `UTIME_NOW`.
+    #[cfg(not(target_os = "redox"))]
+    Now,
+
+    /// Leave the timestamp unchanged, like `UTIME_OMIT`.
+    #[cfg(not(target_os = "redox"))]
+    Omit,
+}
+
+impl Timestamps {
+    /// Convert to the layout expected by the backends, encoding `Now` and
+    /// `Omit` as the `UTIME_NOW` and `UTIME_OMIT` sentinels.
+    #[inline]
+    pub(crate) fn to_raw(&self) -> RawTimestamps {
+        RawTimestamps {
+            last_access: self.last_access.to_raw(),
+            last_modification: self.last_modification.to_raw(),
+        }
+    }
+}
+
+impl Timestamp {
+    #[inline]
+    fn to_raw(self) -> crate::fs::Timespec {
+        match self {
+            Self::SetTo(timespec) => timespec,
+            #[cfg(not(target_os = "redox"))]
+            Self::Now => crate::fs::Timespec {
+                tv_sec: 0,
+                tv_nsec: crate::fs::UTIME_NOW,
+            },
+            #[cfg(not(target_os = "redox"))]
+            Self::Omit => crate::fs::Timespec {
+                tv_sec: 0,
+                tv_nsec: crate::fs::UTIME_OMIT,
+            },
+        }
+    }
+}
+
+/// Timestamps in the form passed to `utimensat` and `futimens`.
+// This is `repr(C)` and specifically laid out to match the representation used
+// by `utimensat` and `futimens`, which expect 2-element arrays of timestamps.
+#[repr(C)]
+#[derive(Clone, Debug)]
+pub(crate) struct RawTimestamps {
+    pub(crate) last_access: crate::fs::Timespec,
+    pub(crate) last_modification: crate::fs::Timespec,
 }
 
 /// The filesystem magic number for procfs.
@@ -78,6 +134,112 @@ pub const PROC_SUPER_MAGIC: FsWord = backend::fs::types::PROC_SUPER_MAGIC;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub const NFS_SUPER_MAGIC: FsWord = backend::fs::types::NFS_SUPER_MAGIC;
 
+/// A filesystem type, identified by the `f_type` magic number reported by
+/// [`statfs`] and [`fstatfs`].
+///
+/// See [the `fstatfs` man page] for more information.
+///
+/// [`statfs`]: crate::fs::statfs
+/// [the `fstatfs` man page]: https://man7.org/linux/man-pages/man2/fstatfs.2.html#DESCRIPTION
+///
+/// `FsType`s compare by their magic numbers, so an `Unknown` holding a magic
+/// number listed here is equal to its named variant. [`FsType::from_raw`]
+/// always returns the named variant.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum FsType {
+    /// `TMPFS_MAGIC`
+    Tmpfs,
+
+    /// `PROC_SUPER_MAGIC`
+    Proc,
+
+    /// `SYSFS_MAGIC`
+    Sysfs,
+
+    /// `CGROUP2_SUPER_MAGIC`
+    Cgroup2,
+
+    /// `OVERLAYFS_SUPER_MAGIC`
+    Overlayfs,
+
+    /// `BTRFS_SUPER_MAGIC`
+    Btrfs,
+
+    /// `EXT4_SUPER_MAGIC`, which is also used by ext2 and ext3.
+    Ext4,
+
+    /// `XFS_SUPER_MAGIC`
+    Xfs,
+
+    /// `NFS_SUPER_MAGIC`
+    Nfs,
+
+    /// A filesystem type not otherwise listed here.
+    Unknown(FsWord),
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl FsType {
+    /// Construct an `FsType` from the `f_type` field of a [`StatFs`].
+    #[inline]
+    pub const fn from_raw(f_type: FsWord) -> Self {
+        use backend::fs::types as t;
+
+        match f_type {
+            t::TMPFS_MAGIC => Self::Tmpfs,
+            t::PROC_SUPER_MAGIC => Self::Proc,
+            t::SYSFS_MAGIC => Self::Sysfs,
+            t::CGROUP2_SUPER_MAGIC => Self::Cgroup2,
+            t::OVERLAYFS_SUPER_MAGIC => Self::Overlayfs,
+            t::BTRFS_SUPER_MAGIC => Self::Btrfs,
+            t::EXT4_SUPER_MAGIC => Self::Ext4,
+            t::XFS_SUPER_MAGIC => Self::Xfs,
+            t::NFS_SUPER_MAGIC => Self::Nfs,
+            other => Self::Unknown(other),
+        }
+    }
+
+    /// Return the raw magic number for this `FsType`.
+    #[inline]
+    pub const fn as_raw(self) -> FsWord {
+        use backend::fs::types as t;
+
+        match self {
+            Self::Tmpfs => t::TMPFS_MAGIC,
+            Self::Proc => t::PROC_SUPER_MAGIC,
+            Self::Sysfs => t::SYSFS_MAGIC,
+            Self::Cgroup2 => t::CGROUP2_SUPER_MAGIC,
+            Self::Overlayfs => t::OVERLAYFS_SUPER_MAGIC,
+            Self::Btrfs => t::BTRFS_SUPER_MAGIC,
+            Self::Ext4 => t::EXT4_SUPER_MAGIC,
+            Self::Xfs => t::XFS_SUPER_MAGIC,
+            Self::Nfs => t::NFS_SUPER_MAGIC,
+            Self::Unknown(f_type) => f_type,
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl PartialEq for FsType {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_raw() == other.as_raw()
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl Eq for FsType {}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl core::hash::Hash for FsType {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_raw().hash(state)
+    }
+}
+
 /// `lseek(fd, offset, whence)`—Repositions a file descriptor within a file.
 ///
 /// # References
@@ -209,6 +371,10 @@ pub fn fstatvfs<Fd: AsFd>(fd: Fd) -> io::Result<StatVfs> {
 
 /// `futimens(fd, times)`—Sets timestamps for an open file or directory.
 ///
+/// This sets timestamps without needing a path, for example on an
+/// `O_TMPFILE` file before it's linked into the filesystem. Use
+/// [`Timestamp::Omit`] to leave either timestamp unchanged.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -217,7 +383,7 @@ pub fn fstatvfs<Fd: AsFd>(fd: Fd) -> io::Result<StatVfs> {
 /// [Linux]: https://man7.org/linux/man-pages/man2/utimensat.2.html
 #[inline]
 pub fn futimens<Fd: AsFd>(fd: Fd, times: &Timestamps) -> io::Result<()> {
-    backend::fs::syscalls::futimens(fd.as_fd(), times)
+    backend::fs::syscalls::futimens(fd.as_fd(), &times.to_raw())
 }
 
 /// `fallocate(fd, mode, offset, len)`—Adjusts file allocation.
@@ -326,6 +492,11 @@ pub fn fdatasync<Fd: AsFd>(fd: Fd) -> io::Result<()> {
 
 /// `ftruncate(fd, length)`—Sets the length of a file.
 ///
+/// The length is always 64 bits, even on 32-bit platforms. See [`truncate`]
+/// for a version that takes a path.
+///
+/// [`truncate`]: crate::fs::truncate
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -0,0 +1,22 @@
+//! Filesystem-oriented `ioctl`s.
+
+use crate::{backend, io};
+use backend::fd::AsFd;
+
+/// `ioctl(fd, FICLONE, src_fd)`—Share all of the data of `src_fd` with
+/// `fd`.
+///
+/// This makes `fd` a copy-on-write clone of `src_fd`, replacing its
+/// contents. Both must be regular files on the same filesystem, and the
+/// filesystem must support reflinks; otherwise this fails with
+/// [`io::Errno::XDEV`] or [`io::Errno::OPNOTSUPP`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ioctl_ficlone.2.html
+#[inline]
+#[doc(alias = "FICLONE")]
+pub fn ioctl_ficlone<Fd: AsFd, SrcFd: AsFd>(fd: Fd, src_fd: SrcFd) -> io::Result<()> {
+    backend::fs::syscalls::ioctl_ficlone(fd.as_fd(), src_fd.as_fd())
+}
@@ -3,6 +3,10 @@ use crate::fs::Dev;
 
 /// `makedev(maj, min)`
 ///
+/// This uses the platform's encoding of device numbers, which on Linux
+/// splits the major and minor numbers into several bit fields, so it isn't a
+/// simple shift.
+///
 /// # References
 ///  - [Linux]
 ///
@@ -5,11 +5,15 @@ mod abs;
 mod at;
 mod constants;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+mod copy_file;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod copy_file_range;
 #[cfg(not(target_os = "redox"))]
 mod cwd;
 #[cfg(not(target_os = "redox"))]
 mod dir;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod direct_io;
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "haiku",
@@ -31,6 +35,8 @@ pub(crate) mod fd;
 mod file_type;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 mod getpath;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod ioctl;
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "freebsd",
@@ -50,13 +56,30 @@ mod memfd_create;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod mount;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+mod open_path;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod openat2;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+mod quota;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod raw_dir;
 #[cfg(target_os = "linux")]
 mod sendfile;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod statx;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod swap;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod sync;
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+mod vnode_watch;
 
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use crate::backend::fs::inotify;
@@ -77,8 +100,19 @@ pub use abs::statfs;
     target_os = "wasi",
 )))]
 pub use abs::statvfs;
+#[cfg(not(target_os = "wasi"))]
+pub use abs::truncate;
 #[cfg(not(any(target_os = "illumos", target_os = "redox", target_os = "solaris")))]
 pub use at::accessat;
+#[cfg(not(any(
+    target_os = "emscripten",
+    target_os = "illumos",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "wasi",
+    all(libc, target_os = "android"),
+)))]
+pub use at::can_access;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use at::fclonefileat;
 #[cfg(not(any(
@@ -88,15 +122,15 @@ pub use at::fclonefileat;
     target_os = "wasi",
 )))]
 pub use at::mknodat;
-#[cfg(any(target_os = "android", target_os = "linux"))]
-pub use at::renameat_with;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
-pub use at::{chmodat, chownat};
+pub use at::{chmodat, chmodat_with, chownat};
 #[cfg(not(target_os = "redox"))]
 pub use at::{
     linkat, mkdirat, openat, readlinkat, renameat, statat, symlinkat, unlinkat, utimensat, RawMode,
     UTIME_NOW, UTIME_OMIT,
 };
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use at::{rename_noreplace, renameat_with};
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use constants::CloneFlags;
 /// `copyfile_flags_t`
@@ -108,11 +142,15 @@ pub use constants::{AtFlags, Dev};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use constants::{MountFlags, MountPropagationFlags, RenameFlags, ResolveFlags, UnmountFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
+pub use copy_file::copy_file;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 pub use copy_file_range::copy_file_range;
 #[cfg(not(target_os = "redox"))]
 pub use cwd::cwd;
 #[cfg(not(target_os = "redox"))]
 pub use dir::{Dir, DirEntry};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use direct_io::{dio_alignment, DioAlignment, DirectIo, DirectIoError};
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "haiku",
@@ -134,7 +172,11 @@ pub use fcntl::fcntl_dupfd_cloexec;
     target_os = "linux",
 ))]
 pub use fcntl::{fcntl_add_seals, fcntl_get_seals, SealFlags};
-pub use fcntl::{fcntl_getfd, fcntl_getfl, fcntl_setfd, fcntl_setfl};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use fcntl::{fcntl_dnotify, DnotifyFlags};
+pub use fcntl::{fcntl_getfd, fcntl_getfl, fcntl_setfd, fcntl_setfl, set_nonblocking};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use fcntl::{fcntl_getlease, fcntl_setlease, LeaseType};
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use fcntl_darwin::{fcntl_fullfsync, fcntl_rdadvise};
 #[cfg(any(target_os = "ios", target_os = "macos"))]
@@ -150,6 +192,7 @@ pub use fcopyfile::{
     target_os = "redox",
 )))]
 pub use fd::fdatasync;
+pub(crate) use fd::RawTimestamps;
 #[cfg(not(any(
     target_os = "aix",
     target_os = "dragonfly",
@@ -164,7 +207,9 @@ pub use fd::{fallocate, FallocateFlags};
 pub use fd::{fchmod, fchown};
 #[cfg(not(any(target_os = "solaris", target_os = "wasi")))]
 pub use fd::{flock, FlockOperation};
-pub use fd::{fstat, fsync, ftruncate, futimens, is_file_read_write, seek, tell, Stat, Timestamps};
+pub use fd::{
+    fstat, fsync, ftruncate, futimens, is_file_read_write, seek, tell, Stat, Timestamp, Timestamps,
+};
 #[cfg(not(any(
     target_os = "haiku",
     target_os = "illumos",
@@ -183,10 +228,12 @@ pub use fd::{fstatfs, StatFs};
 )))]
 pub use fd::{fstatvfs, StatVfs, StatVfsMountFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub use fd::{FsWord, NFS_SUPER_MAGIC, PROC_SUPER_MAGIC};
+pub use fd::{FsType, FsWord, NFS_SUPER_MAGIC, PROC_SUPER_MAGIC};
 pub use file_type::FileType;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use getpath::getpath;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use ioctl::ioctl_ficlone;
 #[cfg(not(any(
     target_os = "dragonfly",
     target_os = "freebsd",
@@ -205,16 +252,35 @@ pub use makedev::{major, makedev, minor};
 pub use memfd_create::{memfd_create, MemfdFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use mount::{
-    bind_mount, change_mount, mount, move_mount, recursive_bind_mount, remount, unmount,
+    bind_mount, change_mount, mount, mount2, move_mount, recursive_bind_mount, remount, unmount,
 };
 #[cfg(any(target_os = "android", target_os = "linux"))]
+pub use open_path::{open_path, PathOpenFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
 pub use openat2::openat2;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+pub use quota::{quotactl_get, quotactl_set, DiskQuota, QuotaType};
+#[cfg(any(target_os = "android", target_os = "linux"))]
 pub use raw_dir::{RawDir, RawDirEntry};
 #[cfg(target_os = "linux")]
 pub use sendfile::sendfile;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use statx::{statx, Statx, StatxFlags, StatxTimestamp};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use swap::{swapoff, swapon, SwapFlags};
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+pub use sync::sync;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use sync::syncfs;
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub use vnode_watch::{VnodeEvents, VnodeWatch};
 
 /// Re-export types common to POSIX-ish platforms.
 #[cfg(feature = "std")]
@@ -3,6 +3,7 @@
 use crate::backend::fs::types::{
     InternalMountFlags, MountFlags, MountFlagsArg, MountPropagationFlags, UnmountFlags,
 };
+use crate::ffi::CStr;
 use crate::{backend, io, path};
 
 /// `mount(source, target, filesystemtype, mountflags, data)`
@@ -36,6 +37,51 @@ pub fn mount<Source: path::Arg, Target: path::Arg, Fs: path::Arg, Data: path::Ar
     })
 }
 
+/// `mount(source, target, filesystemtype, mountflags, data)`—Like
+/// [`mount`], but with optional arguments.
+///
+/// Pass `None` for `source` or `file_system_type` where the operation
+/// doesn't use them, and for `data` when there are no file-system-specific
+/// options; these are passed to the kernel as null pointers.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mount.2.html
+#[inline]
+#[doc(alias = "mount")]
+pub fn mount2<Source: path::Arg, Target: path::Arg, Fs: path::Arg>(
+    source: Option<Source>,
+    target: Target,
+    file_system_type: Option<Fs>,
+    flags: MountFlags,
+    data: Option<&CStr>,
+) -> io::Result<()> {
+    option_into_with_c_str(source, |source| {
+        target.into_with_c_str(|target| {
+            option_into_with_c_str(file_system_type, |file_system_type| {
+                backend::fs::syscalls::mount(
+                    source,
+                    target,
+                    file_system_type,
+                    MountFlagsArg(flags.bits()),
+                    data,
+                )
+            })
+        })
+    })
+}
+
+fn option_into_with_c_str<Arg: path::Arg, T, F>(arg: Option<Arg>, f: F) -> io::Result<T>
+where
+    F: FnOnce(Option<&CStr>) -> io::Result<T>,
+{
+    match arg {
+        Some(arg) => arg.into_with_c_str(|arg| f(Some(arg))),
+        None => f(None),
+    }
+}
+
 /// `mount(null, target, null, MS_REMOUNT | mountflags, data)`
 ///
 /// # References
@@ -0,0 +1,56 @@
+use crate::fd::{AsFd, OwnedFd};
+use crate::fs::{openat, Mode, OFlags};
+use crate::{io, path};
+
+bitflags::bitflags! {
+    /// Flags for use with [`open_path`].
+    pub struct PathOpenFlags: u32 {
+        /// `O_NOFOLLOW`—If `path` is a symlink, refer to the symlink itself
+        /// rather than its target.
+        const NOFOLLOW = OFlags::NOFOLLOW.bits() as u32;
+        /// `O_DIRECTORY`—Fail with [`io::Errno::NOTDIR`] unless `path` is a
+        /// directory.
+        const DIRECTORY = OFlags::DIRECTORY.bits() as u32;
+    }
+}
+
+/// `openat(dirfd, path, O_PATH | O_CLOEXEC | flags, 0)`—Opens a path-only
+/// file descriptor.
+///
+/// An `O_PATH` file descriptor refers to a location in the filesystem
+/// without opening the file itself, so it needs no permission on the file,
+/// only search permission on the directories leading to it. It remains a
+/// stable reference even if the file is renamed.
+///
+/// The operations which work on such a file descriptor are:
+///  - [`fstat`] and [`statx`] with [`AtFlags::EMPTY_PATH`];
+///  - [`fstatfs`];
+///  - using it as the `dirfd` of `*at` calls such as [`openat`], if it
+///    refers to a directory;
+///  - [`fchdir`], if it refers to a directory;
+///  - [`fcntl_getfd`], [`fcntl_setfd`], and duplicating it.
+///
+/// Other operations, including `read` and `write`, fail with
+/// [`io::Errno::BADF`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/open.2.html
+/// [`fstat`]: crate::fs::fstat
+/// [`statx`]: crate::fs::statx
+/// [`AtFlags::EMPTY_PATH`]: crate::fs::AtFlags::EMPTY_PATH
+/// [`fstatfs`]: crate::fs::fstatfs
+/// [`fchdir`]: crate::process::fchdir
+/// [`fcntl_getfd`]: crate::io::fcntl_getfd
+/// [`fcntl_setfd`]: crate::io::fcntl_setfd
+#[inline]
+#[doc(alias = "O_PATH")]
+pub fn open_path<P: path::Arg, Fd: AsFd>(
+    dirfd: Fd,
+    path: P,
+    flags: PathOpenFlags,
+) -> io::Result<OwnedFd> {
+    let oflags = OFlags::PATH | OFlags::CLOEXEC | OFlags::from_bits_truncate(flags.bits() as _);
+    openat(dirfd, path, oflags, Mode::empty())
+}
@@ -0,0 +1,231 @@
+//! Linux disk quotas.
+
+#![allow(unsafe_code)]
+
+use crate::{backend, io, path};
+use core::mem::MaybeUninit;
+
+// These are in `<linux/quota.h>`, which neither `linux-raw-sys` nor the
+// `libc` crate fully provides.
+const Q_GETQUOTA: u32 = 0x80_0007;
+const Q_SETQUOTA: u32 = 0x80_0008;
+const SUBCMDSHIFT: u32 = 8;
+const SUBCMDMASK: u32 = 0x00ff;
+const QIF_BLIMITS: u32 = 1;
+const QIF_ILIMITS: u32 = 4;
+const QIF_LIMITS: u32 = QIF_BLIMITS | QIF_ILIMITS;
+
+/// `QCMD(cmd, type)`
+const fn qcmd(cmd: u32, quota_type: QuotaType) -> u32 {
+    (cmd << SUBCMDSHIFT) | (quota_type as u32 & SUBCMDMASK)
+}
+
+/// `*QUOTA` constants for use with [`quotactl_get`] and [`quotactl_set`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+pub enum QuotaType {
+    /// `USRQUOTA`—Quotas for a user ID.
+    User = 0,
+
+    /// `GRPQUOTA`—Quotas for a group ID.
+    Group = 1,
+
+    /// `PRJQUOTA`—Quotas for a project ID (since Linux 4.1).
+    Project = 2,
+}
+
+/// The quota limits and usage for one user, group, or project, for use with
+/// [`quotactl_get`] and [`quotactl_set`].
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct DiskQuota {
+    /// The hard limit on disk space, in 1024-byte blocks. Zero means no
+    /// limit.
+    pub block_hard_limit: u64,
+
+    /// The soft limit on disk space, in 1024-byte blocks. Zero means no
+    /// limit.
+    pub block_soft_limit: u64,
+
+    /// The disk space in use, in bytes.
+    pub space_used: u64,
+
+    /// The hard limit on the number of inodes. Zero means no limit.
+    pub inode_hard_limit: u64,
+
+    /// The soft limit on the number of inodes. Zero means no limit.
+    pub inode_soft_limit: u64,
+
+    /// The number of inodes in use.
+    pub inodes_used: u64,
+
+    /// When the grace period for exceeding the block soft limit ends, in
+    /// seconds since the Unix epoch, or zero if the soft limit isn't
+    /// exceeded.
+    pub block_grace_time: u64,
+
+    /// When the grace period for exceeding the inode soft limit ends, in
+    /// seconds since the Unix epoch, or zero if the soft limit isn't
+    /// exceeded.
+    pub inode_grace_time: u64,
+}
+
+/// `struct if_dqblk`
+#[allow(non_camel_case_types)]
+#[repr(C)]
+struct if_dqblk {
+    dqb_bhardlimit: u64,
+    dqb_bsoftlimit: u64,
+    dqb_curspace: u64,
+    dqb_ihardlimit: u64,
+    dqb_isoftlimit: u64,
+    dqb_curinodes: u64,
+    dqb_btime: u64,
+    dqb_itime: u64,
+    dqb_valid: u32,
+}
+
+impl if_dqblk {
+    fn from_quota(quota: &DiskQuota, valid: u32) -> Self {
+        Self {
+            dqb_bhardlimit: quota.block_hard_limit,
+            dqb_bsoftlimit: quota.block_soft_limit,
+            dqb_curspace: quota.space_used,
+            dqb_ihardlimit: quota.inode_hard_limit,
+            dqb_isoftlimit: quota.inode_soft_limit,
+            dqb_curinodes: quota.inodes_used,
+            dqb_btime: quota.block_grace_time,
+            dqb_itime: quota.inode_grace_time,
+            dqb_valid: valid,
+        }
+    }
+
+    fn to_quota(&self) -> DiskQuota {
+        DiskQuota {
+            block_hard_limit: self.dqb_bhardlimit,
+            block_soft_limit: self.dqb_bsoftlimit,
+            space_used: self.dqb_curspace,
+            inode_hard_limit: self.dqb_ihardlimit,
+            inode_soft_limit: self.dqb_isoftlimit,
+            inodes_used: self.dqb_curinodes,
+            block_grace_time: self.dqb_btime,
+            inode_grace_time: self.dqb_itime,
+        }
+    }
+}
+
+/// `quotactl(QCMD(Q_GETQUOTA, quota_type), device, id, &dqblk)`—Returns the
+/// disk quota limits and usage of a user, group, or project.
+///
+/// `device` is the block device of a mounted file system with quotas
+/// enabled. This uses the generic quota interface, which also covers XFS's
+/// own quota implementation. Querying an `id` other than the caller's own
+/// user or group requires the `CAP_SYS_ADMIN` capability.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/quotactl.2.html
+#[doc(alias = "quotactl")]
+#[doc(alias = "Q_GETQUOTA")]
+pub fn quotactl_get<P: path::Arg>(
+    device: P,
+    quota_type: QuotaType,
+    id: u32,
+) -> io::Result<DiskQuota> {
+    device.into_with_c_str(|device| {
+        let mut dqblk = MaybeUninit::<if_dqblk>::uninit();
+        unsafe {
+            backend::fs::syscalls::quotactl(
+                qcmd(Q_GETQUOTA, quota_type),
+                Some(device),
+                id,
+                dqblk.as_mut_ptr().cast(),
+            )?;
+            Ok(dqblk.assume_init().to_quota())
+        }
+    })
+}
+
+/// `quotactl(QCMD(Q_SETQUOTA, quota_type), device, id, &dqblk)`—Sets the
+/// disk quota limits of a user, group, or project.
+///
+/// Only the block and inode limits in `quota` are used; the usage and
+/// grace time fields are ignored. This requires the `CAP_SYS_ADMIN`
+/// capability.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/quotactl.2.html
+#[doc(alias = "quotactl")]
+#[doc(alias = "Q_SETQUOTA")]
+pub fn quotactl_set<P: path::Arg>(
+    device: P,
+    quota_type: QuotaType,
+    id: u32,
+    quota: &DiskQuota,
+) -> io::Result<()> {
+    let mut dqblk = if_dqblk::from_quota(quota, QIF_LIMITS);
+    device.into_with_c_str(|device| unsafe {
+        backend::fs::syscalls::quotactl(
+            qcmd(Q_SETQUOTA, quota_type),
+            Some(device),
+            id,
+            (&mut dqblk as *mut if_dqblk).cast(),
+        )
+    })
+}
+
+/// Check that our `if_dqblk` matches the layout of glibc's `struct dqblk`,
+/// which is the same as the kernel's `struct if_dqblk`, and that commands are
+/// encoded the way `QCMD` does.
+#[cfg(target_os = "linux")]
+#[test]
+fn quotactl_encoding() {
+    use core::mem::{align_of, size_of};
+    use memoffset::offset_of;
+
+    assert_eq!(size_of::<if_dqblk>(), size_of::<libc::dqblk>());
+    assert_eq!(align_of::<if_dqblk>(), align_of::<libc::dqblk>());
+    assert_eq!(size_of::<if_dqblk>(), 72);
+    assert_eq!(
+        offset_of!(if_dqblk, dqb_bhardlimit),
+        offset_of!(libc::dqblk, dqb_bhardlimit)
+    );
+    assert_eq!(
+        offset_of!(if_dqblk, dqb_curspace),
+        offset_of!(libc::dqblk, dqb_curspace)
+    );
+    assert_eq!(
+        offset_of!(if_dqblk, dqb_curinodes),
+        offset_of!(libc::dqblk, dqb_curinodes)
+    );
+    assert_eq!(
+        offset_of!(if_dqblk, dqb_itime),
+        offset_of!(libc::dqblk, dqb_itime)
+    );
+    assert_eq!(
+        offset_of!(if_dqblk, dqb_valid),
+        offset_of!(libc::dqblk, dqb_valid)
+    );
+
+    assert_eq!(Q_GETQUOTA, libc::Q_GETQUOTA as u32);
+    assert_eq!(Q_SETQUOTA, libc::Q_SETQUOTA as u32);
+    assert_eq!(qcmd(Q_GETQUOTA, QuotaType::User), 0x8000_0700);
+    assert_eq!(qcmd(Q_GETQUOTA, QuotaType::Group), 0x8000_0701);
+    assert_eq!(qcmd(Q_SETQUOTA, QuotaType::Project), 0x8000_0802);
+
+    let quota = DiskQuota {
+        block_hard_limit: 1,
+        block_soft_limit: 2,
+        space_used: 3,
+        inode_hard_limit: 4,
+        inode_soft_limit: 5,
+        inodes_used: 6,
+        block_grace_time: 7,
+        inode_grace_time: 8,
+    };
+    let dqblk = if_dqblk::from_quota(&quota, QIF_LIMITS);
+    assert_eq!(dqblk.dqb_valid, 5);
+    assert_eq!(dqblk.to_quota(), quota);
+}
@@ -0,0 +1,85 @@
+//! Linux swap area management.
+
+use crate::{backend, io, path};
+use bitflags::bitflags;
+
+// These are in `<linux/swap.h>`, which neither `linux-raw-sys` nor the `libc`
+// crate provides.
+const SWAP_FLAG_PREFER: u32 = 0x8000;
+const SWAP_FLAG_PRIO_MASK: u32 = 0x7fff;
+const SWAP_FLAG_DISCARD: u32 = 0x1_0000;
+const SWAP_FLAG_DISCARD_ONCE: u32 = 0x2_0000;
+const SWAP_FLAG_DISCARD_PAGES: u32 = 0x4_0000;
+
+bitflags! {
+    /// `SWAP_FLAG_*` constants for use with [`swapon`].
+    pub struct SwapFlags: u32 {
+        /// `SWAP_FLAG_PREFER`—Use the priority in the low bits instead of
+        /// a default, decreasing one. Use [`SwapFlags::priority`] to set
+        /// both.
+        const PREFER = SWAP_FLAG_PREFER;
+
+        /// `SWAP_FLAG_PRIO_MASK`—The bits which hold the priority.
+        const PRIORITY_MASK = SWAP_FLAG_PRIO_MASK;
+
+        /// `SWAP_FLAG_DISCARD`—Discard freed swap pages, if the device
+        /// supports it. Without `DISCARD_ONCE` or `DISCARD_PAGES`, this
+        /// implies both.
+        const DISCARD = SWAP_FLAG_DISCARD;
+
+        /// `SWAP_FLAG_DISCARD_ONCE`—Discard the whole swap area once, when
+        /// it's enabled.
+        const DISCARD_ONCE = SWAP_FLAG_DISCARD_ONCE;
+
+        /// `SWAP_FLAG_DISCARD_PAGES`—Discard freed swap pages as they're
+        /// released.
+        const DISCARD_PAGES = SWAP_FLAG_DISCARD_PAGES;
+    }
+}
+
+impl SwapFlags {
+    /// Returns `PREFER` with the given priority, from 0 to 32767. Higher
+    /// priority swap areas are used first.
+    ///
+    /// Priorities above 32767 are clamped to 32767, the highest one the
+    /// kernel can represent.
+    #[inline]
+    pub const fn priority(priority: u16) -> Self {
+        let priority = priority as u32;
+        let priority = if priority > SWAP_FLAG_PRIO_MASK {
+            SWAP_FLAG_PRIO_MASK
+        } else {
+            priority
+        };
+        Self::from_bits_truncate(SWAP_FLAG_PREFER | priority)
+    }
+}
+
+/// `swapon(path, flags)`—Enables swapping to a file or block device.
+///
+/// `path` must already have been prepared with `mkswap`. This requires the
+/// `CAP_SYS_ADMIN` capability, and fails with [`io::Errno::INVAL`] if `path`
+/// isn't a valid swap area.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/swapon.2.html
+#[inline]
+pub fn swapon<P: path::Arg>(path: P, flags: SwapFlags) -> io::Result<()> {
+    path.into_with_c_str(|path| backend::fs::syscalls::swapon(path, flags))
+}
+
+/// `swapoff(path)`—Disables swapping to a file or block device.
+///
+/// This requires the `CAP_SYS_ADMIN` capability, and fails with
+/// [`io::Errno::INVAL`] if `path` isn't an active swap area.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/swapoff.2.html
+#[inline]
+pub fn swapoff<P: path::Arg>(path: P) -> io::Result<()> {
+    path.into_with_c_str(backend::fs::syscalls::swapoff)
+}
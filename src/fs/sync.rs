@@ -0,0 +1,42 @@
+//! The `sync` and `syncfs` functions.
+
+use crate::backend;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::fd::AsFd;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::io;
+
+/// `sync()`—Writes all pending file system modifications to the underlying
+/// storage devices.
+///
+/// On Linux this waits for the writes to complete; POSIX only requires that
+/// they be scheduled. To flush just one file, use [`fsync`], and to flush
+/// just the file system containing a file, use [`syncfs`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sync.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sync.2.html
+/// [`fsync`]: crate::fs::fsync
+#[inline]
+pub fn sync() {
+    backend::fs::syscalls::sync()
+}
+
+/// `syncfs(fd)`—Writes all pending modifications to the file system
+/// containing `fd` to its underlying storage device.
+///
+/// This is much cheaper than [`sync`] on a system with several busy file
+/// systems.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/syncfs.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub fn syncfs<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    backend::fs::syscalls::syncfs(fd.as_fd())
+}
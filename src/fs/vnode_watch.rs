@@ -0,0 +1,94 @@
+//! Watching files for changes with `EVFILT_VNODE`.
+
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd};
+use crate::io;
+use crate::io::kqueue::{kevent, kqueue, Event, EventFilter, EventFlags};
+use alloc::vec::Vec;
+use core::time::Duration;
+
+pub use crate::io::kqueue::VnodeEvents;
+
+/// A watch for changes to an open file, using a kqueue `EVFILT_VNODE`
+/// filter.
+///
+/// This is the closest analog to Linux's [`inotify`] on BSDs and Apple
+/// platforms. Unlike inotify, the watch is on an open file descriptor rather
+/// than a path, so it follows the file across renames, and watching a
+/// directory only reports changes to the directory itself, such as entries
+/// being added or removed.
+///
+/// The `VnodeWatch` owns `fd`, so it stays open for as long as it's being
+/// watched. `VnodeWatch` implements [`AsFd`] for its kqueue, which becomes
+/// readable when an event is pending, so it can be used with `poll` or
+/// registered with another kqueue.
+///
+/// [`inotify`]: https://man7.org/linux/man-pages/man7/inotify.7.html
+pub struct VnodeWatch<Fd: AsFd> {
+    kqueue: OwnedFd,
+    fd: Fd,
+}
+
+impl<Fd: AsFd> VnodeWatch<Fd> {
+    /// Starts watching `fd` for any of the changes in `events`.
+    ///
+    /// Each change is reported once; repeated changes between calls to
+    /// [`wait`] are merged into a single result.
+    ///
+    /// [`wait`]: Self::wait
+    pub fn new(fd: Fd, events: VnodeEvents) -> io::Result<Self> {
+        let kqueue = kqueue()?;
+        let change = Event::new(
+            EventFilter::Vnode {
+                vnode: fd.as_fd().as_raw_fd(),
+                flags: events,
+            },
+            EventFlags::ADD | EventFlags::CLEAR,
+            0,
+        )?;
+
+        // Safety: `fd` is owned by the `VnodeWatch`, so it outlives its
+        // registration with `kqueue`.
+        unsafe { kevent(&kqueue, &[change], &mut Vec::new(), None)? };
+
+        Ok(Self { kqueue, fd })
+    }
+
+    /// Waits for a change to the file, and returns which of the watched
+    /// changes occurred.
+    ///
+    /// A `timeout` of `None` waits indefinitely. If the timeout expires
+    /// first, this returns an empty set of events. A timeout of zero polls
+    /// without blocking.
+    pub fn wait(&self, timeout: Option<Duration>) -> io::Result<VnodeEvents> {
+        let mut events = Vec::with_capacity(1);
+
+        // Safety: We aren't making any changes.
+        unsafe { kevent(&self.kqueue, &[], &mut events, timeout)? };
+
+        Ok(match events.first().map(Event::filter) {
+            Some(EventFilter::Vnode { flags, .. }) => flags,
+            _ => VnodeEvents::empty(),
+        })
+    }
+
+    /// Returns a reference to the file being watched.
+    #[inline]
+    pub fn get_ref(&self) -> &Fd {
+        &self.fd
+    }
+
+    /// Stops watching the file, and returns it.
+    #[inline]
+    pub fn into_inner(self) -> Fd {
+        self.fd
+    }
+}
+
+impl<Fd: AsFd> AsFd for VnodeWatch<Fd> {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.kqueue.as_fd()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::fd::OwnedFd;
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
 use crate::{backend, io};
 
 pub use backend::io::types::EventfdFlags;
@@ -14,3 +14,71 @@ pub use backend::io::types::EventfdFlags;
 pub fn eventfd(initval: u32, flags: EventfdFlags) -> io::Result<OwnedFd> {
     backend::io::syscalls::eventfd(initval, flags)
 }
+
+/// A cross-thread wakeup primitive, using an eventfd.
+///
+/// One or more threads call [`notify`] to wake up a thread which is waiting
+/// for the `Notify`'s file descriptor to become readable, typically in
+/// `poll` or `epoll_wait` along with other file descriptors. The woken
+/// thread then calls [`drain`] to reset it. Notifications which arrive
+/// before the waiting thread drains are merged.
+///
+/// `Notify` implements [`AsFd`], for registering its file descriptor with
+/// `poll` or epoll, waiting for [`PollFlags::IN`] or [`EventFlags::IN`].
+///
+/// [`notify`]: Self::notify
+/// [`drain`]: Self::drain
+/// [`PollFlags::IN`]: io::PollFlags::IN
+/// [`EventFlags::IN`]: io::epoll::EventFlags::IN
+#[derive(Debug)]
+pub struct Notify {
+    fd: OwnedFd,
+}
+
+impl Notify {
+    /// `eventfd(0, EFD_CLOEXEC | EFD_NONBLOCK)`—Creates a new `Notify`.
+    #[inline]
+    #[doc(alias = "eventfd")]
+    pub fn new() -> io::Result<Self> {
+        Ok(Self {
+            fd: eventfd(0, EventfdFlags::CLOEXEC | EventfdFlags::NONBLOCK)?,
+        })
+    }
+
+    /// `write(self, &1_u64.to_ne_bytes())`—Wakes up a thread waiting for
+    /// this `Notify` to become readable.
+    ///
+    /// This performs a single 8-byte `write`, which is async-signal-safe, so
+    /// it may be called from a signal handler.
+    #[doc(alias = "write")]
+    pub fn notify(&self) -> io::Result<()> {
+        match io::write(&self.fd, &1_u64.to_ne_bytes()) {
+            // The eventfd counter is saturated, so there's already a wakeup
+            // pending.
+            Ok(_) | Err(io::Errno::AGAIN) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// `read(self, &mut [0_u8; 8])`—Resets this `Notify`, and returns the
+    /// number of notifications since the last call to `drain`.
+    ///
+    /// This doesn't block; if there are no pending notifications, it returns
+    /// zero.
+    #[doc(alias = "read")]
+    pub fn drain(&self) -> io::Result<u64> {
+        let mut bytes = [0_u8; 8];
+        match io::read(&self.fd, &mut bytes) {
+            Ok(_) => Ok(u64::from_ne_bytes(bytes)),
+            Err(io::Errno::AGAIN) => Ok(0),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl AsFd for Notify {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
@@ -8,6 +8,8 @@
 //!
 //! [`io`]: crate::io
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::process::Pid;
 use crate::{backend, io};
 use backend::fd::{AsFd, OwnedFd, RawFd};
 
@@ -50,6 +52,10 @@ pub fn fcntl_setfd<Fd: AsFd>(fd: Fd, flags: FdFlags) -> io::Result<()> {
 /// rely on this, as file descriptors may be unexpectedly allocated on other
 /// threads or in libraries.
 ///
+/// This sets `O_CLOEXEC` atomically, so unlike a `dup` followed by
+/// [`fcntl_setfd`], there's no window in which another thread could `exec`
+/// and leak the new file descriptor.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -83,3 +89,92 @@ pub fn fcntl_dupfd_cloexec<Fd: AsFd>(fd: Fd, min: RawFd) -> io::Result<OwnedFd>
 pub fn fcntl_dupfd<Fd: AsFd>(fd: Fd, min: RawFd) -> io::Result<OwnedFd> {
     backend::io::syscalls::fcntl_dupfd(fd.as_fd(), min)
 }
+
+/// The owner of a file descriptor, which receives signals for signal-driven
+/// I/O, for use with [`fcntl_setown`] and [`fcntl_getown`].
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Owner {
+    /// `F_OWNER_PID`—A process.
+    Pid(Pid),
+
+    /// `F_OWNER_PGRP`—A process group.
+    ProcessGroup(Pid),
+
+    /// `F_OWNER_TID`—A specific thread.
+    Thread(Pid),
+}
+
+/// `fcntl(fd, F_SETOWN_EX, owner)`—Sets the process, process group, or
+/// thread which receives signals for I/O events on a file descriptor.
+///
+/// Signals are only sent once `O_ASYNC` is enabled, with
+/// [`fcntl_setfl`]. By default the signal is `SIGIO`; use [`fcntl_setsig`]
+/// to pick a different one.
+///
+/// # References
+///  - [Linux]
+///
+/// [`fcntl_setfl`]: crate::fs::fcntl_setfl
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_SETOWN")]
+#[doc(alias = "F_SETOWN_EX")]
+pub fn fcntl_setown<Fd: AsFd>(fd: Fd, owner: Owner) -> io::Result<()> {
+    backend::io::syscalls::fcntl_setown(fd.as_fd(), owner)
+}
+
+/// `fcntl(fd, F_GETOWN_EX)`—Returns the process, process group, or thread
+/// which receives signals for I/O events on a file descriptor.
+///
+/// Returns `None` if no owner has been set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_GETOWN")]
+#[doc(alias = "F_GETOWN_EX")]
+pub fn fcntl_getown<Fd: AsFd>(fd: Fd) -> io::Result<Option<Owner>> {
+    backend::io::syscalls::fcntl_getown(fd.as_fd())
+}
+
+/// `fcntl(fd, F_SETSIG, sig)`—Sets the signal sent to the owner when I/O is
+/// possible on a file descriptor.
+///
+/// `sig` is a raw signal number, so that realtime signals such as
+/// `SIGRTMIN + n` can be used. 0 restores the default, `SIGIO`. With any other
+/// signal, if the handler is installed with `SA_SIGINFO`, its `siginfo_t`
+/// reports the file descriptor in `si_fd` and the event in `si_band`.
+/// Realtime signals are also queued rather than coalesced, so none are lost
+/// when several file descriptors become ready at once.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_SETSIG")]
+pub fn fcntl_setsig<Fd: AsFd>(fd: Fd, sig: i32) -> io::Result<()> {
+    backend::io::syscalls::fcntl_setsig(fd.as_fd(), sig)
+}
+
+/// `fcntl(fd, F_GETSIG)`—Returns the signal sent to the owner when I/O is
+/// possible on a file descriptor.
+///
+/// Returns the raw signal number, or 0 if the default, `SIGIO`, is in use.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/fcntl.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "F_GETSIG")]
+pub fn fcntl_getsig<Fd: AsFd>(fd: Fd) -> io::Result<i32> {
+    backend::io::syscalls::fcntl_getsig(fd.as_fd())
+}
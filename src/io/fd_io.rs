@@ -0,0 +1,99 @@
+//! An adapter implementing the `std::io` traits for file descriptors.
+
+use crate::backend::fd::{AsFd, BorrowedFd};
+use crate::io::{self, IoSlice, IoSliceMut};
+
+/// A file descriptor which implements [`std::io::Read`],
+/// [`std::io::Write`], and, with the `fs` feature, [`std::io::Seek`], by
+/// calling rustix's functions directly.
+///
+/// `Fd` can be anything implementing [`AsFd`], such as an [`OwnedFd`] or a
+/// [`BorrowedFd`]. Errors are converted to [`std::io::Error`] with their
+/// [`raw_os_error`] preserved.
+///
+/// Unlike `std::fs::File`, this doesn't retry on [`io::Errno::INTR`]; the
+/// `std::io` helpers such as `read_to_end` and `write_all` do that already.
+///
+/// [`OwnedFd`]: crate::fd::OwnedFd
+/// [`raw_os_error`]: std::io::Error::raw_os_error
+#[derive(Debug, Clone)]
+pub struct FdIo<Fd> {
+    fd: Fd,
+}
+
+impl<Fd: AsFd> FdIo<Fd> {
+    /// Wrap `fd`.
+    #[inline]
+    pub fn new(fd: Fd) -> Self {
+        Self { fd }
+    }
+
+    /// Return a reference to the wrapped file descriptor.
+    #[inline]
+    pub fn get_ref(&self) -> &Fd {
+        &self.fd
+    }
+
+    /// Return a mutable reference to the wrapped file descriptor.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut Fd {
+        &mut self.fd
+    }
+
+    /// Unwrap the file descriptor.
+    #[inline]
+    pub fn into_inner(self) -> Fd {
+        self.fd
+    }
+}
+
+impl<Fd: AsFd> AsFd for FdIo<Fd> {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+impl<Fd: AsFd> std::io::Read for FdIo<Fd> {
+    #[inline]
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        Ok(io::read(&self.fd, buf)?)
+    }
+
+    #[inline]
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> std::io::Result<usize> {
+        Ok(io::readv(&self.fd, bufs)?)
+    }
+}
+
+impl<Fd: AsFd> std::io::Write for FdIo<Fd> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        Ok(io::write(&self.fd, buf)?)
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> std::io::Result<usize> {
+        Ok(io::writev(&self.fd, bufs)?)
+    }
+
+    /// The data is already in the kernel, so this does nothing. Use `fsync`
+    /// or `fdatasync` to write it to storage.
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "fs")]
+impl<Fd: AsFd> std::io::Seek for FdIo<Fd> {
+    #[inline]
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        let pos = match pos {
+            std::io::SeekFrom::Start(offset) => io::SeekFrom::Start(offset),
+            std::io::SeekFrom::End(offset) => io::SeekFrom::End(offset),
+            std::io::SeekFrom::Current(offset) => io::SeekFrom::Current(offset),
+        };
+        Ok(crate::fs::seek(&self.fd, pos)?)
+    }
+}
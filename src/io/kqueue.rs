@@ -0,0 +1,383 @@
+//! kqueue support.
+//!
+//! This is the BSD and Apple counterpart of [`epoll`]. A `kqueue` is
+//! modified and waited on with a single function, [`kevent`], which applies a
+//! list of changes and then collects a list of triggered events.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use std::time::Duration;
+//! use rustix::io::kqueue::{kevent, kqueue, Event, EventFilter, EventFlags};
+//!
+//! # fn main() -> std::io::Result<()> {
+//! let kq = kqueue()?;
+//!
+//! // Register a one-shot timer which fires after 100 milliseconds.
+//! let timer = Event::new(
+//!     EventFilter::Timer {
+//!         ident: 0,
+//!         timer: Some(Duration::from_millis(100)),
+//!     },
+//!     EventFlags::ADD | EventFlags::ONESHOT,
+//!     7,
+//! )?;
+//!
+//! let mut events = Vec::with_capacity(1);
+//! unsafe { kevent(&kq, &[timer], &mut events, None)? };
+//! assert_eq!(events[0].udata(), 7);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`epoll`]: https://man7.org/linux/man-pages/man7/epoll.7.html
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::fd::{AsFd, OwnedFd, RawFd};
+use crate::io;
+use crate::process::{Pid, Signal};
+use alloc::vec::Vec;
+use bitflags::bitflags;
+use core::ptr::null;
+use core::time::Duration;
+
+/// A kqueue event, for use as both a change and a result of [`kevent`].
+#[derive(Debug, Copy, Clone)]
+#[repr(transparent)]
+pub struct Event {
+    inner: c::kevent,
+}
+
+impl Event {
+    /// Creates a new `Event` for `filter`, with `flags` describing how to
+    /// apply it and `udata` as an opaque token returned with each triggered
+    /// event.
+    ///
+    /// Fails with [`io::Errno::INVAL`] if `filter` is
+    /// [`EventFilter::Unknown`].
+    #[allow(clippy::needless_update)]
+    pub fn new(filter: EventFilter, flags: EventFlags, udata: isize) -> io::Result<Self> {
+        let (ident, data, filter, fflags) = match filter {
+            EventFilter::Read(fd) => (fd as usize, 0, c::EVFILT_READ, 0),
+            EventFilter::Write(fd) => (fd as usize, 0, c::EVFILT_WRITE, 0),
+            EventFilter::Vnode { vnode, flags } => {
+                (vnode as usize, 0, c::EVFILT_VNODE, flags.bits())
+            }
+            EventFilter::Proc { pid, flags } => (
+                Pid::as_raw(Some(pid)) as usize,
+                0,
+                c::EVFILT_PROC,
+                flags.bits(),
+            ),
+            EventFilter::Signal { signal, times: _ } => (signal as usize, 0, c::EVFILT_SIGNAL, 0),
+            EventFilter::Timer { ident, timer } => {
+                let data = match timer {
+                    Some(timer) => timer.as_millis() as isize,
+                    None => isize::MAX,
+                };
+                (ident as usize, data, c::EVFILT_TIMER, 0)
+            }
+            EventFilter::Unknown => return Err(io::Errno::INVAL),
+        };
+
+        Ok(Self {
+            inner: c::kevent {
+                ident: ident as _,
+                filter: filter as _,
+                flags: flags.bits() as _,
+                fflags: fflags as _,
+                data: data as _,
+                udata: udata as _,
+                ..unsafe { core::mem::zeroed() }
+            },
+        })
+    }
+
+    /// Returns the flags of this event.
+    #[inline]
+    pub fn flags(&self) -> EventFlags {
+        EventFlags::from_bits_truncate(self.inner.flags as _)
+    }
+
+    /// Returns the `udata` token of this event.
+    #[inline]
+    pub fn udata(&self) -> isize {
+        self.inner.udata as _
+    }
+
+    /// Returns the filter-specific data of this event.
+    ///
+    /// For a read or write filter, this is the number of bytes available;
+    /// for a timer, it's the number of times the timer has expired since it
+    /// was last reported; and when [`EventFlags::ERROR`] is set, it's the
+    /// error code of a failed change.
+    #[inline]
+    pub fn data(&self) -> i64 {
+        self.inner.data as _
+    }
+
+    /// Returns the filter of this event.
+    pub fn filter(&self) -> EventFilter {
+        match self.inner.filter as _ {
+            c::EVFILT_READ => EventFilter::Read(self.inner.ident as _),
+            c::EVFILT_WRITE => EventFilter::Write(self.inner.ident as _),
+            c::EVFILT_VNODE => EventFilter::Vnode {
+                vnode: self.inner.ident as _,
+                flags: VnodeEvents::from_bits_truncate(self.inner.fflags as _),
+            },
+            c::EVFILT_PROC => match unsafe { Pid::from_raw(self.inner.ident as _) } {
+                Some(pid) => EventFilter::Proc {
+                    pid,
+                    flags: ProcessEvents::from_bits_truncate(self.inner.fflags as _),
+                },
+                None => EventFilter::Unknown,
+            },
+            c::EVFILT_SIGNAL => match Signal::from_raw(self.inner.ident as _) {
+                Some(signal) => EventFilter::Signal {
+                    signal,
+                    times: self.inner.data as _,
+                },
+                None => EventFilter::Unknown,
+            },
+            c::EVFILT_TIMER => EventFilter::Timer {
+                ident: self.inner.ident as _,
+                timer: None,
+            },
+            _ => EventFilter::Unknown,
+        }
+    }
+}
+
+/// The kind of a kqueue event, and the thing it watches.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum EventFilter {
+    /// `EVFILT_READ`—The file descriptor has data available to read.
+    Read(RawFd),
+
+    /// `EVFILT_WRITE`—The file descriptor can be written to.
+    Write(RawFd),
+
+    /// `EVFILT_VNODE`—One of the `flags` events occurred on the file
+    /// referred to by `vnode`.
+    Vnode {
+        /// The file descriptor to watch.
+        vnode: RawFd,
+
+        /// The events to watch for, or the events which occurred.
+        flags: VnodeEvents,
+    },
+
+    /// `EVFILT_PROC`—One of the `flags` events occurred for the process
+    /// `pid`.
+    Proc {
+        /// The process to watch.
+        pid: Pid,
+
+        /// The events to watch for, or the events which occurred.
+        flags: ProcessEvents,
+    },
+
+    /// `EVFILT_SIGNAL`—`signal` was delivered to the process.
+    ///
+    /// This works alongside other signal handling, and records an attempt
+    /// to deliver the signal even if it's ignored.
+    Signal {
+        /// The signal to watch for.
+        signal: Signal,
+
+        /// The number of times the signal was delivered since it was last
+        /// reported. This is ignored when registering.
+        times: usize,
+    },
+
+    /// `EVFILT_TIMER`—A timer identified by `ident` expired.
+    ///
+    /// Unless [`EventFlags::ONESHOT`] is set, the timer is periodic.
+    Timer {
+        /// An identifier for the timer, unique within the kqueue.
+        ident: isize,
+
+        /// The timer period, with millisecond resolution.
+        ///
+        /// Returned events have `None` here, and the number of expirations
+        /// in [`Event::data`] instead. Registering with `None` creates a
+        /// timer which never fires.
+        timer: Option<Duration>,
+    },
+
+    /// An unknown filter.
+    ///
+    /// This is only returned by [`Event::filter`]; it can't be registered.
+    Unknown,
+}
+
+bitflags! {
+    /// `EV_*` flags for use with [`Event::new`].
+    pub struct EventFlags: u16 {
+        /// `EV_ADD`—Add the event to the kqueue, or modify it if it's
+        /// already there.
+        const ADD = c::EV_ADD as _;
+
+        /// `EV_ENABLE`—Allow the event to be returned.
+        const ENABLE = c::EV_ENABLE as _;
+
+        /// `EV_DISABLE`—Keep the event registered, but don't return it.
+        const DISABLE = c::EV_DISABLE as _;
+
+        /// `EV_DELETE`—Remove the event from the kqueue.
+        const DELETE = c::EV_DELETE as _;
+
+        /// `EV_RECEIPT`—Report the result of applying the change, without
+        /// waiting for events.
+        const RECEIPT = c::EV_RECEIPT as _;
+
+        /// `EV_ONESHOT`—Remove the event after it's returned once.
+        const ONESHOT = c::EV_ONESHOT as _;
+
+        /// `EV_CLEAR`—Reset the state of the event after it's returned.
+        const CLEAR = c::EV_CLEAR as _;
+
+        /// `EV_DISPATCH`—Disable the event after it's returned once.
+        const DISPATCH = c::EV_DISPATCH as _;
+
+        /// `EV_EOF`—Set on returned events when the source has reached
+        /// end-of-file.
+        const EOF = c::EV_EOF as _;
+
+        /// `EV_ERROR`—Set on returned events when applying a change failed.
+        const ERROR = c::EV_ERROR as _;
+    }
+}
+
+bitflags! {
+    /// `NOTE_*` flags for use with [`EventFilter::Vnode`].
+    pub struct VnodeEvents: u32 {
+        /// `NOTE_DELETE`—The file was unlinked.
+        const DELETE = c::NOTE_DELETE;
+
+        /// `NOTE_WRITE`—The file was written to.
+        const WRITE = c::NOTE_WRITE;
+
+        /// `NOTE_EXTEND`—The file was extended.
+        const EXTEND = c::NOTE_EXTEND;
+
+        /// `NOTE_ATTRIB`—The file's attributes changed.
+        const ATTRIBUTES = c::NOTE_ATTRIB;
+
+        /// `NOTE_LINK`—The file's link count changed.
+        const LINK = c::NOTE_LINK;
+
+        /// `NOTE_RENAME`—The file was renamed.
+        const RENAME = c::NOTE_RENAME;
+
+        /// `NOTE_REVOKE`—Access to the file was revoked, or its file system
+        /// was unmounted.
+        const REVOKE = c::NOTE_REVOKE;
+    }
+}
+
+bitflags! {
+    /// `NOTE_*` flags for use with [`EventFilter::Proc`].
+    pub struct ProcessEvents: u32 {
+        /// `NOTE_EXIT`—The process exited.
+        const EXIT = c::NOTE_EXIT;
+
+        /// `NOTE_FORK`—The process forked.
+        const FORK = c::NOTE_FORK;
+
+        /// `NOTE_EXEC`—The process called `exec`.
+        const EXEC = c::NOTE_EXEC;
+
+        /// `NOTE_TRACK`—Also watch the children the process forks.
+        const TRACK = c::NOTE_TRACK;
+
+        /// `NOTE_TRACKERR`—Set on returned events when a child couldn't be
+        /// tracked.
+        const TRACKERR = c::NOTE_TRACKERR;
+    }
+}
+
+/// `kqueue()`—Creates a new kqueue.
+///
+/// The returned file descriptor is not inherited by child processes
+/// created with `fork`.
+///
+/// # References
+///  - [Apple]
+///  - [FreeBSD]
+///  - [OpenBSD]
+///  - [NetBSD]
+///  - [DragonFly BSD]
+///
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/kqueue.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=kqueue&sektion=2
+/// [OpenBSD]: https://man.openbsd.org/kqueue.2
+/// [NetBSD]: https://man.netbsd.org/kqueue.2
+/// [DragonFly BSD]: https://man.dragonflybsd.org/?command=kqueue&section=2
+#[inline]
+pub fn kqueue() -> io::Result<OwnedFd> {
+    crate::backend::io::syscalls::kqueue()
+}
+
+/// `kevent(kqueue, changelist, eventlist, timeout)`—Applies a list of changes
+/// to a kqueue, and waits for events.
+///
+/// All of `changelist` is applied first. Then up to `eventlist.capacity()`
+/// triggered events are written to `eventlist`, replacing its contents, and
+/// their number is returned. With a capacity of zero, this returns
+/// immediately after applying the changes. A `timeout` of `None` waits
+/// indefinitely.
+///
+/// If applying a change fails and `eventlist` has room, the change is
+/// returned in `eventlist` with [`EventFlags::ERROR`] set and the error code
+/// in [`Event::data`], and the remaining changes are still applied.
+///
+/// # Safety
+///
+/// The file descriptors referred to by the `Event` structs in `changelist`
+/// must remain valid for as long as they're registered with the kqueue.
+///
+/// # References
+///  - [Apple]
+///  - [FreeBSD]
+///  - [OpenBSD]
+///  - [NetBSD]
+///  - [DragonFly BSD]
+///
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/kevent.2.html
+/// [FreeBSD]: https://man.freebsd.org/cgi/man.cgi?query=kevent&sektion=2
+/// [OpenBSD]: https://man.openbsd.org/kevent.2
+/// [NetBSD]: https://man.netbsd.org/kevent.2
+/// [DragonFly BSD]: https://man.dragonflybsd.org/?command=kevent&section=2
+pub unsafe fn kevent(
+    kqueue: impl AsFd,
+    changelist: &[Event],
+    eventlist: &mut Vec<Event>,
+    timeout: Option<Duration>,
+) -> io::Result<usize> {
+    let timeout = timeout.map(|timeout| c::timespec {
+        tv_sec: timeout.as_secs() as _,
+        tv_nsec: timeout.subsec_nanos() as _,
+    });
+
+    // Populate the event list with events.
+    eventlist.set_len(0);
+    let out_slice =
+        core::slice::from_raw_parts_mut(eventlist.as_mut_ptr().cast(), eventlist.capacity());
+    let res = crate::backend::io::syscalls::kevent(
+        kqueue.as_fd(),
+        core::slice::from_raw_parts(changelist.as_ptr().cast(), changelist.len()),
+        out_slice,
+        timeout.as_ref().map_or(null(), |timeout| timeout),
+    )
+    .map(|res| res as _);
+
+    // Update the event list's length.
+    if let Ok(len) = res {
+        eventlist.set_len(len);
+    }
+
+    res
+}
@@ -10,12 +10,37 @@ mod eventfd;
 mod fcntl;
 #[cfg(not(feature = "std"))]
 pub(crate) mod fd;
+#[cfg(not(windows))]
+#[cfg(feature = "std")]
+mod fd_io;
 mod ioctl;
 #[cfg(not(any(windows, target_os = "redox")))]
 mod is_read_write;
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub mod kqueue;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) mod owned_epoll;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod pipe;
 mod poll;
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+mod poller;
 #[cfg(all(feature = "procfs", any(target_os = "android", target_os = "linux")))]
 mod procfs;
 #[cfg(not(windows))]
@@ -27,15 +52,22 @@ mod stdio;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use crate::backend::io::epoll;
 pub use close::close;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use close::{close_range, CloseRangeFlags};
 #[cfg(not(any(windows, target_os = "aix", target_os = "wasi")))]
 pub use dup::{dup, dup2, dup3, DupFlags};
 pub use errno::{retry_on_intr, Errno, Result};
 #[cfg(any(target_os = "android", target_os = "linux"))]
-pub use eventfd::{eventfd, EventfdFlags};
+pub use eventfd::{eventfd, EventfdFlags, Notify};
 #[cfg(not(any(windows, target_os = "wasi")))]
 pub use fcntl::fcntl_dupfd_cloexec;
 #[cfg(not(windows))]
 pub use fcntl::{fcntl_getfd, fcntl_setfd, FdFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use fcntl::{fcntl_getown, fcntl_getsig, fcntl_setown, fcntl_setsig, Owner};
+#[cfg(not(windows))]
+#[cfg(feature = "std")]
+pub use fd_io::FdIo;
 #[cfg(any(target_os = "ios", target_os = "macos"))]
 pub use ioctl::ioctl_fioclex;
 pub use ioctl::ioctl_fionbio;
@@ -71,19 +103,33 @@ pub use pipe::{pipe_with, PipeFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use pipe::{splice, vmsplice, IoSliceRaw, SpliceFlags};
 pub use poll::{poll, PollFd, PollFlags};
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+pub use poller::{Events, Interest, Poller, Readiness};
 #[cfg(all(feature = "procfs", any(target_os = "android", target_os = "linux")))]
 pub use procfs::{
     proc_self_fd, proc_self_fdinfo_fd, proc_self_maps, proc_self_pagemap, proc_self_status,
 };
 #[cfg(not(windows))]
-pub use read_write::{pread, pwrite, read, readv, write, writev, IoSlice, IoSliceMut};
+pub use read_write::{
+    pread, pread_uninit, pwrite, read, read_uninit, readv, readv_fully, write, writev, writev_all,
+    IoSlice, IoSliceMut,
+};
 #[cfg(not(any(
     windows,
     target_os = "haiku",
     target_os = "redox",
     target_os = "solaris"
 )))]
-pub use read_write::{preadv, pwritev};
+pub use read_write::{preadv, preadv_uninit, pwritev};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use read_write::{preadv2, pwritev2, ReadWriteFlags};
 pub use seek_from::SeekFrom;
@@ -91,3 +137,5 @@ pub use seek_from::SeekFrom;
 pub use stdio::{
     raw_stderr, raw_stdin, raw_stdout, stderr, stdin, stdout, take_stderr, take_stdin, take_stdout,
 };
+#[cfg(not(any(windows, target_os = "wasi")))]
+pub use stdio::{redirect_all_to, replace_stderr, replace_stdin, replace_stdout};
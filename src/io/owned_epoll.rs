@@ -0,0 +1,167 @@
+//! An epoll object which owns the file descriptors registered with it.
+
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::io::{self, epoll};
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+/// An epoll object which owns the file descriptors registered with it.
+///
+/// The free functions in this module, such as [`epoll_add`], only borrow the
+/// file descriptors passed to them. Nothing stops such a file descriptor from
+/// being closed while it's still registered, and when that happens epoll
+/// keeps reporting events for it until every duplicate of the underlying
+/// file description is closed too.
+///
+/// `Epoll` instead takes ownership of each file descriptor in [`add`], keyed
+/// by its `data` token, and closes it when it's [`remove`]d or when the
+/// `Epoll` is dropped. Closing the epoll file descriptor itself drops all of
+/// its registrations, so dropping an `Epoll` leaves nothing behind. The
+/// tradeoff is the bookkeeping: `data` tokens must be unique, and each event
+/// costs a map lookup to find its file descriptor.
+///
+/// `Epoll` implements [`AsFd`], so the free functions, such as
+/// [`epoll_wait`], can still be used with it.
+///
+/// [`add`]: Self::add
+/// [`remove`]: Self::remove
+/// [`epoll_add`]: epoll::epoll_add
+/// [`epoll_wait`]: epoll::epoll_wait
+#[derive(Debug)]
+pub struct Epoll<T: AsFd = OwnedFd> {
+    epoll: OwnedFd,
+    registered: BTreeMap<u64, T>,
+}
+
+impl<T: AsFd> Epoll<T> {
+    /// `epoll_create1(flags)`—Creates a new `Epoll`.
+    ///
+    /// Use the [`CreateFlags::CLOEXEC`] flag to prevent the resulting file
+    /// descriptor from being implicitly passed across `exec` boundaries.
+    ///
+    /// [`CreateFlags::CLOEXEC`]: epoll::CreateFlags::CLOEXEC
+    #[inline]
+    #[doc(alias = "epoll_create1")]
+    pub fn new(flags: epoll::CreateFlags) -> io::Result<Self> {
+        Ok(Self {
+            epoll: epoll::epoll_create(flags)?,
+            registered: BTreeMap::new(),
+        })
+    }
+
+    /// `epoll_ctl(self, EPOLL_CTL_ADD, source, event)`—Adds an element to
+    /// this `Epoll`, taking ownership of it.
+    ///
+    /// This registers interest in any of the events set in `event_flags`
+    /// occurring on `source`, which will be reported with `data`.
+    ///
+    /// Fails with [`io::Errno::EXIST`] if `data` is already in use. On
+    /// failure, `source` is returned along with the error, so that it isn't
+    /// closed.
+    #[doc(alias = "epoll_ctl")]
+    pub fn add(
+        &mut self,
+        source: T,
+        data: u64,
+        event_flags: epoll::EventFlags,
+    ) -> Result<(), (io::Errno, T)> {
+        if self.registered.contains_key(&data) {
+            return Err((io::Errno::EXIST, source));
+        }
+        if let Err(err) = epoll::epoll_add(&self.epoll, &source, data, event_flags) {
+            return Err((err, source));
+        }
+        self.registered.insert(data, source);
+        Ok(())
+    }
+
+    /// `epoll_ctl(self, EPOLL_CTL_MOD, target, event)`—Modifies the events
+    /// of interest for the element registered with `data`.
+    ///
+    /// Fails with [`io::Errno::NOENT`] if nothing is registered with `data`.
+    #[doc(alias = "epoll_ctl")]
+    pub fn modify(&self, data: u64, event_flags: epoll::EventFlags) -> io::Result<()> {
+        let source = self.registered.get(&data).ok_or(io::Errno::NOENT)?;
+        epoll::epoll_mod(&self.epoll, source, data, event_flags)
+    }
+
+    /// `epoll_ctl(self, EPOLL_CTL_DEL, target, NULL)`—Removes the element
+    /// registered with `data` from this `Epoll`.
+    ///
+    /// This returns ownership of the element to the caller.
+    ///
+    /// Fails with [`io::Errno::NOENT`] if nothing is registered with `data`.
+    #[doc(alias = "epoll_ctl")]
+    pub fn remove(&mut self, data: u64) -> io::Result<T> {
+        let source = self.registered.get(&data).ok_or(io::Errno::NOENT)?;
+        epoll::epoll_del(&self.epoll, source)?;
+        Ok(self.registered.remove(&data).unwrap())
+    }
+
+    /// Returns the element registered with `data`, if any.
+    #[inline]
+    pub fn get(&self, data: u64) -> Option<&T> {
+        self.registered.get(&data)
+    }
+
+    /// `epoll_wait(self, events, timeout)`—Waits for registered events of
+    /// interest.
+    ///
+    /// Use [`events`] to pair the results with the registered elements.
+    ///
+    /// [`events`]: Self::events
+    #[inline]
+    #[doc(alias = "epoll_wait")]
+    pub fn wait(&self, event_list: &mut epoll::EventVec, timeout: i32) -> io::Result<()> {
+        epoll::epoll_wait(&self.epoll, event_list, timeout)
+    }
+
+    /// Returns an iterator over the events in `event_list`, along with the
+    /// `data` token and element each one was registered with.
+    ///
+    /// Events for elements which have been removed since `event_list` was
+    /// filled are skipped.
+    #[inline]
+    pub fn events<'a>(&'a self, event_list: &'a epoll::EventVec) -> Events<'a, T> {
+        Events {
+            epoll: self,
+            iter: event_list.iter(),
+        }
+    }
+}
+
+impl<T: AsFd> AsFd for Epoll<T> {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        self.epoll.as_fd()
+    }
+}
+
+/// An iterator over the events of an [`Epoll`], returned by
+/// [`Epoll::events`].
+pub struct Events<'a, T: AsFd> {
+    epoll: &'a Epoll<T>,
+    iter: epoll::Iter<'a>,
+}
+
+impl<'a, T: AsFd + fmt::Debug> fmt::Debug for Events<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Events")
+            .field("epoll", &self.epoll)
+            .finish()
+    }
+}
+
+impl<'a, T: AsFd> Iterator for Events<'a, T> {
+    type Item = (epoll::EventFlags, u64, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let epoll = self.epoll;
+        self.iter.by_ref().find_map(|(event_flags, data)| {
+            epoll
+                .registered
+                .get(&data)
+                .map(|source| (event_flags, data, source))
+        })
+    }
+}
@@ -0,0 +1,338 @@
+//! A readiness poller which uses epoll or kqueue.
+//!
+//! [`Poller`] covers the common subset of the two: level-triggered read and
+//! write readiness for file descriptors, each registered with a token which
+//! is returned with its events. For anything more, such as edge-triggered
+//! events, signals, or timers, use [`epoll`] or [`kqueue`] directly.
+//!
+//! # Examples
+//!
+//! ```
+//! # fn main() -> std::io::Result<()> {
+//! use rustix::io::{pipe, write, Events, Interest, Poller, Readiness};
+//!
+//! let (reader, writer) = pipe()?;
+//! let poller = Poller::new()?;
+//! poller.register(&reader, Interest::READABLE, 7)?;
+//! write(&writer, b"x")?;
+//!
+//! let mut events = Events::with_capacity(4);
+//! poller.poll(&mut events, None)?;
+//! assert!(events.iter().any(|(token, readiness)| token == 7
+//!     && readiness.contains(Readiness::READABLE)));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`epoll`]: https://docs.rs/rustix/*/x86_64-unknown-linux-gnu/rustix/io/epoll/index.html
+//! [`kqueue`]: https://docs.rs/rustix/*/x86_64-apple-darwin/rustix/io/kqueue/index.html
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, OwnedFd};
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+use crate::fd::{AsRawFd, RawFd};
+use crate::io;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::io::epoll;
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+use crate::io::kqueue;
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+use alloc::vec::Vec;
+use bitflags::bitflags;
+use core::time::Duration;
+
+bitflags! {
+    /// The kinds of readiness to wait for, for use with
+    /// [`Poller::register`].
+    pub struct Interest: u8 {
+        /// Wait for the file descriptor to be readable.
+        const READABLE = 1 << 0;
+        /// Wait for the file descriptor to be writable.
+        const WRITABLE = 1 << 1;
+    }
+}
+
+bitflags! {
+    /// The readiness of a file descriptor, as reported by [`Events`].
+    pub struct Readiness: u8 {
+        /// The file descriptor is readable.
+        const READABLE = 1 << 0;
+        /// The file descriptor is writable.
+        const WRITABLE = 1 << 1;
+        /// The peer has closed its end, or the file descriptor has reached
+        /// end-of-file.
+        const HANGUP = 1 << 2;
+        /// An error is pending on the file descriptor.
+        const ERROR = 1 << 3;
+    }
+}
+
+/// An epoll or kqueue instance, for waiting for file descriptors to become
+/// ready.
+///
+/// Registrations are level-triggered: as long as a file descriptor is ready
+/// in a way it was registered for, each call to [`Poller::poll`] reports
+/// it.
+#[derive(Debug)]
+pub struct Poller {
+    fd: OwnedFd,
+}
+
+impl Poller {
+    /// Creates a new `Poller`, with the close-on-exec flag set.
+    #[inline]
+    pub fn new() -> io::Result<Self> {
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        let fd = epoll::epoll_create(epoll::CreateFlags::CLOEXEC)?;
+        // kqueues aren't inherited by `fork`ed children, but plain `kqueue()`
+        // doesn't make them close-on-exec everywhere, such as on FreeBSD.
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        let fd = kqueue::kqueue()?;
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        io::fcntl_setfd(&fd, io::FdFlags::CLOEXEC)?;
+        Ok(Self { fd })
+    }
+
+    /// Registers `fd` with this `Poller`, to wait for `interest`.
+    ///
+    /// `token` is returned with each of `fd`'s events. Registering a file
+    /// descriptor which is already registered fails with
+    /// [`io::Errno::EXIST`] on Linux; use [`Poller::reregister`] instead.
+    ///
+    /// `fd` should be deregistered before it's closed; otherwise, it may
+    /// continue to be reported, for example if it was `dup`ed.
+    pub fn register<Fd: AsFd>(&self, fd: Fd, interest: Interest, token: usize) -> io::Result<()> {
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        {
+            epoll::epoll_add(&self.fd, fd, token as u64, epoll_flags(interest))
+        }
+
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        {
+            self.kevent_changes(fd.as_fd().as_raw_fd(), interest, token)
+        }
+    }
+
+    /// Changes the `interest` and `token` of `fd`, which must already be
+    /// registered with this `Poller`.
+    pub fn reregister<Fd: AsFd>(&self, fd: Fd, interest: Interest, token: usize) -> io::Result<()> {
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        {
+            epoll::epoll_mod(&self.fd, fd, token as u64, epoll_flags(interest))
+        }
+
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        {
+            self.kevent_changes(fd.as_fd().as_raw_fd(), interest, token)
+        }
+    }
+
+    /// Removes `fd` from this `Poller`.
+    ///
+    /// If `fd` isn't registered, this fails with [`io::Errno::NOENT`].
+    pub fn deregister<Fd: AsFd>(&self, fd: Fd) -> io::Result<()> {
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        {
+            epoll::epoll_del(&self.fd, fd)
+        }
+
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        {
+            self.kevent_changes(fd.as_fd().as_raw_fd(), Interest::empty(), 0)
+        }
+    }
+
+    /// Waits for registered file descriptors to become ready, and replaces
+    /// the contents of `events` with up to `events.capacity()` of them.
+    ///
+    /// A `timeout` of `None` waits indefinitely, and a timeout of zero
+    /// returns immediately. On Linux, timeouts are rounded up to whole
+    /// milliseconds.
+    pub fn poll(&self, events: &mut Events, timeout: Option<Duration>) -> io::Result<()> {
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        {
+            let timeout = match timeout {
+                None => -1,
+                Some(timeout) => {
+                    let millis = timeout.as_nanos().saturating_add(999_999) / 1_000_000;
+                    if millis > i32::MAX as u128 {
+                        i32::MAX
+                    } else {
+                        millis as i32
+                    }
+                }
+            };
+            epoll::epoll_wait(&self.fd, &mut events.events, timeout)
+        }
+
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        {
+            let changes: [kqueue::Event; 0] = [];
+            // Safety: There are no changes, so there's nothing to keep
+            // valid.
+            unsafe { kqueue::kevent(&self.fd, &changes, &mut events.events, timeout)? };
+            Ok(())
+        }
+    }
+
+    /// Adds the read and write filters of `fd` which `interest` asks for,
+    /// and deletes the others.
+    #[cfg(not(any(target_os = "android", target_os = "linux")))]
+    fn kevent_changes(&self, fd: RawFd, interest: Interest, token: usize) -> io::Result<()> {
+        use kqueue::{Event, EventFilter, EventFlags};
+
+        let flags = |wanted: bool| {
+            if wanted {
+                EventFlags::ADD | EventFlags::RECEIPT
+            } else {
+                EventFlags::DELETE | EventFlags::RECEIPT
+            }
+        };
+        let changes = [
+            Event::new(
+                EventFilter::Read(fd),
+                flags(interest.contains(Interest::READABLE)),
+                token as isize,
+            )?,
+            Event::new(
+                EventFilter::Write(fd),
+                flags(interest.contains(Interest::WRITABLE)),
+                token as isize,
+            )?,
+        ];
+
+        // With `EV_RECEIPT`, each change is reported back with
+        // `EV_ERROR` set and its result in `data`.
+        let mut receipts = Vec::with_capacity(changes.len());
+        // Safety: Registrations are documented to need deregistering before
+        // the file descriptor is closed.
+        unsafe { kqueue::kevent(&self.fd, &changes, &mut receipts, None)? };
+        let mut error = None;
+        let mut missing = 0;
+        for (change, receipt) in changes.iter().zip(&receipts) {
+            let err = receipt.data();
+            // Deleting a filter which wasn't added fails with `ENOENT`.
+            let deleting = change.flags().contains(EventFlags::DELETE);
+            if deleting && err == i64::from(io::Errno::NOENT.raw_os_error()) {
+                missing += 1;
+            } else if err != 0 && error.is_none() {
+                error = Some(io::Errno::from_raw_os_error(err as i32));
+            }
+        }
+
+        if let Some(err) = error {
+            // Don't leave `fd` half-registered, with only the filter which
+            // succeeded.
+            let deletes = [
+                Event::new(EventFilter::Read(fd), flags(false), 0)?,
+                Event::new(EventFilter::Write(fd), flags(false), 0)?,
+            ];
+            let mut receipts = Vec::with_capacity(deletes.len());
+            // Safety: This only deletes registrations.
+            let _ = unsafe { kqueue::kevent(&self.fd, &deletes, &mut receipts, None) };
+            return Err(err);
+        }
+        // Like `epoll`, fail to deregister a file descriptor which wasn't
+        // registered.
+        if missing == changes.len() {
+            return Err(io::Errno::NOENT);
+        }
+        Ok(())
+    }
+}
+
+impl AsFd for Poller {
+    #[inline]
+    fn as_fd(&self) -> crate::fd::BorrowedFd<'_> {
+        self.fd.as_fd()
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn epoll_flags(interest: Interest) -> epoll::EventFlags {
+    let mut flags = epoll::EventFlags::empty();
+    if interest.contains(Interest::READABLE) {
+        flags |= epoll::EventFlags::IN;
+    }
+    if interest.contains(Interest::WRITABLE) {
+        flags |= epoll::EventFlags::OUT;
+    }
+    flags
+}
+
+/// A buffer of events, for use with [`Poller::poll`].
+pub struct Events {
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    events: epoll::EventVec,
+    #[cfg(not(any(target_os = "android", target_os = "linux")))]
+    events: Vec<kqueue::Event>,
+}
+
+impl Events {
+    /// Creates an `Events` which holds up to `capacity` events.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            events: epoll::EventVec::with_capacity(capacity),
+            #[cfg(not(any(target_os = "android", target_os = "linux")))]
+            events: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of events this `Events` can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.events.capacity()
+    }
+
+    /// Removes all events.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.events.clear()
+    }
+
+    /// Returns an iterator over the tokens and readiness of the events.
+    ///
+    /// With kqueue, a file descriptor which is both readable and writable
+    /// may be reported as two events.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, Readiness)> + '_ {
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        {
+            self.events.iter().map(|(flags, data)| {
+                let mut readiness = Readiness::empty();
+                if flags.intersects(epoll::EventFlags::IN | epoll::EventFlags::PRI) {
+                    readiness |= Readiness::READABLE;
+                }
+                if flags.contains(epoll::EventFlags::OUT) {
+                    readiness |= Readiness::WRITABLE;
+                }
+                if flags.contains(epoll::EventFlags::HUP) {
+                    readiness |= Readiness::HANGUP;
+                }
+                if flags.contains(epoll::EventFlags::ERR) {
+                    readiness |= Readiness::ERROR;
+                }
+                (data as usize, readiness)
+            })
+        }
+
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        {
+            self.events.iter().map(|event| {
+                let mut readiness = match event.filter() {
+                    kqueue::EventFilter::Read(_) => Readiness::READABLE,
+                    kqueue::EventFilter::Write(_) => Readiness::WRITABLE,
+                    _ => Readiness::empty(),
+                };
+                if event.flags().contains(kqueue::EventFlags::EOF) {
+                    readiness |= Readiness::HANGUP;
+                }
+                if event.flags().contains(kqueue::EventFlags::ERROR) {
+                    readiness |= Readiness::ERROR;
+                }
+                (event.udata() as usize, readiness)
+            })
+        }
+    }
+}
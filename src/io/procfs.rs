@@ -16,7 +16,7 @@
 //! namespace. So with the checking here, they may fail, but they won't be able
 //! to succeed with bogus results.
 
-use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd, RawFd};
 use crate::ffi::CStr;
 use crate::fs::{
     cwd, fstat, fstatfs, major, openat, renameat, Dir, FileType, Mode, OFlags, Stat,
@@ -211,8 +211,6 @@ fn proc_opendirat<P: crate::path::Arg, Fd: AsFd>(dirfd: Fd, path: P) -> io::Resu
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
 fn proc() -> io::Result<(BorrowedFd<'static>, &'static Stat)> {
-    static PROC: StaticFd = StaticFd::new();
-
     // `OnceBox` is "racey" in that the initialization function may run
     // multiple times. We're ok with that, since the initialization function
     // has no side effects.
@@ -237,8 +235,6 @@ fn proc() -> io::Result<(BorrowedFd<'static>, &'static Stat)> {
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
 fn proc_self() -> io::Result<(BorrowedFd<'static>, &'static Stat)> {
-    static PROC_SELF: StaticFd = StaticFd::new();
-
     // The init function here may run multiple times; see above.
     PROC_SELF
         .get_or_try_init(|| {
@@ -268,8 +264,6 @@ fn proc_self() -> io::Result<(BorrowedFd<'static>, &'static Stat)> {
 /// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
 #[cfg_attr(doc_cfg, doc(cfg(feature = "procfs")))]
 pub fn proc_self_fd() -> io::Result<BorrowedFd<'static>> {
-    static PROC_SELF_FD: StaticFd = StaticFd::new();
-
     // The init function here may run multiple times; see above.
     PROC_SELF_FD
         .get_or_try_init(|| {
@@ -290,6 +284,19 @@ pub fn proc_self_fd() -> io::Result<BorrowedFd<'static>> {
 
 type StaticFd = OnceCell<(OwnedFd, Stat)>;
 
+static PROC: StaticFd = StaticFd::new();
+static PROC_SELF: StaticFd = StaticFd::new();
+static PROC_SELF_FD: StaticFd = StaticFd::new();
+static PROC_SELF_FDINFO: StaticFd = StaticFd::new();
+
+/// Test whether `fd` is one of the file descriptors opened and kept by the
+/// functions here, which `close_range`'s fallback mustn't close.
+pub(crate) fn is_cached_fd(fd: RawFd) -> bool {
+    [&PROC, &PROC_SELF, &PROC_SELF_FD, &PROC_SELF_FDINFO]
+        .iter()
+        .any(|cell| matches!(cell.get(), Some((owned, _)) if owned.as_raw_fd() == fd))
+}
+
 #[inline]
 fn new_static_fd(fd: OwnedFd, stat: Stat) -> (OwnedFd, Stat) {
     (fd, stat)
@@ -306,8 +313,6 @@ fn new_static_fd(fd: OwnedFd, stat: Stat) -> (OwnedFd, Stat) {
 ///
 /// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
 fn proc_self_fdinfo() -> io::Result<(BorrowedFd<'static>, &'static Stat)> {
-    static PROC_SELF_FDINFO: StaticFd = StaticFd::new();
-
     PROC_SELF_FDINFO
         .get_or_try_init(|| {
             let (_, proc_stat) = proc()?;
@@ -1,7 +1,15 @@
 //! `read` and `write`, optionally positioned, optionally vectored
 
+#![allow(unsafe_code)]
+
 use crate::{backend, io};
+#[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "solaris")))]
+use backend::c;
 use backend::fd::AsFd;
+use core::mem::MaybeUninit;
+#[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "solaris")))]
+use core::ptr;
+use core::slice;
 
 // Declare `IoSlice` and `IoSliceMut`.
 #[cfg(not(windows))]
@@ -27,7 +35,33 @@ pub use backend::io::types::ReadWriteFlags;
 /// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/read.2.html
 #[inline]
 pub fn read<Fd: AsFd>(fd: Fd, buf: &mut [u8]) -> io::Result<usize> {
-    backend::io::syscalls::read(fd.as_fd(), buf)
+    backend::io::syscalls::read(fd.as_fd(), as_uninit_mut(buf))
+}
+
+/// `read(fd, buf)`—Reads from a stream into a buffer which may be
+/// uninitialized.
+///
+/// This avoids the cost of initializing a buffer before reading into it. On
+/// success, this returns the part of `buf` the data was read into, which is
+/// now initialized, and the rest of `buf`, which is left as it was.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///  - [Apple]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/read.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/read.2.html
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/read.2.html
+#[inline]
+pub fn read_uninit<Fd: AsFd>(
+    fd: Fd,
+    buf: &mut [MaybeUninit<u8>],
+) -> io::Result<(&mut [u8], &mut [MaybeUninit<u8>])> {
+    let nread = backend::io::syscalls::read(fd.as_fd(), buf)?;
+
+    // Safety: `read` initialized the first `nread` bytes.
+    Ok(unsafe { split_init(buf, nread) })
 }
 
 /// `write(fd, buf)`—Writes to a stream.
@@ -57,7 +91,33 @@ pub fn write<Fd: AsFd>(fd: Fd, buf: &[u8]) -> io::Result<usize> {
 /// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/pread.2.html
 #[inline]
 pub fn pread<Fd: AsFd>(fd: Fd, buf: &mut [u8], offset: u64) -> io::Result<usize> {
-    backend::io::syscalls::pread(fd.as_fd(), buf, offset)
+    backend::io::syscalls::pread(fd.as_fd(), as_uninit_mut(buf), offset)
+}
+
+/// `pread(fd, buf, offset)`—Reads from a file at a given position into a
+/// buffer which may be uninitialized.
+///
+/// Like [`read_uninit`], this returns the initialized and the remaining
+/// parts of `buf`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///  - [Apple]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/pread.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/pread.2.html
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/pread.2.html
+#[inline]
+pub fn pread_uninit<Fd: AsFd>(
+    fd: Fd,
+    buf: &mut [MaybeUninit<u8>],
+    offset: u64,
+) -> io::Result<(&mut [u8], &mut [MaybeUninit<u8>])> {
+    let nread = backend::io::syscalls::pread(fd.as_fd(), buf, offset)?;
+
+    // Safety: `pread` initialized the first `nread` bytes.
+    Ok(unsafe { split_init(buf, nread) })
 }
 
 /// `pwrite(fd, bufs)`—Writes to a file at a given position.
@@ -109,6 +169,126 @@ pub fn writev<Fd: AsFd>(fd: Fd, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
     backend::io::syscalls::writev(fd.as_fd(), bufs)
 }
 
+/// Reads from a stream until all of `bufs` are filled or the end of the
+/// stream is reached, with repeated calls to `readv`.
+///
+/// `bufs` may hold more buffers than `IOV_MAX`, the limit on the number of
+/// buffers in one `readv` call; they're read into in batches of at most that
+/// many, in order. After a short read, reading resumes in the middle of the
+/// partially filled buffer.
+///
+/// This returns the total number of bytes read, which is less than the total
+/// length of `bufs` only if the end of the stream was reached. Reads which
+/// fail with [`io::Errno::INTR`] are retried. If another error occurs, it's
+/// returned, and the number of bytes already read into `bufs` is lost.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///  - [Apple]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/readv.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/readv.2.html
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/readv.2.html
+pub fn readv_fully<Fd: AsFd>(fd: Fd, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+    let fd = fd.as_fd();
+    let mut cursor = Cursor::default();
+    let mut total = 0;
+    while cursor.skip_full(|i| bufs[i].len(), bufs.len()) {
+        let result = if cursor.offset == 0 {
+            // The backend passes at most `IOV_MAX` buffers at once.
+            backend::io::syscalls::readv(fd, &mut bufs[cursor.index..])
+        } else {
+            backend::io::syscalls::read(fd, as_uninit_mut(&mut bufs[cursor.index][cursor.offset..]))
+        };
+        match result {
+            Ok(0) => break,
+            Ok(n) => {
+                cursor.advance(|i| bufs[i].len(), n);
+                total += n;
+            }
+            Err(io::Errno::INTR) => (),
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(total)
+}
+
+/// Writes all of `bufs` to a stream, with repeated calls to `writev`.
+///
+/// `bufs` may hold more buffers than `IOV_MAX`, the limit on the number of
+/// buffers in one `writev` call; they're written in batches of at most that
+/// many, in order. After a short write, writing resumes in the middle of the
+/// partially written buffer.
+///
+/// Writes which fail with [`io::Errno::INTR`] are retried. If another error
+/// occurs, it's returned, and some of `bufs` may already have been written.
+/// If the stream accepts no data at all, this fails with [`io::Errno::IO`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///  - [Apple]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/writev.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/writev.2.html
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/writev.2.html
+pub fn writev_all<Fd: AsFd>(fd: Fd, bufs: &[IoSlice<'_>]) -> io::Result<()> {
+    let fd = fd.as_fd();
+    let mut cursor = Cursor::default();
+    while cursor.skip_full(|i| bufs[i].len(), bufs.len()) {
+        let result = if cursor.offset == 0 {
+            // The backend passes at most `IOV_MAX` buffers at once.
+            backend::io::syscalls::writev(fd, &bufs[cursor.index..])
+        } else {
+            backend::io::syscalls::write(fd, &bufs[cursor.index][cursor.offset..])
+        };
+        match result {
+            Ok(0) => return Err(io::Errno::IO),
+            Ok(n) => cursor.advance(|i| bufs[i].len(), n),
+            Err(io::Errno::INTR) => (),
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+/// A position within a list of buffers, for [`readv_fully`] and
+/// [`writev_all`].
+#[derive(Default)]
+struct Cursor {
+    /// The buffer the position is in.
+    index: usize,
+    /// The offset within that buffer.
+    offset: usize,
+}
+
+impl Cursor {
+    /// Move past any buffers which are used up, and return whether any
+    /// buffers remain.
+    fn skip_full(&mut self, len: impl Fn(usize) -> usize, count: usize) -> bool {
+        while self.index < count && self.offset == len(self.index) {
+            self.index += 1;
+            self.offset = 0;
+        }
+        self.index < count
+    }
+
+    /// Move forward `n` bytes.
+    fn advance(&mut self, len: impl Fn(usize) -> usize, mut n: usize) {
+        while n > 0 {
+            let avail = len(self.index) - self.offset;
+            if n < avail {
+                self.offset += n;
+                return;
+            }
+            n -= avail;
+            self.index += 1;
+            self.offset = 0;
+        }
+    }
+}
+
 /// `preadv(fd, bufs, offset)`—Reads from a file at a given position into
 /// multiple buffers.
 ///
@@ -122,6 +302,45 @@ pub fn preadv<Fd: AsFd>(fd: Fd, bufs: &mut [IoSliceMut<'_>], offset: u64) -> io:
     backend::io::syscalls::preadv(fd.as_fd(), bufs, offset)
 }
 
+/// `preadv(fd, bufs, offset)`—Reads from a file at a given position into
+/// multiple buffers which may be uninitialized.
+///
+/// On success, this returns the number of bytes read, `n`; the first `n`
+/// bytes of `bufs`, taken in order, are now initialized. At most 16 buffers
+/// are read into at a time, and any after that are left as they were.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/preadv.2.html
+#[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "solaris")))]
+#[inline]
+pub fn preadv_uninit<Fd: AsFd>(
+    fd: Fd,
+    bufs: &mut [&mut [MaybeUninit<u8>]],
+    offset: u64,
+) -> io::Result<usize> {
+    let mut iovs = [c::iovec {
+        iov_base: ptr::null_mut(),
+        iov_len: 0,
+    }; PREADV_UNINIT_MAX];
+    let len = bufs.len().min(PREADV_UNINIT_MAX);
+    for (iov, buf) in iovs.iter_mut().zip(bufs.iter_mut()) {
+        iov.iov_base = buf.as_mut_ptr().cast();
+        iov.iov_len = buf.len() as _;
+    }
+
+    // Safety: `IoSliceMut` has the same layout as `iovec`, and the backend
+    // only ever writes initialized bytes into the buffers.
+    let iovs =
+        unsafe { slice::from_raw_parts_mut(iovs.as_mut_ptr().cast::<IoSliceMut<'_>>(), len) };
+    backend::io::syscalls::preadv(fd.as_fd(), iovs, offset)
+}
+
+/// The most buffers [`preadv_uninit`] reads into in one call.
+#[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "solaris")))]
+const PREADV_UNINIT_MAX: usize = 16;
+
 /// `pwritev(fd, bufs, offset)`—Writes to a file at a given position from
 /// multiple buffers.
 ///
@@ -176,3 +395,24 @@ pub fn pwritev2<Fd: AsFd>(
 ) -> io::Result<usize> {
     backend::io::syscalls::pwritev2(fd.as_fd(), bufs, offset, flags)
 }
+
+/// View an initialized buffer as a possibly uninitialized one, for the
+/// backend functions which read into either.
+#[inline]
+fn as_uninit_mut(buf: &mut [u8]) -> &mut [MaybeUninit<u8>] {
+    // Safety: `MaybeUninit<u8>` has the same layout as `u8`, and the backend
+    // only ever writes initialized bytes into the buffer.
+    unsafe { slice::from_raw_parts_mut(buf.as_mut_ptr().cast(), buf.len()) }
+}
+
+/// Split `buf` into its first `init` bytes, which must be initialized, and
+/// the rest.
+#[inline]
+unsafe fn split_init(
+    buf: &mut [MaybeUninit<u8>],
+    init: usize,
+) -> (&mut [u8], &mut [MaybeUninit<u8>]) {
+    let (init, uninit) = buf.split_at_mut(init);
+    let init = slice::from_raw_parts_mut(init.as_mut_ptr().cast::<u8>(), init.len());
+    (init, uninit)
+}
@@ -10,7 +10,13 @@
 
 use crate::backend;
 use crate::fd::OwnedFd;
+#[cfg(not(target_os = "wasi"))]
+use crate::io;
+#[cfg(not(target_os = "wasi"))]
+use backend::fd::{AsFd, AsRawFd};
 use backend::fd::{BorrowedFd, FromRawFd, RawFd};
+#[cfg(not(target_os = "wasi"))]
+use core::mem::ManuallyDrop;
 
 /// `STDIN_FILENO`—Standard input, borrowed.
 ///
@@ -322,3 +328,97 @@ pub const fn raw_stdout() -> RawFd {
 pub const fn raw_stderr() -> RawFd {
     backend::io::types::STDERR_FILENO as RawFd
 }
+
+/// `dup2(fd, STDIN_FILENO)`—Replaces standard input.
+///
+/// This replaces the file description behind the stdin file descriptor with
+/// the one for `fd`. If `fd` is already the stdin file descriptor, this does
+/// nothing.
+///
+/// # Warning
+///
+/// This doesn't coordinate with the buffering performed by
+/// [`std::io::Stdin`], so input already buffered from the old stdin may still
+/// be read.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/dup2.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/dup2.2.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub fn replace_stdin<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    replace_stdio(fd.as_fd(), raw_stdin())
+}
+
+/// `dup2(fd, STDOUT_FILENO)`—Replaces standard output.
+///
+/// This replaces the file description behind the stdout file descriptor with
+/// the one for `fd`. If `fd` is already the stdout file descriptor, this does
+/// nothing.
+///
+/// # Warning
+///
+/// This doesn't coordinate with the buffering performed by
+/// [`std::io::Stdout`], so output already buffered may be written to the new
+/// stdout. Consider flushing it first.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/dup2.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/dup2.2.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub fn replace_stdout<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    replace_stdio(fd.as_fd(), raw_stdout())
+}
+
+/// `dup2(fd, STDERR_FILENO)`—Replaces standard error.
+///
+/// This replaces the file description behind the stderr file descriptor with
+/// the one for `fd`. If `fd` is already the stderr file descriptor, this does
+/// nothing.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/dup2.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/dup2.2.html
+#[cfg(not(target_os = "wasi"))]
+#[inline]
+pub fn replace_stderr<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    replace_stdio(fd.as_fd(), raw_stderr())
+}
+
+/// Replaces standard input, output, and error.
+///
+/// This calls [`replace_stdin`], [`replace_stdout`], and [`replace_stderr`]
+/// with `fd`. This is typically used by daemons to redirect all stdio to
+/// `/dev/null` or to a log file.
+#[cfg(not(target_os = "wasi"))]
+pub fn redirect_all_to<Fd: AsFd>(fd: Fd) -> io::Result<()> {
+    let fd = fd.as_fd();
+    replace_stdin(fd)?;
+    replace_stdout(fd)?;
+    replace_stderr(fd)
+}
+
+#[cfg(not(target_os = "wasi"))]
+fn replace_stdio(fd: BorrowedFd<'_>, target: RawFd) -> io::Result<()> {
+    // `dup3` fails if the file descriptors are equal, and some platforms
+    // implement `dup2` with `dup3`, so check for this case ourselves.
+    if fd.as_raw_fd() == target {
+        return Ok(());
+    }
+
+    // SAFETY: The stdio file descriptors are assumed to always be open, and
+    // we wrap this in `ManuallyDrop` so that we don't close it. `dup2`
+    // atomically replaces its file description, so it remains open.
+    let mut target = ManuallyDrop::new(unsafe { OwnedFd::from_raw_fd(target) });
+    backend::io::syscalls::dup2(fd, &mut target)
+}
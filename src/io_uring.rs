@@ -618,12 +618,16 @@ bitflags::bitflags! {
 #[allow(missing_docs)]
 pub const IORING_CQE_BUFFER_SHIFT: u32 = sys::IORING_CQE_BUFFER_SHIFT as _;
 
-// Re-export these as `u64`, which is the `offset` type in `rustix::io::mmap`.
-#[allow(missing_docs)]
+// Re-export these as `u64`, which is the `offset` type in `rustix::mm::mmap`.
+
+/// `IORING_OFF_SQ_RING`—The `mmap` offset of the submission queue ring.
 pub const IORING_OFF_SQ_RING: u64 = sys::IORING_OFF_SQ_RING as _;
-#[allow(missing_docs)]
+
+/// `IORING_OFF_CQ_RING`—The `mmap` offset of the completion queue ring.
 pub const IORING_OFF_CQ_RING: u64 = sys::IORING_OFF_CQ_RING as _;
-#[allow(missing_docs)]
+
+/// `IORING_OFF_SQES`—The `mmap` offset of the submission queue entries
+/// array.
 pub const IORING_OFF_SQES: u64 = sys::IORING_OFF_SQES as _;
 
 /// `IORING_REGISTER_FILES_SKIP`
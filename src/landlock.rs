@@ -0,0 +1,297 @@
+//! Linux [Landlock] unprivileged sandboxing.
+//!
+//! A process creates a ruleset which declares the access rights it
+//! handles, adds rules granting some of those rights to particular file
+//! hierarchies or network ports, and then enforces the ruleset on itself.
+//! After that, any handled access that no rule grants is denied, with
+//! `EACCES`. Rulesets stack, and can only ever reduce the access a thread
+//! has.
+//!
+//! Enforcing a ruleset requires the thread to have set `no_new_privs`, or to
+//! have the `CAP_SYS_ADMIN` capability. It applies to the calling thread
+//! and to the children it creates afterward, not to other existing threads.
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://docs.kernel.org/userspace-api/landlock.html
+//! [Landlock]: https://landlock.io/
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, OwnedFd};
+use crate::{backend, io};
+use bitflags::bitflags;
+use core::ffi::c_void;
+
+bitflags! {
+    /// `LANDLOCK_ACCESS_FS_*` flags for use with [`RulesetAttr`] and
+    /// [`RuleAttr::PathBeneath`].
+    #[derive(Default)]
+    pub struct AccessFs: u64 {
+        /// `LANDLOCK_ACCESS_FS_EXECUTE`
+        const EXECUTE = 1 << 0;
+        /// `LANDLOCK_ACCESS_FS_WRITE_FILE`
+        const WRITE_FILE = 1 << 1;
+        /// `LANDLOCK_ACCESS_FS_READ_FILE`
+        const READ_FILE = 1 << 2;
+        /// `LANDLOCK_ACCESS_FS_READ_DIR`
+        const READ_DIR = 1 << 3;
+        /// `LANDLOCK_ACCESS_FS_REMOVE_DIR`
+        const REMOVE_DIR = 1 << 4;
+        /// `LANDLOCK_ACCESS_FS_REMOVE_FILE`
+        const REMOVE_FILE = 1 << 5;
+        /// `LANDLOCK_ACCESS_FS_MAKE_CHAR`
+        const MAKE_CHAR = 1 << 6;
+        /// `LANDLOCK_ACCESS_FS_MAKE_DIR`
+        const MAKE_DIR = 1 << 7;
+        /// `LANDLOCK_ACCESS_FS_MAKE_REG`
+        const MAKE_REG = 1 << 8;
+        /// `LANDLOCK_ACCESS_FS_MAKE_SOCK`
+        const MAKE_SOCK = 1 << 9;
+        /// `LANDLOCK_ACCESS_FS_MAKE_FIFO`
+        const MAKE_FIFO = 1 << 10;
+        /// `LANDLOCK_ACCESS_FS_MAKE_BLOCK`
+        const MAKE_BLOCK = 1 << 11;
+        /// `LANDLOCK_ACCESS_FS_MAKE_SYM`
+        const MAKE_SYM = 1 << 12;
+        /// `LANDLOCK_ACCESS_FS_REFER` (since Landlock ABI 2, Linux 5.19)
+        const REFER = 1 << 13;
+        /// `LANDLOCK_ACCESS_FS_TRUNCATE` (since Landlock ABI 3, Linux 6.2)
+        const TRUNCATE = 1 << 14;
+        /// `LANDLOCK_ACCESS_FS_IOCTL_DEV` (since Landlock ABI 5, Linux 6.10)
+        const IOCTL_DEV = 1 << 15;
+    }
+}
+
+bitflags! {
+    /// `LANDLOCK_ACCESS_NET_*` flags for use with [`RulesetAttr`] and
+    /// [`RuleAttr::NetPort`].
+    #[derive(Default)]
+    pub struct AccessNet: u64 {
+        /// `LANDLOCK_ACCESS_NET_BIND_TCP` (since Landlock ABI 4, Linux 6.7)
+        const BIND_TCP = 1 << 0;
+        /// `LANDLOCK_ACCESS_NET_CONNECT_TCP` (since Landlock ABI 4, Linux
+        /// 6.7)
+        const CONNECT_TCP = 1 << 1;
+    }
+}
+
+bitflags! {
+    /// `LANDLOCK_CREATE_RULESET_*` flags for use with [`create_ruleset`].
+    ///
+    /// `LANDLOCK_CREATE_RULESET_VERSION` doesn't create a ruleset, and is
+    /// available as [`abi_version`] instead.
+    pub struct LandlockCreateFlags: u32 {}
+}
+
+bitflags! {
+    /// Flags for use with [`add_rule`].
+    pub struct LandlockAddRuleFlags: u32 {}
+}
+
+bitflags! {
+    /// Flags for use with [`restrict_self`].
+    pub struct LandlockRestrictSelfFlags: u32 {}
+}
+
+/// `struct landlock_ruleset_attr`—The access rights a ruleset handles.
+///
+/// Access rights which aren't in this set aren't restricted by the ruleset.
+/// Setting rights that the running kernel doesn't know about makes
+/// [`create_ruleset`] fail with `EINVAL`, so check [`abi_version`] first to
+/// find out which are supported.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct RulesetAttr {
+    /// `handled_access_fs`
+    pub handled_access_fs: AccessFs,
+
+    /// `handled_access_net` (since Landlock ABI 4, Linux 6.7)
+    pub handled_access_net: AccessNet,
+}
+
+/// `LANDLOCK_RULE_*` constants for use with [`RuleAttr`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum RuleType {
+    /// `LANDLOCK_RULE_PATH_BENEATH`
+    PathBeneath = 1,
+
+    /// `LANDLOCK_RULE_NET_PORT` (since Landlock ABI 4, Linux 6.7)
+    NetPort = 2,
+}
+
+/// A rule for use with [`add_rule`].
+#[derive(Debug, Copy, Clone)]
+pub enum RuleAttr<'a> {
+    /// `struct landlock_path_beneath_attr`—Grant `allowed_access` to the
+    /// file hierarchy under `parent`, which is typically opened with
+    /// `O_PATH`.
+    PathBeneath {
+        /// `allowed_access`
+        allowed_access: AccessFs,
+        /// `parent_fd`
+        parent: BorrowedFd<'a>,
+    },
+
+    /// `struct landlock_net_port_attr`—Grant `allowed_access` for the TCP
+    /// port `port`.
+    NetPort {
+        /// `allowed_access`
+        allowed_access: AccessNet,
+        /// `port`
+        port: u16,
+    },
+}
+
+impl<'a> RuleAttr<'a> {
+    /// Returns the [`RuleType`] of this rule.
+    #[inline]
+    pub const fn rule_type(&self) -> RuleType {
+        match self {
+            Self::PathBeneath { .. } => RuleType::PathBeneath,
+            Self::NetPort { .. } => RuleType::NetPort,
+        }
+    }
+}
+
+/// `struct landlock_ruleset_attr`, as the kernel defines it.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+pub(crate) struct landlock_ruleset_attr {
+    handled_access_fs: u64,
+    handled_access_net: u64,
+}
+
+/// `struct landlock_path_beneath_attr`, which the kernel declares packed.
+#[allow(non_camel_case_types)]
+#[repr(C, packed)]
+struct landlock_path_beneath_attr {
+    allowed_access: u64,
+    parent_fd: i32,
+}
+
+/// `struct landlock_net_port_attr`
+#[allow(non_camel_case_types)]
+#[repr(C)]
+struct landlock_net_port_attr {
+    allowed_access: u64,
+    port: u64,
+}
+
+/// `landlock_create_ruleset(attr, sizeof(*attr), flags)`—Creates a new
+/// Landlock ruleset.
+///
+/// This fails with `ENOSYS` if the kernel doesn't support Landlock, and with
+/// `EOPNOTSUPP` if Landlock is supported but disabled at boot.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[inline]
+pub fn create_ruleset(attr: &RulesetAttr, flags: LandlockCreateFlags) -> io::Result<OwnedFd> {
+    let attr = landlock_ruleset_attr {
+        handled_access_fs: attr.handled_access_fs.bits(),
+        handled_access_net: attr.handled_access_net.bits(),
+    };
+    backend::landlock::syscalls::landlock_create_ruleset(&attr, flags)
+}
+
+/// `landlock_create_ruleset(NULL, 0, LANDLOCK_CREATE_RULESET_VERSION)`—Returns
+/// the highest Landlock ABI version the kernel supports.
+///
+/// Version 1 supports the access rights through
+/// [`AccessFs::MAKE_SYM`], and later versions add the rights which are
+/// documented with the version that added them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_create_ruleset.2.html
+#[inline]
+#[doc(alias = "LANDLOCK_CREATE_RULESET_VERSION")]
+pub fn abi_version() -> io::Result<u32> {
+    backend::landlock::syscalls::landlock_abi_version()
+}
+
+/// `landlock_add_rule(ruleset_fd, rule_type, &rule_attr, flags)`—Adds a rule
+/// to a Landlock ruleset.
+///
+/// The rule may only grant access rights which the ruleset handles.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_add_rule.2.html
+pub fn add_rule<Fd: AsFd>(
+    ruleset_fd: Fd,
+    rule: &RuleAttr<'_>,
+    flags: LandlockAddRuleFlags,
+) -> io::Result<()> {
+    let ruleset_fd = ruleset_fd.as_fd();
+
+    // Safety: Each attribute struct matches the layout the kernel expects
+    // for its rule type, and outlives the call.
+    match *rule {
+        RuleAttr::PathBeneath {
+            allowed_access,
+            parent,
+        } => {
+            let attr = landlock_path_beneath_attr {
+                allowed_access: allowed_access.bits(),
+                parent_fd: parent.as_raw_fd(),
+            };
+            unsafe {
+                backend::landlock::syscalls::landlock_add_rule(
+                    ruleset_fd,
+                    rule.rule_type(),
+                    (&attr as *const landlock_path_beneath_attr).cast::<c_void>(),
+                    flags,
+                )
+            }
+        }
+        RuleAttr::NetPort {
+            allowed_access,
+            port,
+        } => {
+            let attr = landlock_net_port_attr {
+                allowed_access: allowed_access.bits(),
+                port: port.into(),
+            };
+            unsafe {
+                backend::landlock::syscalls::landlock_add_rule(
+                    ruleset_fd,
+                    rule.rule_type(),
+                    (&attr as *const landlock_net_port_attr).cast::<c_void>(),
+                    flags,
+                )
+            }
+        }
+    }
+}
+
+/// `landlock_restrict_self(ruleset_fd, flags)`—Enforces a Landlock ruleset
+/// on the calling thread.
+///
+/// The thread must have set `no_new_privs`, or have the `CAP_SYS_ADMIN`
+/// capability; otherwise this fails with `EPERM`. The ruleset also applies
+/// to any children the thread creates afterward.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/landlock_restrict_self.2.html
+#[inline]
+pub fn restrict_self<Fd: AsFd>(ruleset_fd: Fd, flags: LandlockRestrictSelfFlags) -> io::Result<()> {
+    backend::landlock::syscalls::landlock_restrict_self(ruleset_fd.as_fd(), flags)
+}
+
+#[test]
+fn landlock_layouts() {
+    use core::mem::size_of;
+
+    assert_eq!(size_of::<landlock_ruleset_attr>(), 16);
+    assert_eq!(size_of::<landlock_path_beneath_attr>(), 12);
+    assert_eq!(size_of::<landlock_net_port_attr>(), 16);
+}
@@ -153,6 +153,10 @@ pub mod fd {
 }
 
 // The public API modules.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "bpf")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bpf")))]
+pub mod bpf;
 #[cfg(not(windows))]
 pub mod ffi;
 #[cfg(not(windows))]
@@ -164,10 +168,18 @@ pub mod io;
 #[cfg(feature = "io_uring")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "io_uring")))]
 pub mod io_uring;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "landlock")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "landlock")))]
+pub mod landlock;
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[cfg(feature = "mm")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "mm")))]
 pub mod mm;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "mq")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "mq")))]
+pub mod mq;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[cfg(feature = "net")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "net")))]
@@ -188,6 +200,23 @@ pub mod process;
 #[cfg(feature = "rand")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "rand")))]
 pub mod rand;
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#[cfg(feature = "shm")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "shm")))]
+pub mod shm;
+#[cfg(target_os = "linux")]
+#[cfg(feature = "sysvipc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "sysvipc")))]
+pub mod sysvipc;
 #[cfg(not(windows))]
 #[cfg(feature = "termios")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "termios")))]
@@ -0,0 +1,136 @@
+//! An owned anonymous memory mapping.
+
+#![allow(unsafe_code)]
+
+use crate::io;
+use crate::mm::{mlock, mmap_anonymous, munmap, MapFlags, ProtFlags};
+use core::ops::{Deref, DerefMut};
+use core::ptr::{null_mut, NonNull};
+use core::slice;
+
+/// A readable and writable anonymous memory mapping, which is unmapped when
+/// dropped.
+///
+/// The memory is created with `MAP_POPULATE`, so it's allocated up front
+/// rather than on first access, and it starts out zeroed. It's aligned to
+/// the page size, which also satisfies the alignment requirements of
+/// `O_DIRECT` I/O on most devices.
+#[derive(Debug)]
+pub struct MmapBuffer {
+    ptr: NonNull<u8>,
+    len: usize,
+}
+
+// Safety: `MmapBuffer` owns its memory exclusively, like a `Vec<u8>`.
+unsafe impl Send for MmapBuffer {}
+unsafe impl Sync for MmapBuffer {}
+
+impl MmapBuffer {
+    /// `mmap(NULL, len, PROT_READ | PROT_WRITE, MAP_PRIVATE |
+    /// MAP_ANONYMOUS | MAP_POPULATE, -1, 0)`—Map a new buffer of `len`
+    /// bytes.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man7.org/linux/man-pages/man2/mmap.2.html
+    pub fn new(len: usize) -> io::Result<Self> {
+        // Safety: We're creating a new mapping, which doesn't affect any
+        // existing memory.
+        let ptr = unsafe {
+            mmap_anonymous(
+                null_mut(),
+                len,
+                ProtFlags::READ | ProtFlags::WRITE,
+                MapFlags::PRIVATE | MapFlags::POPULATE,
+            )?
+        };
+        Ok(Self {
+            ptr: NonNull::new(ptr.cast()).ok_or(io::Errno::NOMEM)?,
+            len,
+        })
+    }
+
+    /// Map a new buffer of `len` bytes, as [`MmapBuffer::new`] does, and lock
+    /// it into memory with `mlock`, so that it's never swapped out.
+    ///
+    /// Locking memory requires `CAP_IPC_LOCK`, or the locked memory to fit
+    /// within `RLIMIT_MEMLOCK`.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man7.org/linux/man-pages/man2/mlock.2.html
+    pub fn new_locked(len: usize) -> io::Result<Self> {
+        let buffer = Self::new(len)?;
+        // Safety: The range is the mapping we just created. If this fails,
+        // dropping `buffer` unmaps it.
+        unsafe { mlock(buffer.ptr.as_ptr().cast(), buffer.len)? };
+        Ok(buffer)
+    }
+
+    /// Return the length of the buffer in bytes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test whether the buffer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return a pointer to the start of the buffer.
+    #[inline]
+    pub fn as_ptr(&self) -> *const u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Return a mutable pointer to the start of the buffer.
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut u8 {
+        self.ptr.as_ptr()
+    }
+
+    /// Return the contents of the buffer.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        // Safety: The mapping is readable and `len` bytes long.
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Return the contents of the buffer, mutably.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        // Safety: The mapping is writable, `len` bytes long, and only
+        // reachable through `self`.
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl Deref for MmapBuffer {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl DerefMut for MmapBuffer {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+}
+
+impl Drop for MmapBuffer {
+    fn drop(&mut self) {
+        // Safety: We own the mapping, and no references to it outlive
+        // `self`. This also unlocks it, if it was locked.
+        unsafe {
+            let _ = munmap(self.ptr.as_ptr().cast(), self.len);
+        }
+    }
+}
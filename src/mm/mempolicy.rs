@@ -0,0 +1,313 @@
+//! NUMA memory policies.
+//!
+//! # Safety
+//!
+//! `mbind` and `move_pages` operate on raw pointers.
+#![allow(unsafe_code)]
+
+use crate::backend::c;
+use crate::process::Pid;
+use crate::{backend, io};
+use alloc::vec::Vec;
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::ptr::null;
+
+// These are in `<linux/mempolicy.h>`, which neither `linux-raw-sys` nor the
+// `libc` crate provides.
+const MPOL_F_STATIC_NODES: i32 = 1 << 15;
+const MPOL_F_RELATIVE_NODES: i32 = 1 << 14;
+const MPOL_F_NUMA_BALANCING: i32 = 1 << 13;
+const MPOL_MODE_FLAGS: i32 = MPOL_F_STATIC_NODES | MPOL_F_RELATIVE_NODES | MPOL_F_NUMA_BALANCING;
+
+/// The number of bits in an element of a `NodeMask`.
+const NODE_WORD_BITS: usize = 8 * size_of::<c::c_ulong>();
+
+/// The number of elements in a `NodeMask`.
+const NODE_WORDS: usize = NodeMask::MAX_NODE / NODE_WORD_BITS;
+
+/// `MPOL_*` constants for use with [`set_mempolicy`] and [`get_mempolicy`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum MemPolicy {
+    /// `MPOL_DEFAULT`—Use the policy of the enclosing scope, which for a
+    /// thread is to allocate on the node of the CPU that triggers the
+    /// allocation.
+    Default = 0,
+
+    /// `MPOL_PREFERRED`—Allocate on the node in the mask if possible, and
+    /// fall back to other nodes.
+    Preferred = 1,
+
+    /// `MPOL_BIND`—Allocate only on the nodes in the mask.
+    Bind = 2,
+
+    /// `MPOL_INTERLEAVE`—Interleave allocations across the nodes in the
+    /// mask.
+    Interleave = 3,
+
+    /// `MPOL_LOCAL`—Allocate on the node of the CPU that triggers the
+    /// allocation (since Linux 3.8).
+    Local = 4,
+}
+
+impl MemPolicy {
+    /// Convert a raw `MPOL_*` value, without any mode flags, into a
+    /// `MemPolicy`.
+    fn from_raw(raw: i32) -> Option<Self> {
+        match raw {
+            0 => Some(Self::Default),
+            1 => Some(Self::Preferred),
+            2 => Some(Self::Bind),
+            3 => Some(Self::Interleave),
+            4 => Some(Self::Local),
+            _ => None,
+        }
+    }
+}
+
+bitflags::bitflags! {
+    /// `MPOL_F_*` flags for use with [`get_mempolicy`].
+    pub struct GetMempolicyFlags: u32 {
+        /// `MPOL_F_ADDR`—Return the policy governing the address passed to
+        /// [`get_mempolicy`], rather than the calling thread's policy.
+        const ADDR = 2;
+        /// `MPOL_F_MEMS_ALLOWED`—Return the set of nodes the calling thread
+        /// may use, with a policy of [`MemPolicy::Default`] (since Linux
+        /// 2.6.24).
+        const MEMS_ALLOWED = 4;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MPOL_MF_*` flags for use with [`mbind`].
+    pub struct MbindFlags: u32 {
+        /// `MPOL_MF_STRICT`—Fail with [`io::Errno::IO`] if any existing pages
+        /// in the range don't follow the policy, and `MOVE` or `MOVE_ALL`
+        /// couldn't move them.
+        const STRICT = 1;
+        /// `MPOL_MF_MOVE`—Move existing pages in the range which are only
+        /// used by this process to follow the policy.
+        const MOVE = 2;
+        /// `MPOL_MF_MOVE_ALL`—Move existing pages in the range to follow the
+        /// policy, even if other processes use them. This requires the
+        /// `CAP_SYS_NICE` capability.
+        const MOVE_ALL = 4;
+    }
+}
+
+bitflags::bitflags! {
+    /// `MPOL_MF_*` flags for use with [`move_pages`].
+    pub struct MovePagesFlags: u32 {
+        /// `MPOL_MF_MOVE`—Only move pages which are only used by the target
+        /// process.
+        const MOVE = 2;
+        /// `MPOL_MF_MOVE_ALL`—Move pages even if other processes use them.
+        /// This requires the `CAP_SYS_NICE` capability.
+        const MOVE_ALL = 4;
+    }
+}
+
+/// `NodeMask` represents a bit-mask of NUMA nodes.
+///
+/// `NodeMask`s are used by [`set_mempolicy`] and [`get_mempolicy`]. A
+/// `NodeMask` has room for [`NodeMask::MAX_NODE`] nodes, which is the
+/// largest number of nodes Linux can be configured to support.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NodeMask {
+    bits: [c::c_ulong; NODE_WORDS],
+}
+
+impl NodeMask {
+    /// The maximum number of nodes in a `NodeMask`.
+    pub const MAX_NODE: usize = 1024;
+
+    /// Create a new and empty `NodeMask`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            bits: [0; NODE_WORDS],
+        }
+    }
+
+    /// Test to see if a node is in the `NodeMask`.
+    ///
+    /// `node` is the node id to test.
+    #[inline]
+    pub fn is_set(&self, node: usize) -> bool {
+        assert!(node < Self::MAX_NODE, "node out of bounds");
+        self.bits[node / NODE_WORD_BITS] & (1 << (node % NODE_WORD_BITS)) != 0
+    }
+
+    /// Add a node to the `NodeMask`.
+    ///
+    /// `node` is the node id to add.
+    #[inline]
+    pub fn set(&mut self, node: usize) {
+        assert!(node < Self::MAX_NODE, "node out of bounds");
+        self.bits[node / NODE_WORD_BITS] |= 1 << (node % NODE_WORD_BITS);
+    }
+
+    /// Remove a node from the `NodeMask`.
+    ///
+    /// `node` is the node id to remove.
+    #[inline]
+    pub fn unset(&mut self, node: usize) {
+        assert!(node < Self::MAX_NODE, "node out of bounds");
+        self.bits[node / NODE_WORD_BITS] &= !(1 << (node % NODE_WORD_BITS));
+    }
+
+    /// Count the number of nodes set in the `NodeMask`.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.bits.iter().map(|word| word.count_ones()).sum()
+    }
+
+    /// Test whether the `NodeMask` is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|word| *word == 0)
+    }
+
+    /// Zeroes the `NodeMask`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.bits = [0; NODE_WORDS];
+    }
+}
+
+impl Default for NodeMask {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `set_mempolicy(policy, nodemask, maxnode)`—Sets the calling thread's NUMA
+/// memory policy.
+///
+/// The policy applies to memory the thread allocates afterward, and is
+/// inherited by child processes and threads. [`MemPolicy::Default`] and
+/// [`MemPolicy::Local`] require `nodemask` to be empty.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/set_mempolicy.2.html
+#[inline]
+pub fn set_mempolicy(policy: MemPolicy, nodemask: &NodeMask) -> io::Result<()> {
+    backend::mm::syscalls::set_mempolicy(policy as i32, &nodemask.bits)
+}
+
+/// `get_mempolicy(&policy, &nodemask, maxnode, addr, flags)`—Returns a NUMA
+/// memory policy and its set of nodes.
+///
+/// By default this returns the calling thread's policy. With
+/// [`GetMempolicyFlags::ADDR`], it returns the policy governing the memory
+/// at `addr` instead; `addr` must be `None` otherwise. For
+/// [`MemPolicy::Default`], the mask is empty.
+///
+/// Policies newer than the ones in [`MemPolicy`] are reported as
+/// [`io::Errno::NOTSUP`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/get_mempolicy.2.html
+#[inline]
+pub fn get_mempolicy(
+    addr: Option<*const c_void>,
+    flags: GetMempolicyFlags,
+) -> io::Result<(MemPolicy, NodeMask)> {
+    let mut nodemask = NodeMask::new();
+    let raw = backend::mm::syscalls::get_mempolicy(
+        &mut nodemask.bits,
+        addr.unwrap_or_else(null),
+        flags.bits(),
+    )?;
+    let policy = MemPolicy::from_raw(raw & !MPOL_MODE_FLAGS).ok_or(io::Errno::NOTSUP)?;
+    Ok((policy, nodemask))
+}
+
+/// `mbind(addr, len, policy, nodemask, maxnode, flags)`—Sets the NUMA memory
+/// policy for a range of memory.
+///
+/// This overrides the calling thread's policy, as set by [`set_mempolicy`],
+/// for memory in the range. By default, only pages allocated afterward
+/// follow the policy; use [`MbindFlags::MOVE`] to also move existing pages.
+///
+/// # Safety
+///
+/// `addr` must be page-aligned, and the range must be memory that is
+/// appropriate to call `mbind` on. Moving pages doesn't change their
+/// contents, but it may stall other threads which access them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/mbind.2.html
+#[inline]
+pub unsafe fn mbind(
+    addr: *mut c_void,
+    len: usize,
+    policy: MemPolicy,
+    nodemask: &NodeMask,
+    flags: MbindFlags,
+) -> io::Result<()> {
+    backend::mm::syscalls::mbind(addr, len, policy as i32, &nodemask.bits, flags.bits())
+}
+
+/// `migrate_pages(pid, maxnode, old_nodes, new_nodes)`—Moves all the pages
+/// of a process from one set of nodes to another.
+///
+/// Pages on nodes in `old_nodes` are moved to nodes in `new_nodes`, keeping
+/// their relative positions where possible. Returns the number of pages
+/// which couldn't be moved. Moving another process's pages requires the
+/// `CAP_SYS_NICE` capability.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/migrate_pages.2.html
+#[inline]
+pub fn migrate_pages(pid: Pid, old_nodes: &NodeMask, new_nodes: &NodeMask) -> io::Result<usize> {
+    backend::mm::syscalls::migrate_pages(pid, &old_nodes.bits, &new_nodes.bits)
+}
+
+/// `move_pages(pid, count, pages, nodes, status, flags)`—Moves individual
+/// pages of a process to other NUMA nodes, or queries which nodes they're on.
+///
+/// If `nodes` is `Some`, each page in `pages` is moved to the corresponding
+/// node in `nodes`, which must be the same length as `pages`. If `nodes` is
+/// `None`, nothing is moved. Either way, this returns the status of each
+/// page: the node it's on after the call, or a negated `errno` value, such as
+/// `-EFAULT` for a page that isn't mapped, or `-ENOENT` for one that has never
+/// been touched. Moving another process's pages requires the `CAP_SYS_NICE`
+/// capability.
+///
+/// # Safety
+///
+/// `pages` must contain addresses of memory that is appropriate to call
+/// `move_pages` on. Moving pages doesn't change their contents, but it may
+/// stall other threads which access them.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/move_pages.2.html
+pub unsafe fn move_pages(
+    pid: Pid,
+    pages: &[*const c_void],
+    nodes: Option<&[i32]>,
+    flags: MovePagesFlags,
+) -> io::Result<Vec<i32>> {
+    if let Some(nodes) = nodes {
+        if nodes.len() != pages.len() {
+            return Err(io::Errno::INVAL);
+        }
+    }
+    let mut status = alloc::vec![0; pages.len()];
+    backend::mm::syscalls::move_pages(pid, pages, nodes, &mut status, flags.bits())?;
+    Ok(status)
+}
@@ -1,14 +1,27 @@
 //! Memory map operations.
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod buffer;
 #[cfg(not(target_os = "redox"))]
 mod madvise;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mempolicy;
 mod mmap;
 mod msync;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+mod shadow_stack;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod userfaultfd;
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use buffer::MmapBuffer;
 #[cfg(not(target_os = "redox"))]
 pub use madvise::{madvise, Advice};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use mempolicy::{
+    get_mempolicy, mbind, migrate_pages, move_pages, set_mempolicy, GetMempolicyFlags, MbindFlags,
+    MemPolicy, MovePagesFlags, NodeMask,
+};
 pub use mmap::{
     mlock, mmap, mmap_anonymous, mprotect, munlock, munmap, MapFlags, MprotectFlags, ProtFlags,
 };
@@ -18,4 +31,6 @@ pub use mmap::{mlock_with, MlockFlags};
 pub use mmap::{mremap, mremap_fixed, MremapFlags};
 pub use msync::{msync, MsyncFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
+pub use shadow_stack::{map_shadow_stack, MapShadowStackFlags};
+#[cfg(any(target_os = "android", target_os = "linux"))]
 pub use userfaultfd::{userfaultfd, UserfaultfdFlags};
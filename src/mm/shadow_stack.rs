@@ -0,0 +1,58 @@
+//! The x86-64 `map_shadow_stack` syscall.
+//!
+//! # Safety
+//!
+//! `map_shadow_stack` creates a memory mapping, and returns a raw pointer to
+//! it.
+#![allow(unsafe_code)]
+
+use crate::io;
+use core::ffi::c_void;
+
+bitflags::bitflags! {
+    /// `SHADOW_STACK_*` flags for use with [`map_shadow_stack`].
+    pub struct MapShadowStackFlags: u32 {
+        /// `SHADOW_STACK_SET_TOKEN`—Write a restore token at the top of the
+        /// shadow stack, so that it can be switched to with `rstorssp`.
+        const SET_TOKEN = 1;
+    }
+}
+
+/// `map_shadow_stack(addr, size, flags)`—Allocates a shadow stack for use
+/// with x86-64 Control-flow Enforcement Technology (CET).
+///
+/// This is for runtimes which switch stacks, such as coroutine and fiber
+/// implementations, and need a shadow stack for each stack. If `addr` is
+/// nonzero, the shadow stack is created at that address, and this fails if
+/// that would overlap an existing mapping.
+///
+/// This fails with [`io::Errno::NOTSUP`] if user shadow stacks aren't
+/// supported by the CPU or enabled for the calling thread. On Linux versions
+/// before 6.6, and on architectures other than x86-64, it fails with
+/// [`io::Errno::NOSYS`].
+///
+/// # Safety
+///
+/// Raw pointers and lots of special semantics.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://docs.kernel.org/next/x86/shstk.html
+#[inline]
+pub unsafe fn map_shadow_stack(
+    addr: usize,
+    size: usize,
+    flags: MapShadowStackFlags,
+) -> io::Result<*mut c_void> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        crate::backend::mm::syscalls::map_shadow_stack(addr, size, flags.bits())
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    {
+        let _ = (addr, size, flags);
+        Err(io::Errno::NOSYS)
+    }
+}
@@ -0,0 +1,263 @@
+//! POSIX message queues.
+//!
+//! A message queue holds up to a fixed number of messages of up to a fixed
+//! size, each with a priority. Receiving takes the oldest of the messages
+//! with the highest priority. Queues are named with a leading `/`, such as
+//! `/my-queue`, and persist until they're removed with [`mq_unlink`], or
+//! the system shuts down.
+//!
+//! On Linux, message queue descriptors are file descriptors, so they can be
+//! waited on with [`poll`] and [`epoll`].
+//!
+//! # References
+//!  - [POSIX]
+//!  - [Linux]
+//!
+//! [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/mqueue.h.html
+//! [Linux]: https://man7.org/linux/man-pages/man7/mq_overview.7.html
+//! [`poll`]: crate::io::poll
+//! [`epoll`]: crate::io::epoll
+
+use crate::fd::{AsFd, OwnedFd};
+use crate::ffi::CStr;
+use crate::fs::Timespec;
+use crate::fs::{Mode, OFlags};
+use crate::{backend, io, path};
+use core::convert::TryInto;
+use linux_raw_sys::general::__kernel_long_t;
+
+/// `struct mq_attr`—The attributes of a message queue.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MqAttr {
+    /// `mq_flags`—Either [`OFlags::NONBLOCK`] or empty. This is the only
+    /// attribute [`mq_setattr`] changes.
+    pub flags: OFlags,
+    /// `mq_maxmsg`—The maximum number of messages the queue holds.
+    pub max_msgs: usize,
+    /// `mq_msgsize`—The maximum size of a message, in bytes.
+    pub msg_size: usize,
+    /// `mq_curmsgs`—The number of messages currently in the queue. This is
+    /// ignored by [`mq_open`] and [`mq_setattr`].
+    pub cur_msgs: usize,
+}
+
+impl MqAttr {
+    /// Attributes for a new queue holding `max_msgs` messages of up to
+    /// `msg_size` bytes each, for use with [`mq_open`].
+    #[inline]
+    pub const fn new(max_msgs: usize, msg_size: usize) -> Self {
+        Self {
+            flags: OFlags::empty(),
+            max_msgs,
+            msg_size,
+            cur_msgs: 0,
+        }
+    }
+
+    pub(crate) fn to_raw(self) -> io::Result<mq_attr> {
+        let long = |n: usize| n.try_into().map_err(|_| io::Errno::INVAL);
+        Ok(mq_attr {
+            mq_flags: self.flags.bits() as __kernel_long_t,
+            mq_maxmsg: long(self.max_msgs)?,
+            mq_msgsize: long(self.msg_size)?,
+            mq_curmsgs: long(self.cur_msgs)?,
+            __reserved: [0; 4],
+        })
+    }
+
+    pub(crate) fn from_raw(raw: &mq_attr) -> Self {
+        Self {
+            flags: OFlags::from_bits_truncate(raw.mq_flags as _),
+            max_msgs: raw.mq_maxmsg as usize,
+            msg_size: raw.mq_msgsize as usize,
+            cur_msgs: raw.mq_curmsgs as usize,
+        }
+    }
+}
+
+/// The kernel's `struct mq_attr`.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+#[allow(non_camel_case_types)]
+pub(crate) struct mq_attr {
+    pub(crate) mq_flags: __kernel_long_t,
+    pub(crate) mq_maxmsg: __kernel_long_t,
+    pub(crate) mq_msgsize: __kernel_long_t,
+    pub(crate) mq_curmsgs: __kernel_long_t,
+    pub(crate) __reserved: [__kernel_long_t; 4],
+}
+
+/// `mq_open(name, oflags, mode, attr)`—Opens or creates a message queue.
+///
+/// `name` must start with a `/`, and contain no other `/`s. `oflags` must
+/// include one of [`OFlags::RDONLY`], [`OFlags::WRONLY`], or
+/// [`OFlags::RDWR`], and may include [`OFlags::CREATE`], [`OFlags::EXCL`],
+/// [`OFlags::NONBLOCK`], and [`OFlags::CLOEXEC`]. `mode` and `attr` are used
+/// if a queue is created; if `attr` is `None`, the system defaults are used.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_open.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_open.3.html
+#[inline]
+pub fn mq_open<P: path::Arg>(
+    name: P,
+    oflags: OFlags,
+    mode: Mode,
+    attr: Option<&MqAttr>,
+) -> io::Result<OwnedFd> {
+    let attr = match attr {
+        Some(attr) => Some(attr.to_raw()?),
+        None => None,
+    };
+    name.into_with_c_str(|name| {
+        backend::mq::syscalls::mq_open(kernel_name(name)?, oflags, mode, attr.as_ref())
+    })
+}
+
+/// `mq_unlink(name)`—Removes a message queue.
+///
+/// The queue is destroyed once all descriptors for it are closed.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_unlink.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_unlink.3.html
+#[inline]
+pub fn mq_unlink<P: path::Arg>(name: P) -> io::Result<()> {
+    name.into_with_c_str(|name| backend::mq::syscalls::mq_unlink(kernel_name(name)?))
+}
+
+/// The kernel takes queue names without the leading `/`.
+fn kernel_name(name: &CStr) -> io::Result<&CStr> {
+    match name.to_bytes_with_nul() {
+        [b'/', rest @ ..] => Ok(CStr::from_bytes_with_nul(rest).unwrap()),
+        _ => Err(io::Errno::INVAL),
+    }
+}
+
+/// `mq_send(mqdes, msg, priority)`—Sends a message to a message queue.
+///
+/// This blocks while the queue is full, unless it's in nonblocking mode, in
+/// which case it fails with [`io::Errno::AGAIN`]. Messages longer than the
+/// queue's [`MqAttr::msg_size`] fail with [`io::Errno::MSGSIZE`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_send.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_send.3.html
+#[inline]
+pub fn mq_send<Fd: AsFd>(mqdes: Fd, msg: &[u8], priority: u32) -> io::Result<()> {
+    backend::mq::syscalls::mq_timedsend(mqdes.as_fd(), msg, priority, None)
+}
+
+/// `mq_timedsend(mqdes, msg, priority, abs_timeout)`—Sends a message to a
+/// message queue, with a timeout.
+///
+/// This is like [`mq_send`], except that if the queue is full, it fails with
+/// [`io::Errno::TIMEDOUT`] once the `CLOCK_REALTIME` clock reaches
+/// `abs_timeout`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_timedsend.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_send.3.html
+#[inline]
+pub fn mq_timedsend<Fd: AsFd>(
+    mqdes: Fd,
+    msg: &[u8],
+    priority: u32,
+    abs_timeout: &Timespec,
+) -> io::Result<()> {
+    backend::mq::syscalls::mq_timedsend(mqdes.as_fd(), msg, priority, Some(abs_timeout))
+}
+
+/// `mq_receive(mqdes, buf, &mut priority)`—Receives a message from a message
+/// queue.
+///
+/// This takes the oldest of the messages with the highest priority, and
+/// returns its length and priority. It blocks while the queue is empty,
+/// unless it's in nonblocking mode, in which case it fails with
+/// [`io::Errno::AGAIN`]. If `buf` is shorter than the queue's
+/// [`MqAttr::msg_size`], this fails with [`io::Errno::MSGSIZE`], even if the
+/// message would fit.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_receive.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_receive.3.html
+#[inline]
+pub fn mq_receive<Fd: AsFd>(mqdes: Fd, buf: &mut [u8]) -> io::Result<(usize, u32)> {
+    backend::mq::syscalls::mq_timedreceive(mqdes.as_fd(), buf, None)
+}
+
+/// `mq_timedreceive(mqdes, buf, &mut priority, abs_timeout)`—Receives a
+/// message from a message queue, with a timeout.
+///
+/// This is like [`mq_receive`], except that if the queue is empty, it fails
+/// with [`io::Errno::TIMEDOUT`] once the `CLOCK_REALTIME` clock reaches
+/// `abs_timeout`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_timedreceive.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_receive.3.html
+#[inline]
+pub fn mq_timedreceive<Fd: AsFd>(
+    mqdes: Fd,
+    buf: &mut [u8],
+    abs_timeout: &Timespec,
+) -> io::Result<(usize, u32)> {
+    backend::mq::syscalls::mq_timedreceive(mqdes.as_fd(), buf, Some(abs_timeout))
+}
+
+/// `mq_getattr(mqdes)`—Returns the attributes of a message queue.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_getattr.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_getattr.3.html
+#[inline]
+pub fn mq_getattr<Fd: AsFd>(mqdes: Fd) -> io::Result<MqAttr> {
+    let raw = backend::mq::syscalls::mq_getsetattr(mqdes.as_fd(), None)?;
+    Ok(MqAttr::from_raw(&raw))
+}
+
+/// `mq_setattr(mqdes, attr, &mut old_attr)`—Sets the attributes of a message
+/// queue, and returns the old ones.
+///
+/// Only [`MqAttr::flags`] can be changed; the other fields are ignored.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/mq_setattr.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/mq_getattr.3.html
+#[inline]
+pub fn mq_setattr<Fd: AsFd>(mqdes: Fd, attr: &MqAttr) -> io::Result<MqAttr> {
+    let raw = backend::mq::syscalls::mq_getsetattr(mqdes.as_fd(), Some(&attr.to_raw()?))?;
+    Ok(MqAttr::from_raw(&raw))
+}
+
+#[test]
+fn mq_attr_layout() {
+    assert_eq!(
+        core::mem::size_of::<mq_attr>(),
+        8 * core::mem::size_of::<__kernel_long_t>()
+    );
+}
@@ -0,0 +1,688 @@
+//! Control messages, also known as ancillary data, received with
+//! [`recvmsg`] and sent with [`sendmsg`].
+//!
+//! # Safety
+//!
+//! This reads `cmsghdr` headers out of a byte buffer filled in by the kernel,
+//! checking their lengths against the buffer, and writes them into a byte
+//! buffer for the kernel to read. File descriptors received in `SCM_RIGHTS`
+//! messages are owned by the iterator that decodes them.
+//!
+//! [`recvmsg`]: crate::net::recvmsg
+//! [`sendmsg`]: crate::net::sendmsg
+#![allow(unsafe_code)]
+
+use crate::backend::{self, c};
+use crate::fd::{AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use crate::net::{Ipv4Addr, Ipv6Addr};
+use crate::process::{Gid, Pid, Uid};
+use core::convert::TryInto;
+use core::mem::size_of;
+
+use backend::time::types::Timespec;
+
+/// An iterator over the control messages in a buffer filled in by
+/// [`recvmsg`].
+///
+/// This doesn't allocate; the messages it yields borrow from the buffer.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// # let fd = rustix::io::stdin();
+/// use rustix::io::IoSliceMut;
+/// use rustix::net::{recvmsg, ControlMessages, RecvFlags};
+///
+/// let mut buf = [0_u8; 1500];
+/// let mut control = [0_u8; 64];
+/// let ret = recvmsg(
+///     &fd,
+///     &mut [IoSliceMut::new(&mut buf)],
+///     &mut control,
+///     RecvFlags::empty(),
+/// )?;
+/// for msg in ControlMessages::new(&control[..ret.control_len]) {
+///     if let Some(timestamp) = msg.timestamp_ns() {
+///         println!("received at {}.{:09}", timestamp.tv_sec, timestamp.tv_nsec);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`recvmsg`]: crate::net::recvmsg
+#[derive(Debug, Clone)]
+pub struct ControlMessages<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> ControlMessages<'a> {
+    /// Constructs a new `ControlMessages` over the control messages in `buf`.
+    ///
+    /// `buf` should be the prefix of the control buffer passed to
+    /// [`recvmsg`] that it reported as filled in, with
+    /// [`RecvMsgReturn::control_len`].
+    ///
+    /// [`recvmsg`]: crate::net::recvmsg
+    /// [`RecvMsgReturn::control_len`]: crate::net::RecvMsgReturn::control_len
+    #[inline]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+}
+
+impl<'a> Iterator for ControlMessages<'a> {
+    type Item = ControlMessage<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (level, type_, len) = match read_header(self.buf) {
+            Some(header) => header,
+            None => {
+                // The message is truncated or malformed, so stop here.
+                self.buf = &[];
+                return None;
+            }
+        };
+
+        let data = &self.buf[size_of::<c::cmsghdr>()..len];
+        let next = cmsg_align(len).min(self.buf.len());
+        self.buf = &self.buf[next..];
+
+        Some(ControlMessage { level, type_, data })
+    }
+}
+
+/// A single control message, yielded by [`ControlMessages`].
+#[derive(Debug, Clone, Copy)]
+pub struct ControlMessage<'a> {
+    level: i32,
+    type_: i32,
+    data: &'a [u8],
+}
+
+impl<'a> ControlMessage<'a> {
+    /// Returns the `cmsg_level` of this message, such as `SOL_SOCKET`.
+    #[inline]
+    pub fn level(&self) -> i32 {
+        self.level
+    }
+
+    /// Returns the `cmsg_type` of this message, such as `SCM_TIMESTAMPNS`.
+    #[inline]
+    pub fn type_(&self) -> i32 {
+        self.type_
+    }
+
+    /// Returns the payload of this message.
+    #[inline]
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// If this is a `SCM_TIMESTAMPNS` message, enabled with
+    /// [`set_socket_timestamp_ns`], returns the time the packet was
+    /// received.
+    ///
+    /// [`set_socket_timestamp_ns`]: crate::net::sockopt::set_socket_timestamp_ns
+    #[inline]
+    pub fn timestamp_ns(&self) -> Option<Timespec> {
+        backend::net::cmsg::timestamp_ns(self.level, self.type_, self.data)
+    }
+
+    /// If this is a `SCM_TIMESTAMPING` message, enabled with
+    /// [`set_socket_timestamping`], returns its three timestamps.
+    ///
+    /// The first is a software timestamp, the second is unused, and the
+    /// third is a raw hardware timestamp. Timestamps that weren't generated
+    /// are zero.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [`set_socket_timestamping`]: crate::net::sockopt::set_socket_timestamping
+    /// [Linux]: https://www.kernel.org/doc/html/latest/networking/timestamping.html
+    #[inline]
+    pub fn timestamping(&self) -> Option<[Timespec; 3]> {
+        backend::net::cmsg::timestamping(self.level, self.type_, self.data)
+    }
+
+    /// If this is a `SCM_CREDENTIALS` message, received on a Unix socket with
+    /// [`set_socket_passcred`] enabled, returns the sender's credentials.
+    ///
+    /// [`set_socket_passcred`]: crate::net::sockopt::set_socket_passcred
+    #[inline]
+    pub fn credentials(&self) -> Option<UnixCredentials> {
+        backend::net::cmsg::credentials(self.level, self.type_, self.data)
+    }
+
+    /// If this is an `IP_PKTINFO` message, enabled with [`set_ip_pktinfo`],
+    /// returns the interface and addresses the datagram arrived on.
+    ///
+    /// [`set_ip_pktinfo`]: crate::net::sockopt::set_ip_pktinfo
+    #[inline]
+    pub fn ipv4_packet_info(&self) -> Option<Ipv4PacketInfo> {
+        backend::net::cmsg::ipv4_packet_info(self.level, self.type_, self.data)
+    }
+
+    /// If this is an `IPV6_PKTINFO` message, enabled with
+    /// [`set_ipv6_recvpktinfo`], returns the interface and address the
+    /// datagram arrived on.
+    ///
+    /// [`set_ipv6_recvpktinfo`]: crate::net::sockopt::set_ipv6_recvpktinfo
+    #[inline]
+    pub fn ipv6_packet_info(&self) -> Option<Ipv6PacketInfo> {
+        backend::net::cmsg::ipv6_packet_info(self.level, self.type_, self.data)
+    }
+
+    /// If this is an `IP_RECVERR` or `IPV6_RECVERR` message holding a
+    /// `MSG_ZEROCOPY` completion notification, returns the completion.
+    ///
+    /// These are received from the socket's error queue, with
+    /// [`RecvFlags::ERRQUEUE`]. See also [`recv_zerocopy_completions`].
+    ///
+    /// [`RecvFlags::ERRQUEUE`]: crate::net::RecvFlags::ERRQUEUE
+    /// [`recv_zerocopy_completions`]: crate::net::recv_zerocopy_completions
+    #[inline]
+    pub fn zerocopy_completion(&self) -> Option<ZerocopyCompletion> {
+        backend::net::cmsg::zerocopy_completion(self.level, self.type_, self.data)
+    }
+}
+
+/// A notification that the kernel is done with the buffers passed to a range
+/// of [`SendFlags::ZEROCOPY`] sends, so they may be reused.
+///
+/// Each `ZEROCOPY` send on a socket is numbered, counting from zero, and a
+/// completion covers the sends numbered `lo` through `hi`, inclusive.
+///
+/// # References
+///  - [Linux]
+///
+/// [`SendFlags::ZEROCOPY`]: crate::net::SendFlags::ZEROCOPY
+/// [Linux]: https://www.kernel.org/doc/html/latest/networking/msg_zerocopy.html
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ZerocopyCompletion {
+    /// The number of the first send covered by this completion.
+    pub lo: u32,
+
+    /// The number of the last send covered by this completion.
+    pub hi: u32,
+
+    /// Whether the kernel copied the data instead of sending it in place,
+    /// as it does on loopback, for example. If this is set, `ZEROCOPY` likely
+    /// isn't beneficial for this socket.
+    pub copied: bool,
+}
+
+/// The size of the control buffer in [`ZerocopyCompletions`], which has room
+/// for a `sock_extended_err` with an IPv6 offender address.
+const ZEROCOPY_CONTROL_LEN: usize = 128;
+
+/// An iterator over the completions returned by
+/// [`recv_zerocopy_completions`].
+///
+/// [`recv_zerocopy_completions`]: crate::net::recv_zerocopy_completions
+#[derive(Debug, Clone)]
+pub struct ZerocopyCompletions {
+    pub(crate) control: [u8; ZEROCOPY_CONTROL_LEN],
+    pub(crate) len: usize,
+    offset: usize,
+}
+
+impl ZerocopyCompletions {
+    pub(crate) fn new() -> Self {
+        Self {
+            control: [0; ZEROCOPY_CONTROL_LEN],
+            len: 0,
+            offset: 0,
+        }
+    }
+}
+
+impl Iterator for ZerocopyCompletions {
+    type Item = ZerocopyCompletion;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mut messages = ControlMessages::new(&self.control[self.offset..self.len]);
+            let message = messages.next()?;
+            self.offset = self.len - messages.buf.len();
+            if let Some(completion) = message.zerocopy_completion() {
+                return Some(completion);
+            }
+        }
+    }
+}
+
+/// An iterator over the control messages in a buffer filled in by
+/// [`recvmsg`], decoding them into [`RecvAncillaryMessage`]s.
+///
+/// This walks the buffer with [`ControlMessages`], and additionally takes
+/// ownership of the file descriptors received in `SCM_RIGHTS` messages. Any
+/// that aren't taken out of the iterator, including those in messages that
+/// are never reached, are closed when the iterator is dropped.
+///
+/// This doesn't allocate; the messages it yields borrow from the buffer.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// # let fd = rustix::io::stdin();
+/// use rustix::io::IoSliceMut;
+/// use rustix::net::{recvmsg, RecvAncillaryMessage, RecvAncillaryMessages, RecvFlags};
+///
+/// let mut buf = [0_u8; 64];
+/// let mut control = [0_u8; 64];
+/// let ret = recvmsg(
+///     &fd,
+///     &mut [IoSliceMut::new(&mut buf)],
+///     &mut control,
+///     RecvFlags::CMSG_CLOEXEC,
+/// )?;
+/// // Safety: `control` was just filled in by `recvmsg`, and isn't decoded
+/// // again.
+/// let messages = unsafe { RecvAncillaryMessages::new(&control[..ret.control_len]) };
+/// for msg in messages {
+///     match msg {
+///         RecvAncillaryMessage::ScmRights(fds) => {
+///             for fd in fds {
+///                 println!("received {:?}", fd);
+///             }
+///         }
+///         RecvAncillaryMessage::ScmCredentials(creds) => {
+///             println!("sent by {:?}", creds);
+///         }
+///         _ => {}
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`recvmsg`]: crate::net::recvmsg
+#[derive(Debug)]
+pub struct RecvAncillaryMessages<'a> {
+    messages: ControlMessages<'a>,
+}
+
+impl<'a> RecvAncillaryMessages<'a> {
+    /// Constructs a new `RecvAncillaryMessages` over the control messages in
+    /// `buf`.
+    ///
+    /// `buf` should be the prefix of the control buffer passed to
+    /// [`recvmsg`] that it reported as filled in, with
+    /// [`RecvMsgReturn::control_len`].
+    ///
+    /// # Safety
+    ///
+    /// `buf` must hold control messages written by the kernel, and the file
+    /// descriptors in any `SCM_RIGHTS` messages in it must be owned by the
+    /// caller, which passes that ownership to the returned iterator. So `buf`
+    /// must not be decoded with `RecvAncillaryMessages` again.
+    ///
+    /// [`recvmsg`]: crate::net::recvmsg
+    /// [`RecvMsgReturn::control_len`]: crate::net::RecvMsgReturn::control_len
+    #[inline]
+    pub unsafe fn new(buf: &'a [u8]) -> Self {
+        Self {
+            messages: ControlMessages::new(buf),
+        }
+    }
+}
+
+impl<'a> Iterator for RecvAncillaryMessages<'a> {
+    type Item = RecvAncillaryMessage<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let msg = self.messages.next()?;
+
+        if backend::net::cmsg::is_rights(msg.level, msg.type_) {
+            return Some(RecvAncillaryMessage::ScmRights(AncillaryFds {
+                data: msg.data,
+            }));
+        }
+        if let Some(creds) = msg.credentials() {
+            return Some(RecvAncillaryMessage::ScmCredentials(creds));
+        }
+        if let Some(timestamp) = msg.timestamp_ns() {
+            return Some(RecvAncillaryMessage::Timestamp(timestamp));
+        }
+        if let Some(info) = msg.ipv4_packet_info() {
+            return Some(RecvAncillaryMessage::Ipv4PacketInfo(info));
+        }
+        if let Some(info) = msg.ipv6_packet_info() {
+            return Some(RecvAncillaryMessage::Ipv6PacketInfo(info));
+        }
+        Some(RecvAncillaryMessage::Unknown {
+            level: msg.level,
+            type_: msg.type_,
+            data: msg.data,
+        })
+    }
+}
+
+impl<'a> Drop for RecvAncillaryMessages<'a> {
+    fn drop(&mut self) {
+        // Close any file descriptors in the messages that weren't reached.
+        self.for_each(drop);
+    }
+}
+
+/// A control message decoded by [`RecvAncillaryMessages`].
+#[cfg_attr(feature = "std", derive(Debug))]
+#[non_exhaustive]
+pub enum RecvAncillaryMessage<'a> {
+    /// A `SCM_RIGHTS` message, holding file descriptors passed over a Unix
+    /// socket.
+    ScmRights(AncillaryFds<'a>),
+
+    /// A `SCM_CREDENTIALS` message, holding the credentials of the sender,
+    /// received on a Unix socket with [`set_socket_passcred`] enabled.
+    ///
+    /// [`set_socket_passcred`]: crate::net::sockopt::set_socket_passcred
+    ScmCredentials(UnixCredentials),
+
+    /// A `SCM_TIMESTAMPNS` message, holding the time the packet was
+    /// received, enabled with [`set_socket_timestamp_ns`].
+    ///
+    /// [`set_socket_timestamp_ns`]: crate::net::sockopt::set_socket_timestamp_ns
+    Timestamp(Timespec),
+
+    /// An `IP_PKTINFO` message, holding the interface and addresses a
+    /// datagram arrived on, enabled with [`set_ip_pktinfo`].
+    ///
+    /// [`set_ip_pktinfo`]: crate::net::sockopt::set_ip_pktinfo
+    Ipv4PacketInfo(Ipv4PacketInfo),
+
+    /// An `IPV6_PKTINFO` message, holding the interface and address a
+    /// datagram arrived on, enabled with [`set_ipv6_recvpktinfo`].
+    ///
+    /// [`set_ipv6_recvpktinfo`]: crate::net::sockopt::set_ipv6_recvpktinfo
+    Ipv6PacketInfo(Ipv6PacketInfo),
+
+    /// A message that isn't decoded by this iterator.
+    Unknown {
+        /// The `cmsg_level` of the message.
+        level: i32,
+        /// The `cmsg_type` of the message.
+        type_: i32,
+        /// The payload of the message.
+        data: &'a [u8],
+    },
+}
+
+/// An iterator over the file descriptors in a `SCM_RIGHTS` message.
+///
+/// Any file descriptors that aren't taken out of this iterator are closed
+/// when it's dropped.
+#[derive(Debug)]
+pub struct AncillaryFds<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for AncillaryFds<'a> {
+    type Item = OwnedFd;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() < size_of::<RawFd>() {
+            return None;
+        }
+        let (raw, rest) = self.data.split_at(size_of::<RawFd>());
+        self.data = rest;
+
+        let fd = RawFd::from_ne_bytes(raw.try_into().unwrap());
+        // Safety: The caller of `RecvAncillaryMessages::new` guaranteed that
+        // the descriptors in the buffer are owned, and each is only read
+        // once.
+        Some(unsafe { OwnedFd::from_raw_fd(fd) })
+    }
+}
+
+impl<'a> Drop for AncillaryFds<'a> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+/// `struct ucred`—The credentials of a process, passed over a Unix socket
+/// in a `SCM_CREDENTIALS` message.
+///
+/// When sending, the kernel checks that these are the sender's own
+/// credentials, unless it has the privilege to send others.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/unix.7.html
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct UnixCredentials {
+    /// The process ID of the sender.
+    pub pid: Option<Pid>,
+
+    /// The user ID of the sender.
+    pub uid: Uid,
+
+    /// The group ID of the sender.
+    pub gid: Gid,
+}
+
+/// `struct in_pktinfo`—The interface and addresses of an IPv4 datagram,
+/// passed in an `IP_PKTINFO` message.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Ipv4PacketInfo {
+    /// The index of the interface the datagram arrived on, or is to be sent
+    /// on.
+    pub ifindex: u32,
+
+    /// The local address the datagram arrived on, for use as the source
+    /// address of a reply, or the source address to send it from.
+    pub local_addr: Ipv4Addr,
+
+    /// The destination address in the datagram's header.
+    pub dest_addr: Ipv4Addr,
+}
+
+/// `struct in6_pktinfo`—The interface and address of an IPv6 datagram,
+/// passed in an `IPV6_PKTINFO` message.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Ipv6PacketInfo {
+    /// The index of the interface the datagram arrived on, or is to be sent
+    /// on.
+    pub ifindex: u32,
+
+    /// The destination address the datagram arrived on, or the source
+    /// address to send it from.
+    pub addr: Ipv6Addr,
+}
+
+/// A control message to send with [`sendmsg`], encoded into a
+/// [`SendAncillaryBuffer`].
+///
+/// [`sendmsg`]: crate::net::sendmsg
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+pub enum SendAncillaryMessage<'a> {
+    /// A `SCM_RIGHTS` message, passing file descriptors over a Unix socket.
+    ScmRights(&'a [BorrowedFd<'a>]),
+
+    /// A `SCM_CREDENTIALS` message, passing credentials over a Unix socket.
+    ScmCredentials(UnixCredentials),
+
+    /// An `IP_PKTINFO` message, selecting the source address of a datagram
+    /// with `local_addr`, and the interface to send it on with `ifindex`,
+    /// if it's non-zero. `dest_addr` is ignored.
+    Ipv4PacketInfo(Ipv4PacketInfo),
+
+    /// An `IPV6_PKTINFO` message, selecting the source address of a datagram
+    /// with `addr`, if it's not unspecified, and the interface to send it on
+    /// with `ifindex`, if it's non-zero.
+    Ipv6PacketInfo(Ipv6PacketInfo),
+}
+
+impl<'a> SendAncillaryMessage<'a> {
+    /// Returns the number of bytes this message takes up in a control buffer,
+    /// like `CMSG_SPACE`, including the padding after it.
+    pub fn space(&self) -> usize {
+        cmsg_align(size_of::<c::cmsghdr>()) + cmsg_align(self.data_len())
+    }
+
+    fn data_len(&self) -> usize {
+        match self {
+            Self::ScmRights(fds) => fds.len() * size_of::<RawFd>(),
+            Self::ScmCredentials(_) => backend::net::cmsg::CREDENTIALS_LEN,
+            Self::Ipv4PacketInfo(_) => backend::net::cmsg::IPV4_PACKET_INFO_LEN,
+            Self::Ipv6PacketInfo(_) => backend::net::cmsg::IPV6_PACKET_INFO_LEN,
+        }
+    }
+}
+
+/// A buffer of control messages to send with [`sendmsg`].
+///
+/// This encodes messages into a caller-provided buffer, so it doesn't
+/// allocate.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// # let fd = rustix::io::stdin();
+/// use rustix::fd::AsFd;
+/// use rustix::io::IoSlice;
+/// use rustix::net::{sendmsg, SendAncillaryBuffer, SendAncillaryMessage, SendFlags};
+///
+/// let stdout = rustix::io::stdout();
+/// let fds = [stdout.as_fd()];
+/// let mut space = [0_u8; 64];
+/// let mut control = SendAncillaryBuffer::new(&mut space);
+/// assert!(control.push(SendAncillaryMessage::ScmRights(&fds)));
+/// sendmsg(&fd, &[IoSlice::new(b"x")], control.as_bytes(), SendFlags::empty())?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`sendmsg`]: crate::net::sendmsg
+#[derive(Debug)]
+pub struct SendAncillaryBuffer<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SendAncillaryBuffer<'a> {
+    /// Constructs a new empty `SendAncillaryBuffer` which encodes messages
+    /// into `buf`.
+    #[inline]
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Appends a message to the buffer.
+    ///
+    /// Returns `false`, leaving the buffer unchanged, if there isn't room for
+    /// it.
+    pub fn push(&mut self, msg: SendAncillaryMessage<'_>) -> bool {
+        let space = msg.space();
+        if self.buf.len() - self.len < space {
+            return false;
+        }
+
+        let header_len = size_of::<c::cmsghdr>();
+        let data_len = msg.data_len();
+        let message = &mut self.buf[self.len..self.len + space];
+        for byte in message.iter_mut() {
+            *byte = 0;
+        }
+        let (level, type_) = match msg {
+            SendAncillaryMessage::ScmRights(fds) => {
+                for (fd, raw) in fds
+                    .iter()
+                    .zip(message[header_len..].chunks_exact_mut(size_of::<RawFd>()))
+                {
+                    raw.copy_from_slice(&fd.as_raw_fd().to_ne_bytes());
+                }
+                backend::net::cmsg::RIGHTS
+            }
+            SendAncillaryMessage::ScmCredentials(creds) => {
+                backend::net::cmsg::write_credentials(creds, &mut message[header_len..]);
+                backend::net::cmsg::CREDENTIALS
+            }
+            SendAncillaryMessage::Ipv4PacketInfo(info) => {
+                backend::net::cmsg::write_ipv4_packet_info(info, &mut message[header_len..]);
+                backend::net::cmsg::IPV4_PACKET_INFO
+            }
+            SendAncillaryMessage::Ipv6PacketInfo(info) => {
+                backend::net::cmsg::write_ipv6_packet_info(info, &mut message[header_len..]);
+                backend::net::cmsg::IPV6_PACKET_INFO
+            }
+        };
+
+        // Safety: `cmsghdr` is plain old data, so zero is a valid value for
+        // any private padding fields it has.
+        let mut header: c::cmsghdr = unsafe { core::mem::zeroed() };
+        header.cmsg_len = (header_len + data_len) as _;
+        header.cmsg_level = level;
+        header.cmsg_type = type_;
+        // Safety: We checked that `message` is long enough to hold a header,
+        // and `write_unaligned` doesn't require the buffer to be aligned.
+        unsafe {
+            message
+                .as_mut_ptr()
+                .cast::<c::cmsghdr>()
+                .write_unaligned(header)
+        };
+
+        self.len += space;
+        true
+    }
+
+    /// Returns the encoded messages, to pass to [`sendmsg`].
+    ///
+    /// [`sendmsg`]: crate::net::sendmsg
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Removes all messages from the buffer.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+/// Read the `cmsg_level`, `cmsg_type`, and `cmsg_len` of the message at the
+/// start of `buf`, or return `None` if it's truncated or malformed.
+fn read_header(buf: &[u8]) -> Option<(i32, i32, usize)> {
+    let header_len = size_of::<c::cmsghdr>();
+    if buf.len() < header_len {
+        return None;
+    }
+
+    // Safety: We checked that `buf` is long enough to hold a header, and
+    // `read_unaligned` doesn't require the buffer to be aligned.
+    let header = unsafe { buf.as_ptr().cast::<c::cmsghdr>().read_unaligned() };
+    let len = header.cmsg_len as usize;
+    if len < header_len || len > buf.len() {
+        return None;
+    }
+    Some((header.cmsg_level, header.cmsg_type, len))
+}
+
+/// Round `len` up to the alignment of a `cmsghdr`, like `CMSG_ALIGN`.
+#[inline]
+const fn cmsg_align(len: usize) -> usize {
+    (len + size_of::<usize>() - 1) & !(size_of::<usize>() - 1)
+}
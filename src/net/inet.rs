@@ -0,0 +1,353 @@
+//! Parsing and formatting of numeric IP addresses, like `inet_pton` and
+//! `inet_ntop`.
+//!
+//! These are implemented in Rust, and never consult a resolver or the
+//! locale.
+
+use crate::io;
+use crate::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use core::fmt;
+
+/// The length of the longest string [`format_ipv6`] produces, eight full
+/// groups such as `ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff`.
+///
+/// IPv4-mapped addresses are shorter, at most 22 bytes for
+/// `::ffff:255.255.255.255`, as are all IPv4 addresses.
+const MAX_LEN: usize = 39;
+
+/// `inet_pton(AF_INET, s, &addr)`—Parses an IPv4 address in dotted-decimal
+/// notation, such as `192.0.2.1`.
+///
+/// Each of the four components must be a decimal number from 0 to 255,
+/// without leading zeros. Otherwise, this fails with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/inet_pton.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/inet_pton.3.html
+#[doc(alias = "inet_pton")]
+pub fn parse_ipv4(s: &str) -> io::Result<Ipv4Addr> {
+    parse_ipv4_octets(s.as_bytes())
+        .map(Ipv4Addr::from)
+        .ok_or(io::Errno::INVAL)
+}
+
+/// `inet_pton(AF_INET6, s, &addr)`—Parses an IPv6 address, such as
+/// `2001:db8::1`, with an optional zone ID, such as the `eth0` in
+/// `fe80::1%eth0`.
+///
+/// This accepts the text forms in [RFC 4291], including `::` for a run of
+/// zero groups, and an embedded IPv4 address in dotted-decimal notation as
+/// the last 32 bits, such as `::ffff:192.0.2.1`. A zone ID follows a `%`,
+/// and is returned as is; it may be an interface name or number. Anything
+/// else fails with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///  - [RFC 4007]
+///
+/// [RFC 4291]: https://www.rfc-editor.org/rfc/rfc4291#section-2.2
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/inet_pton.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/inet_pton.3.html
+/// [RFC 4007]: https://www.rfc-editor.org/rfc/rfc4007#section-11
+#[doc(alias = "inet_pton")]
+pub fn parse_ipv6(s: &str) -> io::Result<(Ipv6Addr, Option<&str>)> {
+    let (addr, zone) = match s.find('%') {
+        Some(pos) => {
+            let zone = &s[pos + 1..];
+            if zone.is_empty() {
+                return Err(io::Errno::INVAL);
+            }
+            (&s[..pos], Some(zone))
+        }
+        None => (s, None),
+    };
+    let segments = parse_ipv6_segments(addr.as_bytes()).ok_or(io::Errno::INVAL)?;
+    Ok((Ipv6Addr::from(segments), zone))
+}
+
+/// `inet_ntop(AF_INET, &addr, buf, len)`—Formats an IPv4 address in
+/// dotted-decimal notation.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/inet_ntop.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/inet_ntop.3.html
+#[doc(alias = "inet_ntop")]
+pub fn format_ipv4(addr: &Ipv4Addr) -> IpAddrString {
+    let mut s = IpAddrString::new();
+    write_ipv4(&mut s, addr.octets());
+    s
+}
+
+/// `inet_ntop(AF_INET6, &addr, buf, len)`—Formats an IPv6 address in the
+/// canonical form of [RFC 5952].
+///
+/// Groups are in lowercase hexadecimal without leading zeros, and the
+/// longest run of two or more zero groups, or the first of several equally
+/// long runs, is replaced by `::`. IPv4-mapped addresses are formatted with
+/// the IPv4 address in dotted-decimal notation, such as `::ffff:192.0.2.1`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [RFC 5952]: https://www.rfc-editor.org/rfc/rfc5952#section-4
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/inet_ntop.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/inet_ntop.3.html
+#[doc(alias = "inet_ntop")]
+pub fn format_ipv6(addr: &Ipv6Addr) -> IpAddrString {
+    let mut s = IpAddrString::new();
+    write_ipv6(&mut s, addr.segments());
+    s
+}
+
+/// Formats an IP address with [`format_ipv4`] or [`format_ipv6`].
+#[doc(alias = "inet_ntop")]
+pub fn format_ip(addr: &IpAddr) -> IpAddrString {
+    match addr {
+        IpAddr::V4(v4) => format_ipv4(v4),
+        IpAddr::V6(v6) => format_ipv6(v6),
+    }
+}
+
+/// A formatted IP address, as returned by [`format_ipv4`], [`format_ipv6`],
+/// and [`format_ip`].
+#[derive(Clone, Copy)]
+pub struct IpAddrString {
+    buf: [u8; MAX_LEN],
+    len: usize,
+}
+
+impl IpAddrString {
+    const fn new() -> Self {
+        Self {
+            buf: [0; MAX_LEN],
+            len: 0,
+        }
+    }
+
+    /// Returns the formatted address as a `&str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // The buffer is only ever written with ASCII.
+        core::str::from_utf8(&self.buf[..self.len]).unwrap()
+    }
+}
+
+impl fmt::Write for IpAddrString {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > MAX_LEN {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+impl fmt::Display for IpAddrString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl fmt::Debug for IpAddrString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl AsRef<str> for IpAddrString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for IpAddrString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for IpAddrString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+fn parse_ipv4_octets(s: &[u8]) -> Option<[u8; 4]> {
+    let mut octets = [0_u8; 4];
+    let mut parts = s.split(|b| *b == b'.');
+    for octet in octets.iter_mut() {
+        *octet = parse_decimal_octet(parts.next()?)?;
+    }
+    if parts.next().is_some() {
+        return None;
+    }
+    Some(octets)
+}
+
+fn parse_decimal_octet(s: &[u8]) -> Option<u8> {
+    // Leading zeros are rejected, as some parsers treat them as octal.
+    if s.is_empty() || s.len() > 3 || (s.len() > 1 && s[0] == b'0') {
+        return None;
+    }
+    let mut value = 0_u16;
+    for b in s {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + u16::from(b - b'0');
+    }
+    if value > 255 {
+        return None;
+    }
+    Some(value as u8)
+}
+
+fn parse_ipv6_segments(s: &[u8]) -> Option<[u16; 8]> {
+    let mut segments = [0_u16; 8];
+
+    match find_double_colon(s) {
+        Some(pos) => {
+            let (head, tail) = (&s[..pos], &s[pos + 2..]);
+            if find_double_colon(tail).is_some() {
+                return None;
+            }
+
+            let mut head_segments = [0_u16; 8];
+            let head_len = parse_ipv6_groups(head, &mut head_segments, false)?;
+            let mut tail_segments = [0_u16; 8];
+            let tail_len = parse_ipv6_groups(tail, &mut tail_segments, true)?;
+            // `::` stands for at least one zero group.
+            if head_len + tail_len > 7 {
+                return None;
+            }
+
+            segments[..head_len].copy_from_slice(&head_segments[..head_len]);
+            segments[8 - tail_len..].copy_from_slice(&tail_segments[..tail_len]);
+        }
+        None => {
+            if parse_ipv6_groups(s, &mut segments, true)? != 8 {
+                return None;
+            }
+        }
+    }
+
+    Some(segments)
+}
+
+fn find_double_colon(s: &[u8]) -> Option<usize> {
+    s.windows(2).position(|w| w == b"::")
+}
+
+/// Parse colon-separated groups into `segments`, returning the number of
+/// segments filled. If `allow_ipv4` is set, the last group may be an IPv4
+/// address, which fills two segments.
+fn parse_ipv6_groups(s: &[u8], segments: &mut [u16; 8], allow_ipv4: bool) -> Option<usize> {
+    if s.is_empty() {
+        return Some(0);
+    }
+
+    let mut len = 0;
+    let mut groups = s.split(|b| *b == b':').peekable();
+    while let Some(group) = groups.next() {
+        if allow_ipv4 && groups.peek().is_none() && group.contains(&b'.') {
+            if len + 2 > segments.len() {
+                return None;
+            }
+            let octets = parse_ipv4_octets(group)?;
+            segments[len] = u16::from_be_bytes([octets[0], octets[1]]);
+            segments[len + 1] = u16::from_be_bytes([octets[2], octets[3]]);
+            len += 2;
+        } else {
+            if len == segments.len() {
+                return None;
+            }
+            segments[len] = parse_hex_group(group)?;
+            len += 1;
+        }
+    }
+    Some(len)
+}
+
+fn parse_hex_group(s: &[u8]) -> Option<u16> {
+    if s.is_empty() || s.len() > 4 {
+        return None;
+    }
+    let mut value = 0_u16;
+    for b in s {
+        let digit = match b {
+            b'0'..=b'9' => b - b'0',
+            b'a'..=b'f' => b - b'a' + 10,
+            b'A'..=b'F' => b - b'A' + 10,
+            _ => return None,
+        };
+        value = (value << 4) | u16::from(digit);
+    }
+    Some(value)
+}
+
+fn write_ipv4(s: &mut IpAddrString, octets: [u8; 4]) {
+    use fmt::Write;
+
+    // `IpAddrString` has room for any address.
+    write!(s, "{}.{}.{}.{}", octets[0], octets[1], octets[2], octets[3]).unwrap();
+}
+
+fn write_ipv6(s: &mut IpAddrString, segments: [u16; 8]) {
+    use fmt::Write;
+
+    // IPv4-mapped addresses, `::ffff:a.b.c.d`.
+    if segments[..5] == [0; 5] && segments[5] == 0xffff {
+        s.write_str("::ffff:").unwrap();
+        let [a, b] = segments[6].to_be_bytes();
+        let [c, d] = segments[7].to_be_bytes();
+        write_ipv4(s, [a, b, c, d]);
+        return;
+    }
+
+    // Find the first longest run of at least two zero groups.
+    let mut best = (0, 0);
+    let mut run = (0, 0);
+    for (i, segment) in segments.iter().enumerate() {
+        if *segment == 0 {
+            if run.1 == 0 {
+                run.0 = i;
+            }
+            run.1 += 1;
+            if run.1 > best.1 {
+                best = run;
+            }
+        } else {
+            run.1 = 0;
+        }
+    }
+    if best.1 < 2 {
+        best = (segments.len(), 0);
+    }
+
+    let mut i = 0;
+    while i < segments.len() {
+        if i == best.0 {
+            s.write_str("::").unwrap();
+            i += best.1;
+            continue;
+        }
+        if i != 0 && i != best.0 + best.1 {
+            s.write_str(":").unwrap();
+        }
+        write!(s, "{:x}", segments[i]).unwrap();
+        i += 1;
+    }
+}
@@ -9,21 +9,36 @@
 
 #[cfg(not(feature = "std"))]
 mod addr;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod cmsg;
+mod inet;
 #[cfg(not(feature = "std"))]
 mod ip;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod netlink;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod packet;
 mod send_recv;
 mod socket;
 mod socket_addr_any;
 #[cfg(not(any(windows, target_os = "wasi")))]
 mod socketpair;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod vsock;
 #[cfg(windows)]
 mod wsa;
 
 pub mod sockopt;
 
+pub use inet::{format_ip, format_ipv4, format_ipv6, parse_ipv4, parse_ipv6, IpAddrString};
 pub use send_recv::{
     recv, recvfrom, send, sendto, sendto_any, sendto_v4, sendto_v6, RecvFlags, SendFlags,
 };
+#[cfg(not(any(windows, target_os = "wasi")))]
+#[cfg(feature = "fs")]
+pub use socket::connect_timeout;
+#[cfg(not(windows))]
+pub use socket::sockatmark;
 pub use socket::{
     accept, accept_with, acceptfrom, acceptfrom_with, bind, bind_any, bind_v4, bind_v6, connect,
     connect_any, connect_v4, connect_v6, getpeername, getsockname, listen, shutdown, socket,
@@ -31,7 +46,7 @@ pub use socket::{
 };
 pub use socket_addr_any::{SocketAddrAny, SocketAddrStorage};
 #[cfg(not(any(windows, target_os = "wasi")))]
-pub use socketpair::socketpair;
+pub use socketpair::{socketpair, socketpair_with};
 #[cfg(feature = "std")]
 pub use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 #[cfg(windows)]
@@ -41,6 +56,25 @@ pub use {
     addr::{SocketAddr, SocketAddrV4, SocketAddrV6},
     ip::{IpAddr, Ipv4Addr, Ipv6Addr, Ipv6MulticastScope},
 };
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use {
+    cmsg::{
+        AncillaryFds, ControlMessage, ControlMessages, Ipv4PacketInfo, Ipv6PacketInfo,
+        RecvAncillaryMessage, RecvAncillaryMessages, SendAncillaryBuffer, SendAncillaryMessage,
+        UnixCredentials, ZerocopyCompletion, ZerocopyCompletions,
+    },
+    netlink::{NetlinkFamily, SocketAddrNetlink},
+    packet::SocketAddrLink,
+    send_recv::{
+        recv_peek_len, recv_zerocopy_completions, recvmsg, sendmsg, sendmsg_addr, sendto_link,
+        sendto_netlink, RecvMsgReturn,
+    },
+    socket::{bind_link, bind_netlink, bind_vsock, connect_netlink, connect_vsock},
+    vsock::{
+        SocketAddrVsock, VMADDR_CID_ANY, VMADDR_CID_HOST, VMADDR_CID_HYPERVISOR, VMADDR_CID_LOCAL,
+        VMADDR_PORT_ANY,
+    },
+};
 #[cfg(unix)]
 pub use {
     send_recv::sendto_unix,
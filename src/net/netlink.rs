@@ -0,0 +1,55 @@
+//! Netlink socket addresses, for use with `AF_NETLINK` sockets.
+
+use crate::backend;
+
+pub use backend::net::types::NetlinkFamily;
+
+/// `struct sockaddr_nl`—A netlink socket address.
+///
+/// This is used with [`AddressFamily::NETLINK`] sockets. A pid of 0
+/// addresses the kernel, and `groups` is a bitmask of multicast groups.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+/// [`AddressFamily::NETLINK`]: crate::net::AddressFamily::NETLINK
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc(alias = "sockaddr_nl")]
+pub struct SocketAddrNetlink {
+    pid: u32,
+    groups: u32,
+}
+
+impl SocketAddrNetlink {
+    /// Construct a new netlink address with the given port id and multicast
+    /// group mask.
+    #[inline]
+    pub const fn new(pid: u32, groups: u32) -> Self {
+        Self { pid, groups }
+    }
+
+    /// Return the `nl_pid` field.
+    #[inline]
+    pub const fn pid(&self) -> u32 {
+        self.pid
+    }
+
+    /// Set the `nl_pid` field.
+    #[inline]
+    pub fn set_pid(&mut self, pid: u32) {
+        self.pid = pid;
+    }
+
+    /// Return the `nl_groups` field.
+    #[inline]
+    pub const fn groups(&self) -> u32 {
+        self.groups
+    }
+
+    /// Set the `nl_groups` field.
+    #[inline]
+    pub fn set_groups(&mut self, groups: u32) {
+        self.groups = groups;
+    }
+}
@@ -0,0 +1,112 @@
+//! Link-layer socket addresses, for use with `AF_PACKET` sockets.
+
+use crate::io;
+
+/// `struct sockaddr_ll`—A link-layer socket address.
+///
+/// This is used with [`AddressFamily::PACKET`] sockets. The protocol is an
+/// `ETH_P_*` value in host byte order; it is converted to network byte order
+/// when encoded.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+/// [`AddressFamily::PACKET`]: crate::net::AddressFamily::PACKET
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc(alias = "sockaddr_ll")]
+pub struct SocketAddrLink {
+    protocol: u16,
+    ifindex: u32,
+    hatype: u16,
+    pkttype: u8,
+    addr_len: u8,
+    addr: [u8; 8],
+}
+
+impl SocketAddrLink {
+    /// Construct a new link-layer address for the `ETH_P_*` protocol
+    /// `protocol` on the interface with index `ifindex`.
+    ///
+    /// An `ifindex` of 0 matches any interface when binding.
+    #[inline]
+    pub const fn new(protocol: u16, ifindex: u32) -> Self {
+        Self {
+            protocol,
+            ifindex,
+            hatype: 0,
+            pkttype: 0,
+            addr_len: 0,
+            addr: [0; 8],
+        }
+    }
+
+    /// Return the `sll_protocol` field, in host byte order.
+    #[inline]
+    pub const fn protocol(&self) -> u16 {
+        self.protocol
+    }
+
+    /// Set the `sll_protocol` field, in host byte order.
+    #[inline]
+    pub fn set_protocol(&mut self, protocol: u16) {
+        self.protocol = protocol;
+    }
+
+    /// Return the `sll_ifindex` field.
+    #[inline]
+    pub const fn ifindex(&self) -> u32 {
+        self.ifindex
+    }
+
+    /// Set the `sll_ifindex` field.
+    #[inline]
+    pub fn set_ifindex(&mut self, ifindex: u32) {
+        self.ifindex = ifindex;
+    }
+
+    /// Return the `sll_hatype` field, an `ARPHRD_*` value.
+    #[inline]
+    pub const fn hatype(&self) -> u16 {
+        self.hatype
+    }
+
+    /// Set the `sll_hatype` field.
+    #[inline]
+    pub fn set_hatype(&mut self, hatype: u16) {
+        self.hatype = hatype;
+    }
+
+    /// Return the `sll_pkttype` field, a `PACKET_*` value.
+    #[inline]
+    pub const fn pkttype(&self) -> u8 {
+        self.pkttype
+    }
+
+    /// Set the `sll_pkttype` field.
+    #[inline]
+    pub fn set_pkttype(&mut self, pkttype: u8) {
+        self.pkttype = pkttype;
+    }
+
+    /// Return the hardware address, `sll_addr[..sll_halen]`.
+    #[inline]
+    pub fn addr(&self) -> &[u8] {
+        &self.addr[..usize::from(self.addr_len)]
+    }
+
+    /// Set the hardware address.
+    ///
+    /// This fails with [`io::Errno::INVAL`] if `addr` is longer than the
+    /// 8 bytes that `sll_addr` can hold.
+    #[inline]
+    pub fn set_addr(&mut self, addr: &[u8]) -> io::Result<()> {
+        if addr.len() > self.addr.len() {
+            return Err(io::Errno::INVAL);
+        }
+        self.addr = [0; 8];
+        self.addr[..addr.len()].copy_from_slice(addr);
+        self.addr_len = addr.len() as u8;
+        Ok(())
+    }
+}
@@ -3,7 +3,14 @@
 #[cfg(unix)]
 use crate::net::SocketAddrUnix;
 use crate::net::{SocketAddr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink};
 use crate::{backend, io};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::{
+    io::{IoSlice, IoSliceMut},
+    net::ZerocopyCompletions,
+};
 use backend::fd::{AsFd, BorrowedFd};
 
 pub use backend::net::send_recv::{RecvFlags, SendFlags};
@@ -25,6 +32,25 @@ pub fn recv<Fd: AsFd>(fd: Fd, buf: &mut [u8], flags: RecvFlags) -> io::Result<us
     backend::net::syscalls::recv(fd.as_fd(), buf, flags)
 }
 
+/// `recv(fd, &mut [], MSG_PEEK | MSG_TRUNC)`—Returns the length of the next
+/// datagram on a socket, without receiving it.
+///
+/// This can be used to size a buffer before receiving a datagram. The
+/// length is the datagram's full length, even though the buffer passed is
+/// empty. This blocks until a datagram is available, unless the socket is
+/// in nonblocking mode.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/udp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "MSG_TRUNC")]
+pub fn recv_peek_len<Fd: AsFd>(fd: Fd) -> io::Result<usize> {
+    recv(fd, &mut [], RecvFlags::PEEK | RecvFlags::TRUNC)
+}
+
 /// `send(fd, buf, flags)`—Writes data to a socket.
 ///
 /// # References
@@ -131,6 +157,14 @@ fn _sendto_any(
         SocketAddrAny::V6(v6) => backend::net::syscalls::sendto_v6(fd, buf, flags, v6),
         #[cfg(unix)]
         SocketAddrAny::Unix(unix) => backend::net::syscalls::sendto_unix(fd, buf, flags, unix),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Link(link) => backend::net::syscalls::sendto_link(fd, buf, flags, link),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Netlink(netlink) => {
+            backend::net::syscalls::sendto_netlink(fd, buf, flags, netlink)
+        }
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Vsock(vsock) => backend::net::syscalls::sendto_vsock(fd, buf, flags, vsock),
     }
 }
 
@@ -207,4 +241,168 @@ pub fn sendto_unix<Fd: AsFd>(
     backend::net::syscalls::sendto_unix(fd.as_fd(), buf, flags, addr)
 }
 
-// TODO: `recvmsg`, `sendmsg`
+/// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_ll))`—Writes data
+/// to a packet socket to a specific link-layer address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "sendto")]
+pub fn sendto_link<Fd: AsFd>(
+    fd: Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrLink,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendto_link(fd.as_fd(), buf, flags, addr)
+}
+
+/// `sendto(fd, buf, flags, addr, sizeof(struct sockaddr_nl))`—Writes data
+/// to a netlink socket to a specific netlink address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "sendto")]
+pub fn sendto_netlink<Fd: AsFd>(
+    fd: Fd,
+    buf: &[u8],
+    flags: SendFlags,
+    addr: &SocketAddrNetlink,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendto_netlink(fd.as_fd(), buf, flags, addr)
+}
+
+/// `recvmsg(fd, msg, flags)`—Reads data and control messages from a
+/// socket.
+///
+/// The data is scattered into `bufs`, and control messages are written into
+/// `control`. Use [`ControlMessages`] or [`RecvAncillaryMessages`] to iterate
+/// over the control messages that were received. If `control` is too small to hold them all,
+/// [`RecvFlags::CTRUNC`] is set in the returned flags. The address the
+/// message was sent from, if any, is returned in
+/// [`RecvMsgReturn::address`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/recvmsg.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/recvmsg.2.html
+/// [`ControlMessages`]: crate::net::ControlMessages
+/// [`RecvAncillaryMessages`]: crate::net::RecvAncillaryMessages
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub fn recvmsg<Fd: AsFd>(
+    fd: Fd,
+    bufs: &mut [IoSliceMut<'_>],
+    control: &mut [u8],
+    flags: RecvFlags,
+) -> io::Result<RecvMsgReturn> {
+    backend::net::syscalls::recvmsg(fd.as_fd(), bufs, control, flags)
+}
+
+/// The result of a successful [`recvmsg`] call.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg_attr(feature = "std", derive(Debug))]
+#[derive(Clone, Eq, PartialEq)]
+pub struct RecvMsgReturn {
+    /// The number of bytes of data received.
+    pub bytes: usize,
+
+    /// The number of bytes of control messages written to the control
+    /// buffer.
+    pub control_len: usize,
+
+    /// Flags describing the received message, such as [`RecvFlags::TRUNC`]
+    /// and [`RecvFlags::CTRUNC`].
+    pub flags: RecvFlags,
+
+    /// The address the message was sent from, or `None` if the OS didn't
+    /// report one, as with connected TCP sockets.
+    pub address: Option<SocketAddrAny>,
+}
+
+/// `recvmsg(fd, msg, MSG_ERRQUEUE | MSG_DONTWAIT)`—Reads `MSG_ZEROCOPY`
+/// completion notifications from a socket's error queue.
+///
+/// This reads one message from the error queue, and returns an iterator
+/// over the [`ZerocopyCompletion`]s in it. If the error queue is empty, this
+/// fails with [`io::Errno::AGAIN`]; completions can be waited for by polling
+/// for `POLLERR`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://www.kernel.org/doc/html/latest/networking/msg_zerocopy.html
+/// [`ZerocopyCompletion`]: crate::net::ZerocopyCompletion
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub fn recv_zerocopy_completions<Fd: AsFd>(fd: Fd) -> io::Result<ZerocopyCompletions> {
+    let mut completions = ZerocopyCompletions::new();
+    let ret = backend::net::syscalls::recvmsg(
+        fd.as_fd(),
+        &mut [],
+        &mut completions.control,
+        RecvFlags::ERRQUEUE | RecvFlags::DONTWAIT,
+    )?;
+    completions.len = ret.control_len;
+    Ok(completions)
+}
+
+/// `sendmsg(fd, msg, flags)`—Writes data and control messages to a socket.
+///
+/// The data is gathered from `bufs`, and `control` holds the control
+/// messages to send with it, which can be encoded with a
+/// [`SendAncillaryBuffer`].
+///
+/// This sends to the socket's peer, so the socket must be connected. To send
+/// to a specific address, use [`sendmsg_addr`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sendmsg.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+/// [`SendAncillaryBuffer`]: crate::net::SendAncillaryBuffer
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+pub fn sendmsg<Fd: AsFd>(
+    fd: Fd,
+    bufs: &[IoSlice<'_>],
+    control: &[u8],
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmsg(fd.as_fd(), None, bufs, control, flags)
+}
+
+/// `sendmsg(fd, msg, flags)`—Writes data and control messages to a socket,
+/// to a specific address.
+///
+/// This is like [`sendmsg`], but sets the message's destination address to
+/// `addr`, for sockets which aren't connected.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sendmsg.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sendmsg.2.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "sendmsg")]
+pub fn sendmsg_addr<Fd: AsFd>(
+    fd: Fd,
+    addr: &SocketAddrAny,
+    bufs: &[IoSlice<'_>],
+    control: &[u8],
+    flags: SendFlags,
+) -> io::Result<usize> {
+    backend::net::syscalls::sendmsg(fd.as_fd(), Some(addr), bufs, control, flags)
+}
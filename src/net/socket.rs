@@ -1,7 +1,12 @@
 use crate::fd::OwnedFd;
 use crate::net::{SocketAddr, SocketAddrAny, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink, SocketAddrVsock};
 use crate::{backend, io};
 use backend::fd::{AsFd, BorrowedFd};
+#[cfg(not(any(windows, target_os = "wasi")))]
+#[cfg(feature = "fs")]
+use {crate::fs::OFlags, core::time::Duration};
 
 #[cfg(unix)]
 pub use backend::net::addr::SocketAddrUnix;
@@ -115,6 +120,12 @@ fn _bind_any(sockfd: BorrowedFd<'_>, addr: &SocketAddrAny) -> io::Result<()> {
         SocketAddrAny::V6(v6) => backend::net::syscalls::bind_v6(sockfd, v6),
         #[cfg(unix)]
         SocketAddrAny::Unix(unix) => backend::net::syscalls::bind_unix(sockfd, unix),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Link(link) => backend::net::syscalls::bind_link(sockfd, link),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Netlink(netlink) => backend::net::syscalls::bind_netlink(sockfd, netlink),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Vsock(vsock) => backend::net::syscalls::bind_vsock(sockfd, vsock),
     }
 }
 
@@ -176,6 +187,54 @@ pub fn bind_unix<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrUnix) -> io::Result<()>
     backend::net::syscalls::bind_unix(sockfd.as_fd(), addr)
 }
 
+/// `bind(sockfd, addr, sizeof(struct sockaddr_ll))`—Binds a socket to a
+/// link-layer address.
+///
+/// This is used with [`AddressFamily::PACKET`] sockets, to restrict the
+/// socket to one interface and protocol.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/packet.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_link<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrLink) -> io::Result<()> {
+    backend::net::syscalls::bind_link(sockfd.as_fd(), addr)
+}
+
+/// `bind(sockfd, addr, sizeof(struct sockaddr_nl))`—Binds a socket to a
+/// netlink address.
+///
+/// Binding with a pid of 0 lets the kernel assign a unique pid, which can be
+/// read back with [`getsockname`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_netlink<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrNetlink) -> io::Result<()> {
+    backend::net::syscalls::bind_netlink(sockfd.as_fd(), addr)
+}
+
+/// `bind(sockfd, addr, sizeof(struct sockaddr_vm))`—Binds a socket to a
+/// VM socket address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/vsock.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "bind")]
+pub fn bind_vsock<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrVsock) -> io::Result<()> {
+    backend::net::syscalls::bind_vsock(sockfd.as_fd(), addr)
+}
+
 /// `connect(sockfd, addr)`—Initiates a connection to an IP address.
 ///
 /// # References
@@ -222,7 +281,86 @@ fn _connect_any(sockfd: BorrowedFd<'_>, addr: &SocketAddrAny) -> io::Result<()>
         SocketAddrAny::V6(v6) => backend::net::syscalls::connect_v6(sockfd, v6),
         #[cfg(unix)]
         SocketAddrAny::Unix(unix) => backend::net::syscalls::connect_unix(sockfd, unix),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Link(link) => backend::net::syscalls::connect_link(sockfd, link),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Netlink(netlink) => backend::net::syscalls::connect_netlink(sockfd, netlink),
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        SocketAddrAny::Vsock(vsock) => backend::net::syscalls::connect_vsock(sockfd, vsock),
+    }
+}
+
+/// `connect(sockfd, addr)` with a timeout—Initiates a connection, waiting
+/// at most `timeout` for it to complete.
+///
+/// This temporarily puts `sockfd` in nonblocking mode, initiates the
+/// connection, and waits for it with [`poll`]. If the connection isn't
+/// established within `timeout`, which is rounded up to whole milliseconds,
+/// this fails with [`io::Errno::TIMEDOUT`]. If the connection fails, this
+/// fails with the socket's pending error, as reported by `SO_ERROR`.
+///
+/// The socket's original status flags are restored before returning. If the
+/// wait is interrupted by a signal, this fails with [`io::Errno::INTR`] and
+/// the connection may still be in progress.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///  - [Apple]
+///
+/// [`poll`]: crate::io::poll
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/connect.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/connect.2.html
+/// [Apple]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man2/connect.2.html
+#[cfg(not(any(windows, target_os = "wasi")))]
+#[cfg(feature = "fs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fs")))]
+pub fn connect_timeout<Fd: AsFd>(
+    sockfd: Fd,
+    addr: &SocketAddrAny,
+    timeout: Duration,
+) -> io::Result<()> {
+    let sockfd = sockfd.as_fd();
+    let flags = crate::fs::fcntl_getfl(sockfd)?;
+    if !flags.contains(OFlags::NONBLOCK) {
+        crate::fs::fcntl_setfl(sockfd, flags | OFlags::NONBLOCK)?;
+    }
+
+    let result = _connect_timeout(sockfd, addr, timeout);
+
+    if !flags.contains(OFlags::NONBLOCK) {
+        // Report the connection's result in preference to a failure to
+        // restore the flags.
+        return result.and(crate::fs::fcntl_setfl(sockfd, flags));
     }
+    result
+}
+
+#[cfg(not(any(windows, target_os = "wasi")))]
+#[cfg(feature = "fs")]
+fn _connect_timeout(
+    sockfd: BorrowedFd<'_>,
+    addr: &SocketAddrAny,
+    timeout: Duration,
+) -> io::Result<()> {
+    match _connect_any(sockfd, addr) {
+        Ok(()) => return Ok(()),
+        Err(io::Errno::INPROGRESS) => (),
+        Err(err) => return Err(err),
+    }
+
+    let millis = timeout.as_nanos().saturating_add(999_999) / 1_000_000;
+    let millis = if millis > i32::MAX as u128 {
+        i32::MAX
+    } else {
+        millis as i32
+    };
+    let mut fds = [io::PollFd::from_borrowed_fd(sockfd, io::PollFlags::OUT)];
+    if io::poll(&mut fds, millis)? == 0 {
+        return Err(io::Errno::TIMEDOUT);
+    }
+
+    backend::net::syscalls::sockopt::get_socket_error(sockfd)?
 }
 
 /// `connect(sockfd, addr, sizeof(struct sockaddr_in))`—Initiates a
@@ -283,6 +421,34 @@ pub fn connect_unix<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrUnix) -> io::Result<(
     backend::net::syscalls::connect_unix(sockfd.as_fd(), addr)
 }
 
+/// `connect(sockfd, addr, sizeof(struct sockaddr_nl))`—Sets the default
+/// destination of a netlink socket.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/netlink.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "connect")]
+pub fn connect_netlink<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrNetlink) -> io::Result<()> {
+    backend::net::syscalls::connect_netlink(sockfd.as_fd(), addr)
+}
+
+/// `connect(sockfd, addr, sizeof(struct sockaddr_vm))`—Initiates a
+/// connection to a VM socket address.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/vsock.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "connect")]
+pub fn connect_vsock<Fd: AsFd>(sockfd: Fd, addr: &SocketAddrVsock) -> io::Result<()> {
+    backend::net::syscalls::connect_vsock(sockfd.as_fd(), addr)
+}
+
 /// `listen(fd, backlog)`—Enables listening for incoming connections.
 ///
 /// # References
@@ -390,6 +556,10 @@ pub fn acceptfrom_with<Fd: AsFd>(
 
 /// `shutdown(fd, how)`—Closes the read and/or write sides of a stream.
 ///
+/// After [`Shutdown::Write`], the peer reads end-of-file, and further writes
+/// on this socket fail with [`io::Errno::PIPE`]. Shutting down a socket that
+/// isn't connected fails with [`io::Errno::NOTCONN`].
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -405,6 +575,26 @@ pub fn shutdown<Fd: AsFd>(sockfd: Fd, how: Shutdown) -> io::Result<()> {
     backend::net::syscalls::shutdown(sockfd.as_fd(), how)
 }
 
+/// `sockatmark(fd)`—Tests whether a socket is at the out-of-band mark.
+///
+/// This returns `true` when the next byte to be read is the urgent byte
+/// sent with [`SendFlags::OOB`], so that reads which stop at the mark can
+/// tell where the out-of-band data was.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sockatmark.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/sockatmark.3.html
+/// [`SendFlags::OOB`]: crate::net::SendFlags::OOB
+#[cfg(not(windows))]
+#[inline]
+#[doc(alias = "SIOCATMARK")]
+pub fn sockatmark<Fd: AsFd>(sockfd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockatmark(sockfd.as_fd())
+}
+
 /// `getsockname(fd, addr, len)`—Returns the address a socket is bound to.
 ///
 /// # References
@@ -12,6 +12,8 @@
 #[cfg(unix)]
 use crate::net::SocketAddrUnix;
 use crate::net::{AddressFamily, SocketAddrV4, SocketAddrV6};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::net::{SocketAddrLink, SocketAddrNetlink, SocketAddrVsock};
 use crate::{backend, io};
 #[cfg(feature = "std")]
 use core::fmt;
@@ -30,6 +32,15 @@ pub enum SocketAddrAny {
     /// `struct sockaddr_un`
     #[cfg(unix)]
     Unix(SocketAddrUnix),
+    /// `struct sockaddr_ll`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Link(SocketAddrLink),
+    /// `struct sockaddr_nl`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Netlink(SocketAddrNetlink),
+    /// `struct sockaddr_vm`
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    Vsock(SocketAddrVsock),
 }
 
 impl From<SocketAddrV4> for SocketAddrAny {
@@ -54,6 +65,30 @@ impl From<SocketAddrUnix> for SocketAddrAny {
     }
 }
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl From<SocketAddrLink> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrLink) -> Self {
+        Self::Link(from)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl From<SocketAddrNetlink> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrNetlink) -> Self {
+        Self::Netlink(from)
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+impl From<SocketAddrVsock> for SocketAddrAny {
+    #[inline]
+    fn from(from: SocketAddrVsock) -> Self {
+        Self::Vsock(from)
+    }
+}
+
 impl SocketAddrAny {
     /// Return the address family of this socket address.
     #[inline]
@@ -63,6 +98,12 @@ impl SocketAddrAny {
             Self::V6(_) => AddressFamily::INET6,
             #[cfg(unix)]
             Self::Unix(_) => AddressFamily::UNIX,
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Link(_) => AddressFamily::PACKET,
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Netlink(_) => AddressFamily::NETLINK,
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Vsock(_) => AddressFamily::VSOCK,
         }
     }
 
@@ -98,6 +139,12 @@ impl fmt::Debug for SocketAddrAny {
             Self::V6(v6) => v6.fmt(fmt),
             #[cfg(unix)]
             Self::Unix(unix) => unix.fmt(fmt),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Link(link) => link.fmt(fmt),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Netlink(netlink) => netlink.fmt(fmt),
+            #[cfg(any(target_os = "android", target_os = "linux"))]
+            Self::Vsock(vsock) => vsock.fmt(fmt),
         }
     }
 }
@@ -2,7 +2,8 @@ use crate::fd::OwnedFd;
 use crate::net::{AddressFamily, Protocol, SocketFlags, SocketType};
 use crate::{backend, io};
 
-/// `socketpair(domain, type_ | accept_flags, protocol)`
+/// `socketpair(domain, type_, protocol)`—Creates a pair of connected
+/// sockets.
 ///
 /// # References
 ///  - [POSIX]
@@ -12,6 +13,28 @@ use crate::{backend, io};
 /// [Linux]: https://man7.org/linux/man-pages/man2/socketpair.2.html
 #[inline]
 pub fn socketpair(
+    domain: AddressFamily,
+    type_: SocketType,
+    protocol: Protocol,
+) -> io::Result<(OwnedFd, OwnedFd)> {
+    backend::net::syscalls::socketpair(domain, type_, SocketFlags::empty(), protocol)
+}
+
+/// `socketpair(domain, type_ | flags, protocol)`—Creates a pair of connected
+/// sockets, with flags.
+///
+/// `socketpair_with` is the same as [`socketpair`] but adds an additional
+/// flags operand, which sets the flags on both sockets atomically.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/socketpair.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/socketpair.2.html
+#[inline]
+#[doc(alias = "socketpair")]
+pub fn socketpair_with(
     domain: AddressFamily,
     type_: SocketType,
     flags: SocketFlags,
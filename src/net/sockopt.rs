@@ -1,17 +1,24 @@
 //! `getsockopt` and `setsockopt` functions.
 //!
 //! In the rustix API, there is a separate function for each option, so that
-//! it can be given an option-specific type signature.
+//! it can be given an option-specific type signature. Options without a
+//! function of their own can be used with [`get_raw`] and [`set_raw`].
 
 #![doc(alias = "getsockopt")]
 #![doc(alias = "setsockopt")]
+#![allow(unsafe_code)]
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::ffi::{CStr, CString};
 use crate::net::{Ipv4Addr, Ipv6Addr, SocketType};
 use crate::{backend, io};
 use backend::fd::AsFd;
+use core::mem::{size_of, MaybeUninit};
 use core::time::Duration;
 
 pub use backend::net::types::Timeout;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use backend::net::types::{IpMtuDiscover, TimestampingFlags};
 
 /// `getsockopt(fd, SOL_SOCKET, SO_TYPE)`—Returns the type of a socket.
 ///
@@ -35,6 +42,35 @@ pub fn get_socket_type<Fd: AsFd>(fd: Fd) -> io::Result<SocketType> {
     backend::net::syscalls::sockopt::get_socket_type(fd.as_fd())
 }
 
+/// `getsockopt(fd, SOL_SOCKET, SO_ERROR)`—Returns and clears the pending
+/// error of a socket.
+///
+/// The outer `Result` reports whether `getsockopt` itself failed. The inner
+/// `Result` is `Err` if the socket had a pending error, such as the result
+/// of a nonblocking `connect` which failed. Reading the error clears it, so
+/// a second call returns `Ok(Ok(()))`.
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `getsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_ERROR")]
+#[doc(alias = "take_error")]
+pub fn get_socket_error<Fd: AsFd>(fd: Fd) -> io::Result<Result<(), io::Errno>> {
+    backend::net::syscalls::sockopt::get_socket_error(fd.as_fd())
+}
+
 /// `setsockopt(fd, SOL_SOCKET, SO_REUSEADDR, value)`
 ///
 /// # References
@@ -57,6 +93,179 @@ pub fn set_socket_reuseaddr<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
     backend::net::syscalls::sockopt::set_socket_reuseaddr(fd.as_fd(), value)
 }
 
+/// `setsockopt(fd, SOL_SOCKET, SO_REUSEPORT, value)`
+///
+/// This allows multiple sockets to bind to the same address and port, with
+/// incoming connections or datagrams distributed among them. It must be set
+/// on each socket before it's bound with `bind`.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///  - [FreeBSD `setsockopt`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [FreeBSD `setsockopt`]: https://man.freebsd.org/cgi/man.cgi?query=setsockopt&sektion=2
+#[cfg(not(any(windows, target_os = "illumos", target_os = "solaris")))]
+#[inline]
+#[doc(alias = "SO_REUSEPORT")]
+pub fn set_socket_reuseport<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_reuseport(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_REUSEPORT)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [FreeBSD `getsockopt`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [FreeBSD `getsockopt`]: https://man.freebsd.org/cgi/man.cgi?query=getsockopt&sektion=2
+#[cfg(not(any(windows, target_os = "illumos", target_os = "solaris")))]
+#[inline]
+#[doc(alias = "SO_REUSEPORT")]
+pub fn get_socket_reuseport<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_socket_reuseport(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_ATTACH_REUSEPORT_EBPF, prog_fd)`
+///
+/// This attaches a BPF program of type `BPF_PROG_TYPE_SOCKET_FILTER` or
+/// `BPF_PROG_TYPE_SK_REUSEPORT` to select which socket in the
+/// [`SO_REUSEPORT`] group receives each incoming connection or datagram.
+///
+/// # References
+///  - [Linux `socket`]
+///
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [`SO_REUSEPORT`]: set_socket_reuseport
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_ATTACH_REUSEPORT_EBPF")]
+pub fn set_socket_attach_reuseport_ebpf<Fd: AsFd, ProgFd: AsFd>(
+    fd: Fd,
+    prog_fd: ProgFd,
+) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_attach_reuseport_ebpf(fd.as_fd(), prog_fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_BINDTODEVICE, interface)`
+///
+/// This restricts the socket to sending and receiving packets on the given
+/// network interface. `None` removes the binding.
+///
+/// Setting this requires the `CAP_NET_RAW` capability; without it, this
+/// fails with [`io::Errno::PERM`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_BINDTODEVICE")]
+pub fn set_socket_bindtodevice<Fd: AsFd>(fd: Fd, interface: Option<&CStr>) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_bindtodevice(fd.as_fd(), interface)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_BINDTODEVICE)`
+///
+/// This returns the name of the network interface the socket is bound to, or
+/// an empty string if it isn't bound to one. `buf` is used to receive the
+/// name, and should be at least `IFNAMSIZ` (16) bytes long.
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_BINDTODEVICE")]
+pub fn get_socket_bindtodevice<Fd: AsFd>(fd: Fd, buf: &mut [u8]) -> io::Result<CString> {
+    let len = backend::net::syscalls::sockopt::get_socket_bindtodevice(fd.as_fd(), buf)?;
+
+    // The name may or may not include a NUL terminator.
+    let name = &buf[..len];
+    let name = match name.iter().position(|b| *b == b'\0') {
+        Some(nul) => &name[..nul],
+        None => name,
+    };
+    Ok(CString::new(name).unwrap())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_ZEROCOPY, value)`
+///
+/// This must be enabled before sending with [`SendFlags::ZEROCOPY`].
+/// Completion notifications are then read with
+/// [`recv_zerocopy_completions`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `MSG_ZEROCOPY`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `MSG_ZEROCOPY`]: https://www.kernel.org/doc/html/latest/networking/msg_zerocopy.html
+/// [`SendFlags::ZEROCOPY`]: crate::net::SendFlags::ZEROCOPY
+/// [`recv_zerocopy_completions`]: crate::net::recv_zerocopy_completions
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_ZEROCOPY")]
+pub fn set_socket_zerocopy<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_zerocopy(fd.as_fd(), value)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_TIMESTAMPNS, value)`
+///
+/// When this is enabled, each datagram received with
+/// [`recvmsg`](crate::net::recvmsg) is accompanied by a `SCM_TIMESTAMPNS`
+/// control message holding the time it was received, which can be decoded
+/// with [`ControlMessage::timestamp_ns`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [`ControlMessage::timestamp_ns`]: crate::net::ControlMessage::timestamp_ns
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPNS")]
+pub fn set_socket_timestamp_ns<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_timestamp_ns(fd.as_fd(), value)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_TIMESTAMPING, flags)`
+///
+/// The flags select which timestamps are generated, and which are reported
+/// in `SCM_TIMESTAMPING` control messages, which can be decoded with
+/// [`ControlMessage::timestamping`]. Transmit timestamps are reported on the
+/// socket's error queue, with [`RecvFlags::ERRQUEUE`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///  - [Linux timestamping]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Linux timestamping]: https://www.kernel.org/doc/html/latest/networking/timestamping.html
+/// [`ControlMessage::timestamping`]: crate::net::ControlMessage::timestamping
+/// [`RecvFlags::ERRQUEUE`]: crate::net::RecvFlags::ERRQUEUE
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_TIMESTAMPING")]
+pub fn set_socket_timestamping<Fd: AsFd>(fd: Fd, flags: TimestampingFlags) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_timestamping(fd.as_fd(), flags)
+}
+
 /// `setsockopt(fd, SOL_SOCKET, SO_BROADCAST, broadcast)`
 ///
 /// # References
@@ -103,6 +312,15 @@ pub fn get_socket_broadcast<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
 
 /// `setsockopt(fd, SOL_SOCKET, SO_LINGER, linger)`
 ///
+/// This controls what happens to unsent data when the socket is closed.
+/// With `None`, `close` returns immediately and the data is sent in the
+/// background. With `Some(timeout)`, `close` blocks until the data is sent
+/// or `timeout` elapses. `timeout` is rounded up to whole seconds.
+///
+/// `Some(Duration::from_secs(0))` causes an abortive close: unsent data is
+/// discarded, and a TCP connection is reset with an RST instead of being
+/// shut down gracefully.
+///
 /// # References
 ///  - [POSIX `setsockopt`]
 ///  - [POSIX `sys/socket.h`]
@@ -125,6 +343,8 @@ pub fn set_socket_linger<Fd: AsFd>(fd: Fd, linger: Option<Duration>) -> io::Resu
 
 /// `getsockopt(fd, SOL_SOCKET, SO_LINGER)`
 ///
+/// See [`set_socket_linger`].
+///
 /// # References
 ///  - [POSIX `getsockopt`]
 ///  - [POSIX `sys/socket.h`]
@@ -145,6 +365,148 @@ pub fn get_socket_linger<Fd: AsFd>(fd: Fd) -> io::Result<Option<Duration>> {
     backend::net::syscalls::sockopt::get_socket_linger(fd.as_fd())
 }
 
+/// `setsockopt(fd, SOL_SOCKET, SO_RCVBUF, size)`
+///
+/// On Linux, the kernel doubles `size`, to allow for its bookkeeping
+/// overhead, and [`get_socket_recv_buffer_size`] returns the doubled value.
+/// `size` is also clamped to `/proc/sys/net/core/rmem_max`; use
+/// [`set_socket_recv_buffer_size_force`] to exceed it.
+///
+/// # References
+///  - [POSIX `setsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `setsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_RCVBUF")]
+pub fn set_socket_recv_buffer_size<Fd: AsFd>(fd: Fd, size: usize) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_recv_buffer_size(fd.as_fd(), size)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_RCVBUFFORCE, size)`
+///
+/// This is like [`set_socket_recv_buffer_size`], but isn't limited by
+/// `/proc/sys/net/core/rmem_max`. It requires the `CAP_NET_ADMIN` capability;
+/// without it, this fails with [`io::Errno::PERM`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_RCVBUFFORCE")]
+pub fn set_socket_recv_buffer_size_force<Fd: AsFd>(fd: Fd, size: usize) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_recv_buffer_size_force(fd.as_fd(), size)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_RCVBUF)`
+///
+/// See [`set_socket_recv_buffer_size`] about how this relates to the size
+/// which was set.
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `getsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_RCVBUF")]
+pub fn get_socket_recv_buffer_size<Fd: AsFd>(fd: Fd) -> io::Result<usize> {
+    backend::net::syscalls::sockopt::get_socket_recv_buffer_size(fd.as_fd())
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_SNDBUF, size)`
+///
+/// On Linux, the kernel doubles `size`, to allow for its bookkeeping
+/// overhead, and [`get_socket_send_buffer_size`] returns the doubled value.
+/// `size` is also clamped to `/proc/sys/net/core/wmem_max`; use
+/// [`set_socket_send_buffer_size_force`] to exceed it.
+///
+/// # References
+///  - [POSIX `setsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `setsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_SNDBUF")]
+pub fn set_socket_send_buffer_size<Fd: AsFd>(fd: Fd, size: usize) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_send_buffer_size(fd.as_fd(), size)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_SNDBUFFORCE, size)`
+///
+/// This is like [`set_socket_send_buffer_size`], but isn't limited by
+/// `/proc/sys/net/core/wmem_max`. It requires the `CAP_NET_ADMIN` capability;
+/// without it, this fails with [`io::Errno::PERM`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_SNDBUFFORCE")]
+pub fn set_socket_send_buffer_size_force<Fd: AsFd>(fd: Fd, size: usize) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_send_buffer_size_force(fd.as_fd(), size)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_SNDBUF)`
+///
+/// See [`set_socket_send_buffer_size`] about how this relates to the size
+/// which was set.
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `getsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_SNDBUF")]
+pub fn get_socket_send_buffer_size<Fd: AsFd>(fd: Fd) -> io::Result<usize> {
+    backend::net::syscalls::sockopt::get_socket_send_buffer_size(fd.as_fd())
+}
+
 /// `setsockopt(fd, SOL_SOCKET, SO_PASSCRED, passcred)`
 ///
 /// # References
@@ -175,6 +537,46 @@ pub fn get_socket_passcred<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::syscalls::sockopt::get_socket_passcred(fd.as_fd())
 }
 
+/// `setsockopt(fd, SOL_SOCKET, SO_PRIORITY, priority)`
+///
+/// This sets the priority of packets sent on the socket, which is used to
+/// select a queue in the network device's queueing discipline. Priorities
+/// outside of 0 to 6 require the `CAP_NET_ADMIN` capability; without it,
+/// this fails with [`io::Errno::PERM`].
+///
+/// Setting [`IP_TOS`] also sets the priority, to one derived from the
+/// type-of-service value.
+///
+/// [`IP_TOS`]: set_ip_tos
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_PRIORITY")]
+pub fn set_socket_priority<Fd: AsFd>(fd: Fd, priority: u32) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_priority(fd.as_fd(), priority)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_PRIORITY)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "SO_PRIORITY")]
+pub fn get_socket_priority<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::syscalls::sockopt::get_socket_priority(fd.as_fd())
+}
+
 /// `setsockopt(fd, SOL_SOCKET, id, timeout)`—Set the sending
 /// or receiving timeout.
 ///
@@ -226,23 +628,121 @@ pub fn get_socket_timeout<Fd: AsFd>(fd: Fd, id: Timeout) -> io::Result<Option<Du
     backend::net::syscalls::sockopt::get_socket_timeout(fd.as_fd(), id)
 }
 
-/// `setsockopt(fd, IPPROTO_IP, IP_TTL, ttl)`
+/// `setsockopt(fd, SOL_SOCKET, SO_RCVTIMEO, timeout)`—Set the receiving timeout.
+///
+/// `None` disables the timeout. Otherwise, the timeout is rounded up to the
+/// system's granularity, so that a non-zero `Duration` never disables it.
+/// On POSIX platforms, when the timeout expires, the operation fails with
+/// [`io::Errno::AGAIN`].
 ///
 /// # References
 ///  - [POSIX `setsockopt`]
-///  - [POSIX `netinet/in.h`]
+///  - [POSIX `sys/socket.h`]
 ///  - [Linux `setsockopt`]
-///  - [Linux `ip`]
+///  - [Linux `socket`]
 ///  - [Winsock2 `setsockopt`]
-///  - [Winsock2 `IPPROTO_IP` options]
+///  - [Winsock2 `SOL_SOCKET` options]
 ///
 /// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
 /// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
-/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
 /// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
-/// [Winsock2 `IPPROTO_IP` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/ipproto-ip-socket-options
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
 #[inline]
-#[doc(alias = "IP_TTL")]
+#[doc(alias = "SO_RCVTIMEO")]
+pub fn set_socket_recv_timeout<Fd: AsFd>(fd: Fd, timeout: Option<Duration>) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_timeout(fd.as_fd(), Timeout::Recv, timeout)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_RCVTIMEO)`—Get the receiving timeout.
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `getsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_RCVTIMEO")]
+pub fn get_socket_recv_timeout<Fd: AsFd>(fd: Fd) -> io::Result<Option<Duration>> {
+    backend::net::syscalls::sockopt::get_socket_timeout(fd.as_fd(), Timeout::Recv)
+}
+
+/// `setsockopt(fd, SOL_SOCKET, SO_SNDTIMEO, timeout)`—Set the sending timeout.
+///
+/// `None` disables the timeout. Otherwise, the timeout is rounded up to the
+/// system's granularity, so that a non-zero `Duration` never disables it.
+/// On POSIX platforms, when the timeout expires, the operation fails with
+/// [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [POSIX `setsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `setsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `setsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_SNDTIMEO")]
+pub fn set_socket_send_timeout<Fd: AsFd>(fd: Fd, timeout: Option<Duration>) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_socket_timeout(fd.as_fd(), Timeout::Send, timeout)
+}
+
+/// `getsockopt(fd, SOL_SOCKET, SO_SNDTIMEO)`—Get the sending timeout.
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [POSIX `sys/socket.h`]
+///  - [Linux `getsockopt`]
+///  - [Linux `socket`]
+///  - [Winsock2 `getsockopt`]
+///  - [Winsock2 `SOL_SOCKET` options]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [POSIX `sys/socket.h`]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_socket.h.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `socket`]: https://man7.org/linux/man-pages/man7/socket.7.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+/// [Winsock2 `SOL_SOCKET` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/sol-socket-socket-options
+#[inline]
+#[doc(alias = "SO_SNDTIMEO")]
+pub fn get_socket_send_timeout<Fd: AsFd>(fd: Fd) -> io::Result<Option<Duration>> {
+    backend::net::syscalls::sockopt::get_socket_timeout(fd.as_fd(), Timeout::Send)
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_TTL, ttl)`
+///
+/// # References
+///  - [POSIX `setsockopt`]
+///  - [POSIX `netinet/in.h`]
+///  - [Linux `setsockopt`]
+///  - [Linux `ip`]
+///  - [Winsock2 `setsockopt`]
+///  - [Winsock2 `IPPROTO_IP` options]
+///
+/// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
+/// [Winsock2 `IPPROTO_IP` options]: https://docs.microsoft.com/en-us/windows/win32/winsock/ipproto-ip-socket-options
+#[inline]
+#[doc(alias = "IP_TTL")]
 pub fn set_ip_ttl<Fd: AsFd>(fd: Fd, ttl: u32) -> io::Result<()> {
     backend::net::syscalls::sockopt::set_ip_ttl(fd.as_fd(), ttl)
 }
@@ -269,6 +769,46 @@ pub fn get_ip_ttl<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
     backend::net::syscalls::sockopt::get_ip_ttl(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_IP, IP_TOS, tos)`
+///
+/// This sets the type-of-service field of outgoing IPv4 packets. The upper
+/// six bits are the DSCP (Differentiated Services Code Point), and the lower
+/// two are the ECN (Explicit Congestion Notification) field. On Linux, the
+/// ECN bits of TCP sockets are managed by the kernel, and the ones given here
+/// are ignored.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ip`]
+///  - [FreeBSD `ip`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [FreeBSD `ip`]: https://man.freebsd.org/cgi/man.cgi?query=ip&sektion=4
+#[cfg(not(windows))]
+#[inline]
+#[doc(alias = "IP_TOS")]
+pub fn set_ip_tos<Fd: AsFd>(fd: Fd, tos: u8) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ip_tos(fd.as_fd(), tos)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_TOS)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ip`]
+///  - [FreeBSD `ip`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [FreeBSD `ip`]: https://man.freebsd.org/cgi/man.cgi?query=ip&sektion=4
+#[cfg(not(windows))]
+#[inline]
+#[doc(alias = "IP_TOS")]
+pub fn get_ip_tos<Fd: AsFd>(fd: Fd) -> io::Result<u8> {
+    backend::net::syscalls::sockopt::get_ip_tos(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IPV6, IPV6_V6ONLY, only_v6)`
 ///
 /// # References
@@ -313,6 +853,64 @@ pub fn get_ipv6_v6only<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::syscalls::sockopt::get_ipv6_v6only(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_TCLASS, tclass)`
+///
+/// This sets the traffic class of outgoing IPv6 packets, which has the same
+/// layout as the IPv4 type-of-service field described in [`set_ip_tos`].
+/// Values above 255 fail with [`io::Errno::INVAL`].
+///
+/// # References
+///  - [RFC 3542]
+///  - [Linux `ipv6`]
+///
+/// [RFC 3542]: https://www.rfc-editor.org/rfc/rfc3542#section-6.5
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "illumos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+))]
+#[inline]
+#[doc(alias = "IPV6_TCLASS")]
+pub fn set_ipv6_tclass<Fd: AsFd>(fd: Fd, tclass: u32) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ipv6_tclass(fd.as_fd(), tclass)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_TCLASS)`
+///
+/// # References
+///  - [RFC 3542]
+///  - [Linux `ipv6`]
+///
+/// [RFC 3542]: https://www.rfc-editor.org/rfc/rfc3542#section-6.5
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "illumos",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+))]
+#[inline]
+#[doc(alias = "IPV6_TCLASS")]
+pub fn get_ipv6_tclass<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::syscalls::sockopt::get_ipv6_tclass(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_IP, IP_MULTICAST_LOOP, multicast_loop)`
 ///
 /// # References
@@ -599,8 +1197,158 @@ pub fn set_ipv6_drop_membership<Fd: AsFd>(
     backend::net::syscalls::sockopt::set_ipv6_drop_membership(fd.as_fd(), multiaddr, interface)
 }
 
+/// `getsockopt(fd, IPPROTO_IP, IP_MTU)`
+///
+/// Returns the current known path MTU of the socket. This is only valid on
+/// connected sockets; on an unconnected socket, it fails with
+/// [`io::Errno::NOTCONN`].
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_MTU")]
+pub fn get_ip_mtu<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::syscalls::sockopt::get_ip_mtu(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_MTU_DISCOVER, value)`
+///
+/// Sets the path MTU discovery mode of the socket.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_MTU_DISCOVER")]
+pub fn set_ip_mtu_discover<Fd: AsFd>(fd: Fd, value: IpMtuDiscover) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ip_mtu_discover(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_MTU)`
+///
+/// Returns the current known path MTU of the socket. As with [`get_ip_mtu`],
+/// this is only valid on connected sockets; on an unconnected socket, it
+/// fails with [`io::Errno::NOTCONN`].
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_MTU")]
+pub fn get_ipv6_mtu<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::syscalls::sockopt::get_ipv6_mtu(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_MTU_DISCOVER, value)`
+///
+/// Sets the path MTU discovery mode of the socket. The `IPV6_PMTUDISC_*`
+/// values are the same as the corresponding `IP_PMTUDISC_*` values.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_MTU_DISCOVER")]
+pub fn set_ipv6_mtu_discover<Fd: AsFd>(fd: Fd, value: IpMtuDiscover) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ipv6_mtu_discover(fd.as_fd(), value)
+}
+
+/// `setsockopt(fd, IPPROTO_IP, IP_PKTINFO, value)`
+///
+/// When this is enabled, each datagram received with
+/// [`recvmsg`](crate::net::recvmsg) is accompanied by an `IP_PKTINFO`
+/// control message holding the interface and local address it arrived on,
+/// which is decoded as [`RecvAncillaryMessage::Ipv4PacketInfo`]. This lets a
+/// server bound to a wildcard address reply from the address it was
+/// reached on.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+/// [`RecvAncillaryMessage::Ipv4PacketInfo`]: crate::net::RecvAncillaryMessage::Ipv4PacketInfo
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_PKTINFO")]
+pub fn set_ip_pktinfo<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ip_pktinfo(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IP, IP_PKTINFO)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ip`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ip`]: https://man7.org/linux/man-pages/man7/ip.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IP_PKTINFO")]
+pub fn get_ip_pktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_ip_pktinfo(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO, value)`
+///
+/// When this is enabled, each datagram received with
+/// [`recvmsg`](crate::net::recvmsg) is accompanied by an `IPV6_PKTINFO`
+/// control message holding the interface and address it arrived on, which
+/// is decoded as [`RecvAncillaryMessage::Ipv6PacketInfo`].
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+/// [`RecvAncillaryMessage::Ipv6PacketInfo`]: crate::net::RecvAncillaryMessage::Ipv6PacketInfo
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn set_ipv6_recvpktinfo<Fd: AsFd>(fd: Fd, value: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_ipv6_recvpktinfo(fd.as_fd(), value)
+}
+
+/// `getsockopt(fd, IPPROTO_IPV6, IPV6_RECVPKTINFO)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `ipv6`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `ipv6`]: https://man7.org/linux/man-pages/man7/ipv6.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "IPV6_RECVPKTINFO")]
+pub fn get_ipv6_recvpktinfo<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_ipv6_recvpktinfo(fd.as_fd())
+}
+
 /// `setsockopt(fd, IPPROTO_TCP, TCP_NODELAY, nodelay)`
 ///
+/// On Linux, this interacts with `TCP_CORK`; while the socket is corked,
+/// partial frames are queued even if `TCP_NODELAY` is set.
+///
 /// # References
 ///  - [POSIX `setsockopt`]
 ///  - [POSIX `netinet/tcp.h`]
@@ -643,6 +1391,217 @@ pub fn get_tcp_nodelay<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
     backend::net::syscalls::sockopt::get_tcp_nodelay(fd.as_fd())
 }
 
+/// `setsockopt(fd, IPPROTO_TCP, TCP_CORK, cork)`
+///
+/// While corked, partial frames are queued rather than sent, until the
+/// socket is uncorked or a full frame's worth of data is queued. This
+/// interacts with [`set_tcp_nodelay`]: on Linux, `TCP_CORK` takes precedence
+/// while it is set, and uncorking sends any queued data immediately,
+/// regardless of `TCP_NODELAY`.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_CORK")]
+pub fn set_tcp_cork<Fd: AsFd>(fd: Fd, cork: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_tcp_cork(fd.as_fd(), cork)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_CORK)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_CORK")]
+pub fn get_tcp_cork<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_tcp_cork(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_QUICKACK, quickack)`
+///
+/// This is not permanent; the kernel may leave quickack mode again later,
+/// so applications that want it typically set it after each `recv`.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_QUICKACK")]
+pub fn set_tcp_quickack<Fd: AsFd>(fd: Fd, quickack: bool) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_tcp_quickack(fd.as_fd(), quickack)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_QUICKACK)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[inline]
+#[doc(alias = "TCP_QUICKACK")]
+pub fn get_tcp_quickack<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
+    backend::net::syscalls::sockopt::get_tcp_quickack(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPIDLE, duration)`
+///
+/// Sets how long the connection must be idle before keepalive probes are
+/// sent, if `SO_KEEPALIVE` is enabled. The duration is rounded up to a whole
+/// number of seconds.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "linux",
+    target_os = "netbsd",
+))]
+#[inline]
+#[doc(alias = "TCP_KEEPIDLE")]
+pub fn set_tcp_keepidle<Fd: AsFd>(fd: Fd, duration: Duration) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_tcp_keepidle(fd.as_fd(), duration)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_KEEPIDLE)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "linux",
+    target_os = "netbsd",
+))]
+#[inline]
+#[doc(alias = "TCP_KEEPIDLE")]
+pub fn get_tcp_keepidle<Fd: AsFd>(fd: Fd) -> io::Result<Duration> {
+    backend::net::syscalls::sockopt::get_tcp_keepidle(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPINTVL, duration)`
+///
+/// Sets the interval between keepalive probes. The duration is rounded up
+/// to a whole number of seconds.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "linux",
+    target_os = "netbsd",
+))]
+#[inline]
+#[doc(alias = "TCP_KEEPINTVL")]
+pub fn set_tcp_keepintvl<Fd: AsFd>(fd: Fd, duration: Duration) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_tcp_keepintvl(fd.as_fd(), duration)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_KEEPINTVL)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "linux",
+    target_os = "netbsd",
+))]
+#[inline]
+#[doc(alias = "TCP_KEEPINTVL")]
+pub fn get_tcp_keepintvl<Fd: AsFd>(fd: Fd) -> io::Result<Duration> {
+    backend::net::syscalls::sockopt::get_tcp_keepintvl(fd.as_fd())
+}
+
+/// `setsockopt(fd, IPPROTO_TCP, TCP_KEEPCNT, count)`
+///
+/// Sets the number of unanswered keepalive probes after which the
+/// connection is dropped.
+///
+/// # References
+///  - [Linux `setsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "linux",
+    target_os = "netbsd",
+))]
+#[inline]
+#[doc(alias = "TCP_KEEPCNT")]
+pub fn set_tcp_keepcnt<Fd: AsFd>(fd: Fd, count: u32) -> io::Result<()> {
+    backend::net::syscalls::sockopt::set_tcp_keepcnt(fd.as_fd(), count)
+}
+
+/// `getsockopt(fd, IPPROTO_TCP, TCP_KEEPCNT)`
+///
+/// # References
+///  - [Linux `getsockopt`]
+///  - [Linux `tcp`]
+///
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Linux `tcp`]: https://man7.org/linux/man-pages/man7/tcp.7.html
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "fuchsia",
+    target_os = "linux",
+    target_os = "netbsd",
+))]
+#[inline]
+#[doc(alias = "TCP_KEEPCNT")]
+pub fn get_tcp_keepcnt<Fd: AsFd>(fd: Fd) -> io::Result<u32> {
+    backend::net::syscalls::sockopt::get_tcp_keepcnt(fd.as_fd())
+}
+
 /// `getsockopt(fd, SOL_SOCKET, SO_NOSIGPIPE)`
 ///
 /// # References
@@ -700,3 +1659,153 @@ pub fn getsockopt_nosigpipe<Fd: AsFd>(fd: Fd) -> io::Result<bool> {
 pub fn setsockopt_nosigpipe<Fd: AsFd>(fd: Fd, val: bool) -> io::Result<()> {
     backend::net::syscalls::sockopt::setsockopt_nosigpipe(fd.as_fd(), val)
 }
+
+/// `getsockopt(fd, level, optname)`—Returns the value of an arbitrary socket
+/// option.
+///
+/// This is an escape hatch for options which rustix doesn't have a function
+/// for. The value's length must be exactly `size_of::<T>()`; otherwise this
+/// fails with [`io::Errno::INVAL`]. For options with variable-length values,
+/// use [`get_raw_bytes`].
+///
+/// Values are in the representation the OS uses, which for most options is
+/// host byte order, including many IP options such as `IP_TTL`, but not, for
+/// example, addresses and ports, which are in network byte order.
+///
+/// # Safety
+///
+/// The option's value must be a valid `T`, and getting the option must not
+/// have side effects which violate Rust's invariants, such as writing to
+/// memory outside of the value.
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [Linux `getsockopt`]
+///  - [Winsock2 `getsockopt`]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+#[inline]
+#[doc(alias = "getsockopt")]
+pub unsafe fn get_raw<T: Copy, Fd: AsFd>(fd: Fd, level: i32, optname: i32) -> io::Result<T> {
+    let mut value = MaybeUninit::<T>::uninit();
+    let len = backend::net::syscalls::sockopt::getsockopt_raw(
+        fd.as_fd(),
+        level as _,
+        optname as _,
+        value.as_mut_ptr().cast(),
+        size_of::<T>(),
+    )?;
+    if len != size_of::<T>() {
+        return Err(io::Errno::INVAL);
+    }
+    Ok(value.assume_init())
+}
+
+/// `getsockopt(fd, level, optname)`—Reads the value of an arbitrary socket
+/// option into a buffer.
+///
+/// Returns the length of the value, which the OS may truncate to fit in
+/// `value`. See [`get_raw`] about byte order.
+///
+/// # Safety
+///
+/// Getting the option must not have side effects which violate Rust's
+/// invariants, such as writing to memory outside of `value`.
+///
+/// # References
+///  - [POSIX `getsockopt`]
+///  - [Linux `getsockopt`]
+///  - [Winsock2 `getsockopt`]
+///
+/// [POSIX `getsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsockopt.html
+/// [Linux `getsockopt`]: https://man7.org/linux/man-pages/man2/getsockopt.2.html
+/// [Winsock2 `getsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-getsockopt
+#[inline]
+#[doc(alias = "getsockopt")]
+pub unsafe fn get_raw_bytes<Fd: AsFd>(
+    fd: Fd,
+    level: i32,
+    optname: i32,
+    value: &mut [u8],
+) -> io::Result<usize> {
+    backend::net::syscalls::sockopt::getsockopt_raw(
+        fd.as_fd(),
+        level as _,
+        optname as _,
+        value.as_mut_ptr(),
+        value.len(),
+    )
+}
+
+/// `setsockopt(fd, level, optname, value)`—Sets the value of an arbitrary
+/// socket option.
+///
+/// This is an escape hatch for options which rustix doesn't have a function
+/// for. See [`get_raw`] about byte order.
+///
+/// # Safety
+///
+/// `value` must be a valid value for the option, and setting the option must
+/// not have side effects which violate Rust's invariants. For example, some
+/// options take pointers, or file descriptors which must be valid.
+///
+/// # References
+///  - [POSIX `setsockopt`]
+///  - [Linux `setsockopt`]
+///  - [Winsock2 `setsockopt`]
+///
+/// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
+#[inline]
+#[doc(alias = "setsockopt")]
+pub unsafe fn set_raw<T: Copy, Fd: AsFd>(
+    fd: Fd,
+    level: i32,
+    optname: i32,
+    value: &T,
+) -> io::Result<()> {
+    backend::net::syscalls::sockopt::setsockopt_raw(
+        fd.as_fd(),
+        level as _,
+        optname as _,
+        (value as *const T).cast(),
+        size_of::<T>(),
+    )
+}
+
+/// `setsockopt(fd, level, optname, value)`—Sets the value of an arbitrary
+/// socket option from a buffer.
+///
+/// See [`get_raw`] about byte order.
+///
+/// # Safety
+///
+/// See [`set_raw`].
+///
+/// # References
+///  - [POSIX `setsockopt`]
+///  - [Linux `setsockopt`]
+///  - [Winsock2 `setsockopt`]
+///
+/// [POSIX `setsockopt`]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsockopt.html
+/// [Linux `setsockopt`]: https://man7.org/linux/man-pages/man2/setsockopt.2.html
+/// [Winsock2 `setsockopt`]: https://docs.microsoft.com/en-us/windows/win32/api/winsock2/nf-winsock2-setsockopt
+#[inline]
+#[doc(alias = "setsockopt")]
+pub unsafe fn set_raw_bytes<Fd: AsFd>(
+    fd: Fd,
+    level: i32,
+    optname: i32,
+    value: &[u8],
+) -> io::Result<()> {
+    backend::net::syscalls::sockopt::setsockopt_raw(
+        fd.as_fd(),
+        level as _,
+        optname as _,
+        value.as_ptr(),
+        value.len(),
+    )
+}
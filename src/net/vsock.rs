@@ -0,0 +1,68 @@
+//! VM socket addresses, for use with `AF_VSOCK` sockets.
+
+use crate::backend::c;
+
+/// `VMADDR_CID_ANY`—Binds to any context id.
+pub const VMADDR_CID_ANY: u32 = c::VMADDR_CID_ANY as _;
+
+/// `VMADDR_CID_HYPERVISOR`—The context id of the hypervisor.
+pub const VMADDR_CID_HYPERVISOR: u32 = c::VMADDR_CID_HYPERVISOR as _;
+
+/// `VMADDR_CID_LOCAL`—The context id for local communication within the same
+/// host, provided by the `vsock_loopback` module.
+pub const VMADDR_CID_LOCAL: u32 = c::VMADDR_CID_LOCAL as _;
+
+/// `VMADDR_CID_HOST`—The context id of the host.
+pub const VMADDR_CID_HOST: u32 = c::VMADDR_CID_HOST as _;
+
+/// `VMADDR_PORT_ANY`—Binds to any available port.
+pub const VMADDR_PORT_ANY: u32 = c::VMADDR_PORT_ANY as _;
+
+/// `struct sockaddr_vm`—A VM socket address.
+///
+/// This is used with [`AddressFamily::VSOCK`] sockets, for communication
+/// between virtual machines and their hosts.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man7/vsock.7.html
+/// [`AddressFamily::VSOCK`]: crate::net::AddressFamily::VSOCK
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[doc(alias = "sockaddr_vm")]
+pub struct SocketAddrVsock {
+    cid: u32,
+    port: u32,
+}
+
+impl SocketAddrVsock {
+    /// Construct a new VM socket address with the given context id and port.
+    #[inline]
+    pub const fn new(cid: u32, port: u32) -> Self {
+        Self { cid, port }
+    }
+
+    /// Return the `svm_cid` field.
+    #[inline]
+    pub const fn cid(&self) -> u32 {
+        self.cid
+    }
+
+    /// Set the `svm_cid` field.
+    #[inline]
+    pub fn set_cid(&mut self, cid: u32) {
+        self.cid = cid;
+    }
+
+    /// Return the `svm_port` field.
+    #[inline]
+    pub const fn port(&self) -> u32 {
+        self.port
+    }
+
+    /// Set the `svm_port` field.
+    #[inline]
+    pub fn set_port(&mut self, port: u32) {
+        self.port = port;
+    }
+}
@@ -94,3 +94,77 @@ pub fn linux_hwcap() -> (usize, usize) {
 pub fn linux_execfn() -> &'static CStr {
     backend::param::auxv::linux_execfn()
 }
+
+/// `AT_*` constants for use with [`auxv`].
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        any(
+            all(target_os = "android", target_pointer_width = "64"),
+            target_os = "linux",
+        )
+    )
+))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AuxvKey {
+    /// `AT_HWCAP`—Architecture-specific hardware capability bits.
+    Hwcap = linux_raw_sys::general::AT_HWCAP,
+
+    /// `AT_HWCAP2`—More architecture-specific hardware capability bits.
+    Hwcap2 = linux_raw_sys::general::AT_HWCAP2,
+
+    /// `AT_PAGESZ`—The page size.
+    Pagesz = linux_raw_sys::general::AT_PAGESZ,
+
+    /// `AT_CLKTCK`—The number of clock ticks per second, as used by `times`.
+    Clktck = linux_raw_sys::general::AT_CLKTCK,
+
+    /// `AT_SECURE`—Nonzero if the program was executed in secure mode, for
+    /// example because it's set-user-ID or set-group-ID, or gained
+    /// capabilities.
+    Secure = linux_raw_sys::general::AT_SECURE,
+
+    /// `AT_RANDOM`—The address of 16 random bytes, provided by the kernel.
+    Random = linux_raw_sys::general::AT_RANDOM,
+
+    /// `AT_EXECFN`—The address of the NUL-terminated path that the program
+    /// was executed with.
+    Execfn = linux_raw_sys::general::AT_EXECFN,
+
+    /// `AT_SYSINFO_EHDR`—The address of the ELF header of the vDSO.
+    SysinfoEhdr = linux_raw_sys::general::AT_SYSINFO_EHDR,
+}
+
+/// `getauxval(key)`—Returns a value from the Linux auxiliary vector.
+///
+/// Returns `None` if the value is not available. As with `getauxval`, a
+/// value of zero is indistinguishable from a missing one, so it's also
+/// reported as `None`, except for [`AuxvKey::Secure`], where zero means the
+/// program wasn't executed in secure mode.
+///
+/// [`AuxvKey::Secure`] is the value to use for deciding whether to distrust
+/// the environment, as `secure_getenv` does; unlike comparing the real and
+/// effective user IDs, it also accounts for capabilities and security
+/// modules.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/getauxval.3.html
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        any(
+            all(target_os = "android", target_pointer_width = "64"),
+            target_os = "linux",
+        )
+    )
+))]
+#[inline]
+#[doc(alias = "getauxval")]
+pub fn auxv(key: AuxvKey) -> Option<usize> {
+    backend::param::auxv::auxv(key)
+}
@@ -0,0 +1,352 @@
+//! CPU feature detection using the Linux `AT_HWCAP` and `AT_HWCAP2` values.
+//!
+//! # Safety
+//!
+//! On x86 and x86-64, this uses the `cpuid` and `xgetbv` instructions
+//! instead.
+#![allow(unsafe_code)]
+
+use crate::param::linux_hwcap;
+
+/// A CPU feature, for use with [`CpuFeatures::has`].
+///
+/// The available features depend on the architecture.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Feature {
+    /// `sse`
+    Sse,
+    /// `sse2`
+    Sse2,
+    /// `sse3`
+    Sse3,
+    /// `ssse3`
+    Ssse3,
+    /// `sse4.1`
+    Sse41,
+    /// `sse4.2`
+    Sse42,
+    /// `popcnt`
+    Popcnt,
+    /// `pclmulqdq`
+    Pclmulqdq,
+    /// `aes`
+    Aes,
+    /// `rdrand`
+    Rdrand,
+    /// `sha`
+    Sha,
+    /// `avx`, which also requires the OS to save the AVX registers.
+    Avx,
+    /// `avx2`, which also requires the OS to save the AVX registers.
+    Avx2,
+    /// `fma`, which also requires the OS to save the AVX registers.
+    Fma,
+}
+
+/// A CPU feature, for use with [`CpuFeatures::has`].
+///
+/// The available features depend on the architecture.
+#[cfg(target_arch = "aarch64")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Feature {
+    /// `HWCAP_FP`
+    Fp,
+    /// `HWCAP_ASIMD`
+    #[doc(alias = "Asimd")]
+    Neon,
+    /// `HWCAP_AES`
+    Aes,
+    /// `HWCAP_PMULL`
+    Pmull,
+    /// `HWCAP_SHA1`
+    Sha1,
+    /// `HWCAP_SHA2`
+    Sha2,
+    /// `HWCAP_CRC32`
+    Crc32,
+    /// `HWCAP_ATOMICS`
+    #[doc(alias = "Lse")]
+    Atomics,
+    /// `HWCAP_SHA3`
+    Sha3,
+    /// `HWCAP_SHA512`
+    Sha512,
+    /// `HWCAP_ASIMDDP`
+    DotProd,
+    /// `HWCAP_SVE`
+    Sve,
+    /// `HWCAP2_SVE2`
+    Sve2,
+    /// `HWCAP2_RNG`
+    Rng,
+}
+
+/// A CPU feature, for use with [`CpuFeatures::has`].
+///
+/// The available features depend on the architecture.
+#[cfg(target_arch = "arm")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Feature {
+    /// `HWCAP_VFP`
+    Vfp,
+    /// `HWCAP_NEON`
+    Neon,
+    /// `HWCAP2_AES`
+    Aes,
+    /// `HWCAP2_PMULL`
+    Pmull,
+    /// `HWCAP2_SHA1`
+    Sha1,
+    /// `HWCAP2_SHA2`
+    Sha2,
+    /// `HWCAP2_CRC32`
+    Crc32,
+}
+
+/// A CPU feature, for use with [`CpuFeatures::has`].
+///
+/// The available features depend on the architecture.
+#[cfg(target_arch = "riscv64")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Feature {
+    /// The `I` base integer instruction set.
+    I,
+    /// The `M` extension, for integer multiplication and division.
+    M,
+    /// The `A` extension, for atomics.
+    A,
+    /// The `F` extension, for single-precision floating point.
+    F,
+    /// The `D` extension, for double-precision floating point.
+    D,
+    /// The `C` extension, for compressed instructions.
+    C,
+    /// The `V` extension, for vectors.
+    V,
+}
+
+/// A CPU feature, for use with [`CpuFeatures::has`].
+///
+/// The available features depend on the architecture.
+#[cfg(target_arch = "powerpc64")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum Feature {
+    /// `PPC_FEATURE_HAS_ALTIVEC`
+    Altivec,
+    /// `PPC_FEATURE_HAS_VSX`
+    Vsx,
+    /// `PPC_FEATURE2_HAS_VEC_CRYPTO`
+    VecCrypto,
+}
+
+/// A CPU feature, for use with [`CpuFeatures::has`].
+///
+/// No features are currently detected on this architecture.
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "powerpc64",
+)))]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Feature {}
+
+/// The set of features supported by the CPU and enabled by the OS, as
+/// returned by [`cpu_features`].
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct CpuFeatures {
+    bits: u64,
+}
+
+impl CpuFeatures {
+    /// Test whether `feature` is supported.
+    #[inline]
+    pub fn has(&self, feature: Feature) -> bool {
+        #[cfg(not(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "riscv64",
+            target_arch = "powerpc64",
+        )))]
+        match feature {}
+
+        #[cfg(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "riscv64",
+            target_arch = "powerpc64",
+        ))]
+        {
+            self.bits & (1 << feature as u8) != 0
+        }
+    }
+
+    #[allow(dead_code)]
+    #[inline]
+    fn set(&mut self, feature: Feature, value: bool) {
+        if value {
+            self.bits |= 1 << feature as u8;
+        }
+    }
+}
+
+/// Returns the set of features supported by the CPU and enabled by the OS.
+///
+/// This decodes the Linux `AT_HWCAP` and `AT_HWCAP2` values, which report
+/// the features the kernel has found and enabled. On x86 and x86-64,
+/// `AT_HWCAP` only covers the oldest features, and glibc replaces it with
+/// its own flags, so this uses `cpuid` instead, and checks with `xgetbv`
+/// that the OS saves the AVX registers.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man3/getauxval.3.html
+#[inline]
+pub fn cpu_features() -> CpuFeatures {
+    let (hwcap, hwcap2) = linux_hwcap();
+    decode(hwcap, hwcap2)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(unused_unsafe)]
+fn decode(_hwcap: usize, _hwcap2: usize) -> CpuFeatures {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{__cpuid, __cpuid_count, _xgetbv};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{__cpuid, __cpuid_count, _xgetbv};
+
+    let mut features = CpuFeatures::default();
+
+    // Safety: Every x86 CPU that Linux supports has `cpuid`.
+    let max_leaf = unsafe { __cpuid(0) }.eax;
+    let leaf1 = unsafe { __cpuid(1) };
+    let (ecx1, edx1) = (leaf1.ecx, leaf1.edx);
+    let ebx7 = if max_leaf >= 7 {
+        unsafe { __cpuid_count(7, 0) }.ebx
+    } else {
+        0
+    };
+
+    features.set(Feature::Sse, edx1 & (1 << 25) != 0);
+    features.set(Feature::Sse2, edx1 & (1 << 26) != 0);
+    features.set(Feature::Sse3, ecx1 & (1 << 0) != 0);
+    features.set(Feature::Pclmulqdq, ecx1 & (1 << 1) != 0);
+    features.set(Feature::Ssse3, ecx1 & (1 << 9) != 0);
+    features.set(Feature::Sse41, ecx1 & (1 << 19) != 0);
+    features.set(Feature::Sse42, ecx1 & (1 << 20) != 0);
+    features.set(Feature::Popcnt, ecx1 & (1 << 23) != 0);
+    features.set(Feature::Aes, ecx1 & (1 << 25) != 0);
+    features.set(Feature::Rdrand, ecx1 & (1 << 30) != 0);
+    features.set(Feature::Sha, ebx7 & (1 << 29) != 0);
+
+    // The AVX features also need the OS to save the `xmm` and `ymm`
+    // registers, which it reports with `osxsave` and `XCR0`.
+    let os_avx = ecx1 & (1 << 27) != 0 && {
+        // Safety: `osxsave` means `xgetbv` is enabled.
+        let xcr0 = unsafe { _xgetbv(0) };
+        xcr0 & 0b110 == 0b110
+    };
+    if os_avx {
+        features.set(Feature::Avx, ecx1 & (1 << 28) != 0);
+        features.set(Feature::Fma, ecx1 & (1 << 12) != 0);
+        features.set(Feature::Avx2, ebx7 & (1 << 5) != 0);
+    }
+
+    features
+}
+
+#[cfg(target_arch = "aarch64")]
+fn decode(hwcap: usize, hwcap2: usize) -> CpuFeatures {
+    let mut features = CpuFeatures::default();
+
+    features.set(Feature::Fp, hwcap & (1 << 0) != 0);
+    features.set(Feature::Neon, hwcap & (1 << 1) != 0);
+    features.set(Feature::Aes, hwcap & (1 << 3) != 0);
+    features.set(Feature::Pmull, hwcap & (1 << 4) != 0);
+    features.set(Feature::Sha1, hwcap & (1 << 5) != 0);
+    features.set(Feature::Sha2, hwcap & (1 << 6) != 0);
+    features.set(Feature::Crc32, hwcap & (1 << 7) != 0);
+    features.set(Feature::Atomics, hwcap & (1 << 8) != 0);
+    features.set(Feature::Sha3, hwcap & (1 << 17) != 0);
+    features.set(Feature::DotProd, hwcap & (1 << 20) != 0);
+    features.set(Feature::Sha512, hwcap & (1 << 21) != 0);
+    features.set(Feature::Sve, hwcap & (1 << 22) != 0);
+    features.set(Feature::Sve2, hwcap2 & (1 << 1) != 0);
+    features.set(Feature::Rng, hwcap2 & (1 << 16) != 0);
+
+    features
+}
+
+#[cfg(target_arch = "arm")]
+fn decode(hwcap: usize, hwcap2: usize) -> CpuFeatures {
+    let mut features = CpuFeatures::default();
+
+    features.set(Feature::Vfp, hwcap & (1 << 6) != 0);
+    features.set(Feature::Neon, hwcap & (1 << 12) != 0);
+    features.set(Feature::Aes, hwcap2 & (1 << 0) != 0);
+    features.set(Feature::Pmull, hwcap2 & (1 << 1) != 0);
+    features.set(Feature::Sha1, hwcap2 & (1 << 2) != 0);
+    features.set(Feature::Sha2, hwcap2 & (1 << 3) != 0);
+    features.set(Feature::Crc32, hwcap2 & (1 << 4) != 0);
+
+    features
+}
+
+#[cfg(target_arch = "riscv64")]
+fn decode(hwcap: usize, _hwcap2: usize) -> CpuFeatures {
+    let mut features = CpuFeatures::default();
+
+    // On RISC-V, `AT_HWCAP` has a bit for each single-letter extension.
+    let has = |letter: u8| hwcap & (1 << (letter - b'A')) != 0;
+    features.set(Feature::I, has(b'I'));
+    features.set(Feature::M, has(b'M'));
+    features.set(Feature::A, has(b'A'));
+    features.set(Feature::F, has(b'F'));
+    features.set(Feature::D, has(b'D'));
+    features.set(Feature::C, has(b'C'));
+    features.set(Feature::V, has(b'V'));
+
+    features
+}
+
+#[cfg(target_arch = "powerpc64")]
+fn decode(hwcap: usize, hwcap2: usize) -> CpuFeatures {
+    let mut features = CpuFeatures::default();
+
+    features.set(Feature::Altivec, hwcap & 0x1000_0000 != 0);
+    features.set(Feature::Vsx, hwcap & 0x0000_0080 != 0);
+    features.set(Feature::VecCrypto, hwcap2 & 0x0200_0000 != 0);
+
+    features
+}
+
+#[cfg(not(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "powerpc64",
+)))]
+fn decode(_hwcap: usize, _hwcap2: usize) -> CpuFeatures {
+    CpuFeatures::default()
+}
@@ -7,8 +7,23 @@
 
 #[cfg(feature = "param")]
 mod auxv;
+#[cfg(feature = "param")]
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        any(
+            all(target_os = "android", target_pointer_width = "64"),
+            target_os = "linux",
+        )
+    )
+))]
+mod cpu_features;
 #[cfg(target_vendor = "mustang")]
 mod init;
+#[cfg(feature = "param")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod sysconf;
 
 #[cfg(feature = "param")]
 #[cfg(not(target_os = "wasi"))]
@@ -26,6 +41,21 @@ pub use auxv::page_size;
         )
     )
 ))]
-pub use auxv::{linux_execfn, linux_hwcap};
+pub use auxv::{auxv, linux_execfn, linux_hwcap, AuxvKey};
+#[cfg(feature = "param")]
+#[cfg(any(
+    linux_raw,
+    all(
+        libc,
+        any(
+            all(target_os = "android", target_pointer_width = "64"),
+            target_os = "linux",
+        )
+    )
+))]
+pub use cpu_features::{cpu_features, CpuFeatures, Feature};
 #[cfg(target_vendor = "mustang")]
 pub use init::init;
+#[cfg(feature = "param")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use sysconf::{configured_cpus, online_cpus, open_max};
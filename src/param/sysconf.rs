@@ -0,0 +1,123 @@
+//! Linux implementations of `sysconf` queries, using syscalls and `/sys`
+//! instead of libc.
+
+use crate::fs::{cwd, openat, Mode, OFlags};
+use crate::io;
+use crate::process::{getrlimit, sched_getaffinity, Resource};
+
+/// `sysconf(_SC_NPROCESSORS_ONLN)`—Returns the number of CPUs this process
+/// may run on.
+///
+/// Unlike libc's `sysconf(_SC_NPROCESSORS_ONLN)`, this reflects the
+/// process' CPU affinity mask, which is also reported as `Cpus_allowed` in
+/// `/proc/self/status`, and which is how cgroup `cpuset` limits and
+/// `taskset` restrict the process. So it's the number of threads worth
+/// running in parallel. It's always at least 1, and never more than
+/// [`configured_cpus`].
+///
+/// # References
+///  - [Linux `sysconf`]
+///  - [Linux `sched_getaffinity`]
+///
+/// [Linux `sysconf`]: https://man7.org/linux/man-pages/man3/sysconf.3.html
+/// [Linux `sched_getaffinity`]: https://man7.org/linux/man-pages/man2/sched_getaffinity.2.html
+#[doc(alias = "_SC_NPROCESSORS_ONLN")]
+pub fn online_cpus() -> usize {
+    let online = match sched_getaffinity(None) {
+        Ok(cpuset) => cpuset.count() as usize,
+        Err(_) => 0,
+    };
+    online.max(1)
+}
+
+/// `sysconf(_SC_NPROCESSORS_CONF)`—Returns the number of CPUs in the
+/// system.
+///
+/// This counts the CPUs in `/sys/devices/system/cpu/present`, including
+/// ones which are offline.
+///
+/// # References
+///  - [Linux `sysconf`]
+///  - [Linux CPU topology]
+///
+/// [Linux `sysconf`]: https://man7.org/linux/man-pages/man3/sysconf.3.html
+/// [Linux CPU topology]: https://docs.kernel.org/admin-guide/cputopology.html
+#[doc(alias = "_SC_NPROCESSORS_CONF")]
+pub fn configured_cpus() -> usize {
+    let configured = read_cpu_list("/sys/devices/system/cpu/present").unwrap_or(0);
+    configured.max(online_cpus())
+}
+
+/// `sysconf(_SC_OPEN_MAX)`—Returns the maximum number of open files this
+/// process may have.
+///
+/// This is the soft `RLIMIT_NOFILE` limit, or `usize::MAX` if there is no
+/// limit.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sysconf.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/sysconf.3.html
+#[doc(alias = "_SC_OPEN_MAX")]
+pub fn open_max() -> usize {
+    match getrlimit(Resource::Nofile).current {
+        Some(current) => current as usize,
+        None => usize::MAX,
+    }
+}
+
+/// Read a CPU list file, such as "0-3,6\n", and return the number of CPUs in
+/// it.
+fn read_cpu_list(path: &str) -> io::Result<usize> {
+    let file = openat(cwd(), path, OFlags::RDONLY | OFlags::CLOEXEC, Mode::empty())?;
+
+    // Even a system with thousands of CPUs has a short list, as long as its
+    // CPU numbers are contiguous.
+    let mut buf = [0_u8; 256];
+    let mut len = 0;
+    loop {
+        match io::read(&file, &mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(io::Errno::INTR) => continue,
+            Err(err) => return Err(err),
+        }
+        if len == buf.len() {
+            return Err(io::Errno::FBIG);
+        }
+    }
+
+    parse_cpu_list(&buf[..len]).ok_or(io::Errno::INVAL)
+}
+
+/// Parse a CPU list, such as "0-3,6\n", and return the number of CPUs in it.
+fn parse_cpu_list(list: &[u8]) -> Option<usize> {
+    let list = core::str::from_utf8(list).ok()?.trim();
+    if list.is_empty() {
+        return Some(0);
+    }
+
+    let mut count = 0;
+    for range in list.split(',') {
+        let mut bounds = range.splitn(2, '-');
+        let start = bounds.next()?.parse::<usize>().ok()?;
+        let end = match bounds.next() {
+            Some(end) => end.parse::<usize>().ok()?,
+            None => start,
+        };
+        count += end.checked_sub(start)? + 1;
+    }
+    Some(count)
+}
+
+#[test]
+fn test_parse_cpu_list() {
+    assert_eq!(parse_cpu_list(b"0\n"), Some(1));
+    assert_eq!(parse_cpu_list(b"0-3\n"), Some(4));
+    assert_eq!(parse_cpu_list(b"0-3,6,8-9\n"), Some(7));
+    assert_eq!(parse_cpu_list(b"\n"), Some(0));
+    assert_eq!(parse_cpu_list(b"3-1\n"), None);
+    assert_eq!(parse_cpu_list(b"x\n"), None);
+}
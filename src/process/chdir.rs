@@ -4,6 +4,8 @@ use crate::{backend, io, path};
 use alloc::vec::Vec;
 #[cfg(not(target_os = "fuchsia"))]
 use backend::fd::AsFd;
+#[cfg(all(feature = "fs", not(target_os = "fuchsia")))]
+use backend::fd::OwnedFd;
 
 /// `chdir(path)`—Change the current working directory.
 ///
@@ -32,9 +34,72 @@ pub fn fchdir<Fd: AsFd>(fd: Fd) -> io::Result<()> {
     backend::process::syscalls::fchdir(fd.as_fd())
 }
 
+/// A guard which saves the current working directory, and restores it when
+/// dropped.
+///
+/// This holds a file descriptor for the original working directory, so it
+/// restores it even if that directory has been renamed, and even if the guard
+/// is dropped while unwinding from a panic.
+///
+/// The current working directory is shared by all threads in a process, so
+/// while a `WorkingDirGuard` is live, other threads using relative paths may
+/// observe the changed working directory, and if any of them changes it too,
+/// the restorations may happen in an unexpected order. Consider using
+/// `*at` functions with a directory file descriptor instead, to avoid
+/// depending on the current working directory at all.
+#[cfg(all(feature = "fs", not(target_os = "fuchsia")))]
+#[derive(Debug)]
+pub struct WorkingDirGuard {
+    dir: OwnedFd,
+}
+
+#[cfg(all(feature = "fs", not(target_os = "fuchsia")))]
+impl WorkingDirGuard {
+    /// Save the current working directory.
+    ///
+    /// On Linux, this opens `.` with `O_PATH`, so it works even if the
+    /// directory isn't readable.
+    pub fn new() -> io::Result<Self> {
+        use crate::fs::{cwd, openat, Mode, OFlags};
+
+        #[cfg(any(target_os = "android", target_os = "linux"))]
+        let oflags = OFlags::PATH | OFlags::DIRECTORY | OFlags::CLOEXEC;
+        #[cfg(not(any(target_os = "android", target_os = "linux")))]
+        let oflags = OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC;
+
+        Ok(Self {
+            dir: openat(cwd(), ".", oflags, Mode::empty())?,
+        })
+    }
+
+    /// Restore the saved working directory now, reporting any error.
+    ///
+    /// The saved working directory is restored again when the guard is
+    /// dropped, ignoring errors.
+    #[inline]
+    pub fn restore(&self) -> io::Result<()> {
+        fchdir(&self.dir)
+    }
+}
+
+#[cfg(all(feature = "fs", not(target_os = "fuchsia")))]
+impl Drop for WorkingDirGuard {
+    #[inline]
+    fn drop(&mut self) {
+        let _ = fchdir(&self.dir);
+    }
+}
+
 /// `getcwd()`—Return the current working directory.
 ///
 /// If `reuse` is non-empty, reuse its buffer to store the result if possible.
+/// If the buffer is too small, it's grown and the call is retried.
+///
+/// On Linux, with the linux_raw backend, if the current working directory
+/// isn't reachable from the process' root directory, such as after a
+/// `chroot`, the raw `getcwd` syscall reports a path prefixed with
+/// `(unreachable)`, which is returned as-is. libc implementations typically
+/// fail with [`io::Errno::NOENT`] in this case instead.
 ///
 /// # References
 ///  - [POSIX]
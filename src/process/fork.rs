@@ -0,0 +1,52 @@
+#![allow(unsafe_code)]
+
+use crate::process::Pid;
+use crate::{backend, io};
+
+/// The result of a successful [`fork`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Fork {
+    /// This is the child process.
+    Child,
+
+    /// This is the parent process, and the child has the given pid.
+    Parent(Pid),
+}
+
+/// `fork()`—Creates a new process by duplicating the calling process.
+///
+/// On success, this returns [`Fork::Parent`] with the child's pid in the
+/// parent, and [`Fork::Child`] in the child.
+///
+/// The child process is created with only one thread, which is a copy of the
+/// calling thread. Any locks held by other threads in the parent remain
+/// locked forever in the child, including locks used by the global allocator
+/// and by `std`, so **in the child, only [async-signal-safe] operations may be
+/// performed until it calls `execve` or exits**. In particular, the child must
+/// not allocate memory, and should exit with `_exit` rather than
+/// [`std::process::exit`].
+///
+/// On `linux_raw`, this is implemented with a `clone` system call, so
+/// `pthread_atfork` handlers are not run.
+///
+/// # Safety
+///
+/// In a program with multiple threads, this is fundamentally unsafe: the
+/// child must avoid invoking any undefined behavior through any function that
+/// is not guaranteed to be async-signal-safe, and must avoid accessing any
+/// memory shared with the parent in a way that invokes undefined behavior.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fork.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/fork.2.html
+/// [async-signal-safe]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/V2_chap02.html#tag_15_04_03
+#[inline]
+pub unsafe fn fork() -> io::Result<Fork> {
+    Ok(match backend::process::syscalls::fork()? {
+        Some(pid) => Fork::Parent(pid),
+        None => Fork::Child,
+    })
+}
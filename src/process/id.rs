@@ -280,6 +280,23 @@ pub fn getpgid(pid: Option<Pid>) -> io::Result<Pid> {
     backend::process::syscalls::getpgid(pid)
 }
 
+/// `setpgid(pid, pgid)`—Sets the process group ID of the given process.
+///
+/// If `pid` is `None`, this sets the process group ID of the calling process.
+/// If `pgid` is `None`, the process group ID is made the same as the process
+/// ID of the process, making it the leader of a new process group.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setpgid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/setpgid.2.html
+#[inline]
+pub fn setpgid(pid: Option<Pid>, pgid: Option<Pid>) -> io::Result<()> {
+    backend::process::syscalls::setpgid(pid, pgid)
+}
+
 /// `getpgrp()`—Returns the process' group ID.
 ///
 /// # References
@@ -296,17 +313,39 @@ pub fn getpgrp() -> Pid {
 
 /// `setsid()`—Create a new session.
 ///
+/// The calling process becomes the leader of a new session and of a new
+/// process group in it, with no controlling terminal, and the new session ID
+/// is returned. This fails with [`io::Errno::PERM`] if the calling process is
+/// already a process group leader, so it's usually called in a newly forked
+/// child.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/setsid.html
 /// [Linux]: https://man7.org/linux/man-pages/man2/setsid.2.html
+/// [`io::Errno::PERM`]: crate::io::Errno::PERM
 #[inline]
 pub fn setsid() -> io::Result<Pid> {
     backend::process::syscalls::setsid()
 }
 
+/// `getsid(pid)`—Returns the session ID of the given process.
+///
+/// If `pid` is `None`, this returns the session ID of the calling process.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/getsid.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/getsid.2.html
+#[inline]
+pub fn getsid(pid: Option<Pid>) -> io::Result<Pid> {
+    backend::process::syscalls::getsid(pid)
+}
+
 // translate_fchown_args returns the raw value of the IDs. In case of `None`
 // it returns `u32::MAX` since it has the same bit pattern as `-1` indicating
 // no change to the owner/group ID.
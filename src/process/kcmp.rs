@@ -0,0 +1,134 @@
+#![allow(unsafe_code)]
+
+use crate::fd::RawFd;
+use crate::process::Pid;
+use crate::{backend, io};
+use core::cmp::Ordering;
+
+/// `KCMP_*` constants for use with [`kcmp`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum KcmpType {
+    /// `KCMP_FILE`—Compare the open file descriptions of the file
+    /// descriptors `idx1` and `idx2`.
+    File = 0,
+    /// `KCMP_VM`—Compare address spaces.
+    Vm = 1,
+    /// `KCMP_FILES`—Compare file descriptor tables.
+    Files = 2,
+    /// `KCMP_FS`—Compare filesystem information, such as the working
+    /// directory and umask.
+    Fs = 3,
+    /// `KCMP_SIGHAND`—Compare signal handler tables.
+    Sighand = 4,
+    /// `KCMP_IO`—Compare I/O contexts.
+    Io = 5,
+    /// `KCMP_EPOLL_TFD`—Compare a file descriptor with a file registered
+    /// in an epoll instance. This needs a `kcmp_epoll_slot`, so it's used
+    /// through [`kcmp_epoll_tfd`]; passing it to [`kcmp`] fails with
+    /// [`io::Errno::INVAL`].
+    EPollTfd = 7,
+}
+
+/// `struct kcmp_epoll_slot`—A file registered in an epoll instance, for use
+/// with [`kcmp_epoll_tfd`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct KcmpEpollSlot {
+    /// `efd`—The epoll file descriptor.
+    pub efd: RawFd,
+    /// `tfd`—The file descriptor number the file was registered with.
+    pub tfd: RawFd,
+    /// `toff`—Which of the registrations with that number to use, counting
+    /// from zero, if the same number was registered for several files.
+    pub toff: u64,
+}
+
+/// `kcmp(pid1, pid2, type_, idx1, idx2)`—Compares kernel resources of two
+/// processes.
+///
+/// This tests whether the processes `pid1` and `pid2` share the resource
+/// selected by `type_`. For [`KcmpType::File`], `idx1` and `idx2` are file
+/// descriptors in the respective processes; the other types ignore them.
+///
+/// The result is [`Ordering::Equal`] if the resource is shared. Otherwise
+/// it's an arbitrary but consistent ordering between the two resources,
+/// which can be used to sort them.
+///
+/// This requires `PTRACE_MODE_READ` access to both processes, and the
+/// kernel to be built with `CONFIG_KCMP`; otherwise this fails with
+/// [`io::Errno::PERM`] or [`io::Errno::NOSYS`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/kcmp.2.html
+#[inline]
+pub fn kcmp(
+    pid1: Pid,
+    pid2: Pid,
+    type_: KcmpType,
+    idx1: usize,
+    idx2: usize,
+) -> io::Result<Ordering> {
+    if type_ == KcmpType::EPollTfd {
+        return Err(io::Errno::INVAL);
+    }
+
+    // Safety: None of the types besides `KCMP_EPOLL_TFD` use `idx2` as a
+    // pointer.
+    let result = unsafe { backend::process::syscalls::kcmp(pid1, pid2, type_ as u32, idx1, idx2)? };
+    kcmp_ordering(result)
+}
+
+/// `kcmp(pid1, pid2, KCMP_EPOLL_TFD, fd1, &slot)`—Compares a file descriptor
+/// in one process with a file registered in an epoll instance in another.
+///
+/// This tests whether the file descriptor `fd1` in `pid1` refers to the same
+/// open file description as the file `slot` describes, in `pid2`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/kcmp.2.html
+#[inline]
+#[doc(alias = "KCMP_EPOLL_TFD")]
+pub fn kcmp_epoll_tfd(
+    pid1: Pid,
+    pid2: Pid,
+    fd1: RawFd,
+    slot: &KcmpEpollSlot,
+) -> io::Result<Ordering> {
+    // Safety: `KCMP_EPOLL_TFD` reads a `kcmp_epoll_slot` from `idx2`.
+    let result = unsafe {
+        backend::process::syscalls::kcmp(
+            pid1,
+            pid2,
+            KcmpType::EPollTfd as u32,
+            fd1 as usize,
+            slot as *const KcmpEpollSlot as usize,
+        )?
+    };
+    kcmp_ordering(result)
+}
+
+/// Convert a `kcmp` result to an `Ordering`.
+///
+/// `kcmp` returns 1 if the first resource orders before the second, and 2 if
+/// after.
+fn kcmp_ordering(result: u32) -> io::Result<Ordering> {
+    match result {
+        0 => Ok(Ordering::Equal),
+        1 => Ok(Ordering::Less),
+        2 => Ok(Ordering::Greater),
+        // The man page reserves 3 for resources which differ but can't be
+        // ordered, which Linux doesn't currently report.
+        _ => Err(io::Errno::NOTSUP),
+    }
+}
+
+#[test]
+fn kcmp_epoll_slot_layout() {
+    assert_eq!(core::mem::size_of::<KcmpEpollSlot>(), 16);
+}
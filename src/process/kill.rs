@@ -35,6 +35,21 @@ pub fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
     backend::process::syscalls::kill_process_group(pid, sig)
 }
 
+/// `killpg(pgid, sig)`—Sends a signal to all processes in a process group.
+///
+/// This is equivalent to [`kill_process_group`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/killpg.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/killpg.3.html
+#[inline]
+pub fn killpg(pgid: Pid, sig: Signal) -> io::Result<()> {
+    backend::process::syscalls::kill_process_group(pgid, sig)
+}
+
 /// `kill(0, sig)`—Sends a signal to all processes in the current process
 /// group.
 ///
@@ -49,3 +64,30 @@ pub fn kill_process_group(pid: Pid, sig: Signal) -> io::Result<()> {
 pub fn kill_current_process_group(sig: Signal) -> io::Result<()> {
     backend::process::syscalls::kill_current_process_group(sig)
 }
+
+/// `kill(pid, 0)`—Check whether a process exists and whether the current
+/// process has permission to send signals to it.
+///
+/// This returns `Ok(true)` if the process exists and can be signaled, and
+/// `Ok(false)` if there is no such process (`ESRCH`). Other errors, such as
+/// `EPERM` if the process exists but the current process doesn't have
+/// permission to signal it, are returned as errors.
+///
+/// Note that a process which has exited but hasn't yet been waited for still
+/// exists.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/kill.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/kill.2.html
+#[inline]
+#[doc(alias = "kill")]
+pub fn test_kill_process(pid: Pid) -> io::Result<bool> {
+    match backend::process::syscalls::test_kill_process(pid) {
+        Ok(()) => Ok(true),
+        Err(io::Errno::SRCH) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
@@ -0,0 +1,151 @@
+//! Querying the memory usage of the current process.
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+use crate::backend;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+use crate::fs::{cwd, openat, Mode, OFlags};
+use crate::io;
+
+/// The memory usage of the current process, as returned by [`memory_usage`].
+///
+/// All sizes are in bytes.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct MemoryUsage {
+    /// The resident set size, which is the amount of memory currently in
+    /// RAM.
+    pub resident: u64,
+
+    /// The peak resident set size over the lifetime of the process.
+    pub resident_peak: u64,
+
+    /// The size of the virtual address space.
+    pub virtual_size: u64,
+
+    /// The size of the data segment, including the heap and anonymous
+    /// mappings. This is always zero on Apple platforms, which don't report
+    /// it.
+    pub data: u64,
+}
+
+/// Returns the memory usage of the current process.
+///
+/// On Linux, this reads the `VmRSS`, `VmHWM`, `VmSize`, and `VmData` fields
+/// of `/proc/self/status`, which are the same counters `/proc/self/statm`
+/// reports in pages. On Apple platforms, this uses
+/// `task_info(MACH_TASK_BASIC_INFO)`.
+///
+/// The values are read fresh on each call; nothing is cached.
+///
+/// # References
+///  - [Linux]
+///  - [Apple]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man5/proc.5.html
+/// [Apple]: https://developer.apple.com/documentation/kernel/1537934-task_info
+pub fn memory_usage() -> io::Result<MemoryUsage> {
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+        read_proc_self_status()
+    }
+
+    #[cfg(any(target_os = "ios", target_os = "macos"))]
+    {
+        let (resident, resident_peak, virtual_size) =
+            backend::process::syscalls::task_basic_info()?;
+        Ok(MemoryUsage {
+            resident,
+            resident_peak,
+            virtual_size,
+            data: 0,
+        })
+    }
+}
+
+/// Read and parse `/proc/self/status`.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn read_proc_self_status() -> io::Result<MemoryUsage> {
+    let file = openat(
+        cwd(),
+        "/proc/self/status",
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )?;
+
+    // `/proc/self/status` is a few kilobytes, and grows only slowly with
+    // new kernel versions.
+    let mut buf = [0_u8; 8192];
+    let mut len = 0;
+    loop {
+        match io::read(&file, &mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(io::Errno::INTR) => continue,
+            Err(err) => return Err(err),
+        }
+        if len == buf.len() {
+            return Err(io::Errno::FBIG);
+        }
+    }
+
+    parse_status(&buf[..len]).ok_or(io::Errno::INVAL)
+}
+
+/// Parse the contents of a `/proc/<pid>/status` file.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn parse_status(status: &[u8]) -> Option<MemoryUsage> {
+    let status = core::str::from_utf8(status).ok()?;
+
+    let mut resident = None;
+    let mut resident_peak = None;
+    let mut virtual_size = None;
+    let mut data = None;
+    for line in status.lines() {
+        let mut fields = line.splitn(2, ':');
+        let field = match fields.next()? {
+            "VmRSS" => &mut resident,
+            "VmHWM" => &mut resident_peak,
+            "VmSize" => &mut virtual_size,
+            "VmData" => &mut data,
+            _ => continue,
+        };
+        *field = Some(parse_kb(fields.next()?)?);
+    }
+
+    Some(MemoryUsage {
+        resident: resident?,
+        resident_peak: resident_peak?,
+        virtual_size: virtual_size?,
+        data: data?,
+    })
+}
+
+/// Parse a value such as "   1234 kB" into a number of bytes.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+fn parse_kb(value: &str) -> Option<u64> {
+    let mut words = value.split_whitespace();
+    let kb = words.next()?.parse::<u64>().ok()?;
+    if words.next()? != "kB" {
+        return None;
+    }
+    kb.checked_mul(1024)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_parse_status() {
+    let status = b"Name:\tcat\nVmPeak:\t    9000 kB\nVmSize:\t    8000 kB\n\
+        VmHWM:\t     800 kB\nVmRSS:\t     700 kB\nVmData:\t     400 kB\nThreads:\t1\n";
+    assert_eq!(
+        parse_status(status),
+        Some(MemoryUsage {
+            resident: 700 * 1024,
+            resident_peak: 800 * 1024,
+            virtual_size: 8000 * 1024,
+            data: 400 * 1024,
+        })
+    );
+
+    // Kernel threads have no `Vm*` fields.
+    assert_eq!(parse_status(b"Name:\tkthreadd\nThreads:\t1\n"), None);
+    assert_eq!(parse_status(b"VmRSS:\t700\n"), None);
+}
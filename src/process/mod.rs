@@ -3,18 +3,40 @@
 #[cfg(not(target_os = "wasi"))]
 mod chdir;
 mod exit;
+#[cfg(feature = "process")]
+#[cfg(not(target_os = "wasi"))]
+mod fork;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod kcmp;
 #[cfg(not(target_os = "wasi"))]
 mod kill;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod membarrier;
+#[cfg(feature = "process")]
+#[cfg(any(
+    all(feature = "fs", any(target_os = "android", target_os = "linux")),
+    target_os = "ios",
+    target_os = "macos",
+))]
+mod memory_usage;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod perf_event;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod pidfd;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod prctl;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))] // WASI doesn't have [gs]etpriority.
 mod priority;
 #[cfg(target_os = "freebsd")]
 mod procctl;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub mod ptrace;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
 #[cfg(any(
@@ -25,6 +47,14 @@ mod rlimit;
 ))]
 mod sched;
 mod sched_yield;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod seccomp;
+#[cfg(not(target_os = "wasi"))]
+mod sigset;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod spawn;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
 mod uname;
 #[cfg(not(target_os = "wasi"))]
@@ -36,22 +66,52 @@ pub use chdir::chdir;
 pub use chdir::fchdir;
 #[cfg(not(target_os = "wasi"))]
 pub use chdir::getcwd;
+#[cfg(all(feature = "fs", not(any(target_os = "wasi", target_os = "fuchsia"))))]
+pub use chdir::WorkingDirGuard;
 #[cfg(not(target_os = "wasi"))]
 pub use exit::EXIT_SIGNALED_SIGABRT;
 pub use exit::{EXIT_FAILURE, EXIT_SUCCESS};
+#[cfg(feature = "process")]
+#[cfg(not(target_os = "wasi"))]
+pub use fork::{fork, Fork};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use id::Cpuid;
 #[cfg(not(target_os = "wasi"))]
 pub use id::{
-    getegid, geteuid, getgid, getpgid, getpgrp, getpid, getppid, getuid, setsid, Gid, Pid, RawGid,
-    RawNonZeroPid, RawPid, RawUid, Uid,
+    getegid, geteuid, getgid, getpgid, getpgrp, getpid, getppid, getsid, getuid, setpgid, setsid,
+    Gid, Pid, RawGid, RawNonZeroPid, RawPid, RawUid, Uid,
 };
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use kcmp::{kcmp, kcmp_epoll_tfd, KcmpEpollSlot, KcmpType};
 #[cfg(not(target_os = "wasi"))]
-pub use kill::{kill_current_process_group, kill_process, kill_process_group, Signal};
+pub use kill::{
+    kill_current_process_group, kill_process, kill_process_group, killpg, test_kill_process, Signal,
+};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use membarrier::{
     membarrier, membarrier_cpu, membarrier_query, MembarrierCommand, MembarrierQuery,
 };
+#[cfg(feature = "process")]
+#[cfg(any(
+    all(feature = "fs", any(target_os = "android", target_os = "linux")),
+    target_os = "ios",
+    target_os = "macos",
+))]
+pub use memory_usage::{memory_usage, MemoryUsage};
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) use perf_event::perf_event_attr;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use perf_event::{
+    perf_event_disable, perf_event_enable, perf_event_open, perf_event_open_cpu, perf_event_reset,
+    PerfEventAttr, PerfEventAttrFlags, PerfEventFlags, PerfHwId, PerfIocFlags, PerfReadFormat,
+    PerfSampleType, PerfSwId, PerfType,
+};
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use pidfd::{pidfd_open, process_mrelease, PidfdFlags, ProcessMreleaseFlags};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use prctl::*;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
@@ -75,10 +135,25 @@ pub use rlimit::{getrlimit, setrlimit, Resource, Rlimit};
 ))]
 pub use sched::{sched_getaffinity, sched_setaffinity, CpuSet};
 pub use sched_yield::sched_yield;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) use seccomp::seccomp_notif_sizes;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use seccomp::{
+    seccomp_notif_id_valid, seccomp_notif_recv, seccomp_notif_send, seccomp_set_mode_filter,
+    seccomp_set_mode_filter_listener, seccomp_set_mode_strict, SeccompData, SeccompFilterFlags,
+    SeccompNotif, SeccompNotifResp, SeccompNotifRespFlags, SockFilter, SockFprog,
+};
+#[cfg(not(target_os = "wasi"))]
+pub use sigset::SigSet;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub use spawn::Spawn;
 #[cfg(not(target_os = "wasi"))]
 pub use uname::{uname, Uname};
 #[cfg(not(target_os = "wasi"))]
-pub use wait::{wait, waitpid, WaitOptions, WaitStatus};
+pub use wait::{wait, waitpid, WaitOptions, WaitStatus, WaitStatusKind};
 
 #[cfg(not(target_os = "wasi"))]
 #[cfg(feature = "fs")]
@@ -0,0 +1,499 @@
+use crate::fd::{AsFd, BorrowedFd, OwnedFd};
+use crate::process::Pid;
+use crate::{backend, io};
+use core::mem::size_of;
+
+/// `PERF_TYPE_*` constants for use with [`PerfEventAttr::new`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum PerfType {
+    /// `PERF_TYPE_HARDWARE`—A generalized hardware event; the config is a
+    /// [`PerfHwId`].
+    Hardware = 0,
+    /// `PERF_TYPE_SOFTWARE`—A software event provided by the kernel; the
+    /// config is a [`PerfSwId`].
+    Software = 1,
+    /// `PERF_TYPE_TRACEPOINT`—A kernel tracepoint; the config is the
+    /// tracepoint ID from `tracing/events/*/*/id` in tracefs.
+    Tracepoint = 2,
+    /// `PERF_TYPE_HW_CACHE`—A hardware cache event.
+    HwCache = 3,
+    /// `PERF_TYPE_RAW`—A CPU-specific raw event.
+    Raw = 4,
+    /// `PERF_TYPE_BREAKPOINT`—A hardware breakpoint.
+    Breakpoint = 5,
+}
+
+/// `PERF_COUNT_HW_*` constants for use with [`PerfEventAttr::hardware`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u64)]
+#[non_exhaustive]
+pub enum PerfHwId {
+    /// `PERF_COUNT_HW_CPU_CYCLES`
+    CpuCycles = 0,
+    /// `PERF_COUNT_HW_INSTRUCTIONS`
+    Instructions = 1,
+    /// `PERF_COUNT_HW_CACHE_REFERENCES`
+    CacheReferences = 2,
+    /// `PERF_COUNT_HW_CACHE_MISSES`
+    CacheMisses = 3,
+    /// `PERF_COUNT_HW_BRANCH_INSTRUCTIONS`
+    BranchInstructions = 4,
+    /// `PERF_COUNT_HW_BRANCH_MISSES`
+    BranchMisses = 5,
+    /// `PERF_COUNT_HW_BUS_CYCLES`
+    BusCycles = 6,
+    /// `PERF_COUNT_HW_STALLED_CYCLES_FRONTEND`
+    StalledCyclesFrontend = 7,
+    /// `PERF_COUNT_HW_STALLED_CYCLES_BACKEND`
+    StalledCyclesBackend = 8,
+    /// `PERF_COUNT_HW_REF_CPU_CYCLES`
+    RefCpuCycles = 9,
+}
+
+/// `PERF_COUNT_SW_*` constants for use with [`PerfEventAttr::software`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u64)]
+#[non_exhaustive]
+pub enum PerfSwId {
+    /// `PERF_COUNT_SW_CPU_CLOCK`—CPU time, in nanoseconds.
+    CpuClock = 0,
+    /// `PERF_COUNT_SW_TASK_CLOCK`—CPU time of the measured task, in
+    /// nanoseconds.
+    TaskClock = 1,
+    /// `PERF_COUNT_SW_PAGE_FAULTS`
+    PageFaults = 2,
+    /// `PERF_COUNT_SW_CONTEXT_SWITCHES`
+    ContextSwitches = 3,
+    /// `PERF_COUNT_SW_CPU_MIGRATIONS`
+    CpuMigrations = 4,
+    /// `PERF_COUNT_SW_PAGE_FAULTS_MIN`
+    PageFaultsMin = 5,
+    /// `PERF_COUNT_SW_PAGE_FAULTS_MAJ`
+    PageFaultsMaj = 6,
+    /// `PERF_COUNT_SW_ALIGNMENT_FAULTS`
+    AlignmentFaults = 7,
+    /// `PERF_COUNT_SW_EMULATION_FAULTS`
+    EmulationFaults = 8,
+    /// `PERF_COUNT_SW_DUMMY`—A placeholder which counts nothing.
+    Dummy = 9,
+    /// `PERF_COUNT_SW_BPF_OUTPUT`
+    BpfOutput = 10,
+    /// `PERF_COUNT_SW_CGROUP_SWITCHES` (since Linux 5.13)
+    CgroupSwitches = 11,
+}
+
+bitflags::bitflags! {
+    /// `PERF_SAMPLE_*` flags for use with [`PerfEventAttr::sample_type`].
+    #[derive(Default)]
+    pub struct PerfSampleType: u64 {
+        /// `PERF_SAMPLE_IP`
+        const IP = 1 << 0;
+        /// `PERF_SAMPLE_TID`
+        const TID = 1 << 1;
+        /// `PERF_SAMPLE_TIME`
+        const TIME = 1 << 2;
+        /// `PERF_SAMPLE_ADDR`
+        const ADDR = 1 << 3;
+        /// `PERF_SAMPLE_READ`
+        const READ = 1 << 4;
+        /// `PERF_SAMPLE_CALLCHAIN`
+        const CALLCHAIN = 1 << 5;
+        /// `PERF_SAMPLE_ID`
+        const ID = 1 << 6;
+        /// `PERF_SAMPLE_CPU`
+        const CPU = 1 << 7;
+        /// `PERF_SAMPLE_PERIOD`
+        const PERIOD = 1 << 8;
+        /// `PERF_SAMPLE_STREAM_ID`
+        const STREAM_ID = 1 << 9;
+        /// `PERF_SAMPLE_RAW`
+        const RAW = 1 << 10;
+    }
+}
+
+bitflags::bitflags! {
+    /// `PERF_FORMAT_*` flags for use with [`PerfEventAttr::read_format`].
+    ///
+    /// These select what a `read` of the event's file descriptor returns,
+    /// after the `u64` count.
+    #[derive(Default)]
+    pub struct PerfReadFormat: u64 {
+        /// `PERF_FORMAT_TOTAL_TIME_ENABLED`
+        const TOTAL_TIME_ENABLED = 1 << 0;
+        /// `PERF_FORMAT_TOTAL_TIME_RUNNING`
+        const TOTAL_TIME_RUNNING = 1 << 1;
+        /// `PERF_FORMAT_ID`
+        const ID = 1 << 2;
+        /// `PERF_FORMAT_GROUP`
+        const GROUP = 1 << 3;
+        /// `PERF_FORMAT_LOST` (since Linux 6.0)
+        const LOST = 1 << 4;
+    }
+}
+
+bitflags::bitflags! {
+    /// The single-bit fields of `struct perf_event_attr`, for use with
+    /// [`PerfEventAttr::flags`].
+    #[derive(Default)]
+    pub struct PerfEventAttrFlags: u64 {
+        /// `disabled`—Start the event disabled, to be enabled with
+        /// [`perf_event_enable`].
+        const DISABLED = 1 << 0;
+        /// `inherit`—Also count events of children created afterward.
+        const INHERIT = 1 << 1;
+        /// `pinned`—Always keep the event on the PMU.
+        const PINNED = 1 << 2;
+        /// `exclusive`—Be the only group on the PMU while scheduled.
+        const EXCLUSIVE = 1 << 3;
+        /// `exclude_user`—Don't count events in user space.
+        const EXCLUDE_USER = 1 << 4;
+        /// `exclude_kernel`—Don't count events in the kernel.
+        const EXCLUDE_KERNEL = 1 << 5;
+        /// `exclude_hv`—Don't count events in the hypervisor.
+        const EXCLUDE_HV = 1 << 6;
+        /// `exclude_idle`—Don't count events while idle.
+        const EXCLUDE_IDLE = 1 << 7;
+        /// `mmap`—Record `mmap` events in the ring buffer.
+        const MMAP = 1 << 8;
+        /// `comm`—Record `exec` events in the ring buffer.
+        const COMM = 1 << 9;
+        /// `inherit_stat`—Save the counts of inherited events on context
+        /// switches.
+        const INHERIT_STAT = 1 << 11;
+        /// `enable_on_exec`—Enable the event on the next `exec`.
+        const ENABLE_ON_EXEC = 1 << 12;
+        /// `task`—Record fork and exit events in the ring buffer.
+        const TASK = 1 << 13;
+        /// `sample_id_all`—Include the `sample_type` IDs in all records.
+        const SAMPLE_ID_ALL = 1 << 18;
+        /// `exclude_host`—Don't count events in the host, when running
+        /// virtual machines.
+        const EXCLUDE_HOST = 1 << 19;
+        /// `exclude_guest`—Don't count events in guests.
+        const EXCLUDE_GUEST = 1 << 20;
+    }
+}
+
+/// `freq`, which is set by [`PerfEventAttr::sample_freq`] rather than
+/// through [`PerfEventAttrFlags`].
+const PERF_ATTR_FREQ: u64 = 1 << 10;
+
+bitflags::bitflags! {
+    /// `PERF_FLAG_*` flags for use with [`perf_event_open`].
+    pub struct PerfEventFlags: u32 {
+        /// `PERF_FLAG_FD_NO_GROUP`
+        const FD_NO_GROUP = 1 << 0;
+        /// `PERF_FLAG_FD_OUTPUT`
+        const FD_OUTPUT = 1 << 1;
+        /// `PERF_FLAG_FD_CLOEXEC`
+        const FD_CLOEXEC = 1 << 3;
+    }
+}
+
+bitflags::bitflags! {
+    /// `PERF_IOC_FLAG_*` flags for use with [`perf_event_enable`],
+    /// [`perf_event_disable`], and [`perf_event_reset`].
+    pub struct PerfIocFlags: u32 {
+        /// `PERF_IOC_FLAG_GROUP`—Apply to all the events in the group led
+        /// by the given event.
+        const GROUP = 1 << 0;
+    }
+}
+
+/// `struct perf_event_attr`, up to `PERF_ATTR_SIZE_VER5`.
+///
+/// Newer kernels treat the fields we don't define as zero.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+pub(crate) struct perf_event_attr {
+    type_: u32,
+    size: u32,
+    config: u64,
+    sample_period_or_freq: u64,
+    sample_type: u64,
+    read_format: u64,
+    flags: u64,
+    wakeup_events: u32,
+    bp_type: u32,
+    config1: u64,
+    config2: u64,
+    branch_sample_type: u64,
+    sample_regs_user: u64,
+    sample_stack_user: u32,
+    clockid: i32,
+    sample_regs_intr: u64,
+    aux_watermark: u32,
+    sample_max_stack: u16,
+    __reserved_2: u16,
+}
+
+/// `struct perf_event_attr`—The configuration of an event, for use with
+/// [`perf_event_open`].
+///
+/// This is a builder; construct it with [`PerfEventAttr::new`] or one of
+/// the constructors for a specific event type, and then set any other
+/// fields with its methods.
+#[derive(Debug, Copy, Clone)]
+pub struct PerfEventAttr {
+    pub(crate) raw: perf_event_attr,
+}
+
+impl PerfEventAttr {
+    /// Constructs a configuration for the event `config` of type `type_`.
+    pub fn new(type_: PerfType, config: u64) -> Self {
+        Self {
+            raw: perf_event_attr {
+                type_: type_ as u32,
+                size: size_of::<perf_event_attr>() as u32,
+                config,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Constructs a configuration for a generalized hardware event.
+    pub fn hardware(id: PerfHwId) -> Self {
+        Self::new(PerfType::Hardware, id as u64)
+    }
+
+    /// Constructs a configuration for a software event.
+    pub fn software(id: PerfSwId) -> Self {
+        Self::new(PerfType::Software, id as u64)
+    }
+
+    /// Constructs a configuration for the tracepoint with the given ID.
+    pub fn tracepoint(id: u64) -> Self {
+        Self::new(PerfType::Tracepoint, id)
+    }
+
+    /// Sets `config1`, which extends `config` for some event types, such as
+    /// the breakpoint address for [`PerfType::Breakpoint`].
+    pub fn config1(&mut self, config1: u64) -> &mut Self {
+        self.raw.config1 = config1;
+        self
+    }
+
+    /// Sets `config2`, which extends `config1` for some event types, such
+    /// as the breakpoint length for [`PerfType::Breakpoint`].
+    pub fn config2(&mut self, config2: u64) -> &mut Self {
+        self.raw.config2 = config2;
+        self
+    }
+
+    /// Sets `sample_period`, to take a sample every `period` events.
+    ///
+    /// This replaces any frequency set with [`Self::sample_freq`].
+    pub fn sample_period(&mut self, period: u64) -> &mut Self {
+        self.raw.sample_period_or_freq = period;
+        self.raw.flags &= !Self::raw_flags(PERF_ATTR_FREQ);
+        self
+    }
+
+    /// Sets `sample_freq` and `freq`, to take `freq` samples per second,
+    /// with the kernel adjusting the period to match.
+    ///
+    /// This replaces any period set with [`Self::sample_period`].
+    pub fn sample_freq(&mut self, freq: u64) -> &mut Self {
+        self.raw.sample_period_or_freq = freq;
+        self.raw.flags |= Self::raw_flags(PERF_ATTR_FREQ);
+        self
+    }
+
+    /// Sets `sample_type`, the values to record in each sample.
+    pub fn sample_type(&mut self, sample_type: PerfSampleType) -> &mut Self {
+        self.raw.sample_type = sample_type.bits();
+        self
+    }
+
+    /// Sets `read_format`, the values a `read` of the file descriptor
+    /// returns.
+    pub fn read_format(&mut self, read_format: PerfReadFormat) -> &mut Self {
+        self.raw.read_format = read_format.bits();
+        self
+    }
+
+    /// Sets the single-bit fields.
+    pub fn flags(&mut self, flags: PerfEventAttrFlags) -> &mut Self {
+        let freq = self.raw.flags & Self::raw_flags(PERF_ATTR_FREQ);
+        self.raw.flags = Self::raw_flags(flags.bits()) | freq;
+        self
+    }
+
+    /// Sets `wakeup_events`, to wake up readers of the ring buffer after
+    /// every `events` samples.
+    pub fn wakeup_events(&mut self, events: u32) -> &mut Self {
+        self.raw.wakeup_events = events;
+        self
+    }
+
+    /// Convert bit-field bits into the layout of the kernel's `flags` word.
+    ///
+    /// C allocates bit-fields starting from the high bits of their storage
+    /// unit on big-endian platforms.
+    #[inline]
+    const fn raw_flags(bits: u64) -> u64 {
+        if cfg!(target_endian = "big") {
+            bits.reverse_bits()
+        } else {
+            bits
+        }
+    }
+}
+
+/// `perf_event_open(attr, pid, cpu, group_fd, flags)`—Opens a performance
+/// monitoring event.
+///
+/// `pid` is the thread to measure, or the current thread if it's `None`.
+/// `cpu` is the CPU to measure it on, or any CPU if it's `None`. To measure
+/// all threads on a CPU, use [`perf_event_open_cpu`].
+///
+/// `group_fd` is the event which leads the group this event joins; with
+/// `None`, this event leads a new group.
+///
+/// Reading the returned file descriptor reads the event's count, as a
+/// `u64`, followed by the values selected with
+/// [`PerfEventAttr::read_format`].
+///
+/// Access is limited by the `kernel.perf_event_paranoid` sysctl; when it's
+/// too restrictive, this fails with [`io::Errno::ACCESS`] or
+/// [`io::Errno::PERM`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+pub fn perf_event_open(
+    attr: &PerfEventAttr,
+    pid: Option<Pid>,
+    cpu: Option<u32>,
+    group_fd: Option<BorrowedFd<'_>>,
+    flags: PerfEventFlags,
+) -> io::Result<OwnedFd> {
+    let cpu = match cpu {
+        Some(cpu) => cpu as i32,
+        None => -1,
+    };
+    backend::process::syscalls::perf_event_open(
+        &attr.raw,
+        Pid::as_raw(pid) as i32,
+        cpu,
+        group_fd,
+        flags,
+    )
+}
+
+/// `perf_event_open(attr, -1, cpu, group_fd, flags)`—Opens a performance
+/// monitoring event which measures all threads on the CPU `cpu`.
+///
+/// This typically requires the `CAP_PERFMON` or `CAP_SYS_ADMIN`
+/// capability. See [`perf_event_open`] for the other arguments.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+pub fn perf_event_open_cpu(
+    attr: &PerfEventAttr,
+    cpu: u32,
+    group_fd: Option<BorrowedFd<'_>>,
+    flags: PerfEventFlags,
+) -> io::Result<OwnedFd> {
+    backend::process::syscalls::perf_event_open(&attr.raw, -1, cpu as i32, group_fd, flags)
+}
+
+// The `_IO` encoding of "no direction", which differs on a few
+// architectures.
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+)))]
+const IOC_NONE: u32 = 0;
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+))]
+const IOC_NONE: u32 = 1 << 29;
+
+/// `_IO('$', nr)`
+const fn perf_event_io(nr: u32) -> u32 {
+    IOC_NONE | ((b'$' as u32) << 8) | nr
+}
+
+const PERF_EVENT_IOC_ENABLE: u32 = perf_event_io(0);
+const PERF_EVENT_IOC_DISABLE: u32 = perf_event_io(1);
+const PERF_EVENT_IOC_RESET: u32 = perf_event_io(3);
+
+/// `ioctl(fd, PERF_EVENT_IOC_ENABLE, flags)`—Enables an event.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_ENABLE")]
+pub fn perf_event_enable<Fd: AsFd>(fd: Fd, flags: PerfIocFlags) -> io::Result<()> {
+    backend::process::syscalls::perf_event_ioctl(fd.as_fd(), PERF_EVENT_IOC_ENABLE, flags)
+}
+
+/// `ioctl(fd, PERF_EVENT_IOC_DISABLE, flags)`—Disables an event.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_DISABLE")]
+pub fn perf_event_disable<Fd: AsFd>(fd: Fd, flags: PerfIocFlags) -> io::Result<()> {
+    backend::process::syscalls::perf_event_ioctl(fd.as_fd(), PERF_EVENT_IOC_DISABLE, flags)
+}
+
+/// `ioctl(fd, PERF_EVENT_IOC_RESET, flags)`—Resets an event's count to
+/// zero.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/perf_event_open.2.html
+#[inline]
+#[doc(alias = "PERF_EVENT_IOC_RESET")]
+pub fn perf_event_reset<Fd: AsFd>(fd: Fd, flags: PerfIocFlags) -> io::Result<()> {
+    backend::process::syscalls::perf_event_ioctl(fd.as_fd(), PERF_EVENT_IOC_RESET, flags)
+}
+
+#[test]
+fn perf_event_attr_layouts() {
+    use memoffset::offset_of;
+
+    // `PERF_ATTR_SIZE_VER0` through `PERF_ATTR_SIZE_VER5`.
+    assert_eq!(offset_of!(perf_event_attr, config2), 64);
+    assert_eq!(offset_of!(perf_event_attr, branch_sample_type), 72);
+    assert_eq!(offset_of!(perf_event_attr, sample_regs_user), 80);
+    assert_eq!(offset_of!(perf_event_attr, sample_regs_intr), 96);
+    assert_eq!(offset_of!(perf_event_attr, aux_watermark), 104);
+    assert_eq!(size_of::<perf_event_attr>(), 112);
+
+    assert_eq!(PERF_EVENT_IOC_ENABLE & 0xffff, 0x2400);
+    assert_eq!(PERF_EVENT_IOC_RESET & 0xffff, 0x2403);
+
+    let mut attr = PerfEventAttr::software(PerfSwId::CpuClock);
+    attr.sample_freq(1000)
+        .flags(PerfEventAttrFlags::DISABLED | PerfEventAttrFlags::EXCLUDE_HV);
+    assert_eq!(
+        attr.raw.flags,
+        PerfEventAttr::raw_flags(PERF_ATTR_FREQ | (1 << 0) | (1 << 6))
+    );
+    attr.sample_period(10);
+    assert_eq!(
+        attr.raw.flags,
+        PerfEventAttr::raw_flags((1 << 0) | (1 << 6))
+    );
+}
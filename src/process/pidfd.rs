@@ -0,0 +1,54 @@
+use crate::fd::{AsFd, OwnedFd};
+use crate::process::Pid;
+use crate::{backend, io};
+
+bitflags::bitflags! {
+    /// `PIDFD_*` flags for use with [`pidfd_open`].
+    pub struct PidfdFlags: u32 {
+        /// `PIDFD_NONBLOCK` (since Linux 5.10)
+        const NONBLOCK = backend::c::O_NONBLOCK as _;
+    }
+}
+
+bitflags::bitflags! {
+    /// Flags for use with [`process_mrelease`].
+    ///
+    /// No flags are currently defined; this exists for forward
+    /// compatibility.
+    pub struct ProcessMreleaseFlags: u32 {}
+}
+
+/// `pidfd_open(pid, flags)`—Creates a file descriptor referring to the
+/// process with the given pid.
+///
+/// The file descriptor has `O_CLOEXEC` set.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/pidfd_open.2.html
+#[inline]
+pub fn pidfd_open(pid: Pid, flags: PidfdFlags) -> io::Result<OwnedFd> {
+    backend::process::syscalls::pidfd_open(pid, flags)
+}
+
+/// `process_mrelease(pidfd, flags)`—Reclaims the memory of a dying process.
+///
+/// This frees the memory of the process referred to by `pidfd` from the
+/// calling context, rather than waiting for the process to release it as it
+/// exits, which OOM-handling daemons use to recover memory sooner after a
+/// kill. The process must already be dying, for example from a `SIGKILL`,
+/// or this fails with [`io::Errno::INVAL`]. The caller also needs
+/// appropriate privileges over the process, such as being able to send it
+/// signals.
+///
+/// On Linux versions before 5.15, this fails with [`io::Errno::NOSYS`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/process_mrelease.2.html
+#[inline]
+pub fn process_mrelease<Fd: AsFd>(pidfd: Fd, flags: ProcessMreleaseFlags) -> io::Result<()> {
+    backend::process::syscalls::process_mrelease(pidfd.as_fd(), flags)
+}
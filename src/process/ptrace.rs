@@ -0,0 +1,479 @@
+//! Basic `ptrace` operations, for debuggers and tracers.
+//!
+//! A tracee stops whenever it receives a signal, and the tracer observes the
+//! stop with [`waitpid`], inspects or modifies the tracee, and resumes it
+//! with [`cont`] or [`step`].
+//!
+//! # References
+//!  - [Linux]
+//!
+//! [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+//! [`waitpid`]: crate::process::waitpid
+#![allow(unsafe_code)]
+
+use crate::process::{Pid, Signal};
+use crate::{backend, io};
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+use core::mem::{size_of, MaybeUninit};
+
+const PTRACE_TRACEME: u32 = 0;
+const PTRACE_PEEKTEXT: u32 = 1;
+const PTRACE_POKETEXT: u32 = 4;
+const PTRACE_CONT: u32 = 7;
+const PTRACE_SINGLESTEP: u32 = 9;
+const PTRACE_ATTACH: u32 = 16;
+const PTRACE_DETACH: u32 = 17;
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+const PTRACE_GETREGSET: u32 = 0x4204;
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+const PTRACE_SETREGSET: u32 = 0x4205;
+const PTRACE_SEIZE: u32 = 0x4206;
+
+/// `NT_PRSTATUS`, the register set with the general-purpose registers.
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+const NT_PRSTATUS: usize = 1;
+
+bitflags::bitflags! {
+    /// `PTRACE_O_*` flags for use with [`seize`].
+    pub struct PtraceOptions: u32 {
+        /// `PTRACE_O_TRACESYSGOOD`—Report syscall stops with `SIGTRAP | 0x80`.
+        const TRACESYSGOOD = 1 << 0;
+        /// `PTRACE_O_TRACEFORK`—Trace children created with `fork`.
+        const TRACEFORK = 1 << 1;
+        /// `PTRACE_O_TRACEVFORK`—Trace children created with `vfork`.
+        const TRACEVFORK = 1 << 2;
+        /// `PTRACE_O_TRACECLONE`—Trace children created with `clone`.
+        const TRACECLONE = 1 << 3;
+        /// `PTRACE_O_TRACEEXEC`—Stop the tracee at its next `execve`.
+        const TRACEEXEC = 1 << 4;
+        /// `PTRACE_O_TRACEVFORKDONE`—Stop the tracee when a `vfork` child
+        /// releases it.
+        const TRACEVFORKDONE = 1 << 5;
+        /// `PTRACE_O_TRACEEXIT`—Stop the tracee when it exits.
+        const TRACEEXIT = 1 << 6;
+        /// `PTRACE_O_TRACESECCOMP`—Stop the tracee when a seccomp filter
+        /// returns `SECCOMP_RET_TRACE`.
+        const TRACESECCOMP = 1 << 7;
+        /// `PTRACE_O_EXITKILL`—Kill the tracee if the tracer exits.
+        const EXITKILL = 1 << 20;
+        /// `PTRACE_O_SUSPEND_SECCOMP`—Suspend the tracee's seccomp
+        /// filters.
+        const SUSPEND_SECCOMP = 1 << 21;
+    }
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "x86_64")]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub r15: u64,
+    pub r14: u64,
+    pub r13: u64,
+    pub r12: u64,
+    pub rbp: u64,
+    pub rbx: u64,
+    pub r11: u64,
+    pub r10: u64,
+    pub r9: u64,
+    pub r8: u64,
+    pub rax: u64,
+    pub rcx: u64,
+    pub rdx: u64,
+    pub rsi: u64,
+    pub rdi: u64,
+    pub orig_rax: u64,
+    pub rip: u64,
+    pub cs: u64,
+    pub eflags: u64,
+    pub rsp: u64,
+    pub ss: u64,
+    pub fs_base: u64,
+    pub gs_base: u64,
+    pub ds: u64,
+    pub es: u64,
+    pub fs: u64,
+    pub gs: u64,
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "x86")]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub ebx: u32,
+    pub ecx: u32,
+    pub edx: u32,
+    pub esi: u32,
+    pub edi: u32,
+    pub ebp: u32,
+    pub eax: u32,
+    pub xds: u32,
+    pub xes: u32,
+    pub xfs: u32,
+    pub xgs: u32,
+    pub orig_eax: u32,
+    pub eip: u32,
+    pub xcs: u32,
+    pub eflags: u32,
+    pub esp: u32,
+    pub xss: u32,
+}
+
+/// `struct user_pt_regs`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "aarch64")]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct UserRegs {
+    /// `x0` through `x30`.
+    pub regs: [u64; 31],
+    /// The stack pointer.
+    pub sp: u64,
+    /// The program counter.
+    pub pc: u64,
+    /// The processor state.
+    pub pstate: u64,
+}
+
+/// `struct user_regs`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "arm")]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct UserRegs {
+    /// `r0` through `r15`, then `cpsr` and `orig_r0`.
+    pub uregs: [u32; 18],
+}
+
+/// `struct user_regs_struct`—The general-purpose registers of a tracee.
+#[cfg(target_arch = "riscv64")]
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub struct UserRegs {
+    pub pc: u64,
+    pub ra: u64,
+    pub sp: u64,
+    pub gp: u64,
+    pub tp: u64,
+    pub t0: u64,
+    pub t1: u64,
+    pub t2: u64,
+    pub s0: u64,
+    pub s1: u64,
+    pub a0: u64,
+    pub a1: u64,
+    pub a2: u64,
+    pub a3: u64,
+    pub a4: u64,
+    pub a5: u64,
+    pub a6: u64,
+    pub a7: u64,
+    pub s2: u64,
+    pub s3: u64,
+    pub s4: u64,
+    pub s5: u64,
+    pub s6: u64,
+    pub s7: u64,
+    pub s8: u64,
+    pub s9: u64,
+    pub s10: u64,
+    pub s11: u64,
+    pub t3: u64,
+    pub t4: u64,
+    pub t5: u64,
+    pub t6: u64,
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+impl UserRegs {
+    /// Returns the instruction pointer.
+    #[inline]
+    pub const fn instruction_pointer(&self) -> usize {
+        #[cfg(target_arch = "x86_64")]
+        let ip = self.rip;
+        #[cfg(target_arch = "x86")]
+        let ip = self.eip;
+        #[cfg(any(target_arch = "aarch64", target_arch = "riscv64"))]
+        let ip = self.pc;
+        #[cfg(target_arch = "arm")]
+        let ip = self.uregs[15];
+        ip as usize
+    }
+}
+
+/// `struct iovec`, as `PTRACE_GETREGSET` and `PTRACE_SETREGSET` use it.
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[repr(C)]
+struct Iovec {
+    base: *mut UserRegs,
+    len: usize,
+}
+
+/// Convert an optional signal into a `ptrace` data argument.
+#[inline]
+fn signal_data(sig: Option<Signal>) -> usize {
+    sig.map_or(0, |sig| sig as usize)
+}
+
+/// `ptrace(PTRACE_TRACEME, 0, 0, 0)`—Makes the calling process a tracee of
+/// its parent.
+///
+/// This is typically called in a child between `fork` and `execve`; the
+/// `execve` then stops the child with `SIGTRAP`, so that the parent can set
+/// it up before it runs.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_TRACEME")]
+pub fn traceme() -> io::Result<()> {
+    unsafe { backend::process::syscalls::ptrace(PTRACE_TRACEME, None, 0, 0) }
+}
+
+/// `ptrace(PTRACE_ATTACH, pid, 0, 0)`—Attaches to a process, and stops it
+/// with `SIGSTOP`.
+///
+/// The process isn't necessarily stopped yet when this returns; wait for
+/// the stop with [`waitpid`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+/// [`waitpid`]: crate::process::waitpid
+#[inline]
+#[doc(alias = "PTRACE_ATTACH")]
+pub fn attach(pid: Pid) -> io::Result<()> {
+    unsafe { backend::process::syscalls::ptrace(PTRACE_ATTACH, Some(pid), 0, 0) }
+}
+
+/// `ptrace(PTRACE_SEIZE, pid, 0, options)`—Attaches to a process without
+/// stopping it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_SEIZE")]
+pub fn seize(pid: Pid, options: PtraceOptions) -> io::Result<()> {
+    unsafe {
+        backend::process::syscalls::ptrace(PTRACE_SEIZE, Some(pid), 0, options.bits() as usize)
+    }
+}
+
+/// `ptrace(PTRACE_CONT, pid, 0, sig)`—Resumes a stopped tracee.
+///
+/// If `sig` is given, it's delivered to the tracee; this is typically the
+/// signal which stopped it, if the tracee should see it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_CONT")]
+pub fn cont(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    unsafe { backend::process::syscalls::ptrace(PTRACE_CONT, Some(pid), 0, signal_data(sig)) }
+}
+
+/// `ptrace(PTRACE_SINGLESTEP, pid, 0, sig)`—Resumes a stopped tracee for a
+/// single instruction.
+///
+/// The tracee stops again with `SIGTRAP` afterward. If `sig` is given, it's
+/// delivered to the tracee, as with [`cont`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_SINGLESTEP")]
+pub fn step(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    unsafe { backend::process::syscalls::ptrace(PTRACE_SINGLESTEP, Some(pid), 0, signal_data(sig)) }
+}
+
+/// `ptrace(PTRACE_DETACH, pid, 0, sig)`—Detaches from a stopped tracee,
+/// and resumes it.
+///
+/// If `sig` is given, it's delivered to the tracee, as with [`cont`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_DETACH")]
+pub fn detach(pid: Pid, sig: Option<Signal>) -> io::Result<()> {
+    unsafe { backend::process::syscalls::ptrace(PTRACE_DETACH, Some(pid), 0, signal_data(sig)) }
+}
+
+/// `ptrace(PTRACE_GETREGSET, pid, NT_PRSTATUS, &iov)`—Reads the
+/// general-purpose registers of a stopped tracee.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[doc(alias = "PTRACE_GETREGSET")]
+#[doc(alias = "PTRACE_GETREGS")]
+pub fn getregs(pid: Pid) -> io::Result<UserRegs> {
+    let mut regs = MaybeUninit::<UserRegs>::uninit();
+    let mut iov = Iovec {
+        base: regs.as_mut_ptr(),
+        len: size_of::<UserRegs>(),
+    };
+    unsafe {
+        backend::process::syscalls::ptrace(
+            PTRACE_GETREGSET,
+            Some(pid),
+            NT_PRSTATUS,
+            &mut iov as *mut Iovec as usize,
+        )?;
+
+        // The kernel sets `len` to the size it wrote, which is the whole
+        // struct for `NT_PRSTATUS`.
+        if iov.len != size_of::<UserRegs>() {
+            return Err(io::Errno::IO);
+        }
+        Ok(regs.assume_init())
+    }
+}
+
+/// `ptrace(PTRACE_SETREGSET, pid, NT_PRSTATUS, &iov)`—Writes the
+/// general-purpose registers of a stopped tracee.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "riscv64",
+    target_arch = "x86",
+    target_arch = "x86_64",
+))]
+#[doc(alias = "PTRACE_SETREGSET")]
+#[doc(alias = "PTRACE_SETREGS")]
+pub fn setregs(pid: Pid, regs: &UserRegs) -> io::Result<()> {
+    // The kernel only reads through `base`.
+    let mut iov = Iovec {
+        base: regs as *const UserRegs as *mut UserRegs,
+        len: size_of::<UserRegs>(),
+    };
+    unsafe {
+        backend::process::syscalls::ptrace(
+            PTRACE_SETREGSET,
+            Some(pid),
+            NT_PRSTATUS,
+            &mut iov as *mut Iovec as usize,
+        )
+    }
+}
+
+/// `ptrace(PTRACE_PEEKTEXT, pid, addr, &word)`—Reads a word from the
+/// memory of a stopped tracee.
+///
+/// Unlike the libc wrapper, this reports failure only through its result,
+/// so words equal to -1 need no special handling.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[doc(alias = "PTRACE_PEEKTEXT")]
+#[doc(alias = "PTRACE_PEEKDATA")]
+pub fn peektext(pid: Pid, addr: usize) -> io::Result<usize> {
+    let mut word = 0_usize;
+    unsafe {
+        backend::process::syscalls::ptrace(
+            PTRACE_PEEKTEXT,
+            Some(pid),
+            addr,
+            &mut word as *mut usize as usize,
+        )?;
+    }
+    Ok(word)
+}
+
+/// `ptrace(PTRACE_POKETEXT, pid, addr, word)`—Writes a word to the memory
+/// of a stopped tracee.
+///
+/// This can write to read-only mappings of the tracee, such as its code,
+/// which is how debuggers insert breakpoints.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/ptrace.2.html
+#[inline]
+#[doc(alias = "PTRACE_POKETEXT")]
+#[doc(alias = "PTRACE_POKEDATA")]
+pub fn poketext(pid: Pid, addr: usize, word: usize) -> io::Result<()> {
+    unsafe { backend::process::syscalls::ptrace(PTRACE_POKETEXT, Some(pid), addr, word) }
+}
+
+#[test]
+fn user_regs_layouts() {
+    #[cfg(target_arch = "x86_64")]
+    assert_eq!(size_of::<UserRegs>(), 27 * 8);
+    #[cfg(target_arch = "x86")]
+    assert_eq!(size_of::<UserRegs>(), 17 * 4);
+    #[cfg(target_arch = "aarch64")]
+    assert_eq!(size_of::<UserRegs>(), 34 * 8);
+    #[cfg(target_arch = "riscv64")]
+    assert_eq!(size_of::<UserRegs>(), 32 * 8);
+    #[cfg(target_arch = "arm")]
+    assert_eq!(size_of::<UserRegs>(), 18 * 4);
+
+    #[cfg(all(target_arch = "x86_64", target_env = "gnu"))]
+    assert_eq!(size_of::<UserRegs>(), size_of::<libc::user_regs_struct>());
+}
@@ -0,0 +1,482 @@
+use crate::fd::{AsFd, OwnedFd};
+use crate::process::{Pid, RawPid};
+use crate::{backend, io};
+use alloc::vec;
+use core::marker::PhantomData;
+use core::mem::size_of;
+
+bitflags::bitflags! {
+    /// `SECCOMP_FILTER_FLAG_*` flags for use with [`seccomp_set_mode_filter`].
+    pub struct SeccompFilterFlags: u32 {
+        /// `SECCOMP_FILTER_FLAG_TSYNC`—Install the filter on all threads of
+        /// the process, not just the calling thread.
+        const TSYNC = 1 << 0;
+        /// `SECCOMP_FILTER_FLAG_LOG` (since Linux 4.14)—Log all actions
+        /// the filter takes except `SECCOMP_RET_ALLOW`.
+        const LOG = 1 << 1;
+        /// `SECCOMP_FILTER_FLAG_SPEC_ALLOW` (since Linux 4.17)—Don't enable
+        /// mitigations for Speculative Store Bypass.
+        const SPEC_ALLOW = 1 << 2;
+        /// `SECCOMP_FILTER_FLAG_NEW_LISTENER` (since Linux 5.0)—Return a
+        /// file descriptor for receiving `SECCOMP_RET_USER_NOTIF`
+        /// notifications. This is passed by
+        /// [`seccomp_set_mode_filter_listener`]; passing it to
+        /// [`seccomp_set_mode_filter`] fails with [`io::Errno::INVAL`].
+        const NEW_LISTENER = 1 << 3;
+    }
+}
+
+/// `struct sock_filter`—A classic BPF instruction.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct SockFilter {
+    /// `code`—The opcode.
+    pub code: u16,
+    /// `jt`—The jump offset if the condition is true.
+    pub jt: u8,
+    /// `jf`—The jump offset if the condition is false.
+    pub jf: u8,
+    /// `k`—The generic operand.
+    pub k: u32,
+}
+
+/// `struct sock_fprog`—A classic BPF program.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct SockFprog<'a> {
+    len: u16,
+    filter: *const SockFilter,
+    _phantom: PhantomData<&'a [SockFilter]>,
+}
+
+// Safety: A `SockFprog` is just a borrowed slice of `SockFilter`s.
+#[allow(unsafe_code)]
+unsafe impl<'a> Send for SockFprog<'a> {}
+#[allow(unsafe_code)]
+unsafe impl<'a> Sync for SockFprog<'a> {}
+
+impl<'a> SockFprog<'a> {
+    /// Constructs a program from a sequence of instructions.
+    ///
+    /// This fails with [`io::Errno::INVAL`] if there are more instructions
+    /// than fit in the `len` field.
+    #[inline]
+    pub fn new(filter: &'a [SockFilter]) -> io::Result<Self> {
+        if filter.len() > u16::MAX as usize {
+            return Err(io::Errno::INVAL);
+        }
+        Ok(Self {
+            len: filter.len() as u16,
+            filter: filter.as_ptr(),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the instructions of the program.
+    #[inline]
+    pub fn filter(&self) -> &'a [SockFilter] {
+        // Safety: `filter` and `len` come from a slice which lives for `'a`.
+        #[allow(unsafe_code)]
+        unsafe {
+            core::slice::from_raw_parts(self.filter, self.len.into())
+        }
+    }
+}
+
+/// `seccomp(SECCOMP_SET_MODE_STRICT, 0, NULL)`—Restricts the calling thread
+/// to `read`, `write`, `_exit`, and `sigreturn`.
+///
+/// Any other system call kills the thread with `SIGKILL`, so this is rarely
+/// useful outside of code written specifically for it.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[inline]
+#[doc(alias = "seccomp")]
+#[doc(alias = "SECCOMP_SET_MODE_STRICT")]
+pub fn seccomp_set_mode_strict() -> io::Result<()> {
+    backend::process::syscalls::seccomp_set_mode_strict()
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags, prog)`—Installs a seccomp BPF
+/// filter on the calling thread.
+///
+/// Unless the caller has the `CAP_SYS_ADMIN` capability, the thread must
+/// have set `no_new_privs` first, or this fails with [`io::Errno::ACCESS`].
+/// With [`SeccompFilterFlags::TSYNC`], if another thread can't be
+/// synchronized to the same filter, this fails with [`io::Errno::SRCH`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[inline]
+#[doc(alias = "seccomp")]
+#[doc(alias = "SECCOMP_SET_MODE_FILTER")]
+pub fn seccomp_set_mode_filter(prog: &SockFprog<'_>, flags: SeccompFilterFlags) -> io::Result<()> {
+    if flags.contains(SeccompFilterFlags::NEW_LISTENER) {
+        return Err(io::Errno::INVAL);
+    }
+
+    // With `TSYNC`, the kernel returns the ID of a thread it couldn't
+    // synchronize.
+    match backend::process::syscalls::seccomp_set_mode_filter(prog, flags)? {
+        0 => Ok(()),
+        _ => Err(io::Errno::SRCH),
+    }
+}
+
+/// `seccomp(SECCOMP_SET_MODE_FILTER, flags | SECCOMP_FILTER_FLAG_NEW_LISTENER,
+/// prog)`—Installs a seccomp BPF filter on the calling thread, and returns a
+/// file descriptor for its user-space notifications.
+///
+/// This is like [`seccomp_set_mode_filter`], except that it also returns a
+/// file descriptor which receives a notification whenever the filter returns
+/// `SECCOMP_RET_USER_NOTIF`. [`SeccompFilterFlags::TSYNC`] can't be combined
+/// with this.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp.2.html
+#[inline]
+#[doc(alias = "seccomp")]
+#[doc(alias = "SECCOMP_FILTER_FLAG_NEW_LISTENER")]
+pub fn seccomp_set_mode_filter_listener(
+    prog: &SockFprog<'_>,
+    flags: SeccompFilterFlags,
+) -> io::Result<OwnedFd> {
+    backend::process::syscalls::seccomp_set_mode_filter_listener(
+        prog,
+        flags | SeccompFilterFlags::NEW_LISTENER,
+    )
+}
+
+bitflags::bitflags! {
+    /// `SECCOMP_USER_NOTIF_FLAG_*` flags for use with [`SeccompNotifResp`].
+    #[derive(Default)]
+    pub struct SeccompNotifRespFlags: u32 {
+        /// `SECCOMP_USER_NOTIF_FLAG_CONTINUE` (since Linux 5.5)—Let the
+        /// system call run as usual, instead of returning `val` or `error`.
+        const CONTINUE = 1 << 0;
+    }
+}
+
+/// `struct seccomp_data`—The system call that a seccomp filter is
+/// inspecting.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct SeccompData {
+    /// `nr`—The system call number.
+    pub nr: i32,
+    /// `arch`—The `AUDIT_ARCH_*` value for the system call's calling
+    /// convention.
+    pub arch: u32,
+    /// `instruction_pointer`—The address of the system call instruction.
+    pub instruction_pointer: u64,
+    /// `args`—The system call's arguments.
+    pub args: [u64; 6],
+}
+
+/// `struct seccomp_notif`—A system call intercepted by a filter which
+/// returned `SECCOMP_RET_USER_NOTIF`, as returned by [`seccomp_notif_recv`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SeccompNotif {
+    /// `id`—The ID to respond to this notification with.
+    pub id: u64,
+    /// `pid`—The thread that made the system call, or `None` if it isn't
+    /// visible in the listener's PID namespace.
+    pub pid: Option<Pid>,
+    /// `data`—The system call.
+    pub data: SeccompData,
+}
+
+/// `struct seccomp_notif_resp`—A response to a [`SeccompNotif`], for use
+/// with [`seccomp_notif_send`].
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+pub struct SeccompNotifResp {
+    /// `id`—The [`SeccompNotif::id`] of the notification being responded
+    /// to.
+    pub id: u64,
+    /// `val`—The value the system call returns, if `error` is zero.
+    pub val: i64,
+    /// `error`—Zero, or a negated `errno` value for the system call to
+    /// fail with.
+    pub error: i32,
+    /// `flags`
+    pub flags: SeccompNotifRespFlags,
+}
+
+/// `struct seccomp_notif_sizes`
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Default)]
+pub(crate) struct seccomp_notif_sizes {
+    pub(crate) seccomp_notif: u16,
+    pub(crate) seccomp_notif_resp: u16,
+    pub(crate) seccomp_data: u16,
+}
+
+/// `struct seccomp_data`, as the kernel defines it.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct seccomp_data {
+    nr: i32,
+    arch: u32,
+    instruction_pointer: u64,
+    args: [u64; 6],
+}
+
+/// `struct seccomp_notif`, as the kernel defines it.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct seccomp_notif {
+    id: u64,
+    pid: u32,
+    flags: u32,
+    data: seccomp_data,
+}
+
+/// `struct seccomp_notif_resp`, as the kernel defines it.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Copy, Clone)]
+struct seccomp_notif_resp {
+    id: u64,
+    val: i64,
+    error: i32,
+    flags: u32,
+}
+
+// The `_IOC` encoding, which differs on a few architectures.
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+)))]
+const IOC_WRITE: u32 = 1;
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+))]
+const IOC_WRITE: u32 = 4;
+const IOC_READ: u32 = 2;
+#[cfg(not(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+)))]
+const IOC_DIRSHIFT: u32 = 30;
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips64",
+    target_arch = "powerpc",
+    target_arch = "powerpc64",
+))]
+const IOC_DIRSHIFT: u32 = 29;
+
+/// `SECCOMP_IOC_MAGIC`
+const SECCOMP_IOC_MAGIC: u32 = b'!' as u32;
+
+/// `_IOC(dir, SECCOMP_IOC_MAGIC, nr, size)`
+const fn seccomp_ioc(dir: u32, nr: u32, size: usize) -> u32 {
+    (dir << IOC_DIRSHIFT) | ((size as u32) << 16) | (SECCOMP_IOC_MAGIC << 8) | nr
+}
+
+const SECCOMP_IOCTL_NOTIF_RECV: u32 =
+    seccomp_ioc(IOC_READ | IOC_WRITE, 0, size_of::<seccomp_notif>());
+const SECCOMP_IOCTL_NOTIF_SEND: u32 =
+    seccomp_ioc(IOC_READ | IOC_WRITE, 1, size_of::<seccomp_notif_resp>());
+const SECCOMP_IOCTL_NOTIF_ID_VALID: u32 = seccomp_ioc(IOC_WRITE, 2, size_of::<u64>());
+
+/// Returns a zeroed buffer of at least `size` bytes which is also big enough
+/// and suitably aligned for a `T`.
+fn notif_buffer<T>(size: u16) -> vec::Vec<u64> {
+    let len = size_of::<T>().max(size.into());
+    vec![0_u64; (len + size_of::<u64>() - 1) / size_of::<u64>()]
+}
+
+/// `ioctl(listener_fd, SECCOMP_IOCTL_NOTIF_RECV, &notif)`—Waits for a system
+/// call to be intercepted by the filter with the given listener.
+///
+/// `listener_fd` is returned by [`seccomp_set_mode_filter_listener`]. The
+/// buffer for the notification is sized with
+/// `seccomp(SECCOMP_GET_NOTIF_SIZES, ...)`, so this works with kernels whose
+/// `struct seccomp_notif` has grown. If the thread which made the system
+/// call is interrupted before the notification is received, this fails with
+/// [`io::Errno::NOENT`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_RECV")]
+pub fn seccomp_notif_recv<Fd: AsFd>(listener_fd: Fd) -> io::Result<SeccompNotif> {
+    let sizes = backend::process::syscalls::seccomp_get_notif_sizes()?;
+
+    // The kernel requires the buffer to be zeroed.
+    let mut buf = notif_buffer::<seccomp_notif>(sizes.seccomp_notif);
+
+    // Safety: `buf` is zeroed, big enough for the kernel's
+    // `struct seccomp_notif`, and aligned for ours.
+    #[allow(unsafe_code)]
+    let notif = unsafe {
+        backend::process::syscalls::seccomp_notif_ioctl(
+            listener_fd.as_fd(),
+            SECCOMP_IOCTL_NOTIF_RECV,
+            buf.as_mut_ptr().cast(),
+        )?;
+        buf.as_ptr().cast::<seccomp_notif>().read()
+    };
+
+    Ok(SeccompNotif {
+        id: notif.id,
+        // Safety: The kernel gives us a valid PID, or zero.
+        #[allow(unsafe_code)]
+        pid: unsafe { Pid::from_raw(notif.pid as RawPid) },
+        data: SeccompData {
+            nr: notif.data.nr,
+            arch: notif.data.arch,
+            instruction_pointer: notif.data.instruction_pointer,
+            args: notif.data.args,
+        },
+    })
+}
+
+/// `ioctl(listener_fd, SECCOMP_IOCTL_NOTIF_SEND, &resp)`—Responds to a
+/// notification received by [`seccomp_notif_recv`].
+///
+/// This unblocks the thread which made the system call. If that thread has
+/// since been interrupted, or the response's ID is otherwise stale, this
+/// fails with [`io::Errno::NOENT`].
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_SEND")]
+pub fn seccomp_notif_send<Fd: AsFd>(listener_fd: Fd, resp: &SeccompNotifResp) -> io::Result<()> {
+    let sizes = backend::process::syscalls::seccomp_get_notif_sizes()?;
+    let mut buf = notif_buffer::<seccomp_notif_resp>(sizes.seccomp_notif_resp);
+
+    // Safety: `buf` is zeroed, big enough for the kernel's
+    // `struct seccomp_notif_resp`, and aligned for ours.
+    #[allow(unsafe_code)]
+    unsafe {
+        buf.as_mut_ptr()
+            .cast::<seccomp_notif_resp>()
+            .write(seccomp_notif_resp {
+                id: resp.id,
+                val: resp.val,
+                error: resp.error,
+                flags: resp.flags.bits(),
+            });
+        backend::process::syscalls::seccomp_notif_ioctl(
+            listener_fd.as_fd(),
+            SECCOMP_IOCTL_NOTIF_SEND,
+            buf.as_mut_ptr().cast(),
+        )
+    }
+}
+
+/// `ioctl(listener_fd, SECCOMP_IOCTL_NOTIF_ID_VALID, &id)`—Tests whether a
+/// notification is still pending.
+///
+/// Supervisors that read the target's memory, for example through
+/// `/proc/<pid>/mem`, should check this afterward, to make sure the thread
+/// wasn't interrupted and its PID reused in the meantime.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/seccomp_unotify.2.html
+#[doc(alias = "SECCOMP_IOCTL_NOTIF_ID_VALID")]
+pub fn seccomp_notif_id_valid<Fd: AsFd>(listener_fd: Fd, id: u64) -> io::Result<bool> {
+    let mut id = id;
+
+    // Safety: `SECCOMP_IOCTL_NOTIF_ID_VALID` reads a `u64`.
+    #[allow(unsafe_code)]
+    match unsafe {
+        backend::process::syscalls::seccomp_notif_ioctl(
+            listener_fd.as_fd(),
+            SECCOMP_IOCTL_NOTIF_ID_VALID,
+            (&mut id as *mut u64).cast(),
+        )
+    } {
+        Ok(()) => Ok(true),
+        Err(io::Errno::NOENT) => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// Check that our structs match the layouts of the kernel's.
+#[cfg(target_os = "linux")]
+#[test]
+fn sock_fprog_layouts() {
+    use core::mem::align_of;
+    use memoffset::offset_of;
+
+    assert_eq!(size_of::<SockFilter>(), size_of::<libc::sock_filter>());
+    assert_eq!(align_of::<SockFilter>(), align_of::<libc::sock_filter>());
+    assert_eq!(
+        offset_of!(SockFilter, code),
+        offset_of!(libc::sock_filter, code)
+    );
+    assert_eq!(
+        offset_of!(SockFilter, jt),
+        offset_of!(libc::sock_filter, jt)
+    );
+    assert_eq!(
+        offset_of!(SockFilter, jf),
+        offset_of!(libc::sock_filter, jf)
+    );
+    assert_eq!(offset_of!(SockFilter, k), offset_of!(libc::sock_filter, k));
+
+    assert_eq!(size_of::<SockFprog<'_>>(), size_of::<libc::sock_fprog>());
+    assert_eq!(align_of::<SockFprog<'_>>(), align_of::<libc::sock_fprog>());
+    assert_eq!(
+        offset_of!(SockFprog<'_>, len),
+        offset_of!(libc::sock_fprog, len)
+    );
+    assert_eq!(
+        offset_of!(SockFprog<'_>, filter),
+        offset_of!(libc::sock_fprog, filter)
+    );
+
+    assert_eq!(size_of::<seccomp_notif>(), size_of::<libc::seccomp_notif>());
+    assert_eq!(
+        size_of::<seccomp_notif_resp>(),
+        size_of::<libc::seccomp_notif_resp>()
+    );
+    assert_eq!(
+        size_of::<seccomp_notif_sizes>(),
+        size_of::<libc::seccomp_notif_sizes>()
+    );
+    assert_eq!(
+        offset_of!(seccomp_notif, data),
+        offset_of!(libc::seccomp_notif, data)
+    );
+    assert_eq!(
+        offset_of!(seccomp_data, args),
+        offset_of!(libc::seccomp_data, args)
+    );
+    assert_eq!(
+        SECCOMP_IOCTL_NOTIF_RECV as u64,
+        libc::SECCOMP_IOCTL_NOTIF_RECV as u64
+    );
+    assert_eq!(
+        SECCOMP_IOCTL_NOTIF_SEND as u64,
+        libc::SECCOMP_IOCTL_NOTIF_SEND as u64
+    );
+    assert_eq!(
+        SECCOMP_IOCTL_NOTIF_ID_VALID as u64,
+        libc::SECCOMP_IOCTL_NOTIF_ID_VALID as u64
+    );
+}
@@ -0,0 +1,79 @@
+use crate::backend;
+use crate::process::Signal;
+
+/// `SigSet` represents a set of signals, the C `sigset_t` type.
+///
+/// `SigSet`s are used by [`sigprocmask`] and [`sigpending`], for example.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigemptyset.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/sigsetops.3.html
+/// [`sigprocmask`]: crate::thread::sigprocmask
+/// [`sigpending`]: crate::thread::sigpending
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SigSet {
+    sigset: backend::process::types::RawSigSet,
+}
+
+impl SigSet {
+    /// Create a new and empty `SigSet`, with `sigemptyset`.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            sigset: backend::process::types::raw_sig_set_new(),
+        }
+    }
+
+    /// Create a new `SigSet` containing all signals, with `sigfillset`.
+    #[inline]
+    pub fn full() -> Self {
+        let mut set = Self::new();
+        backend::process::sigset::sigfillset(&mut set.sigset);
+        set
+    }
+
+    /// Test to see if a signal is in the `SigSet`, with `sigismember`.
+    #[inline]
+    pub fn is_set(&self, sig: Signal) -> bool {
+        backend::process::sigset::sigismember(&self.sigset, sig)
+    }
+
+    /// Add a signal to the `SigSet`, with `sigaddset`.
+    #[inline]
+    pub fn set(&mut self, sig: Signal) {
+        backend::process::sigset::sigaddset(&mut self.sigset, sig)
+    }
+
+    /// Remove a signal from the `SigSet`, with `sigdelset`.
+    #[inline]
+    pub fn unset(&mut self, sig: Signal) {
+        backend::process::sigset::sigdelset(&mut self.sigset, sig)
+    }
+
+    /// Remove all signals from the `SigSet`, with `sigemptyset`.
+    #[inline]
+    pub fn clear(&mut self) {
+        backend::process::sigset::sigemptyset(&mut self.sigset)
+    }
+
+    #[inline]
+    pub(crate) fn as_raw(&self) -> &backend::process::types::RawSigSet {
+        &self.sigset
+    }
+
+    #[inline]
+    pub(crate) fn from_raw(sigset: backend::process::types::RawSigSet) -> Self {
+        Self { sigset }
+    }
+}
+
+impl Default for SigSet {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
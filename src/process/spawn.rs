@@ -0,0 +1,301 @@
+//! A `posix_spawn`-like builder for starting child processes.
+//!
+//! # Safety
+//!
+//! This forks the current process, and performs only async-signal-safe
+//! operations in the child before calling `execve`.
+#![allow(unsafe_code)]
+
+use crate::fd::{AsFd, AsRawFd, BorrowedFd, FromRawFd, OwnedFd, RawFd};
+use crate::ffi::CString;
+use crate::io::{self, FdFlags, PipeFlags};
+use crate::process::{self, Pid, Signal, WaitOptions};
+use crate::{backend, path};
+use alloc::vec::Vec;
+use core::mem::ManuallyDrop;
+use core::ptr::null;
+
+/// The status used when the child fails to `execve`.
+const EXEC_FAILED: i32 = 127;
+
+/// A file descriptor action to perform in the child.
+#[derive(Debug)]
+enum FdAction<'a> {
+    Dup2(BorrowedFd<'a>, RawFd),
+    Close(RawFd),
+}
+
+/// A builder for spawning a child process, in the style of `posix_spawn`.
+///
+/// The child is created with `fork` (or `clone` with `CLONE_PIDFD`), and only
+/// async-signal-safe operations are performed between the fork and the
+/// `execve`. All memory allocation happens up front in the parent.
+///
+/// Unlike [`std::process::Command`], `Spawn` does not search `PATH` for the
+/// program, and the child's environment contains only the variables added
+/// with [`Spawn::env`].
+///
+/// If anything fails in the child before or during the `execve`, the error is
+/// reported to the parent and returned from [`Spawn::spawn`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # fn main() -> rustix::io::Result<()> {
+/// use rustix::process::Spawn;
+///
+/// let (reader, writer) = rustix::io::pipe()?;
+/// let pid = Spawn::new("/bin/echo")
+///     .arg("hello")
+///     .dup2(&writer, 1)
+///     .spawn()?;
+/// # let _ = (pid, reader);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`std::process::Command`]: https://doc.rust-lang.org/stable/std/process/struct.Command.html
+#[derive(Debug)]
+pub struct Spawn<'a> {
+    program: CString,
+    args: Vec<CString>,
+    envs: Vec<CString>,
+    cwd: Option<BorrowedFd<'a>>,
+    actions: Vec<FdAction<'a>>,
+    setsid: bool,
+    pdeathsig: Option<Signal>,
+    saw_nul: bool,
+}
+
+impl<'a> Spawn<'a> {
+    /// Constructs a new `Spawn` for running the program at `program`.
+    ///
+    /// `program` is also used as the first argument, `argv[0]`.
+    pub fn new<P: path::Arg>(program: P) -> Self {
+        let mut saw_nul = false;
+        let program = to_c_string(program, &mut saw_nul);
+        Self {
+            args: alloc::vec![program.clone()],
+            program,
+            envs: Vec::new(),
+            cwd: None,
+            actions: Vec::new(),
+            setsid: false,
+            pdeathsig: None,
+            saw_nul,
+        }
+    }
+
+    /// Adds an argument to pass to the program.
+    pub fn arg<A: path::Arg>(&mut self, arg: A) -> &mut Self {
+        let arg = to_c_string(arg, &mut self.saw_nul);
+        self.args.push(arg);
+        self
+    }
+
+    /// Adds a `key=value` environment variable for the program.
+    pub fn env<K: path::Arg, V: path::Arg>(&mut self, key: K, value: V) -> &mut Self {
+        let mut var = to_c_string(key, &mut self.saw_nul).into_bytes();
+        var.push(b'=');
+        var.extend_from_slice(to_c_string(value, &mut self.saw_nul).as_bytes());
+        self.envs.push(CString::new(var).unwrap());
+        self
+    }
+
+    /// Sets the working directory of the child with `fchdir(fd)`.
+    pub fn cwd_fd<Fd: AsFd>(&mut self, fd: &'a Fd) -> &mut Self {
+        self.cwd = Some(fd.as_fd());
+        self
+    }
+
+    /// Makes `to` refer to the same file description as `from` in the child,
+    /// with `dup2(from, to)`.
+    ///
+    /// The new file descriptor does not have `FD_CLOEXEC` set, so it's
+    /// inherited by the program, even if `from` and `to` are the same.
+    ///
+    /// File descriptor actions are performed in the order they're added.
+    pub fn dup2<Fd: AsFd>(&mut self, from: &'a Fd, to: RawFd) -> &mut Self {
+        self.actions.push(FdAction::Dup2(from.as_fd(), to));
+        self
+    }
+
+    /// Closes `fd` in the child.
+    ///
+    /// File descriptor actions are performed in the order they're added.
+    pub fn close(&mut self, fd: RawFd) -> &mut Self {
+        self.actions.push(FdAction::Close(fd));
+        self
+    }
+
+    /// Makes the child a session leader, with `setsid()`.
+    pub fn setsid(&mut self) -> &mut Self {
+        self.setsid = true;
+        self
+    }
+
+    /// Sets the signal the child receives when its parent exits, with
+    /// `prctl(PR_SET_PDEATHSIG, sig)`.
+    ///
+    /// If the parent exits before the child sets this, the child exits
+    /// without running the program.
+    pub fn pdeathsig(&mut self, sig: Signal) -> &mut Self {
+        self.pdeathsig = Some(sig);
+        self
+    }
+
+    /// Spawns the child process, returning its pid.
+    ///
+    /// The caller is responsible for waiting for the child, with
+    /// [`waitpid`](crate::process::waitpid).
+    pub fn spawn(&self) -> io::Result<Pid> {
+        self.spawn_impl(false).map(|(pid, _pidfd)| pid)
+    }
+
+    /// Spawns the child process, returning its pid and a pidfd referring to
+    /// it.
+    ///
+    /// This uses `clone` with `CLONE_PIDFD`, so the pidfd refers to the child
+    /// from the moment it's created. The pidfd has `O_CLOEXEC` set.
+    ///
+    /// # References
+    ///  - [Linux]
+    ///
+    /// [Linux]: https://man7.org/linux/man-pages/man2/clone.2.html
+    pub fn spawn_pidfd(&self) -> io::Result<(Pid, OwnedFd)> {
+        self.spawn_impl(true)
+            .map(|(pid, pidfd)| (pid, pidfd.unwrap()))
+    }
+
+    fn spawn_impl(&self, want_pidfd: bool) -> io::Result<(Pid, Option<OwnedFd>)> {
+        if self.saw_nul {
+            return Err(io::Errno::INVAL);
+        }
+
+        // Allocate everything the child needs before forking.
+        let argv = null_terminated(&self.args);
+        let envp = null_terminated(&self.envs);
+        let parent = process::getpid();
+        let (err_reader, err_writer) = io::pipe_with(PipeFlags::CLOEXEC)?;
+
+        let forked = unsafe {
+            if want_pidfd {
+                backend::process::syscalls::fork_pidfd()?.map(|(pid, pidfd)| (pid, Some(pidfd)))
+            } else {
+                backend::process::syscalls::fork()?.map(|pid| (pid, None))
+            }
+        };
+        let (pid, pidfd) = match forked {
+            Some(forked) => forked,
+            None => unsafe { self.child(&argv, &envp, parent, err_writer.as_fd()) },
+        };
+        drop(err_writer);
+
+        // Wait for the child to either `execve`, which closes the `O_CLOEXEC`
+        // pipe, or report an error.
+        let err = match read_child_error(&err_reader) {
+            Ok(None) => return Ok((pid, pidfd)),
+            Ok(Some(err)) | Err(err) => err,
+        };
+
+        // The child failed, or we couldn't tell whether it did, so make sure
+        // it's gone and reap it before returning the error.
+        let _ = process::kill_process(pid, Signal::Kill);
+        let _ = io::retry_on_intr(|| process::waitpid(Some(pid), WaitOptions::empty()));
+        Err(err)
+    }
+
+    /// Runs in the child after the fork. This must only perform
+    /// async-signal-safe operations, and must not allocate.
+    unsafe fn child(
+        &self,
+        argv: &[*const u8],
+        envp: &[*const u8],
+        parent: Pid,
+        err_writer: BorrowedFd<'_>,
+    ) -> ! {
+        let err = match self.child_setup(parent) {
+            Ok(()) => {
+                backend::process::syscalls::execve(&self.program, argv.as_ptr(), envp.as_ptr())
+            }
+            Err(err) => err,
+        };
+        let _ = io::write(err_writer, &err.raw_os_error().to_ne_bytes());
+        backend::process::syscalls::exit_group(EXEC_FAILED)
+    }
+
+    fn child_setup(&self, parent: Pid) -> io::Result<()> {
+        if self.setsid {
+            process::setsid()?;
+        }
+        if let Some(sig) = self.pdeathsig {
+            process::set_parent_process_death_signal(Some(sig))?;
+            // If the parent exited before we set the signal, we won't get it.
+            if process::getppid() != Some(parent) {
+                backend::process::syscalls::exit_group(EXEC_FAILED);
+            }
+        }
+        if let Some(cwd) = self.cwd {
+            process::fchdir(cwd)?;
+        }
+        for action in &self.actions {
+            match *action {
+                FdAction::Dup2(from, to) => {
+                    if from.as_raw_fd() == to {
+                        // `dup2` does nothing if `from` and `to` are the
+                        // same, so just clear `FD_CLOEXEC`.
+                        io::fcntl_setfd(from, FdFlags::empty())?;
+                    } else {
+                        // Safety: `dup2` doesn't require `to` to be open, and
+                        // we don't close it.
+                        let mut to = ManuallyDrop::new(unsafe { OwnedFd::from_raw_fd(to) });
+                        io::dup2(from, &mut to)?;
+                    }
+                }
+                FdAction::Close(fd) => unsafe { io::close(fd) },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Read the error the child reports if it fails before or during `execve`,
+/// or `None` if it closed the pipe without writing anything.
+fn read_child_error(err_reader: &OwnedFd) -> io::Result<Option<io::Errno>> {
+    let mut buf = [0_u8; 4];
+    let mut len = 0;
+    while len < buf.len() {
+        match io::read(err_reader, &mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(io::Errno::INTR) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    match len {
+        0 => Ok(None),
+        4 => Ok(Some(io::Errno::from_raw_os_error(i32::from_ne_bytes(buf)))),
+        // The child writes the errno with a single `write`, so a partial one
+        // means it failed in some way it couldn't report.
+        _ => Err(io::Errno::IO),
+    }
+}
+
+/// Convert `arg` into a `CString`, recording whether it contained a NUL.
+fn to_c_string<A: path::Arg>(arg: A, saw_nul: &mut bool) -> CString {
+    match arg.into_c_str() {
+        Ok(arg) => arg.into_owned(),
+        Err(_) => {
+            *saw_nul = true;
+            CString::default()
+        }
+    }
+}
+
+/// Collect pointers to the given strings into a NUL-terminated array.
+fn null_terminated(strs: &[CString]) -> Vec<*const u8> {
+    let mut ptrs = Vec::with_capacity(strs.len() + 1);
+    ptrs.extend(strs.iter().map(|s| s.as_ptr().cast::<u8>()));
+    ptrs.push(null());
+    ptrs
+}
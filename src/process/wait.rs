@@ -18,6 +18,33 @@ bitflags! {
 #[derive(Debug, Clone, Copy)]
 pub struct WaitStatus(u32);
 
+/// The decoded form of a [`WaitStatus`], as returned by [`WaitStatus::kind`].
+///
+/// Signals are raw signal numbers, because they may be real-time signals,
+/// or `ptrace` stops such as `SIGTRAP | 0x80`, which [`Signal`] can't
+/// represent.
+///
+/// [`Signal`]: crate::process::Signal
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum WaitStatusKind {
+    /// The process exited normally, with the given exit status.
+    Exited(u32),
+
+    /// The process was terminated by a signal.
+    Signaled {
+        /// The number of the signal.
+        signal: u32,
+        /// Whether the process dumped core.
+        core_dumped: bool,
+    },
+
+    /// The process was stopped by the given signal.
+    Stopped(u32),
+
+    /// The process continued from a job control stop.
+    Continued,
+}
+
 impl WaitStatus {
     /// create a `WaitStatus` out of an integer.
     #[inline]
@@ -75,6 +102,33 @@ impl WaitStatus {
             None
         }
     }
+
+    /// Returns whether the process dumped core, if it was terminated by a
+    /// signal.
+    #[inline]
+    pub fn core_dumped(self) -> bool {
+        backend::process::wait::WIFSIGNALED(self.0 as _)
+            && backend::process::wait::WCOREDUMP(self.0 as _)
+    }
+
+    /// Decodes this status into a [`WaitStatusKind`], for use with `match`.
+    #[inline]
+    pub fn kind(self) -> WaitStatusKind {
+        if let Some(status) = self.exit_status() {
+            WaitStatusKind::Exited(status)
+        } else if let Some(signal) = self.terminating_signal() {
+            WaitStatusKind::Signaled {
+                signal,
+                core_dumped: self.core_dumped(),
+            }
+        } else if let Some(signal) = self.stopping_signal() {
+            WaitStatusKind::Stopped(signal)
+        } else {
+            // `waitpid` only reports the four kinds of state changes.
+            debug_assert!(self.continued());
+            WaitStatusKind::Continued
+        }
+    }
 }
 
 /// `waitpid(pid, waitopts)`—Wait for a specific process to change state.
@@ -90,7 +144,9 @@ impl WaitStatus {
 ///
 /// Otherwise, the call will wait for the child process with the given pid.
 ///
-/// On Success, returns the status of the selected process.
+/// On success, returns the pid of the child process whose state changed, and
+/// the status of said process. This is useful when `pid` selects more than
+/// one child.
 ///
 /// If `NOHANG` was specified in the options, and the selected child process
 /// didn't change state, returns `None`.
@@ -103,8 +159,8 @@ impl WaitStatus {
 /// [Linux]: https://man7.org/linux/man-pages/man2/waitpid.2.html
 #[cfg(not(target_os = "wasi"))]
 #[inline]
-pub fn waitpid(pid: Option<Pid>, waitopts: WaitOptions) -> io::Result<Option<WaitStatus>> {
-    Ok(backend::process::syscalls::waitpid(pid, waitopts)?.map(|(_, status)| status))
+pub fn waitpid(pid: Option<Pid>, waitopts: WaitOptions) -> io::Result<Option<(Pid, WaitStatus)>> {
+    backend::process::syscalls::waitpid(pid, waitopts)
 }
 
 /// `wait(waitopts)`—Wait for any of the children of calling process to
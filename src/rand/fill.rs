@@ -0,0 +1,129 @@
+//! Filling buffers with random bytes.
+
+#[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+use crate::rand::GetRandomFlags;
+use crate::{backend, io};
+
+/// Fills all of `buf` with cryptographically secure random bytes.
+///
+/// On Linux, this uses [`getrandom`] without any flags, so it blocks until
+/// the kernel's entropy pool has been initialized, and never returns bytes
+/// from an uninitialized pool. On kernels older than 3.17, which don't have
+/// `getrandom`, and on other platforms, this reads from `/dev/urandom`.
+///
+/// Unlike a single call to `getrandom` or `read`, this retries on
+/// [`io::Errno::INTR`] and short reads until the whole buffer is filled, so
+/// the only errors it returns are ones which retrying wouldn't fix.
+///
+/// [`getrandom`]: crate::rand::getrandom
+pub fn fill_secure(buf: &mut [u8]) -> io::Result<()> {
+    #[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+    {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match backend::rand::syscalls::getrandom(&mut buf[filled..], GetRandomFlags::empty()) {
+                // `getrandom` never reports end of file, so don't loop
+                // forever if something else is going on.
+                Ok(0) => return Err(io::Errno::IO),
+                Ok(nread) => filled += nread,
+                Err(io::Errno::INTR) => continue,
+                Err(io::Errno::NOSYS) => return fill_from_dev_urandom(&mut buf[filled..]),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(linux_raw, all(libc, target_os = "linux"))))]
+    {
+        fill_from_dev_urandom(buf)
+    }
+}
+
+fn fill_from_dev_urandom(buf: &mut [u8]) -> io::Result<()> {
+    let urandom = backend::rand::syscalls::open_dev_urandom()?;
+
+    let mut filled = 0;
+    while filled < buf.len() {
+        match io::read(&urandom, &mut buf[filled..]) {
+            // `/dev/urandom` never reports end of file, so don't loop forever
+            // if something else is going on.
+            Ok(0) => return Err(io::Errno::IO),
+            Ok(nread) => filled += nread,
+            Err(io::Errno::INTR) => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(())
+}
+
+/// Fills all of `buf` with random bytes, as cheaply as possible.
+///
+/// This is for uses of randomness which don't need to hold up against an
+/// adversary, such as seeding hash tables or randomized tests. For anything
+/// security-sensitive, [`fill_secure`] is the conservative choice.
+///
+/// On BSDs and Apple platforms, this uses `arc4random_buf`, which doesn't
+/// need a file descriptor or, usually, a system call. It has no way to report
+/// errors; if it can't seed itself from the kernel, it aborts the process
+/// instead, so this only ever returns `Ok` there. On
+/// Linux, this uses [`getrandom`] with [`GetRandomFlags::INSECURE`], which
+/// doesn't wait for the kernel's entropy pool to be initialized, falling back
+/// to [`fill_secure`] on kernels older than 5.6 which don't support it. On
+/// other platforms, this is the same as [`fill_secure`].
+///
+/// # References
+///  - [FreeBSD `arc4random_buf`]
+///  - [Apple `arc4random_buf`]
+///  - [Linux `getrandom`]
+///
+/// [`getrandom`]: crate::rand::getrandom
+/// [FreeBSD `arc4random_buf`]: https://man.freebsd.org/cgi/man.cgi?query=arc4random_buf&sektion=3
+/// [Apple `arc4random_buf`]: https://developer.apple.com/library/archive/documentation/System/Conceptual/ManPages_iPhoneOS/man3/arc4random_buf.3.html
+/// [Linux `getrandom`]: https://man7.org/linux/man-pages/man2/getrandom.2.html
+pub fn fill_fast(buf: &mut [u8]) -> io::Result<()> {
+    #[cfg(any(linux_raw, all(libc, target_os = "linux")))]
+    {
+        let mut filled = 0;
+        while filled < buf.len() {
+            match backend::rand::syscalls::getrandom(&mut buf[filled..], GetRandomFlags::INSECURE) {
+                Ok(0) => return Err(io::Errno::IO),
+                Ok(nread) => filled += nread,
+                Err(io::Errno::INTR) => continue,
+                Err(io::Errno::INVAL) => return fill_secure(&mut buf[filled..]),
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(all(
+        libc,
+        any(
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "ios",
+            target_os = "macos",
+            target_os = "netbsd",
+            target_os = "openbsd",
+        )
+    ))]
+    {
+        backend::rand::syscalls::arc4random_buf(buf);
+        Ok(())
+    }
+
+    #[cfg(not(any(
+        linux_raw,
+        all(libc, target_os = "linux"),
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "ios",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "openbsd",
+    )))]
+    {
+        fill_secure(buf)
+    }
+}
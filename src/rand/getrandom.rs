@@ -19,3 +19,26 @@ pub use backend::rand::types::GetRandomFlags;
 pub fn getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usize> {
     backend::rand::syscalls::getrandom(buf, flags)
 }
+
+/// `getrandom(buf, flags)`—Reads a sequence of random bytes, using the vDSO
+/// when possible.
+///
+/// This is like [`getrandom`](fn@getrandom), but on Linux 6.11 and newer,
+/// on platforms where the vDSO provides `getrandom`, it generates the bytes
+/// in userspace without making a syscall, which is much faster for filling
+/// many small buffers. Each thread that calls it allocates its own state
+/// for the vDSO, which is freed when the thread exits. Without the vDSO, or
+/// without the `std` feature to hold the per-thread state, this makes the
+/// syscall.
+///
+/// # References
+///  - [Linux]
+///  - [Linux vDSO]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/getrandom.2.html
+/// [Linux vDSO]: https://man7.org/linux/man-pages/man7/vdso.7.html
+#[inline]
+#[doc(alias = "vgetrandom")]
+pub fn vdso_getrandom(buf: &mut [u8], flags: GetRandomFlags) -> io::Result<usize> {
+    backend::rand::syscalls::vdso_getrandom(buf, flags)
+}
@@ -1,7 +1,11 @@
 //! Random-related operations.
 
+#[cfg(not(target_os = "wasi"))]
+mod fill;
 #[cfg(any(linux_raw, all(libc, target_os = "linux")))]
 mod getrandom;
 
+#[cfg(not(target_os = "wasi"))]
+pub use fill::{fill_fast, fill_secure};
 #[cfg(any(linux_raw, all(libc, target_os = "linux")))]
-pub use getrandom::{getrandom, GetRandomFlags};
+pub use getrandom::{getrandom, vdso_getrandom, GetRandomFlags};
@@ -28,12 +28,23 @@ use crate::fs::AtFlags;
 #[cfg(linux_raw)]
 use crate::io;
 #[cfg(linux_raw)]
-use crate::process::Pid;
+use crate::process::{Pid, SigSet, Signal};
+#[cfg(linux_raw)]
+#[cfg(feature = "fs")]
+use alloc::vec::Vec;
 #[cfg(linux_raw)]
 #[cfg(feature = "fs")]
 use backend::fd::AsFd;
 #[cfg(linux_raw)]
+use backend::time::types::Timespec;
+#[cfg(linux_raw)]
+#[cfg(feature = "fs")]
+use core::convert::Infallible;
+#[cfg(linux_raw)]
 use core::ffi::c_void;
+#[cfg(linux_raw)]
+#[cfg(feature = "fs")]
+use core::ptr::null;
 
 #[cfg(linux_raw)]
 #[cfg(target_arch = "x86")]
@@ -221,16 +232,15 @@ pub use backend::runtime::tls::StartupTlsInfo;
 /// [async-signal-safe]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/V2_chap02.html#tag_15_04_03
 #[cfg(linux_raw)]
 pub unsafe fn fork() -> io::Result<Option<Pid>> {
-    backend::runtime::syscalls::fork()
+    backend::process::syscalls::fork()
 }
 
 /// `execveat(dirfd, path.as_c_str(), argv, envp, flags)`—Execute a new
 /// command using the current process.
 ///
-/// # Safety
-///
-/// The `argv` and `envp` pointers must point to NUL-terminated arrays, and
-/// their contents must be pointers to NUL-terminated byte arrays.
+/// `argv` and `envp` are copied into NUL-terminated arrays of pointers, as
+/// expected by the kernel. On success, this function doesn't return, so the
+/// `Ok` type is [`Infallible`].
 ///
 /// # References
 ///  - [Linux]
@@ -240,14 +250,53 @@ pub unsafe fn fork() -> io::Result<Option<Pid>> {
 #[inline]
 #[cfg(feature = "fs")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "fs")))]
-pub unsafe fn execveat<Fd: AsFd>(
+pub fn execveat<Fd: AsFd>(
     dirfd: Fd,
     path: &CStr,
-    argv: *const *const u8,
-    envp: *const *const u8,
+    argv: &[&CStr],
+    envp: &[&CStr],
     flags: AtFlags,
-) -> io::Errno {
-    backend::runtime::syscalls::execveat(dirfd.as_fd(), path, argv, envp, flags)
+) -> io::Result<Infallible> {
+    let argv = null_terminated(argv);
+    let envp = null_terminated(envp);
+    Err(unsafe {
+        backend::runtime::syscalls::execveat(
+            dirfd.as_fd(),
+            path,
+            argv.as_ptr(),
+            envp.as_ptr(),
+            flags,
+        )
+    })
+}
+
+/// `fexecve(fd, argv, envp)`—Execute the program referred to by a file
+/// descriptor using the current process.
+///
+/// This is implemented as `execveat(fd, "", argv, envp, AT_EMPTY_PATH)`.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/fexecve.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/fexecve.3.html
+#[cfg(linux_raw)]
+#[inline]
+#[cfg(feature = "fs")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "fs")))]
+pub fn fexecve<Fd: AsFd>(fd: Fd, argv: &[&CStr], envp: &[&CStr]) -> io::Result<Infallible> {
+    execveat(fd, cstr!(""), argv, envp, AtFlags::EMPTY_PATH)
+}
+
+/// Collect pointers to the given strings into a NUL-terminated array.
+#[cfg(linux_raw)]
+#[cfg(feature = "fs")]
+fn null_terminated(strs: &[&CStr]) -> Vec<*const u8> {
+    let mut ptrs = Vec::with_capacity(strs.len() + 1);
+    ptrs.extend(strs.iter().map(|s| s.as_ptr().cast::<u8>()));
+    ptrs.push(null());
+    ptrs
 }
 
 /// `execve(path.as_c_str(), argv, envp)`—Execute a new command using the
@@ -265,5 +314,113 @@ pub unsafe fn execveat<Fd: AsFd>(
 #[cfg(linux_raw)]
 #[inline]
 pub unsafe fn execve(path: &CStr, argv: *const *const u8, envp: *const *const u8) -> io::Errno {
-    backend::runtime::syscalls::execve(path, argv, envp)
+    backend::process::syscalls::execve(path, argv, envp)
+}
+
+/// `rt_sigaction(sig, new, old)`—Modify and/or query a signal handler.
+///
+/// If `new` is `Some`, it's installed as the new action for `sig`. The
+/// previous action is returned in either case.
+///
+/// On x86-64, this installs rustix's own restorer, which calls
+/// `rt_sigreturn`; on other architectures, the kernel's default restorer is
+/// used.
+///
+/// # Safety
+///
+/// Signal handlers run asynchronously, and must only perform
+/// [async-signal-safe] operations. Changing the action for a signal that
+/// other code in the process depends on, such as a signal used internally by
+/// a threading library, may cause that code to misbehave.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigaction.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigaction.2.html
+/// [async-signal-safe]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/V2_chap02.html#tag_15_04_03
+#[cfg(linux_raw)]
+#[inline]
+#[doc(alias = "rt_sigaction")]
+pub unsafe fn sigaction(sig: Signal, new: Option<&Sigaction>) -> io::Result<Sigaction> {
+    backend::runtime::syscalls::sigaction(sig, new)
 }
+
+#[cfg(linux_raw)]
+pub use backend::runtime::types::{SigHandler, Sigaction, SigactionFlags};
+
+/// `sigaltstack(new, old)`—Modify and/or query the calling thread's
+/// alternate signal stack.
+///
+/// If `new` is `Some`, it's installed as the new alternate signal stack; to
+/// remove the alternate signal stack, pass a `Stack` with
+/// [`StackFlags::DISABLE`]. The previous stack is returned in either case.
+///
+/// The stack must be at least [`MINSIGSTKSZ`] bytes; [`SIGSTKSZ`] is the
+/// recommended size. Signal handlers installed with [`SigactionFlags::ONSTACK`]
+/// run on this stack, which allows them to handle stack overflows.
+///
+/// # Safety
+///
+/// The stack memory must remain valid and unused by anything else for as
+/// long as it's installed.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigaltstack.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigaltstack.2.html
+#[cfg(linux_raw)]
+#[inline]
+pub unsafe fn sigaltstack(new: Option<&Stack>) -> io::Result<Stack> {
+    backend::runtime::syscalls::sigaltstack(new.copied())
+}
+
+#[cfg(linux_raw)]
+pub use backend::runtime::types::{Stack, StackFlags, MINSIGSTKSZ, SIGSTKSZ};
+
+/// `sigwaitinfo(set, info)`—Waits for one of the signals in `set` to be
+/// pending, and consumes it.
+///
+/// The signals in `set` should be blocked, with [`sigprocmask`], so that
+/// they stay pending rather than being delivered to a handler.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigwaitinfo.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigwaitinfo.2.html
+/// [`sigprocmask`]: crate::thread::sigprocmask
+#[cfg(linux_raw)]
+#[inline]
+#[doc(alias = "rt_sigtimedwait")]
+pub fn sigwaitinfo(set: &SigSet) -> io::Result<Siginfo> {
+    backend::runtime::syscalls::sigtimedwait(set, None)
+}
+
+/// `sigtimedwait(set, info, timeout)`—Waits for one of the signals in `set`
+/// to be pending, and consumes it, or times out.
+///
+/// This is like [`sigwaitinfo`], except that if `timeout` is `Some` and
+/// none of the signals become pending within it, this fails with
+/// [`io::Errno::AGAIN`]. A timeout of zero polls for a pending signal
+/// without waiting.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigtimedwait.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigtimedwait.2.html
+#[cfg(linux_raw)]
+#[inline]
+#[doc(alias = "rt_sigtimedwait")]
+pub fn sigtimedwait(set: &SigSet, timeout: Option<&Timespec>) -> io::Result<Siginfo> {
+    backend::runtime::syscalls::sigtimedwait(set, timeout.copied())
+}
+
+#[cfg(linux_raw)]
+pub use backend::runtime::types::Siginfo;
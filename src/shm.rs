@@ -0,0 +1,130 @@
+//! POSIX shared memory objects.
+//!
+//! A shared memory object is a file which is named with a leading `/`, such
+//! as `/my-shm`, and which can be opened by unrelated processes. It starts
+//! out empty; it's typically sized with [`ftruncate`] and then mapped with
+//! [`mmap`] and `MAP_SHARED`. It persists until it's removed with
+//! [`shm_unlink`], or the system shuts down.
+//!
+//! On Linux, shared memory objects are files in `/dev/shm`, and these
+//! functions open and remove them there, as glibc and musl do. On other
+//! platforms, they call the libc functions.
+//!
+//! # References
+//!  - [POSIX]
+//!  - [Linux]
+//!
+//! [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/shm_open.html
+//! [Linux]: https://man7.org/linux/man-pages/man7/shm_overview.7.html
+//! [`ftruncate`]: crate::fs::ftruncate
+//! [`mmap`]: https://docs.rs/rustix/*/rustix/mm/fn.mmap.html
+
+use crate::fd::OwnedFd;
+use crate::fs::{Mode, OFlags};
+use crate::{io, path};
+
+/// `shm_open(name, oflags, mode)`—Opens or creates a shared memory object.
+///
+/// `name` must start with a `/`, and contain no other `/`s. `oflags` must
+/// include [`OFlags::RDONLY`] or [`OFlags::RDWR`], and may include
+/// [`OFlags::CREATE`], [`OFlags::EXCL`], and [`OFlags::TRUNC`]. The file
+/// descriptor is always opened with [`OFlags::CLOEXEC`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/shm_open.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/shm_open.3.html
+#[inline]
+pub fn shm_open<P: path::Arg>(name: P, oflags: OFlags, mode: Mode) -> io::Result<OwnedFd> {
+    name.into_with_c_str(|name| imp::shm_open(name, oflags | OFlags::CLOEXEC, mode))
+}
+
+/// `shm_unlink(name)`—Removes a shared memory object.
+///
+/// Existing file descriptors and mappings of the object remain valid.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/shm_unlink.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/shm_unlink.3.html
+#[inline]
+pub fn shm_unlink<P: path::Arg>(name: P) -> io::Result<()> {
+    name.into_with_c_str(imp::shm_unlink)
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod imp {
+    use crate::fd::OwnedFd;
+    use crate::ffi::CStr;
+    use crate::fs::{cwd, openat, unlinkat, AtFlags, Mode, OFlags};
+    use crate::io;
+
+    /// Where Linux mounts the tmpfs which holds shared memory objects.
+    const SHM_DIR: &[u8] = b"/dev/shm/";
+
+    /// The longest name a tmpfs file can have.
+    const NAME_MAX: usize = 255;
+
+    pub(super) fn shm_open(name: &CStr, oflags: OFlags, mode: Mode) -> io::Result<OwnedFd> {
+        // Like glibc, don't follow symlinks planted in `/dev/shm`.
+        with_shm_path(name, |path| {
+            openat(cwd(), path, oflags | OFlags::NOFOLLOW, mode)
+        })
+    }
+
+    pub(super) fn shm_unlink(name: &CStr) -> io::Result<()> {
+        with_shm_path(name, |path| unlinkat(cwd(), path, AtFlags::empty()))
+    }
+
+    /// Calls `f` with the path in `/dev/shm` for the object named `name`.
+    pub(super) fn with_shm_path<R>(
+        name: &CStr,
+        f: impl FnOnce(&CStr) -> io::Result<R>,
+    ) -> io::Result<R> {
+        let name = match name.to_bytes() {
+            [b'/', name @ ..] => name,
+            _ => return Err(io::Errno::INVAL),
+        };
+        if name.is_empty() || name == b"." || name == b".." || name.contains(&b'/') {
+            return Err(io::Errno::INVAL);
+        }
+        if name.len() > NAME_MAX {
+            return Err(io::Errno::NAMETOOLONG);
+        }
+
+        let mut buf = [0_u8; SHM_DIR.len() + NAME_MAX + 1];
+        let len = SHM_DIR.len() + name.len();
+        buf[..SHM_DIR.len()].copy_from_slice(SHM_DIR);
+        buf[SHM_DIR.len()..len].copy_from_slice(name);
+        f(CStr::from_bytes_with_nul(&buf[..=len]).unwrap())
+    }
+}
+
+#[cfg(not(any(target_os = "android", target_os = "linux")))]
+mod imp {
+    pub(super) use crate::backend::shm::syscalls::{shm_open, shm_unlink};
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_shm_names() {
+    imp::with_shm_path(cstr!("/x"), |path| {
+        assert_eq!(path.to_bytes(), b"/dev/shm/x");
+        Ok(())
+    })
+    .unwrap();
+    for name in &[
+        cstr!(""),
+        cstr!("x"),
+        cstr!("/"),
+        cstr!("/."),
+        cstr!("/.."),
+        cstr!("/a/b"),
+    ] {
+        assert_eq!(imp::with_shm_path(name, |_| Ok(())), Err(io::Errno::INVAL));
+    }
+}
@@ -0,0 +1,460 @@
+//! System V IPC: shared memory segments, semaphore sets, and message
+//! queues.
+//!
+//! Each kind of object is identified by an ID, which [`shmget`],
+//! [`semget`], or [`msgget`] returns for an [`IpcKey`]. Processes which use
+//! the same key get the same object; [`ftok`] derives a key from a file
+//! path. Objects persist until they're removed with `IPC_RMID`, or the system
+//! shuts down.
+//!
+//! On 32-bit platforms, these may need Linux 5.1 or newer, which added the
+//! individual IPC syscalls to x86 and `semtimedop_time64`.
+//!
+//! # References
+//!  - [POSIX]
+//!  - [Linux]
+//!
+//! [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/basedefs/sys_ipc.h.html
+//! [Linux]: https://man7.org/linux/man-pages/man7/sysvipc.7.html
+#![allow(unsafe_code)]
+
+use crate::fs::Mode;
+use crate::{backend, io, path};
+use alloc::vec;
+use bitflags::bitflags;
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::ptr;
+
+/// `key_t`—A key identifying a System V IPC object.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct IpcKey(i32);
+
+impl IpcKey {
+    /// `IPC_PRIVATE`—A key which always creates a new object.
+    pub const PRIVATE: Self = Self(0);
+
+    /// Converts a raw `key_t` into an `IpcKey`.
+    #[inline]
+    pub const fn from_raw(raw: i32) -> Self {
+        Self(raw)
+    }
+
+    /// Converts an `IpcKey` into a raw `key_t`.
+    #[inline]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+}
+
+/// A System V shared memory segment identifier.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct ShmId(i32);
+
+/// A System V semaphore set identifier.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct SemId(i32);
+
+/// A System V message queue identifier.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct MsgId(i32);
+
+macro_rules! raw_id {
+    ($id:ident) => {
+        impl $id {
+            /// Converts a raw ID into this type.
+            #[inline]
+            pub const fn from_raw(raw: i32) -> Self {
+                Self(raw)
+            }
+
+            /// Converts this type into a raw ID.
+            #[inline]
+            pub const fn as_raw(self) -> i32 {
+                self.0
+            }
+        }
+    };
+}
+
+raw_id!(ShmId);
+raw_id!(SemId);
+raw_id!(MsgId);
+
+bitflags! {
+    /// `IPC_*` flags for use with [`semget`] and [`msgget`].
+    pub struct IpcFlags: u32 {
+        /// `IPC_CREAT`
+        const CREATE = 0o1000;
+        /// `IPC_EXCL`
+        const EXCL = 0o2000;
+    }
+}
+
+bitflags! {
+    /// `IPC_*` and `SHM_*` flags for use with [`shmget`].
+    pub struct ShmFlags: u32 {
+        /// `IPC_CREAT`
+        const CREATE = 0o1000;
+        /// `IPC_EXCL`
+        const EXCL = 0o2000;
+        /// `SHM_HUGETLB`
+        const HUGETLB = 0o4000;
+        /// `SHM_NORESERVE`
+        const NORESERVE = 0o10000;
+    }
+}
+
+bitflags! {
+    /// `SHM_*` flags for use with [`shmat`].
+    pub struct ShmatFlags: u32 {
+        /// `SHM_RDONLY`
+        const RDONLY = 0o10000;
+        /// `SHM_EXEC`
+        const EXEC = 0o100000;
+    }
+}
+
+bitflags! {
+    /// `IPC_NOWAIT` and `SEM_UNDO` flags for use with [`Sembuf`].
+    #[repr(transparent)]
+    pub struct SemopFlags: i16 {
+        /// `IPC_NOWAIT`
+        const NOWAIT = 0o4000;
+        /// `SEM_UNDO`
+        const UNDO = 0x1000;
+    }
+}
+
+bitflags! {
+    /// `IPC_NOWAIT` and `MSG_*` flags for use with [`msgsnd`] and
+    /// [`msgrcv`].
+    pub struct MsgFlags: u32 {
+        /// `IPC_NOWAIT`
+        const NOWAIT = 0o4000;
+        /// `MSG_NOERROR`
+        const NOERROR = 0o10000;
+        /// `MSG_EXCEPT`
+        const EXCEPT = 0o20000;
+        /// `MSG_COPY`
+        const COPY = 0o40000;
+    }
+}
+
+/// `struct sembuf`—An operation on one semaphore, for use with [`semop`].
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Sembuf {
+    /// `sem_num`—The index of the semaphore in the set.
+    pub sem_num: u16,
+    /// `sem_op`—A positive value to add, a negative value to wait for and
+    /// subtract, or zero to wait for the semaphore to become zero.
+    pub sem_op: i16,
+    /// `sem_flg`
+    pub sem_flg: SemopFlags,
+}
+
+/// `IPC_RMID`
+const IPC_RMID: i32 = 0;
+/// `GETVAL`
+const GETVAL: i32 = 12;
+/// `SETVAL`
+const SETVAL: i32 = 16;
+
+/// `ftok(path, proj_id)`—Derives an [`IpcKey`] from a file path.
+///
+/// This combines the low bits of the file's inode and device numbers with
+/// `proj_id`, as glibc and musl do, so keys for different files may
+/// collide.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/ftok.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/ftok.3.html
+pub fn ftok<P: path::Arg>(path: P, proj_id: u8) -> io::Result<IpcKey> {
+    let stat = crate::fs::statat(crate::fs::cwd(), path, crate::fs::AtFlags::empty())?;
+    let key = (stat.st_ino as u32 & 0xffff)
+        | ((stat.st_dev as u32 & 0xff) << 16)
+        | (u32::from(proj_id) << 24);
+    Ok(IpcKey(key as i32))
+}
+
+/// `shmget(key, size, flags | mode)`—Gets or creates a shared memory
+/// segment.
+///
+/// `mode` sets the permissions of a newly created segment.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/shmget.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmget.2.html
+#[inline]
+pub fn shmget(key: IpcKey, size: usize, flags: ShmFlags, mode: Mode) -> io::Result<ShmId> {
+    let id = backend::sysvipc::syscalls::shmget(key.0, size, flags.bits() | mode_bits(mode))?;
+    Ok(ShmId(id))
+}
+
+/// `shmat(id, NULL, flags)`—Attaches a shared memory segment.
+///
+/// This maps the whole segment at an address the kernel chooses, and
+/// returns that address. Detach it with [`shmdt`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/shmat.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmat.2.html
+#[inline]
+pub fn shmat(id: ShmId, flags: ShmatFlags) -> io::Result<*mut c_void> {
+    // Safety: With a null address, the kernel picks an unused range, so
+    // this doesn't replace any existing mappings.
+    unsafe { backend::sysvipc::syscalls::shmat(id.0, ptr::null(), flags.bits()) }
+}
+
+/// `shmdt(addr)`—Detaches a shared memory segment.
+///
+/// # Safety
+///
+/// `addr` must be an address returned by [`shmat`], and nothing may use the
+/// memory there afterward.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/shmdt.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmdt.2.html
+#[inline]
+pub unsafe fn shmdt(addr: *const c_void) -> io::Result<()> {
+    backend::sysvipc::syscalls::shmdt(addr)
+}
+
+/// `shmctl(id, IPC_RMID, NULL)`—Marks a shared memory segment to be
+/// removed.
+///
+/// The segment is destroyed once the last process detaches it.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/shmctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/shmctl.2.html
+#[inline]
+#[doc(alias = "shmctl")]
+pub fn shmctl_rmid(id: ShmId) -> io::Result<()> {
+    // Safety: `IPC_RMID` doesn't use the buffer.
+    unsafe { backend::sysvipc::syscalls::shmctl(id.0, IPC_RMID, ptr::null_mut()) }
+}
+
+/// `semget(key, nsems, flags | mode)`—Gets or creates a semaphore set.
+///
+/// `mode` sets the permissions of a newly created set. The semaphores of a
+/// new set start at zero.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/semget.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semget.2.html
+#[inline]
+pub fn semget(key: IpcKey, nsems: u16, flags: IpcFlags, mode: Mode) -> io::Result<SemId> {
+    let id = backend::sysvipc::syscalls::semget(
+        key.0,
+        i32::from(nsems),
+        flags.bits() | mode_bits(mode),
+    )?;
+    Ok(SemId(id))
+}
+
+/// `semop(id, sops, sops.len())`—Performs operations on a semaphore set.
+///
+/// The operations are performed atomically: this blocks until all of them
+/// can be performed, unless one of them has [`SemopFlags::NOWAIT`], in
+/// which case it fails with [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/semop.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semop.2.html
+#[inline]
+pub fn semop(id: SemId, sops: &[Sembuf]) -> io::Result<()> {
+    backend::sysvipc::syscalls::semop(id.0, sops)
+}
+
+/// `semctl(id, semnum, GETVAL)`—Returns the value of a semaphore.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+pub fn semctl_getval(id: SemId, semnum: u16) -> io::Result<i32> {
+    // Safety: `GETVAL` doesn't use the argument.
+    unsafe { backend::sysvipc::syscalls::semctl(id.0, i32::from(semnum), GETVAL, 0) }
+}
+
+/// `semctl(id, semnum, SETVAL, value)`—Sets the value of a semaphore.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+pub fn semctl_setval(id: SemId, semnum: u16, value: i32) -> io::Result<()> {
+    // Safety: `SETVAL` uses the argument as an `int`.
+    unsafe {
+        backend::sysvipc::syscalls::semctl(id.0, i32::from(semnum), SETVAL, value as usize)?;
+    }
+    Ok(())
+}
+
+/// `semctl(id, 0, IPC_RMID)`—Removes a semaphore set.
+///
+/// Processes blocked in [`semop`] on the set fail with
+/// [`io::Errno::IDRM`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/semctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/semctl.2.html
+#[inline]
+#[doc(alias = "semctl")]
+pub fn semctl_rmid(id: SemId) -> io::Result<()> {
+    // Safety: `IPC_RMID` doesn't use the argument.
+    unsafe {
+        backend::sysvipc::syscalls::semctl(id.0, 0, IPC_RMID, 0)?;
+    }
+    Ok(())
+}
+
+/// `msgget(key, flags | mode)`—Gets or creates a message queue.
+///
+/// `mode` sets the permissions of a newly created queue.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/msgget.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgget.2.html
+#[inline]
+pub fn msgget(key: IpcKey, flags: IpcFlags, mode: Mode) -> io::Result<MsgId> {
+    let id = backend::sysvipc::syscalls::msgget(key.0, flags.bits() | mode_bits(mode))?;
+    Ok(MsgId(id))
+}
+
+/// `msgsnd(id, &{mtype, msg}, msg.len(), flags)`—Sends a message to a
+/// message queue.
+///
+/// `mtype` must be positive. This blocks while the queue is full, unless
+/// `flags` has [`MsgFlags::NOWAIT`], in which case it fails with
+/// [`io::Errno::AGAIN`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/msgsnd.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgsnd.2.html
+pub fn msgsnd(id: MsgId, mtype: isize, msg: &[u8], flags: MsgFlags) -> io::Result<()> {
+    // The kernel reads a `long` type followed by the message text, so copy
+    // them into a `long`-aligned buffer.
+    let mut buf = vec![0_isize; 1 + (msg.len() + size_of::<isize>() - 1) / size_of::<isize>()];
+    buf[0] = mtype;
+    // Safety: `buf` has room for `msg` after the type.
+    unsafe {
+        ptr::copy_nonoverlapping(msg.as_ptr(), buf[1..].as_mut_ptr().cast(), msg.len());
+        backend::sysvipc::syscalls::msgsnd(id.0, buf.as_ptr().cast(), msg.len(), flags.bits())
+    }
+}
+
+/// `msgrcv(id, &{mtype, buf}, buf.len(), mtype, flags)`—Receives a message
+/// from a message queue.
+///
+/// If `mtype` is zero, this takes the first message in the queue. If it's
+/// positive, it takes the first message of that type, or, with
+/// [`MsgFlags::EXCEPT`], of any other type. If it's negative, it takes the
+/// first message with the lowest type less than or equal to its absolute
+/// value. This returns the length and type of the message.
+///
+/// This fails with [`io::Errno::TOOBIG`] if the message is longer than
+/// `buf`, unless `flags` has [`MsgFlags::NOERROR`], in which case the
+/// message is truncated.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/msgrcv.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgrcv.2.html
+pub fn msgrcv(
+    id: MsgId,
+    buf: &mut [u8],
+    mtype: isize,
+    flags: MsgFlags,
+) -> io::Result<(usize, isize)> {
+    let mut raw = vec![0_isize; 1 + (buf.len() + size_of::<isize>() - 1) / size_of::<isize>()];
+    // Safety: `raw` has room for `buf.len()` bytes after the type, and we
+    // only copy out what the kernel wrote.
+    unsafe {
+        let len = backend::sysvipc::syscalls::msgrcv(
+            id.0,
+            raw.as_mut_ptr().cast(),
+            buf.len(),
+            mtype,
+            flags.bits(),
+        )?;
+        ptr::copy_nonoverlapping(raw[1..].as_ptr().cast(), buf.as_mut_ptr(), len);
+        Ok((len, raw[0]))
+    }
+}
+
+/// `msgctl(id, IPC_RMID, NULL)`—Removes a message queue.
+///
+/// Processes blocked in [`msgsnd`] or [`msgrcv`] on the queue fail with
+/// [`io::Errno::IDRM`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/msgctl.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/msgctl.2.html
+#[inline]
+#[doc(alias = "msgctl")]
+pub fn msgctl_rmid(id: MsgId) -> io::Result<()> {
+    // Safety: `IPC_RMID` doesn't use the buffer.
+    unsafe { backend::sysvipc::syscalls::msgctl(id.0, IPC_RMID, ptr::null_mut()) }
+}
+
+/// The permission bits of `mode`, to combine with the `*get` flags.
+#[inline]
+fn mode_bits(mode: Mode) -> u32 {
+    mode.bits() as u32 & 0o777
+}
+
+#[test]
+fn sembuf_layout() {
+    assert_eq!(size_of::<Sembuf>(), 6);
+}
@@ -785,6 +785,8 @@ pub use tc::{
     tcsetpgrp, tcsetwinsize, Action, OptionalActions, QueueSelector, Speed, Tcflag, Termios,
     Winsize,
 };
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+pub use tty::ctermid;
 #[cfg(not(windows))]
 pub use tty::isatty;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
@@ -44,12 +44,16 @@ pub fn tcgetwinsize<Fd: AsFd>(fd: Fd) -> io::Result<Winsize> {
 ///
 /// Also known as the `TIOCGPGRP` operation with `ioctl`.
 ///
+/// This fails with [`io::Errno::NOTTY`] if `fd` isn't the controlling
+/// terminal of the calling process.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcgetpgrp.html
 /// [Linux]: https://man7.org/linux/man-pages/man3/tcgetpgrp.3.html
+/// [`io::Errno::NOTTY`]: crate::io::Errno::NOTTY
 #[cfg(not(any(windows, target_os = "wasi")))]
 #[inline]
 #[doc(alias = "TIOCGPGRP")]
@@ -61,6 +65,11 @@ pub fn tcgetpgrp<Fd: AsFd>(fd: Fd) -> io::Result<Pid> {
 ///
 /// Also known as the `TIOCSPGRP` operation with `ioctl`.
 ///
+/// `pid` must be a process group in the same session as the calling
+/// process, and `fd` must be that session's controlling terminal. If the
+/// caller is in a background process group, it's sent `SIGTTOU`, unless
+/// that's ignored or blocked.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -169,12 +178,18 @@ pub fn tcflow<Fd: AsFd>(fd: Fd, action: Action) -> io::Result<()> {
 /// `tcgetsid(fd)`—Return the session ID of the current session with `fd` as
 /// its controlling terminal.
 ///
+/// Also known as the `TIOCGSID` operation with `ioctl`.
+///
+/// This fails with [`io::Errno::NOTTY`] if `fd` isn't a terminal, or if it
+/// isn't the controlling terminal of the calling process' session.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
 ///
 /// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/tcgetsid.html
 /// [Linux]: https://man7.org/linux/man-pages/man3/tcgetsid.3.html
+/// [`io::Errno::NOTTY`]: crate::io::Errno::NOTTY
 #[inline]
 #[doc(alias = "TIOCGSID")]
 pub fn tcgetsid<Fd: AsFd>(fd: Fd) -> io::Result<Pid> {
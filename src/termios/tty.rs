@@ -1,6 +1,8 @@
 //! Functions which operate on file descriptors which might be terminals.
 
 use crate::backend;
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+use crate::ffi::CString;
 #[cfg(any(
     all(linux_raw, feature = "procfs"),
     all(libc, not(any(target_os = "fuchsia", target_os = "wasi"))),
@@ -11,10 +13,7 @@ use backend::fd::AsFd;
     all(linux_raw, feature = "procfs"),
     all(libc, not(any(target_os = "fuchsia", target_os = "wasi"))),
 ))]
-use {
-    crate::ffi::CString, crate::path::SMALL_PATH_BUFFER_SIZE, alloc::vec::Vec,
-    backend::fd::BorrowedFd,
-};
+use {crate::path::SMALL_PATH_BUFFER_SIZE, alloc::vec::Vec, backend::fd::BorrowedFd};
 
 /// `isatty(fd)`—Tests whether a file descriptor refers to a terminal.
 ///
@@ -29,6 +28,25 @@ pub fn isatty<Fd: AsFd>(fd: Fd) -> bool {
     backend::termios::syscalls::isatty(fd.as_fd())
 }
 
+/// `ctermid(buf)`—Returns a path that refers to the controlling terminal of
+/// the current process.
+///
+/// On Linux, this is always `/dev/tty`. Opening it fails with
+/// [`io::Errno::NXIO`] if the process has no controlling terminal.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/ctermid.html
+/// [Linux]: https://man7.org/linux/man-pages/man3/ctermid.3.html
+/// [`io::Errno::NXIO`]: crate::io::Errno::NXIO
+#[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))]
+#[inline]
+pub fn ctermid() -> CString {
+    backend::termios::syscalls::ctermid()
+}
+
 /// `ttyname_r(fd)`
 ///
 /// If `reuse` is non-empty, reuse its buffer to store the result if possible.
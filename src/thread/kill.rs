@@ -0,0 +1,34 @@
+use crate::process::{self, Pid, Signal};
+use crate::{backend, io};
+
+/// `tgkill(tgid, tid, sig)`—Sends a signal to a thread in a thread group.
+///
+/// The thread is identified by both its thread group ID, which is its
+/// process ID, and its thread ID, so a signal can't be delivered to an
+/// unrelated thread that happens to reuse the thread ID after the intended
+/// thread exits.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/tgkill.2.html
+#[inline]
+pub fn tgkill(tgid: Pid, tid: Pid, sig: Signal) -> io::Result<()> {
+    backend::thread::syscalls::tgkill(tgid, tid, sig)
+}
+
+/// `tgkill(getpid(), tid, sig)`—Sends a signal to a thread in the current
+/// process.
+///
+/// This is the race-free replacement for the deprecated `tkill`.
+///
+/// # References
+///  - [Linux]
+///
+/// [Linux]: https://man7.org/linux/man-pages/man2/tgkill.2.html
+#[inline]
+#[doc(alias = "tgkill")]
+#[doc(alias = "tkill")]
+pub fn kill_thread(tid: Pid, sig: Signal) -> io::Result<()> {
+    backend::thread::syscalls::tgkill(process::getpid(), tid, sig)
+}
@@ -7,11 +7,15 @@ mod futex;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod id;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+mod kill;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod libcap;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod prctl;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod setns;
+#[cfg(not(target_os = "wasi"))]
+mod sigmask;
 
 #[cfg(not(any(
     target_os = "dragonfly",
@@ -32,8 +36,12 @@ pub use futex::{futex, FutexFlags, FutexOperation};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use id::{gettid, set_thread_gid, set_thread_uid};
 #[cfg(any(target_os = "android", target_os = "linux"))]
+pub use kill::{kill_thread, tgkill};
+#[cfg(any(target_os = "android", target_os = "linux"))]
 pub use libcap::{capabilities, set_capabilities, CapabilityFlags, CapabilitySets};
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use prctl::*;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 pub use setns::*;
+#[cfg(not(target_os = "wasi"))]
+pub use sigmask::{sigpending, sigprocmask, SigmaskHow};
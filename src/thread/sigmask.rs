@@ -0,0 +1,38 @@
+use crate::process::SigSet;
+use crate::{backend, io};
+
+pub use backend::process::types::SigmaskHow;
+
+/// `pthread_sigmask(how, set, oldset)`—Adjusts the calling thread's signal
+/// mask, returning the previous mask.
+///
+/// On Linux, this is implemented with `rt_sigprocmask`, which only affects
+/// the calling thread.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/pthread_sigmask.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigprocmask.2.html
+#[inline]
+#[doc(alias = "pthread_sigmask")]
+#[doc(alias = "rt_sigprocmask")]
+pub fn sigprocmask(how: SigmaskHow, set: &SigSet) -> io::Result<SigSet> {
+    backend::thread::syscalls::sigprocmask(how, set.as_raw()).map(SigSet::from_raw)
+}
+
+/// `sigpending()`—Returns the set of signals that are pending for delivery
+/// to the calling thread.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/sigpending.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/sigpending.2.html
+#[inline]
+#[doc(alias = "rt_sigpending")]
+pub fn sigpending() -> io::Result<SigSet> {
+    backend::thread::syscalls::sigpending().map(SigSet::from_raw)
+}
@@ -8,6 +8,11 @@ pub use backend::time::types::{ClockId, DynamicClockId};
 
 /// `clock_getres(id)`—Returns the resolution of a clock.
 ///
+/// This is the granularity the clock advertises, which doesn't necessarily
+/// match how often its value actually changes. For example, Linux reports
+/// one nanosecond for all of its high-resolution clocks, even when the
+/// underlying hardware ticks less often than that.
+///
 /// # References
 ///  - [POSIX]
 ///  - [Linux]
@@ -1,6 +1,9 @@
 //! Time-related operations.
 
 mod clock;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+mod timer;
 #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
 #[cfg(feature = "time")]
 mod timerfd;
@@ -12,6 +15,12 @@ pub use clock::clock_getres;
 #[cfg(not(target_os = "wasi"))]
 pub use clock::{clock_gettime, clock_gettime_dynamic, ClockId, DynamicClockId};
 pub use clock::{Nsecs, Secs, Timespec};
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "time")]
+pub use timer::{
+    timer_create, timer_delete, timer_getoverrun, timer_gettime, timer_settime, Sigevent, TimerId,
+    TimerSettimeFlags,
+};
 #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
 #[cfg(feature = "time")]
 pub use timerfd::{
@@ -0,0 +1,119 @@
+//! POSIX per-process timers, created with [`timer_create`], which notify
+//! the process or one of its threads with a signal when they expire.
+
+use crate::process::Pid;
+use crate::time::{ClockId, Itimerspec};
+use crate::{backend, io};
+
+pub use backend::time::types::{TimerId, TimerSettimeFlags};
+
+/// `struct sigevent`—How a timer notifies the process when it expires, for
+/// use with [`timer_create`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Sigevent {
+    /// `SIGEV_NONE`—Don't notify. The timer can still be queried with
+    /// [`timer_gettime`].
+    #[doc(alias = "SIGEV_NONE")]
+    None,
+
+    /// `SIGEV_SIGNAL`—Send `signal` to the process, with `value` as its
+    /// `sigval`.
+    ///
+    /// `signal` is a raw signal number, so that realtime signals such as
+    /// `SIGRTMIN + n` can be used.
+    #[doc(alias = "SIGEV_SIGNAL")]
+    Signal {
+        /// The signal number to send.
+        signal: i32,
+        /// The value reported in the `si_value` field of the signal's
+        /// `siginfo_t`.
+        value: isize,
+    },
+
+    /// `SIGEV_THREAD_ID`—Send `signal` to the thread `tid`, with `value` as
+    /// its `sigval`.
+    #[doc(alias = "SIGEV_THREAD_ID")]
+    ThreadId {
+        /// The signal number to send.
+        signal: i32,
+        /// The value reported in the `si_value` field of the signal's
+        /// `siginfo_t`.
+        value: isize,
+        /// The thread to send it to, which must be in the calling process.
+        tid: Pid,
+    },
+}
+
+/// `timer_create(clockid, sevp)`—Creates a POSIX per-process timer.
+///
+/// The timer starts out disarmed; arm it with [`timer_settime`].
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/timer_create.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/timer_create.2.html
+#[inline]
+pub fn timer_create(clockid: ClockId, sevp: Sigevent) -> io::Result<TimerId> {
+    backend::time::syscalls::timer_create(clockid, &sevp)
+}
+
+/// `timer_settime(timerid, flags, new_value, old_value)`—Arms or disarms a
+/// timer, and returns its previous setting.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/timer_settime.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/timer_settime.2.html
+#[inline]
+pub fn timer_settime(
+    timerid: TimerId,
+    flags: TimerSettimeFlags,
+    new_value: &Itimerspec,
+) -> io::Result<Itimerspec> {
+    backend::time::syscalls::timer_settime(timerid, flags, new_value)
+}
+
+/// `timer_gettime(timerid)`—Queries the time until a timer next expires,
+/// and its interval.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/timer_gettime.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/timer_settime.2.html
+#[inline]
+pub fn timer_gettime(timerid: TimerId) -> io::Result<Itimerspec> {
+    backend::time::syscalls::timer_gettime(timerid)
+}
+
+/// `timer_getoverrun(timerid)`—Returns the number of expirations of a timer
+/// which were missed because its last signal was still pending.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/timer_getoverrun.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/timer_getoverrun.2.html
+#[inline]
+pub fn timer_getoverrun(timerid: TimerId) -> io::Result<u32> {
+    backend::time::syscalls::timer_getoverrun(timerid)
+}
+
+/// `timer_delete(timerid)`—Deletes a timer.
+///
+/// # References
+///  - [POSIX]
+///  - [Linux]
+///
+/// [POSIX]: https://pubs.opengroup.org/onlinepubs/9699919799/functions/timer_delete.html
+/// [Linux]: https://man7.org/linux/man-pages/man2/timer_delete.2.html
+#[inline]
+pub fn timer_delete(timerid: TimerId) -> io::Result<()> {
+    backend::time::syscalls::timer_delete(timerid)
+}
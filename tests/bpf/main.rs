@@ -0,0 +1,8 @@
+//! Tests for [`rustix::bpf`].
+
+#![cfg(feature = "bpf")]
+#![cfg(any(target_os = "android", target_os = "linux"))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+mod map;
+mod prog;
@@ -0,0 +1,78 @@
+use rustix::bpf::{
+    map_create, map_delete_elem, map_lookup_elem, map_update_elem, MapCreateAttr, MapFlags,
+    MapType, UpdateFlags,
+};
+use rustix::io;
+
+#[test]
+fn test_bpf_array_map() {
+    let map = match map_create(&MapCreateAttr {
+        map_type: MapType::Array,
+        key_size: 4,
+        value_size: 8,
+        max_entries: 4,
+        map_flags: MapFlags::empty(),
+    }) {
+        Ok(map) => map,
+        // BPF is restricted to privileged users, or filtered by a sandbox.
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let key = 0_u32.to_ne_bytes();
+    let mut value = [0_u8; 8];
+    unsafe {
+        // Array elements start out zeroed.
+        map_lookup_elem(&map, &key, &mut value).unwrap();
+        assert_eq!(u64::from_ne_bytes(value), 0);
+
+        map_update_elem(&map, &key, &0x1234_u64.to_ne_bytes(), UpdateFlags::EXIST).unwrap();
+        map_lookup_elem(&map, &key, &mut value).unwrap();
+        assert_eq!(u64::from_ne_bytes(value), 0x1234);
+
+        // Array elements always exist, and can't be deleted.
+        assert_eq!(
+            map_update_elem(&map, &key, &value, UpdateFlags::NOEXIST),
+            Err(io::Errno::EXIST)
+        );
+        assert_eq!(map_delete_elem(&map, &key), Err(io::Errno::INVAL));
+
+        let key = 4_u32.to_ne_bytes();
+        assert_eq!(
+            map_lookup_elem(&map, &key, &mut value),
+            Err(io::Errno::NOENT)
+        );
+    }
+}
+
+#[test]
+fn test_bpf_hash_map() {
+    let map = match map_create(&MapCreateAttr {
+        map_type: MapType::Hash,
+        key_size: 4,
+        value_size: 4,
+        max_entries: 16,
+        map_flags: MapFlags::empty(),
+    }) {
+        Ok(map) => map,
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let key = 7_u32.to_ne_bytes();
+    let mut value = [0_u8; 4];
+    unsafe {
+        assert_eq!(
+            map_lookup_elem(&map, &key, &mut value),
+            Err(io::Errno::NOENT)
+        );
+        map_update_elem(&map, &key, &42_u32.to_ne_bytes(), UpdateFlags::NOEXIST).unwrap();
+        map_lookup_elem(&map, &key, &mut value).unwrap();
+        assert_eq!(u32::from_ne_bytes(value), 42);
+        map_delete_elem(&map, &key).unwrap();
+        assert_eq!(
+            map_lookup_elem(&map, &key, &mut value),
+            Err(io::Errno::NOENT)
+        );
+    }
+}
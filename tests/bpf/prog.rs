@@ -0,0 +1,45 @@
+use rustix::bpf::{prog_load, BpfInsn, ProgLoadAttr, ProgType};
+use rustix::io;
+
+/// `BPF_ALU64 | BPF_MOV | BPF_K`
+const MOV64_IMM: u8 = 0xb7;
+/// `BPF_JMP | BPF_EXIT`
+const EXIT: u8 = 0x95;
+
+#[test]
+fn test_bpf_prog_load() {
+    // `r0 = 0; exit`, a socket filter which drops everything.
+    let insns = [
+        BpfInsn::new(MOV64_IMM, 0, 0, 0, 0),
+        BpfInsn::new(EXIT, 0, 0, 0, 0),
+    ];
+    let mut log = vec![0_u8; 4096];
+    let attr = ProgLoadAttr {
+        prog_type: ProgType::SocketFilter,
+        insns: &insns,
+        license: rustix::cstr!("GPL"),
+        log_level: 1,
+        kern_version: 0,
+        prog_flags: 0,
+    };
+    match prog_load(&attr, Some(&mut log)) {
+        Ok(_prog) => {}
+        Err(io::Errno::PERM) | Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // The verifier rejects a program which exits without setting `r0`, and
+    // says why in the log.
+    let mut log = vec![0_u8; 4096];
+    let attr = ProgLoadAttr {
+        insns: &insns[1..],
+        ..attr
+    };
+    assert_eq!(
+        prog_load(&attr, Some(&mut log)).unwrap_err(),
+        io::Errno::ACCESS
+    );
+    let len = log.iter().position(|b| *b == 0).unwrap();
+    assert!(len > 0);
+    assert!(std::str::from_utf8(&log[..len]).unwrap().contains("R0"));
+}
@@ -0,0 +1,62 @@
+#[test]
+fn test_accessat_eaccess() {
+    use rustix::fs::{accessat, cwd, openat, symlinkat, Access, AtFlags, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let _ = openat(
+        &dir,
+        "rw",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    let _ = openat(&dir, "ro", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+
+    accessat(&dir, "rw", Access::WRITE_OK, AtFlags::EACCESS).unwrap();
+    accessat(&dir, "ro", Access::READ_OK, AtFlags::EACCESS).unwrap();
+
+    // The superuser can write to files without write permission.
+    if !rustix::process::geteuid().is_root() {
+        assert_eq!(
+            accessat(&dir, "ro", Access::WRITE_OK, AtFlags::EACCESS),
+            Err(rustix::io::Errno::ACCESS)
+        );
+    }
+
+    // With `AT_SYMLINK_NOFOLLOW`, a dangling symlink still exists.
+    symlinkat("missing", &dir, "link").unwrap();
+    assert_eq!(
+        accessat(&dir, "link", Access::EXISTS, AtFlags::empty()),
+        Err(rustix::io::Errno::NOENT)
+    );
+    match accessat(&dir, "link", Access::EXISTS, AtFlags::SYMLINK_NOFOLLOW) {
+        // Older kernels without `faccessat2` can't emulate this flag.
+        Ok(()) | Err(rustix::io::Errno::INVAL) => {}
+        Err(err) => panic!("{:?}", err),
+    }
+}
+
+#[test]
+fn test_can_access() {
+    use rustix::fs::{can_access, cwd, openat, Access, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let _ = openat(&dir, "ro", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+
+    assert!(can_access(&dir, "ro", Access::READ_OK).unwrap());
+    assert!(can_access(&dir, "ro", Access::EXISTS).unwrap());
+
+    // The superuser can write to files without write permission.
+    if !rustix::process::geteuid().is_root() {
+        assert!(!can_access(&dir, "ro", Access::WRITE_OK).unwrap());
+    }
+
+    // Other failures are errors, rather than `false`.
+    assert_eq!(
+        can_access(&dir, "missing", Access::READ_OK),
+        Err(rustix::io::Errno::NOENT)
+    );
+}
@@ -0,0 +1,62 @@
+#[cfg(feature = "process")]
+#[test]
+fn test_chownat_nofollow() {
+    use rustix::fs::{chownat, cwd, openat, statat, symlinkat, AtFlags, Mode, OFlags};
+    use rustix::process::{geteuid, Gid, Uid};
+
+    // Changing ownership to another user requires privileges.
+    if !geteuid().is_root() {
+        return;
+    }
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    symlinkat("file", &dir, "link").unwrap();
+
+    let before = statat(&dir, "file", AtFlags::empty()).unwrap();
+    let (uid, gid) = unsafe { (Uid::from_raw(12345), Gid::from_raw(12345)) };
+    chownat(
+        &dir,
+        "link",
+        Some(uid),
+        Some(gid),
+        AtFlags::SYMLINK_NOFOLLOW,
+    )
+    .unwrap();
+
+    let link = statat(&dir, "link", AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    assert_eq!(link.st_uid, uid.as_raw());
+    assert_eq!(link.st_gid, gid.as_raw());
+
+    let file = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(file.st_uid, before.st_uid);
+    assert_eq!(file.st_gid, before.st_gid);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_chmodat_with_nofollow() {
+    use rustix::fs::{chmodat_with, cwd, openat, statat, symlinkat, AtFlags, Mode, OFlags};
+    use rustix::io;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let _ = openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
+    symlinkat("file", &dir, "link").unwrap();
+
+    // A regular file can have its mode changed without following symlinks.
+    chmodat_with(&dir, "file", Mode::RWXU, AtFlags::SYMLINK_NOFOLLOW).unwrap();
+    let stat = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_mode & 0o777, Mode::RWXU.bits() as _);
+
+    // Linux doesn't support modes on symlinks.
+    assert_eq!(
+        chmodat_with(&dir, "link", Mode::RUSR, AtFlags::SYMLINK_NOFOLLOW),
+        Err(io::Errno::OPNOTSUPP)
+    );
+    let stat = statat(&dir, "file", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_mode & 0o777, Mode::RWXU.bits() as _);
+}
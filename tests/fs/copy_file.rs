@@ -0,0 +1,58 @@
+use rustix::fs::{copy_file, cwd, fstat, ftruncate, openat, Mode, OFlags};
+use rustix::io::{pread, pwrite};
+use std::path::Path;
+
+fn create(path: &Path) -> rustix::fd::OwnedFd {
+    openat(
+        cwd(),
+        path,
+        OFlags::CREATE | OFlags::TRUNC | OFlags::RDWR | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap()
+}
+
+fn check_copy(src_dir: &Path, dst_dir: &Path) {
+    let src = create(&src_dir.join("src"));
+    pwrite(&src, b"hello", 0).unwrap();
+    pwrite(&src, b"world", 1 << 20).unwrap();
+    ftruncate(&src, 2 << 20).unwrap();
+
+    let dst = create(&dst_dir.join("dst"));
+    pwrite(&dst, b"stale", 3 << 20).unwrap();
+
+    assert_eq!(copy_file(&src, &dst), Ok(2 << 20));
+    assert_eq!(fstat(&dst).unwrap().st_size, 2 << 20);
+
+    let mut buf = vec![0_u8; 2 << 20];
+    let mut pos = 0;
+    while pos < buf.len() {
+        pos += pread(&dst, &mut buf[pos..], pos as u64).unwrap();
+    }
+    let mut expected = vec![0_u8; 2 << 20];
+    expected[..5].copy_from_slice(b"hello");
+    expected[1 << 20..(1 << 20) + 5].copy_from_slice(b"world");
+    assert!(buf == expected);
+}
+
+#[test]
+fn test_copy_file_same_fs() {
+    let tmp = tempfile::tempdir().unwrap();
+    check_copy(tmp.path(), tmp.path());
+}
+
+#[test]
+fn test_copy_file_cross_fs() {
+    // `/dev/shm` is normally a tmpfs, separate from the filesystem holding
+    // the temporary directory, which makes `FICLONE` fail with `EXDEV`.
+    let shm = Path::new("/dev/shm");
+    if !shm.is_dir() {
+        return;
+    }
+    let src_dir = tempfile::tempdir().unwrap();
+    let dst_dir = match tempfile::tempdir_in(shm) {
+        Ok(dir) => dir,
+        Err(_) => return,
+    };
+    check_copy(src_dir.path(), dst_dir.path());
+}
@@ -0,0 +1,67 @@
+use rustix::fs::{cwd, dio_alignment, openat, DirectIo, DirectIoError, Mode, OFlags};
+
+/// Return a subslice of `buf` of `len` bytes whose address is aligned to
+/// `align`.
+fn aligned(buf: &mut [u8], align: usize, len: usize) -> &mut [u8] {
+    let start = (align - buf.as_ptr() as usize % align) % align;
+    &mut buf[start..start + len]
+}
+
+#[test]
+fn test_direct_io() {
+    let tmp = tempfile::tempdir().unwrap();
+    let fd = match openat(
+        cwd(),
+        tmp.path().join("file"),
+        OFlags::CREATE | OFlags::RDWR | OFlags::DIRECT | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    ) {
+        Ok(fd) => fd,
+        // The filesystem doesn't support `O_DIRECT`.
+        Err(rustix::io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let alignment = match dio_alignment(&fd) {
+        Ok(alignment) => alignment,
+        Err(rustix::io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert!(alignment.mem_align.is_power_of_two());
+    assert!(alignment.offset_align.is_power_of_two());
+
+    let dio = DirectIo::new(&fd).unwrap();
+    assert_eq!(dio.alignment(), alignment);
+
+    let mem_align = alignment.mem_align as usize;
+    let block = alignment.offset_align as usize;
+    let mut storage = vec![0_u8; 2 * block + mem_align];
+    let buf = aligned(&mut storage, mem_align, 2 * block);
+    for (i, b) in buf.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    assert_eq!(dio.pwrite(buf, block as u64), Ok(2 * block));
+
+    let mut storage = vec![0_u8; 2 * block + mem_align];
+    let out = aligned(&mut storage, mem_align, 2 * block);
+    assert_eq!(dio.pread(out, block as u64), Ok(2 * block));
+    assert!(out.iter().enumerate().all(|(i, b)| *b == i as u8));
+
+    // Misaligned requests are caught before they reach the kernel.
+    if block > 1 {
+        assert_eq!(
+            dio.pread(&mut out[..block], 1),
+            Err(DirectIoError::MisalignedOffset)
+        );
+        assert_eq!(
+            dio.pread(&mut out[..block - 1], 0),
+            Err(DirectIoError::MisalignedLength)
+        );
+    }
+    if mem_align > 1 {
+        assert_eq!(
+            dio.pread(&mut out[1..=block], 0),
+            Err(DirectIoError::MisalignedBuffer)
+        );
+    }
+}
@@ -15,3 +15,121 @@ fn test_fcntl_dupfd_cloexec() {
     let new = rustix::fs::fcntl_dupfd_cloexec(&file, 700).unwrap();
     assert_eq!(new.as_fd().as_raw_fd(), 700);
 }
+
+#[test]
+fn test_set_nonblocking() {
+    use rustix::fs::{fcntl_getfl, set_nonblocking, OFlags};
+    use rustix::io::{pipe, read, Errno};
+
+    let (reader, _writer) = pipe().unwrap();
+    assert!(!fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+
+    set_nonblocking(&reader, true).unwrap();
+    assert!(fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+
+    // With no data in the pipe, a read fails rather than blocking.
+    assert_eq!(read(&reader, &mut [0_u8; 8]), Err(Errno::AGAIN));
+
+    set_nonblocking(&reader, false).unwrap();
+    assert!(!fcntl_getfl(&reader).unwrap().contains(OFlags::NONBLOCK));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_fcntl_lease() {
+    use rustix::fs::{cwd, fcntl_getlease, fcntl_setlease, openat, LeaseType, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    drop(
+        openat(
+            &dir,
+            "file",
+            OFlags::CREATE | OFlags::WRONLY,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .unwrap(),
+    );
+
+    // A read lease requires the file to be opened read-only.
+    let file = openat(&dir, "file", OFlags::RDONLY, Mode::empty()).unwrap();
+    assert_eq!(fcntl_getlease(&file).unwrap(), LeaseType::Unlock);
+
+    match fcntl_setlease(&file, LeaseType::Read) {
+        Ok(()) => {}
+        // Some filesystems, and some configurations, don't support leases.
+        Err(rustix::io::Errno::INVAL) | Err(rustix::io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(fcntl_getlease(&file).unwrap(), LeaseType::Read);
+
+    fcntl_setlease(&file, LeaseType::Unlock).unwrap();
+    assert_eq!(fcntl_getlease(&file).unwrap(), LeaseType::Unlock);
+}
+
+#[cfg(all(feature = "process", any(target_os = "android", target_os = "linux")))]
+static GOT_DNOTIFY: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(false);
+
+#[cfg(all(feature = "process", any(target_os = "android", target_os = "linux")))]
+extern "C" fn dnotify_handler(_sig: libc::c_int) {
+    GOT_DNOTIFY.store(true, std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Arm dnotify in a child process, so that the signal handler doesn't
+/// interfere with the test harness.
+#[cfg(all(feature = "process", any(target_os = "android", target_os = "linux")))]
+#[test]
+fn test_fcntl_dnotify() {
+    use rustix::fs::{cwd, fcntl_dnotify, openat, DnotifyFlags, Mode, OFlags};
+    use rustix::io::fcntl_setsig;
+    use rustix::process::{fork, waitpid, Fork, Signal, WaitOptions};
+    use std::sync::atomic::Ordering;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => {
+            let ok = (|| {
+                unsafe {
+                    let mut action: libc::sigaction = core::mem::zeroed();
+                    action.sa_sigaction =
+                        dnotify_handler as extern "C" fn(libc::c_int) as libc::sighandler_t;
+                    if libc::sigaction(libc::SIGUSR2, &action, core::ptr::null_mut()) != 0 {
+                        return false;
+                    }
+                }
+
+                if fcntl_setsig(&dir, Signal::Usr2 as i32).is_err()
+                    || fcntl_dnotify(&dir, DnotifyFlags::CREATE).is_err()
+                {
+                    return false;
+                }
+                if openat(&dir, "file", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).is_err() {
+                    return false;
+                }
+
+                // The signal is sent while the file is created, but allow
+                // some time for it to be delivered.
+                for _ in 0..100 {
+                    if GOT_DNOTIFY.load(Ordering::SeqCst) {
+                        return true;
+                    }
+                    std::thread::sleep(std::time::Duration::from_millis(10));
+                }
+                false
+            })();
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) }
+        }
+        Fork::Parent(child) => {
+            let (_, status) = waitpid(Some(child), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(0));
+        }
+    }
+}
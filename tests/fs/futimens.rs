@@ -1,7 +1,7 @@
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[test]
 fn test_futimens() {
-    use rustix::fs::{cwd, fstat, futimens, openat, Mode, OFlags, Timespec, Timestamps};
+    use rustix::fs::{cwd, fstat, futimens, openat, Mode, OFlags, Timespec, Timestamp, Timestamps};
 
     let tmp = tempfile::tempdir().unwrap();
     let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
@@ -14,29 +14,75 @@ fn test_futimens() {
     )
     .unwrap();
 
+    let atime = Timespec {
+        tv_sec: 44000,
+        tv_nsec: 45000,
+    };
+    let mtime = Timespec {
+        tv_sec: 46000,
+        tv_nsec: 47000,
+    };
     let times = Timestamps {
-        last_access: Timespec {
-            tv_sec: 44000,
-            tv_nsec: 45000,
-        },
-        last_modification: Timespec {
-            tv_sec: 46000,
-            tv_nsec: 47000,
-        },
+        last_access: Timestamp::SetTo(atime),
+        last_modification: Timestamp::SetTo(mtime),
     };
     futimens(&foo, &times).unwrap();
 
     let after = fstat(&foo).unwrap();
 
-    assert_eq!(times.last_modification.tv_sec as u64, after.st_mtime as u64);
+    assert_eq!(mtime.tv_sec as u64, after.st_mtime as u64);
     #[cfg(not(target_os = "netbsd"))]
-    assert_eq!(
-        times.last_modification.tv_nsec as u64,
-        after.st_mtime_nsec as u64
-    );
+    assert_eq!(mtime.tv_nsec as u64, after.st_mtime_nsec as u64);
     #[cfg(target_os = "netbsd")]
-    assert_eq!(
-        times.last_modification.tv_nsec as u64,
-        after.st_mtimensec as u64
-    );
+    assert_eq!(mtime.tv_nsec as u64, after.st_mtimensec as u64);
+}
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_futimens_both() {
+    use rustix::fs::{fstat, futimens, Timespec, Timestamp, Timestamps};
+
+    let file = tempfile::tempfile().unwrap();
+
+    let atime = Timespec {
+        tv_sec: 54000,
+        tv_nsec: 55000,
+    };
+    let mtime = Timespec {
+        tv_sec: 56000,
+        tv_nsec: 57000,
+    };
+    let times = Timestamps {
+        last_access: Timestamp::SetTo(atime),
+        last_modification: Timestamp::SetTo(mtime),
+    };
+    futimens(&file, &times).unwrap();
+
+    let after = fstat(&file).unwrap();
+
+    assert_eq!(atime.tv_sec as u64, after.st_atime as u64);
+    assert_eq!(mtime.tv_sec as u64, after.st_mtime as u64);
+    #[cfg(not(target_os = "netbsd"))]
+    {
+        assert_eq!(atime.tv_nsec as u64, after.st_atime_nsec as u64);
+        assert_eq!(mtime.tv_nsec as u64, after.st_mtime_nsec as u64);
+    }
+    #[cfg(target_os = "netbsd")]
+    {
+        assert_eq!(atime.tv_nsec as u64, after.st_atimensec as u64);
+        assert_eq!(mtime.tv_nsec as u64, after.st_mtimensec as u64);
+    }
+
+    // `Omit` leaves the modification time alone while the access time
+    // changes.
+    let times = Timestamps {
+        last_access: Timestamp::Now,
+        last_modification: Timestamp::Omit,
+    };
+    futimens(&file, &times).unwrap();
+
+    let after = fstat(&file).unwrap();
+
+    assert_ne!(atime.tv_sec as u64, after.st_atime as u64);
+    assert_eq!(mtime.tv_sec as u64, after.st_mtime as u64);
 }
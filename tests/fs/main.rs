@@ -6,8 +6,16 @@
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 #![cfg_attr(core_c_str, feature(core_c_str))]
 
+#[cfg(all(feature = "process", any(target_os = "android", target_os = "linux")))]
+mod accessat;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod chownat;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod copy_file;
 mod cwd;
 mod dir;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod direct_io;
 mod fcntl;
 mod file;
 #[cfg(not(target_os = "wasi"))]
@@ -31,11 +39,20 @@ mod long_paths;
 mod makedev;
 mod mkdirat;
 mod mknodat;
+#[cfg(all(feature = "process", feature = "thread"))]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mount;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod open_path;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod openat;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod openat2;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod quota;
 mod readdir;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod readlinkat;
 mod renameat;
 #[cfg(not(any(
     target_os = "haiku",
@@ -47,5 +64,19 @@ mod renameat;
 mod statfs;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod statx;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod swap;
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod sync;
+mod truncate;
 mod utimensat;
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+mod vnode_watch;
 mod y2038;
@@ -8,3 +8,25 @@ fn makedev_roundtrip() {
     assert_eq!(maj, major(dev));
     assert_eq!(min, minor(dev));
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn makedev_glibc_layout() {
+    // glibc splits the major and minor numbers into 12+20 and 8+24 bit
+    // pieces, rather than simply concatenating them.
+    let dev = makedev(0x123, 0x45678);
+    assert_eq!(dev, 0x4561_2378);
+    assert_eq!(major(dev), 0x123);
+    assert_eq!(minor(dev), 0x45678);
+
+    let dev = makedev(0xabcd_e123, 0x1234_5678);
+    assert_eq!(dev, 0xabcd_e123_4561_2378);
+    assert_eq!(major(dev), 0xabcd_e123);
+    assert_eq!(minor(dev), 0x1234_5678);
+
+    // The traditional 8-bit values are laid out as before.
+    let dev = makedev(1, 3);
+    assert_eq!(dev, 0x103);
+    assert_eq!(major(dev), 1);
+    assert_eq!(minor(dev), 3);
+}
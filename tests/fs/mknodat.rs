@@ -25,3 +25,37 @@ fn test_mknodat() {
     assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Fifo);
     unlinkat(&dir, "foo", AtFlags::empty()).unwrap();
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_mknodat_chr() {
+    use rustix::fs::{
+        cwd, major, makedev, minor, mknodat, openat, statat, unlinkat, AtFlags, FileType, Mode,
+        OFlags,
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // Create a node like `/dev/null`. This requires privileges.
+    match mknodat(
+        &dir,
+        "null",
+        FileType::CharacterDevice,
+        Mode::RUSR | Mode::WUSR,
+        makedev(1, 3),
+    ) {
+        Ok(()) => (),
+        Err(rustix::io::Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let stat = statat(&dir, "null", AtFlags::empty()).unwrap();
+    assert_eq!(
+        FileType::from_raw_mode(stat.st_mode),
+        FileType::CharacterDevice
+    );
+    assert_eq!(major(stat.st_rdev), 1);
+    assert_eq!(minor(stat.st_rdev), 3);
+    unlinkat(&dir, "null", AtFlags::empty()).unwrap();
+}
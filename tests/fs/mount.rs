@@ -0,0 +1,98 @@
+use rustix::fs::{
+    bind_mount, change_mount, cwd, mount2, openat, unmount, Mode, MountFlags,
+    MountPropagationFlags, OFlags, UnmountFlags,
+};
+use rustix::io;
+use rustix::process::{fork, waitpid, Fork, WaitOptions};
+use rustix::thread::{unshare, UnshareFlags};
+use std::ffi::CString;
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+
+/// Convert `path` to a `CString` before forking, so that the child doesn't
+/// need to allocate.
+fn c_path(path: &Path) -> CString {
+    CString::new(path.as_os_str().as_bytes()).unwrap()
+}
+
+/// Enter a new user and mount namespace in a child process.
+fn enter_mount_namespace() -> io::Result<()> {
+    unshare(UnshareFlags::NEWUSER | UnshareFlags::NEWNS)?;
+
+    // Keep our mounts from propagating back to the parent namespace.
+    change_mount(
+        rustix::cstr!("/"),
+        MountPropagationFlags::PRIVATE | MountPropagationFlags::REC,
+    )
+}
+
+/// Run `f` in a child process inside a new mount namespace, skipping the
+/// test if namespaces aren't available.
+///
+/// The test harness is multithreaded, so the child may only make syscalls
+/// before it exits; it mustn't allocate or panic.
+fn in_mount_namespace(f: impl FnOnce() -> bool) {
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => {
+            let code = match enter_mount_namespace() {
+                Ok(()) if f() => 0,
+                Ok(()) => 1,
+                Err(io::Errno::PERM) | Err(io::Errno::INVAL) | Err(io::Errno::NOSPC) => 2,
+                Err(_) => 1,
+            };
+            unsafe { libc::_exit(code) }
+        }
+        Fork::Parent(child) => {
+            let (_, status) = waitpid(Some(child), WaitOptions::empty()).unwrap().unwrap();
+            match status.exit_status() {
+                Some(0) => {}
+                Some(2) => eprintln!("skipping: mount namespaces are unavailable"),
+                status => panic!("child failed: {:?}", status),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_bind_mount() {
+    let src = tempfile::tempdir().unwrap();
+    let dst = tempfile::tempdir().unwrap();
+    std::fs::write(src.path().join("file"), b"hello").unwrap();
+
+    let src_path = c_path(src.path());
+    let dst_path = c_path(dst.path());
+    let dst_file = c_path(&dst.path().join("file"));
+
+    in_mount_namespace(|| {
+        bind_mount(&*src_path, &*dst_path).is_ok()
+            && openat(cwd(), &*dst_file, OFlags::RDONLY, Mode::empty()).is_ok()
+            && unmount(&*dst_path, UnmountFlags::DETACH).is_ok()
+    });
+}
+
+#[test]
+fn test_mount2_tmpfs() {
+    let dst = tempfile::tempdir().unwrap();
+    std::fs::write(dst.path().join("file"), b"hello").unwrap();
+
+    let dst_path = c_path(dst.path());
+    let dst_file = c_path(&dst.path().join("file"));
+
+    in_mount_namespace(|| {
+        if mount2(
+            Some(rustix::cstr!("none")),
+            &*dst_path,
+            Some(rustix::cstr!("tmpfs")),
+            MountFlags::NOSUID,
+            Some(rustix::cstr!("size=1m")),
+        )
+        .is_err()
+        {
+            return false;
+        }
+
+        // The new tmpfs starts out empty, hiding the file underneath it.
+        openat(cwd(), &*dst_file, OFlags::RDONLY, Mode::empty()).err() == Some(io::Errno::NOENT)
+            && unmount(&*dst_path, UnmountFlags::empty()).is_ok()
+    });
+}
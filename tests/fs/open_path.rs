@@ -0,0 +1,52 @@
+use rustix::fs::{cwd, fstat, fstatfs, open_path, openat, FileType, Mode, OFlags, PathOpenFlags};
+use rustix::io;
+
+#[test]
+fn test_open_path_dirfd() {
+    let tmp = tempfile::tempdir().unwrap();
+    std::fs::write(tmp.path().join("child"), b"hello").unwrap();
+
+    let dir = open_path(cwd(), tmp.path(), PathOpenFlags::DIRECTORY).unwrap();
+
+    // A path-only fd works as the `dirfd` of `*at` calls.
+    let child = openat(
+        &dir,
+        "child",
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+    let mut buf = [0_u8; 16];
+    let n = io::read(&child, &mut buf).unwrap();
+    assert_eq!(&buf[..n], b"hello");
+
+    // It can be `fstat`ed, but not read.
+    let stat = fstat(&dir).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Directory);
+    fstatfs(&dir).unwrap();
+    assert_eq!(io::read(&dir, &mut buf), Err(io::Errno::BADF));
+
+    let child = open_path(&dir, "child", PathOpenFlags::empty()).unwrap();
+    assert_eq!(io::read(&child, &mut buf), Err(io::Errno::BADF));
+    assert_eq!(io::write(&child, b"x"), Err(io::Errno::BADF));
+    assert_eq!(
+        open_path(&dir, "child", PathOpenFlags::DIRECTORY).unwrap_err(),
+        io::Errno::NOTDIR
+    );
+}
+
+#[test]
+fn test_open_path_nofollow() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = open_path(cwd(), tmp.path(), PathOpenFlags::DIRECTORY).unwrap();
+    rustix::fs::symlinkat("missing", &dir, "link").unwrap();
+
+    // With `NOFOLLOW`, a dangling symlink can be referred to directly.
+    assert_eq!(
+        open_path(&dir, "link", PathOpenFlags::empty()).unwrap_err(),
+        io::Errno::NOENT
+    );
+    let link = open_path(&dir, "link", PathOpenFlags::NOFOLLOW).unwrap();
+    let stat = fstat(&link).unwrap();
+    assert_eq!(FileType::from_raw_mode(stat.st_mode), FileType::Symlink);
+}
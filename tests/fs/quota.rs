@@ -0,0 +1,29 @@
+use rustix::fs::{quotactl_get, QuotaType};
+use rustix::io;
+use rustix::process::getuid;
+
+/// Querying quotas on something that isn't a block device fails with
+/// `NOTBLK`, unless the kernel is built without quota support (`NOSYS`), or
+/// a seccomp sandbox, such as Docker's default one, rejects the syscall
+/// (`PERM`).
+#[test]
+fn test_quotactl_not_block_device() {
+    match quotactl_get("/dev/null", QuotaType::User, getuid().as_raw()) {
+        Err(io::Errno::NOTBLK) | Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => {}
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+/// Set `RUSTIX_TEST_QUOTA_DEVICE` to the block device of a mounted file
+/// system with user quotas enabled to run this test.
+#[test]
+fn test_quotactl_get() {
+    let device = match std::env::var_os("RUSTIX_TEST_QUOTA_DEVICE") {
+        Some(device) => device,
+        None => return,
+    };
+
+    let quota = quotactl_get(&device, QuotaType::User, getuid().as_raw()).unwrap();
+    assert!(quota.block_soft_limit <= quota.block_hard_limit || quota.block_hard_limit == 0);
+    assert!(quota.inode_soft_limit <= quota.inode_hard_limit || quota.inode_hard_limit == 0);
+}
@@ -0,0 +1,59 @@
+#[test]
+fn test_readlinkat() {
+    use rustix::fs::{cwd, openat, readlinkat, symlinkat, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    symlinkat("some/target", &dir, "link").unwrap();
+
+    let target = readlinkat(&dir, "link", Vec::new()).unwrap();
+    assert_eq!(target.to_bytes(), b"some/target");
+
+    // Reusing a buffer works too, and its previous contents are discarded.
+    let target = readlinkat(&dir, "link", b"previous contents".to_vec()).unwrap();
+    assert_eq!(target.to_bytes(), b"some/target");
+}
+
+#[test]
+fn test_readlinkat_long() {
+    use rustix::fs::{cwd, openat, readlinkat, symlinkat, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    // Use a target much longer than the initial buffer. Some platforms,
+    // including Linux, limit symlink targets to less than `PATH_MAX`, so fall
+    // back to a shorter one if needed.
+    let mut target = "a/".repeat(2500);
+    if symlinkat(target.as_str(), &dir, "link") == Err(rustix::io::Errno::NAMETOOLONG) {
+        target.truncate(4000);
+        symlinkat(target.as_str(), &dir, "link").unwrap();
+    }
+
+    let read = readlinkat(&dir, "link", Vec::new()).unwrap();
+    assert_eq!(read.to_bytes(), target.as_bytes());
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_readlinkat_proc_self_fd() {
+    use rustix::fs::{cwd, openat, readlinkat, Mode, OFlags};
+    use std::os::unix::ffi::OsStrExt;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let proc_self_fd = match openat(cwd(), "/proc/self/fd", OFlags::RDONLY, Mode::empty()) {
+        Ok(fd) => fd,
+        Err(_) => return,
+    };
+
+    // `lstat` reports a size of 64 for these magic symlinks regardless of the
+    // length of their target, so readlinkat mustn't depend on it.
+    let name = rustix::path::DecInt::from_fd(&dir);
+    let target = readlinkat(&proc_self_fd, name.as_c_str(), Vec::new()).unwrap();
+    assert_eq!(
+        target.to_bytes(),
+        tmp.path().canonicalize().unwrap().as_os_str().as_bytes()
+    );
+}
@@ -102,3 +102,38 @@ fn test_renameat_with() {
         assert!(same(&before, &orig));
     }
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_rename_noreplace() {
+    use rustix::fs::{cwd, openat, rename_noreplace, statat, AtFlags, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::PATH,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let _ = openat(&dir, "foo", OFlags::CREATE | OFlags::WRONLY, Mode::empty()).unwrap();
+    let _ = openat(&dir, "bar", OFlags::CREATE | OFlags::WRONLY, Mode::empty()).unwrap();
+    let foo = statat(&dir, "foo", AtFlags::empty()).unwrap();
+    let bar = statat(&dir, "bar", AtFlags::empty()).unwrap();
+
+    // The destination exists, so nothing changes.
+    assert_eq!(
+        rename_noreplace(&dir, "foo", &dir, "bar"),
+        Err(rustix::io::Errno::EXIST)
+    );
+    assert!(same(&foo, &statat(&dir, "foo", AtFlags::empty()).unwrap()));
+    assert!(same(&bar, &statat(&dir, "bar", AtFlags::empty()).unwrap()));
+
+    rename_noreplace(&dir, "foo", &dir, "baz").unwrap();
+    assert!(same(&foo, &statat(&dir, "baz", AtFlags::empty()).unwrap()));
+    assert_eq!(
+        statat(&dir, "foo", AtFlags::empty()).unwrap_err(),
+        rustix::io::Errno::NOENT
+    );
+}
@@ -89,3 +89,39 @@ fn test_fstatvfs() {
     let f_frsize = statvfs.f_frsize;
     assert_ne!(f_frsize, 0);
 }
+
+/// Test that `FsType` identifies procfs, and round-trips magic numbers.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_statfs_fs_type() {
+    use rustix::fs::{FsType, PROC_SUPER_MAGIC};
+
+    if let Ok(statfs) = rustix::fs::statfs("/proc") {
+        let f_type = statfs.f_type;
+        assert_eq!(FsType::from_raw(f_type), FsType::Proc);
+        assert_eq!(FsType::Proc.as_raw(), PROC_SUPER_MAGIC);
+    }
+
+    // An `Unknown` holding a known magic number is the named type.
+    assert_eq!(FsType::Unknown(PROC_SUPER_MAGIC), FsType::Proc);
+
+    for fs_type in &[
+        FsType::Tmpfs,
+        FsType::Proc,
+        FsType::Sysfs,
+        FsType::Cgroup2,
+        FsType::Overlayfs,
+        FsType::Btrfs,
+        FsType::Ext4,
+        FsType::Xfs,
+        FsType::Nfs,
+        FsType::Unknown(0x1234),
+    ] {
+        assert_eq!(FsType::from_raw(fs_type.as_raw()), *fs_type);
+    }
+
+    // Whatever filesystem we're on, its type should round-trip too.
+    let statfs = rustix::fs::statfs("Cargo.toml").unwrap();
+    let f_type = statfs.f_type;
+    assert_eq!(FsType::from_raw(f_type).as_raw(), f_type);
+}
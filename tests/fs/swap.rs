@@ -0,0 +1,40 @@
+use rustix::fs::{cwd, openat, swapoff, swapon, Mode, OFlags, SwapFlags};
+use rustix::io;
+
+#[test]
+fn test_swap_flags_priority() {
+    assert_eq!(SwapFlags::priority(0), SwapFlags::PREFER);
+    assert_eq!(SwapFlags::priority(5).bits(), 0x8005);
+    assert_eq!(SwapFlags::priority(0x7fff).bits(), 0xffff);
+    // Higher priorities are clamped rather than wrapping around.
+    assert_eq!(SwapFlags::priority(0x8000).bits(), 0xffff);
+    assert_eq!(SwapFlags::priority(u16::MAX).bits(), 0xffff);
+}
+
+/// Check the error paths only; actually enabling swap would affect the whole
+/// system.
+#[test]
+fn test_swapon_not_swap() {
+    let tmp = tempfile::tempdir().unwrap();
+    let path = tmp.path().join("file");
+    openat(
+        cwd(),
+        &path,
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    // An empty file has no swap signature, so this fails with `INVAL` if
+    // we're privileged, or `PERM` if not.
+    match swapon(&path, SwapFlags::priority(1) | SwapFlags::DISCARD) {
+        Err(io::Errno::INVAL) | Err(io::Errno::PERM) => {}
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // The file isn't an active swap area.
+    match swapoff(&path) {
+        Err(io::Errno::INVAL) | Err(io::Errno::PERM) => {}
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
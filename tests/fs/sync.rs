@@ -0,0 +1,23 @@
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_syncfs() {
+    use rustix::fs::{cwd, openat, syncfs, Mode, OFlags};
+    use rustix::io::write;
+
+    let tmp = tempfile::tempdir().unwrap();
+    let file = openat(
+        cwd(),
+        tmp.path().join("file"),
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    assert_eq!(write(&file, b"hello").unwrap(), 5);
+
+    assert_eq!(syncfs(&file), Ok(()));
+}
+
+#[test]
+fn test_sync() {
+    rustix::fs::sync();
+}
@@ -0,0 +1,65 @@
+#[cfg(not(target_os = "wasi"))]
+#[test]
+fn test_truncate() {
+    use rustix::fs::{cwd, openat, statat, truncate, AtFlags, Mode, OFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+
+    let _ = openat(
+        &dir,
+        "foo",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    truncate(tmp.path().join("foo"), 4096).unwrap();
+    let stat = statat(&dir, "foo", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_size, 4096);
+
+    truncate(tmp.path().join("foo"), 17).unwrap();
+    let stat = statat(&dir, "foo", AtFlags::empty()).unwrap();
+    assert_eq!(stat.st_size, 17);
+
+    assert_eq!(
+        truncate(tmp.path().join("bar"), 0),
+        Err(rustix::io::Errno::NOENT)
+    );
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_ftruncate_large_sparse() {
+    use rustix::fs::{ftruncate, statx, AtFlags, StatxFlags};
+    use rustix::io;
+
+    const LEN: u64 = 5 << 30;
+
+    let file = tempfile::tempfile().unwrap();
+
+    // Skip if the filesystem doesn't support files this large.
+    match ftruncate(&file, LEN) {
+        Ok(()) => (),
+        Err(io::Errno::FBIG) | Err(io::Errno::INVAL) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let stat = match statx(
+        &file,
+        "",
+        AtFlags::EMPTY_PATH,
+        StatxFlags::SIZE | StatxFlags::BLOCKS,
+    ) {
+        Ok(stat) => stat,
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert_eq!(stat.stx_size, LEN);
+
+    // The extension should be a hole, with few, if any, blocks allocated.
+    // `stx_blocks` is in 512-byte units.
+    if stat.stx_mask & StatxFlags::BLOCKS.bits() != 0 {
+        assert!(stat.stx_blocks * 512 < LEN / 1024);
+    }
+}
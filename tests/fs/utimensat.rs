@@ -1,7 +1,9 @@
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[test]
 fn test_utimensat() {
-    use rustix::fs::{cwd, openat, statat, utimensat, AtFlags, Mode, OFlags, Timespec, Timestamps};
+    use rustix::fs::{
+        cwd, openat, statat, utimensat, AtFlags, Mode, OFlags, Timespec, Timestamp, Timestamps,
+    };
 
     let tmp = tempfile::tempdir().unwrap();
     let dir = openat(
@@ -20,48 +22,46 @@ fn test_utimensat() {
     )
     .unwrap();
 
+    let atime = Timespec {
+        tv_sec: 44000,
+        tv_nsec: 45000,
+    };
+    let mtime = Timespec {
+        tv_sec: 46000,
+        tv_nsec: 47000,
+    };
     let times = Timestamps {
-        last_access: Timespec {
-            tv_sec: 44000,
-            tv_nsec: 45000,
-        },
-        last_modification: Timespec {
-            tv_sec: 46000,
-            tv_nsec: 47000,
-        },
+        last_access: Timestamp::SetTo(atime),
+        last_modification: Timestamp::SetTo(mtime),
     };
     utimensat(&dir, "foo", &times, AtFlags::empty()).unwrap();
 
     let after = statat(&dir, "foo", AtFlags::empty()).unwrap();
 
-    assert_eq!(times.last_modification.tv_sec as u64, after.st_mtime as u64);
+    assert_eq!(mtime.tv_sec as u64, after.st_mtime as u64);
     #[cfg(not(target_os = "netbsd"))]
-    assert_eq!(
-        times.last_modification.tv_nsec as u64,
-        after.st_mtime_nsec as u64
-    );
+    assert_eq!(mtime.tv_nsec as u64, after.st_mtime_nsec as u64);
     #[cfg(target_os = "netbsd")]
-    assert_eq!(
-        times.last_modification.tv_nsec as u64,
-        after.st_mtimensec as u64
-    );
-    assert!(times.last_access.tv_sec as u64 >= after.st_atime as u64);
+    assert_eq!(mtime.tv_nsec as u64, after.st_mtimensec as u64);
+    assert!(atime.tv_sec as u64 >= after.st_atime as u64);
     #[cfg(not(target_os = "netbsd"))]
     assert!(
-        times.last_access.tv_sec as u64 > after.st_atime as u64
-            || times.last_access.tv_nsec as u64 >= after.st_atime_nsec as u64
+        atime.tv_sec as u64 > after.st_atime as u64
+            || atime.tv_nsec as u64 >= after.st_atime_nsec as u64
     );
     #[cfg(target_os = "netbsd")]
     assert!(
-        times.last_access.tv_sec as u64 > after.st_atime as u64
-            || times.last_access.tv_nsec as u64 >= after.st_atimensec as u64
+        atime.tv_sec as u64 > after.st_atime as u64
+            || atime.tv_nsec as u64 >= after.st_atimensec as u64
     );
 }
 
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[test]
 fn test_utimensat_noent() {
-    use rustix::fs::{cwd, openat, utimensat, AtFlags, Mode, OFlags, Timespec, Timestamps};
+    use rustix::fs::{
+        cwd, openat, utimensat, AtFlags, Mode, OFlags, Timespec, Timestamp, Timestamps,
+    };
 
     let tmp = tempfile::tempdir().unwrap();
     let dir = openat(
@@ -72,15 +72,17 @@ fn test_utimensat_noent() {
     )
     .unwrap();
 
+    let atime = Timespec {
+        tv_sec: 44000,
+        tv_nsec: 45000,
+    };
+    let mtime = Timespec {
+        tv_sec: 46000,
+        tv_nsec: 47000,
+    };
     let times = Timestamps {
-        last_access: Timespec {
-            tv_sec: 44000,
-            tv_nsec: 45000,
-        },
-        last_modification: Timespec {
-            tv_sec: 46000,
-            tv_nsec: 47000,
-        },
+        last_access: Timestamp::SetTo(atime),
+        last_modification: Timestamp::SetTo(mtime),
     };
     assert_eq!(
         utimensat(&dir, "foo", &times, AtFlags::empty()).unwrap_err(),
@@ -91,7 +93,9 @@ fn test_utimensat_noent() {
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 #[test]
 fn test_utimensat_notdir() {
-    use rustix::fs::{cwd, openat, utimensat, AtFlags, Mode, OFlags, Timespec, Timestamps};
+    use rustix::fs::{
+        cwd, openat, utimensat, AtFlags, Mode, OFlags, Timespec, Timestamp, Timestamps,
+    };
 
     let tmp = tempfile::tempdir().unwrap();
     let dir = openat(
@@ -110,18 +114,83 @@ fn test_utimensat_notdir() {
     )
     .unwrap();
 
+    let atime = Timespec {
+        tv_sec: 44000,
+        tv_nsec: 45000,
+    };
+    let mtime = Timespec {
+        tv_sec: 46000,
+        tv_nsec: 47000,
+    };
     let times = Timestamps {
-        last_access: Timespec {
-            tv_sec: 44000,
-            tv_nsec: 45000,
-        },
-        last_modification: Timespec {
-            tv_sec: 46000,
-            tv_nsec: 47000,
-        },
+        last_access: Timestamp::SetTo(atime),
+        last_modification: Timestamp::SetTo(mtime),
     };
     assert_eq!(
         utimensat(&foo, "bar", &times, AtFlags::empty()).unwrap_err(),
         rustix::io::Errno::NOTDIR
     );
 }
+
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+#[test]
+fn test_utimensat_omit() {
+    use rustix::fs::{
+        cwd, openat, statat, utimensat, AtFlags, Mode, OFlags, Timespec, Timestamp, Timestamps,
+    };
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let _ = openat(
+        &dir,
+        "foo",
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    let atime = Timespec {
+        tv_sec: 44000,
+        tv_nsec: 45000,
+    };
+    let times = Timestamps {
+        last_access: Timestamp::SetTo(atime),
+        last_modification: Timestamp::Now,
+    };
+    utimensat(&dir, "foo", &times, AtFlags::empty()).unwrap();
+    let before = statat(&dir, "foo", AtFlags::empty()).unwrap();
+
+    // Set only the modification time, leaving the access time alone.
+    let mtime = Timespec {
+        tv_sec: 46000,
+        tv_nsec: 47000,
+    };
+    let times = Timestamps {
+        last_access: Timestamp::Omit,
+        last_modification: Timestamp::SetTo(mtime),
+    };
+    utimensat(&dir, "foo", &times, AtFlags::empty()).unwrap();
+    let after = statat(&dir, "foo", AtFlags::empty()).unwrap();
+
+    assert_ne!(before.st_mtime as u64, after.st_mtime as u64);
+    assert_eq!(mtime.tv_sec as u64, after.st_mtime as u64);
+    assert_eq!(before.st_atime as u64, after.st_atime as u64);
+    assert_eq!(atime.tv_sec as u64, after.st_atime as u64);
+    #[cfg(not(target_os = "netbsd"))]
+    {
+        assert_eq!(mtime.tv_nsec as u64, after.st_mtime_nsec as u64);
+        assert_eq!(before.st_atime_nsec as u64, after.st_atime_nsec as u64);
+    }
+    #[cfg(target_os = "netbsd")]
+    {
+        assert_eq!(mtime.tv_nsec as u64, after.st_mtimensec as u64);
+        assert_eq!(before.st_atimensec as u64, after.st_atimensec as u64);
+    }
+}
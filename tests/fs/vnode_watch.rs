@@ -0,0 +1,33 @@
+use rustix::fs::{cwd, openat, Mode, OFlags, VnodeEvents, VnodeWatch};
+use rustix::io::write;
+use std::time::Duration;
+
+#[test]
+fn test_vnode_watch_write() {
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let file = openat(
+        &dir,
+        "file",
+        OFlags::CREATE | OFlags::WRONLY,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let watch = VnodeWatch::new(
+        &file,
+        VnodeEvents::WRITE | VnodeEvents::EXTEND | VnodeEvents::DELETE,
+    )
+    .unwrap();
+
+    // Nothing has happened yet.
+    assert!(watch.wait(Some(Duration::from_secs(0))).unwrap().is_empty());
+
+    write(&file, b"hello").unwrap();
+    let events = watch.wait(Some(Duration::from_secs(5))).unwrap();
+    assert!(events.contains(VnodeEvents::WRITE), "{:?}", events);
+    assert!(!events.contains(VnodeEvents::DELETE));
+
+    // The change was only reported once.
+    assert!(watch.wait(Some(Duration::from_secs(0))).unwrap().is_empty());
+}
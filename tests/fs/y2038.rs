@@ -8,7 +8,8 @@
 #[test]
 fn test_y2038_with_utimensat() {
     use rustix::fs::{
-        cwd, fstat, openat, statat, utimensat, AtFlags, Mode, OFlags, Timespec, Timestamps,
+        cwd, fstat, openat, statat, utimensat, AtFlags, Mode, OFlags, Timespec, Timestamp,
+        Timestamps,
     };
     use std::convert::TryInto;
 
@@ -21,14 +22,14 @@ fn test_y2038_with_utimensat() {
     let a_nsec = m_nsec + 1;
 
     let timestamps = Timestamps {
-        last_modification: Timespec {
+        last_modification: Timestamp::SetTo(Timespec {
             tv_sec: m_sec as _,
             tv_nsec: m_nsec as _,
-        },
-        last_access: Timespec {
+        }),
+        last_access: Timestamp::SetTo(Timespec {
             tv_sec: a_sec as _,
             tv_nsec: a_nsec as _,
-        },
+        }),
     };
     let _ = openat(&dir, "foo", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
 
@@ -107,7 +108,8 @@ fn test_y2038_with_utimensat() {
 #[test]
 fn test_y2038_with_futimens() {
     use rustix::fs::{
-        cwd, fstat, futimens, openat, statat, AtFlags, Mode, OFlags, Timespec, Timestamps,
+        cwd, fstat, futimens, openat, statat, AtFlags, Mode, OFlags, Timespec, Timestamp,
+        Timestamps,
     };
     use std::convert::TryInto;
 
@@ -120,14 +122,14 @@ fn test_y2038_with_futimens() {
     let a_nsec = m_nsec + 1;
 
     let timestamps = Timestamps {
-        last_modification: Timespec {
+        last_modification: Timestamp::SetTo(Timespec {
             tv_sec: m_sec as _,
             tv_nsec: m_nsec as _,
-        },
-        last_access: Timespec {
+        }),
+        last_access: Timestamp::SetTo(Timespec {
             tv_sec: a_sec as _,
             tv_nsec: a_nsec as _,
-        },
+        }),
     };
     let file = openat(&dir, "foo", OFlags::CREATE | OFlags::WRONLY, Mode::RUSR).unwrap();
 
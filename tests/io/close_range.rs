@@ -0,0 +1,59 @@
+use rustix::fd::AsRawFd;
+use rustix::io::{close_range, fcntl_getfd, pipe, read, CloseRangeFlags, Errno, FdFlags};
+use rustix::process::{fork, waitpid, Fork, WaitOptions};
+
+/// Close all file descriptors above stdio in a child process, so that we
+/// don't interfere with the test harness.
+#[test]
+fn test_close_range() {
+    let (reader, writer) = pipe().unwrap();
+    let (other_reader, other_writer) = pipe().unwrap();
+
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => {
+            let ok = unsafe { close_range(3, rustix::fd::RawFd::MAX, CloseRangeFlags::empty()) }
+                .is_ok()
+                && read(&reader, &mut [0_u8]) == Err(Errno::BADF)
+                && read(&other_reader, &mut [0_u8]) == Err(Errno::BADF)
+                && fcntl_getfd(&writer) == Err(Errno::BADF)
+                && fcntl_getfd(&other_writer) == Err(Errno::BADF);
+            // The closed file descriptors are still owned by `OwnedFd`s, so
+            // exit without dropping them.
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) }
+        }
+        Fork::Parent(child) => {
+            let (_, status) = waitpid(Some(child), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(0));
+        }
+    }
+}
+
+#[test]
+fn test_close_range_cloexec() {
+    let (reader, writer) = pipe().unwrap();
+
+    // Marking file descriptors close-on-exec doesn't close them, so it's safe
+    // to do in the test process, but only for our own, as other tests may be
+    // using the ones in between.
+    for fd in &[reader.as_raw_fd(), writer.as_raw_fd()] {
+        match unsafe { close_range(*fd, *fd, CloseRangeFlags::CLOEXEC) } {
+            Ok(()) => {}
+            Err(Errno::NOSYS) | Err(Errno::INVAL) => return,
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+    assert!(fcntl_getfd(&reader).unwrap().contains(FdFlags::CLOEXEC));
+    assert!(fcntl_getfd(&writer).unwrap().contains(FdFlags::CLOEXEC));
+}
+
+#[test]
+fn test_close_range_inval() {
+    assert_eq!(
+        unsafe { close_range(10, 9, CloseRangeFlags::empty()) },
+        Err(Errno::INVAL)
+    );
+    assert_eq!(
+        unsafe { close_range(-1, 9, CloseRangeFlags::empty()) },
+        Err(Errno::INVAL)
+    );
+}
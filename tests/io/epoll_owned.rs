@@ -0,0 +1,70 @@
+use rustix::io::epoll::{CreateFlags, Epoll, EventFlags, EventVec};
+use rustix::io::{self, eventfd, pipe, read, write, EventfdFlags};
+
+#[test]
+fn test_epoll_owned_eventfd() {
+    let mut epoll = Epoll::new(CreateFlags::CLOEXEC).unwrap();
+
+    let efd = eventfd(0, EventfdFlags::CLOEXEC).unwrap();
+    epoll.add(efd, 7, EventFlags::IN).unwrap();
+
+    // The registered eventfd stays alive, and is reachable through its
+    // data token.
+    write(epoll.get(7).unwrap(), &1_u64.to_ne_bytes()).unwrap();
+
+    let mut event_list = EventVec::with_capacity(4);
+    epoll.wait(&mut event_list, -1).unwrap();
+    let events = epoll.events(&event_list).collect::<Vec<_>>();
+    assert_eq!(events.len(), 1);
+    let (event_flags, data, efd) = events[0];
+    assert!(event_flags.contains(EventFlags::IN));
+    assert_eq!(data, 7);
+
+    let mut buf = [0_u8; 8];
+    assert_eq!(read(efd, &mut buf).unwrap(), 8);
+    assert_eq!(u64::from_ne_bytes(buf), 1);
+}
+
+#[test]
+fn test_epoll_owned_duplicate_data() {
+    let mut epoll = Epoll::new(CreateFlags::CLOEXEC).unwrap();
+    let (reader, writer) = pipe().unwrap();
+    epoll.add(reader, 1, EventFlags::IN).unwrap();
+    let (err, writer) = epoll.add(writer, 1, EventFlags::OUT).unwrap_err();
+    assert_eq!(err, io::Errno::EXIST);
+    // The rejected source is handed back, still open.
+    assert_eq!(write(&writer, b"x"), Ok(1));
+    assert_eq!(epoll.modify(2, EventFlags::IN), Err(io::Errno::NOENT));
+    assert_eq!(epoll.remove(2).err(), Some(io::Errno::NOENT));
+}
+
+#[test]
+fn test_epoll_owned_remove() {
+    let mut epoll = Epoll::new(CreateFlags::CLOEXEC).unwrap();
+    let (reader, writer) = pipe().unwrap();
+    epoll.add(reader, 1, EventFlags::IN).unwrap();
+
+    let reader = epoll.remove(1).unwrap();
+    assert!(epoll.get(1).is_none());
+
+    // The removed pipe no longer reports events.
+    write(&writer, b"x").unwrap();
+    let mut event_list = EventVec::with_capacity(4);
+    epoll.wait(&mut event_list, 0).unwrap();
+    assert!(event_list.is_empty());
+    drop(reader);
+}
+
+/// Dropping the `Epoll` closes the file descriptors it owns.
+#[test]
+fn test_epoll_owned_drop_closes() {
+    let mut epoll = Epoll::new(CreateFlags::CLOEXEC).unwrap();
+    let (reader, writer) = pipe().unwrap();
+    epoll.add(writer, 1, EventFlags::OUT).unwrap();
+
+    drop(epoll);
+
+    // With the only write end closed, reading sees end-of-file.
+    let mut buf = [0_u8; 1];
+    assert_eq!(read(&reader, &mut buf).unwrap(), 0);
+}
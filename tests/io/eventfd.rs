@@ -22,3 +22,28 @@ fn test_eventfd() {
     let u = u64::from_ne_bytes(bytes);
     assert_eq!(u, 5021);
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_notify() {
+    use rustix::io::{poll, Notify, PollFd, PollFlags};
+    use std::sync::Arc;
+    use std::thread;
+
+    let notify = Arc::new(Notify::new().unwrap());
+    assert_eq!(notify.drain().unwrap(), 0);
+
+    let child = thread::spawn({
+        let notify = Arc::clone(&notify);
+        move || {
+            let mut fds = [PollFd::new(&*notify, PollFlags::IN)];
+            assert_eq!(poll(&mut fds, -1).unwrap(), 1);
+            assert!(fds[0].revents().contains(PollFlags::IN));
+            notify.drain().unwrap()
+        }
+    });
+
+    notify.notify().unwrap();
+    assert_eq!(child.join().unwrap(), 1);
+    assert_eq!(notify.drain().unwrap(), 0);
+}
@@ -0,0 +1,32 @@
+#[cfg(not(target_os = "wasi"))]
+#[test]
+fn test_fcntl_dupfd_cloexec_pipe() {
+    use rustix::fd::AsRawFd;
+    use rustix::io::{fcntl_dupfd_cloexec, fcntl_getfd, pipe, read, write, FdFlags};
+
+    let (reader, writer) = pipe().unwrap();
+    let new = fcntl_dupfd_cloexec(&reader, 10).unwrap();
+
+    assert!(new.as_raw_fd() >= 10);
+    assert_ne!(new.as_raw_fd(), reader.as_raw_fd());
+    assert!(fcntl_getfd(&new).unwrap().contains(FdFlags::CLOEXEC));
+
+    // The duplicate shares the pipe with the original.
+    assert_eq!(write(&writer, b"x").unwrap(), 1);
+    let mut buf = [0_u8];
+    assert_eq!(read(&new, &mut buf).unwrap(), 1);
+    assert_eq!(&buf, b"x");
+}
+
+#[test]
+fn test_fcntl_setfd() {
+    use rustix::io::{fcntl_getfd, fcntl_setfd, pipe, FdFlags};
+
+    let (reader, _writer) = pipe().unwrap();
+
+    fcntl_setfd(&reader, FdFlags::empty()).unwrap();
+    assert!(!fcntl_getfd(&reader).unwrap().contains(FdFlags::CLOEXEC));
+
+    fcntl_setfd(&reader, FdFlags::CLOEXEC).unwrap();
+    assert!(fcntl_getfd(&reader).unwrap().contains(FdFlags::CLOEXEC));
+}
@@ -0,0 +1,93 @@
+use rustix::fs::{fcntl_getfl, fcntl_setfl, OFlags};
+use rustix::io::{fcntl_getown, fcntl_getsig, fcntl_setown, fcntl_setsig, pipe, write, Owner};
+use rustix::process::{fork, getpgrp, getpid, waitpid, Fork, Signal, WaitOptions};
+use rustix::thread::gettid;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[test]
+fn test_fcntl_setown_getown() {
+    let (reader, _writer) = pipe().unwrap();
+
+    assert_eq!(fcntl_getown(&reader).unwrap(), None);
+
+    fcntl_setown(&reader, Owner::Pid(getpid())).unwrap();
+    assert_eq!(fcntl_getown(&reader).unwrap(), Some(Owner::Pid(getpid())));
+
+    fcntl_setown(&reader, Owner::Thread(gettid())).unwrap();
+    assert_eq!(
+        fcntl_getown(&reader).unwrap(),
+        Some(Owner::Thread(gettid()))
+    );
+
+    fcntl_setown(&reader, Owner::ProcessGroup(getpgrp())).unwrap();
+    assert_eq!(
+        fcntl_getown(&reader).unwrap(),
+        Some(Owner::ProcessGroup(getpgrp()))
+    );
+}
+
+#[test]
+fn test_fcntl_setsig_getsig() {
+    let (reader, _writer) = pipe().unwrap();
+
+    assert_eq!(fcntl_getsig(&reader).unwrap(), 0);
+
+    fcntl_setsig(&reader, Signal::Usr2 as i32).unwrap();
+    assert_eq!(fcntl_getsig(&reader).unwrap(), Signal::Usr2 as i32);
+
+    // Realtime signals can't be represented by `Signal`.
+    for n in 0..4 {
+        let sig = libc::SIGRTMIN() + n;
+        fcntl_setsig(&reader, sig).unwrap();
+        assert_eq!(fcntl_getsig(&reader).unwrap(), sig);
+    }
+
+    fcntl_setsig(&reader, 0).unwrap();
+    assert_eq!(fcntl_getsig(&reader).unwrap(), 0);
+}
+
+static GOT_SIGNAL: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn handler(_sig: libc::c_int) {
+    GOT_SIGNAL.store(true, Ordering::SeqCst);
+}
+
+/// Set up signal-driven I/O on a pipe in a child process, so that the signal
+/// handler doesn't interfere with the test harness.
+#[test]
+fn test_fcntl_signal_driven_io() {
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => {
+            let ok = (|| {
+                unsafe {
+                    let mut action: libc::sigaction = core::mem::zeroed();
+                    action.sa_sigaction =
+                        handler as extern "C" fn(libc::c_int) as libc::sighandler_t;
+                    if libc::sigaction(libc::SIGUSR1, &action, core::ptr::null_mut()) != 0 {
+                        return false;
+                    }
+                }
+
+                let (reader, writer) = match pipe() {
+                    Ok(pipe) => pipe,
+                    Err(_) => return false,
+                };
+                let flags = match fcntl_getfl(&reader) {
+                    Ok(flags) => flags,
+                    Err(_) => return false,
+                };
+                fcntl_setfl(&reader, flags | OFlags::ASYNC).is_ok()
+                    && fcntl_setown(&reader, Owner::Pid(getpid())).is_ok()
+                    && fcntl_setsig(&reader, Signal::Usr1 as i32).is_ok()
+                    && !GOT_SIGNAL.load(Ordering::SeqCst)
+                    && write(&writer, b"x").is_ok()
+                    && GOT_SIGNAL.load(Ordering::SeqCst)
+            })();
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) }
+        }
+        Fork::Parent(child) => {
+            let (_, status) = waitpid(Some(child), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(0));
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use rustix::fs::{cwd, openat, seek, Mode, OFlags};
+use rustix::io::{pread, FdIo, SeekFrom};
+use std::io::{Read, Seek, Write};
+
+#[test]
+fn test_fd_io() {
+    let tmp = tempfile::tempdir().unwrap();
+    let fd = openat(
+        cwd(),
+        tmp.path().join("file"),
+        OFlags::CREATE | OFlags::RDWR | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let mut file = FdIo::new(&fd);
+    file.write_all(b"hello, world").unwrap();
+    file.flush().unwrap();
+
+    // The writes went to the fd itself.
+    assert_eq!(seek(&fd, SeekFrom::Current(0)), Ok(12));
+    let mut buf = [0_u8; 12];
+    assert_eq!(pread(&fd, &mut buf, 0), Ok(12));
+    assert_eq!(&buf, b"hello, world");
+
+    assert_eq!(file.seek(std::io::SeekFrom::Start(7)).unwrap(), 7);
+    let mut tail = Vec::new();
+    file.read_to_end(&mut tail).unwrap();
+    assert_eq!(tail, b"world");
+
+    assert_eq!(file.seek(std::io::SeekFrom::End(-12)).unwrap(), 0);
+    assert_eq!(file.seek(std::io::SeekFrom::Current(5)).unwrap(), 5);
+    let mut all = Vec::new();
+    file.seek(std::io::SeekFrom::Start(0)).unwrap();
+    file.read_to_end(&mut all).unwrap();
+    assert_eq!(all, b"hello, world");
+}
+
+#[test]
+fn test_fd_io_errors() {
+    let tmp = tempfile::tempdir().unwrap();
+    let fd = openat(
+        cwd(),
+        tmp.path().join("file"),
+        OFlags::CREATE | OFlags::WRONLY | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let mut file = FdIo::new(fd);
+    let err = file.read(&mut [0_u8; 4]).unwrap_err();
+    assert_eq!(err.raw_os_error(), Some(libc::EBADF));
+    assert_eq!(
+        rustix::io::Errno::from_io_error(&err),
+        Some(rustix::io::Errno::BADF)
+    );
+
+    let err = file.seek(std::io::SeekFrom::Current(-1)).unwrap_err();
+    assert_eq!(err.raw_os_error(), Some(libc::EINVAL));
+}
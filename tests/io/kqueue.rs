@@ -0,0 +1,59 @@
+use rustix::io::kqueue::{kevent, kqueue, Event, EventFilter, EventFlags};
+use std::time::{Duration, Instant};
+
+#[test]
+fn test_kqueue_timer() {
+    let kq = kqueue().unwrap();
+
+    let timer = Event::new(
+        EventFilter::Timer {
+            ident: 1,
+            timer: Some(Duration::from_millis(30)),
+        },
+        EventFlags::ADD | EventFlags::ONESHOT,
+        42,
+    )
+    .unwrap();
+
+    let start = Instant::now();
+    let mut events = Vec::with_capacity(4);
+    let n = unsafe { kevent(&kq, &[timer], &mut events, None) }.unwrap();
+    assert_eq!(n, 1);
+    assert_eq!(events.len(), 1);
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    let event = &events[0];
+    assert_eq!(event.udata(), 42);
+    assert!(!event.flags().contains(EventFlags::ERROR));
+    match event.filter() {
+        EventFilter::Timer { ident, .. } => assert_eq!(ident, 1),
+        filter => panic!("unexpected filter: {:?}", filter),
+    }
+    assert_eq!(event.data(), 1);
+
+    // The timer was one-shot, so nothing more fires.
+    let n = unsafe { kevent(&kq, &[], &mut events, Some(Duration::from_millis(60))) }.unwrap();
+    assert_eq!(n, 0);
+    assert!(events.is_empty());
+}
+
+/// A failed change is reported with `EV_ERROR`.
+#[test]
+fn test_kqueue_change_error() {
+    let kq = kqueue().unwrap();
+
+    let bogus = Event::new(EventFilter::Read(-1), EventFlags::ADD, 0).unwrap();
+    let mut events = Vec::with_capacity(1);
+    let n = unsafe { kevent(&kq, &[bogus], &mut events, Some(Duration::from_secs(0))) }.unwrap();
+    assert_eq!(n, 1);
+    assert!(events[0].flags().contains(EventFlags::ERROR));
+    assert_eq!(events[0].data(), libc::EBADF as i64);
+}
+
+#[test]
+fn test_kqueue_unknown_filter() {
+    assert_eq!(
+        Event::new(EventFilter::Unknown, EventFlags::ADD, 0).err(),
+        Some(rustix::io::Errno::INVAL)
+    );
+}
@@ -3,6 +3,9 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod close_range;
 #[cfg(not(feature = "rustc-dep-of-std"))]
 #[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
@@ -12,21 +15,56 @@ mod dup2_to_replace_stdio;
 #[cfg(feature = "net")]
 #[cfg(not(target_os = "wasi"))]
 mod epoll;
+#[cfg(not(feature = "rustc-dep-of-std"))]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod epoll_owned;
 mod error;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
 mod eventfd;
 #[cfg(not(windows))]
+mod fcntl;
+#[cfg(all(feature = "fs", feature = "process", feature = "thread"))]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod fcntl_setown;
+#[cfg(not(windows))]
+#[cfg(all(feature = "std", feature = "fs"))]
+mod fd_io;
+#[cfg(not(windows))]
 mod from_into;
 #[cfg(not(target_os = "redox"))]
 mod ioctl;
+#[cfg(any(
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+mod kqueue;
 mod pipe;
 mod poll;
+#[cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+mod poller;
 #[cfg(all(feature = "procfs", any(target_os = "android", target_os = "linux")))]
 mod procfs;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "redox"))] // redox doesn't have cwd/openat
 #[cfg(not(target_os = "wasi"))] // wasi support for S_IRUSR etc. submitted to libc in #2264
 mod read_write;
+#[cfg(not(windows))]
+#[cfg(feature = "process")]
+#[cfg(not(target_os = "wasi"))]
+mod replace_stdio;
 #[cfg(any(target_os = "linux", target_os = "freebsd", target_os = "android"))]
 mod seals;
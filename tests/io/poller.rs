@@ -0,0 +1,51 @@
+use rustix::io::{self, pipe, read, write, Events, Interest, Poller, Readiness};
+use std::time::Duration;
+
+#[test]
+fn test_poller_pipe() {
+    let (reader, writer) = pipe().unwrap();
+    let poller = Poller::new().unwrap();
+    poller.register(&reader, Interest::READABLE, 7).unwrap();
+
+    let mut events = Events::with_capacity(4);
+    poller
+        .poll(&mut events, Some(Duration::from_secs(0)))
+        .unwrap();
+    assert_eq!(events.iter().count(), 0);
+
+    write(&writer, b"x").unwrap();
+    poller.poll(&mut events, None).unwrap();
+    let ready = events.iter().collect::<Vec<_>>();
+    assert_eq!(ready.len(), 1);
+    assert_eq!(ready[0].0, 7);
+    assert!(ready[0].1.contains(Readiness::READABLE));
+
+    // Readiness is level-triggered, so it's reported until the data is read.
+    poller
+        .poll(&mut events, Some(Duration::from_secs(0)))
+        .unwrap();
+    assert_eq!(events.iter().count(), 1);
+    let mut buf = [0_u8; 1];
+    assert_eq!(read(&reader, &mut buf), Ok(1));
+    poller
+        .poll(&mut events, Some(Duration::from_millis(1)))
+        .unwrap();
+    assert_eq!(events.iter().count(), 0);
+
+    // Switch the registration over to the write end.
+    poller.deregister(&reader).unwrap();
+    assert_eq!(poller.deregister(&reader), Err(io::Errno::NOENT));
+    poller.register(&writer, Interest::READABLE, 8).unwrap();
+    poller.reregister(&writer, Interest::WRITABLE, 9).unwrap();
+    poller.poll(&mut events, None).unwrap();
+    let ready = events.iter().collect::<Vec<_>>();
+    assert_eq!(ready.len(), 1);
+    assert_eq!(ready[0].0, 9);
+    assert!(ready[0].1.contains(Readiness::WRITABLE));
+
+    // Closing the read end is reported on the write end.
+    drop(reader);
+    poller.poll(&mut events, None).unwrap();
+    assert!(events.iter().any(|(token, readiness)| token == 9
+        && readiness.intersects(Readiness::ERROR | Readiness::HANGUP)));
+}
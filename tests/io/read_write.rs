@@ -198,3 +198,132 @@ fn test_pwritev2() {
     .unwrap();
     assert_eq!(&buf, b"world");
 }
+
+#[cfg(feature = "fs")]
+#[test]
+fn test_read_uninit() {
+    use core::mem::MaybeUninit;
+    use rustix::fs::{cwd, openat, Mode, OFlags};
+    use rustix::io::{pread_uninit, read_uninit, write};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let foo = openat(
+        &dir,
+        "foo",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&foo, b"hello, world").unwrap();
+
+    let mut buf = [MaybeUninit::<u8>::uninit(); 64];
+    let (init, uninit) = pread_uninit(&foo, &mut buf, 7).unwrap();
+    assert_eq!(init, b"world");
+    assert_eq!(uninit.len(), 59);
+
+    let foo = openat(&dir, "foo", OFlags::RDONLY, Mode::empty()).unwrap();
+    let (init, uninit) = read_uninit(&foo, &mut buf).unwrap();
+    assert_eq!(init, b"hello, world");
+    assert_eq!(uninit.len(), 52);
+
+    // At the end of the file, nothing is initialized.
+    let (init, uninit) = read_uninit(&foo, &mut buf).unwrap();
+    assert!(init.is_empty());
+    assert_eq!(uninit.len(), 64);
+}
+
+#[cfg(feature = "fs")]
+#[cfg(not(any(target_os = "haiku", target_os = "redox", target_os = "solaris")))]
+#[test]
+fn test_preadv_uninit() {
+    use core::mem::MaybeUninit;
+    use rustix::fs::{cwd, openat, Mode, OFlags};
+    use rustix::io::{preadv_uninit, write};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(cwd(), tmp.path(), OFlags::RDONLY, Mode::empty()).unwrap();
+    let foo = openat(
+        &dir,
+        "foo",
+        OFlags::RDWR | OFlags::CREATE | OFlags::TRUNC,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    write(&foo, b"hello, world").unwrap();
+
+    let mut first = [MaybeUninit::<u8>::uninit(); 2];
+    let mut second = [MaybeUninit::<u8>::uninit(); 8];
+    let n = preadv_uninit(&foo, &mut [&mut first, &mut second], 7).unwrap();
+    assert_eq!(n, 5);
+    let read: Vec<u8> = first
+        .iter()
+        .chain(&second[..3])
+        .map(|b| unsafe { b.assume_init() })
+        .collect();
+    assert_eq!(read, b"world");
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[test]
+fn test_readv_writev_more_than_iov_max() {
+    use rustix::io::{pipe, readv_fully, writev_all};
+
+    // More buffers than the usual `IOV_MAX` of 1024.
+    let data: Vec<u8> = (0..2000).map(|i| i as u8).collect();
+    let (reader, writer) = pipe().unwrap();
+
+    let bufs: Vec<std::io::IoSlice<'_>> = data.chunks(1).map(std::io::IoSlice::new).collect();
+    writev_all(&writer, &bufs).unwrap();
+    drop(writer);
+
+    let mut out = vec![0_u8; 2000];
+    let mut bufs: Vec<std::io::IoSliceMut<'_>> =
+        out.chunks_mut(1).map(std::io::IoSliceMut::new).collect();
+    assert_eq!(readv_fully(&reader, &mut bufs).unwrap(), 2000);
+    drop(bufs);
+    assert_eq!(out, data);
+
+    // At the end of the stream, nothing more is read.
+    let mut more = [0_u8; 4];
+    assert_eq!(
+        readv_fully(&reader, &mut [std::io::IoSliceMut::new(&mut more)]),
+        Ok(0)
+    );
+}
+
+#[cfg(not(target_os = "wasi"))]
+#[test]
+fn test_readv_fully_short_reads() {
+    use rustix::io::{pipe, readv_fully, write};
+
+    let (reader, writer) = pipe().unwrap();
+    let thread = std::thread::spawn(move || {
+        // Write in pieces which don't line up with the buffers.
+        for piece in &[&b"he"[..], b"llo, w", b"orl", b"d"] {
+            write(&writer, piece).unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    });
+
+    let mut a = [0_u8; 5];
+    let mut b = [0_u8; 0];
+    let mut c = [0_u8; 4];
+    let mut d = [0_u8; 8];
+    let n = readv_fully(
+        &reader,
+        &mut [
+            std::io::IoSliceMut::new(&mut a),
+            std::io::IoSliceMut::new(&mut b),
+            std::io::IoSliceMut::new(&mut c),
+            std::io::IoSliceMut::new(&mut d),
+        ],
+    )
+    .unwrap();
+    thread.join().unwrap();
+
+    assert_eq!(n, 12);
+    assert_eq!(&a, b"hello");
+    assert_eq!(&c, b", wo");
+    assert_eq!(&d[..3], b"rld");
+}
@@ -0,0 +1,33 @@
+use rustix::io::{pipe, read, replace_stdout, stdout, write};
+use rustix::process::{fork, waitpid, Fork, WaitOptions};
+
+/// Redirect stdout to a pipe in a child process, so that we don't interfere
+/// with the test harness.
+#[test]
+fn test_replace_stdout() {
+    let (reader, writer) = pipe().unwrap();
+
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => {
+            drop(reader);
+            let ok = replace_stdout(&writer).is_ok()
+                && write(stdout(), b"hello, world!").ok() == Some(13);
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) }
+        }
+        Fork::Parent(child) => {
+            drop(writer);
+            let (_, status) = waitpid(Some(child), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(0));
+
+            let mut buf = [0_u8; 32];
+            let n = read(&reader, &mut buf).unwrap();
+            assert_eq!(&buf[..n], b"hello, world!");
+        }
+    }
+}
+
+/// Replacing stdout with itself does nothing.
+#[test]
+fn test_replace_stdout_with_itself() {
+    replace_stdout(stdout()).unwrap();
+}
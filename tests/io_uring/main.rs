@@ -0,0 +1,8 @@
+//! Tests for [`rustix::io_uring`].
+
+#![cfg(feature = "io_uring")]
+#![cfg(any(target_os = "android", target_os = "linux"))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+#[cfg(feature = "mm")]
+mod nop;
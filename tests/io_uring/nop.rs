@@ -0,0 +1,89 @@
+use core::ffi::c_void;
+use core::mem::size_of;
+use core::ptr::null_mut;
+use core::sync::atomic::{AtomicU32, Ordering};
+use rustix::io;
+use rustix::io_uring::{
+    io_uring_cqe, io_uring_enter, io_uring_params, io_uring_setup, io_uring_sqe,
+    io_uring_user_data, IoringEnterFlags, IoringOp, IORING_OFF_CQ_RING, IORING_OFF_SQES,
+    IORING_OFF_SQ_RING,
+};
+use rustix::mm::{mmap, munmap, MapFlags, ProtFlags};
+
+/// Set up a ring, map its queues, submit a `NOP`, and reap its completion.
+#[test]
+fn test_io_uring_nop() {
+    let mut params = io_uring_params::default();
+    let ring = match io_uring_setup(4, &mut params) {
+        Ok(ring) => ring,
+        // io_uring may be unavailable or disabled by a seccomp policy.
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    assert!(params.sq_entries >= 4);
+
+    let sq_len = params.sq_off.array as usize + params.sq_entries as usize * size_of::<u32>();
+    let cq_len =
+        params.cq_off.cqes as usize + params.cq_entries as usize * size_of::<io_uring_cqe>();
+    let sqes_len = params.sq_entries as usize * size_of::<io_uring_sqe>();
+
+    unsafe {
+        let map = |len, offset| {
+            mmap(
+                null_mut(),
+                len,
+                ProtFlags::READ | ProtFlags::WRITE,
+                MapFlags::SHARED | MapFlags::POPULATE,
+                &ring,
+                offset,
+            )
+            .unwrap()
+        };
+        let sq = map(sq_len, IORING_OFF_SQ_RING);
+        let cq = map(cq_len, IORING_OFF_CQ_RING);
+        let sqes = map(sqes_len, IORING_OFF_SQES).cast::<io_uring_sqe>();
+
+        let field =
+            |base: *mut c_void, offset: u32| base.cast::<u8>().add(offset as usize).cast::<u32>();
+        let atomic = |base: *mut c_void, offset: u32| &*field(base, offset).cast::<AtomicU32>();
+
+        // Submit a `NOP`.
+        let sq_tail = atomic(sq, params.sq_off.tail);
+        let sq_mask = *field(sq, params.sq_off.ring_mask);
+        let tail = sq_tail.load(Ordering::Relaxed);
+        let index = tail & sq_mask;
+        let sqe = io_uring_sqe {
+            opcode: IoringOp::Nop,
+            user_data: io_uring_user_data::from_u64(0x1234_5678),
+            ..Default::default()
+        };
+        sqes.add(index as usize).write(sqe);
+        field(sq, params.sq_off.array)
+            .add(index as usize)
+            .write(index);
+        sq_tail.store(tail.wrapping_add(1), Ordering::Release);
+
+        let submitted =
+            io_uring_enter(&ring, 1, 1, IoringEnterFlags::GETEVENTS, null_mut(), 0).unwrap();
+        assert_eq!(submitted, 1);
+
+        // Reap the completion.
+        let cq_head = atomic(cq, params.cq_off.head);
+        let cq_tail = atomic(cq, params.cq_off.tail);
+        let cq_mask = *field(cq, params.cq_off.ring_mask);
+        let head = cq_head.load(Ordering::Relaxed);
+        assert_eq!(cq_tail.load(Ordering::Acquire), head.wrapping_add(1));
+        let cqes = cq
+            .cast::<u8>()
+            .add(params.cq_off.cqes as usize)
+            .cast::<io_uring_cqe>();
+        let cqe = cqes.add((head & cq_mask) as usize).read();
+        assert_eq!(cqe.user_data.u64_(), 0x1234_5678);
+        assert_eq!(cqe.res, 0);
+        cq_head.store(head.wrapping_add(1), Ordering::Release);
+
+        munmap(sqes.cast(), sqes_len).unwrap();
+        munmap(cq, cq_len).unwrap();
+        munmap(sq, sq_len).unwrap();
+    }
+}
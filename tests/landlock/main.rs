@@ -0,0 +1,8 @@
+//! Tests for [`rustix::landlock`].
+
+#![cfg(feature = "landlock")]
+#![cfg(any(target_os = "android", target_os = "linux"))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+#[cfg(feature = "fs")]
+mod ruleset;
@@ -0,0 +1,80 @@
+use rustix::fs::{cwd, openat, Mode, OFlags};
+use rustix::io;
+use rustix::landlock::{
+    abi_version, add_rule, create_ruleset, restrict_self, AccessFs, LandlockAddRuleFlags,
+    LandlockCreateFlags, LandlockRestrictSelfFlags, RuleAttr, RulesetAttr,
+};
+
+#[test]
+fn test_landlock_read_only() {
+    match abi_version() {
+        Ok(version) => assert!(version >= 1),
+        // Landlock isn't compiled in, or is disabled at boot.
+        Err(io::Errno::NOSYS) | Err(io::Errno::OPNOTSUPP) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    let tmp = tempfile::tempdir().unwrap();
+    let dir = openat(
+        cwd(),
+        tmp.path(),
+        OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    // Landlock restricts only the calling thread, so do it in a new thread
+    // to leave the rest of the tests unrestricted.
+    std::thread::spawn(move || {
+        let ruleset = create_ruleset(
+            &RulesetAttr {
+                handled_access_fs: AccessFs::READ_FILE
+                    | AccessFs::READ_DIR
+                    | AccessFs::WRITE_FILE
+                    | AccessFs::MAKE_REG,
+                ..RulesetAttr::default()
+            },
+            LandlockCreateFlags::empty(),
+        )
+        .unwrap();
+        add_rule(
+            &ruleset,
+            &RuleAttr::PathBeneath {
+                allowed_access: AccessFs::READ_FILE | AccessFs::READ_DIR,
+                parent: rustix::fd::AsFd::as_fd(&dir),
+            },
+            LandlockAddRuleFlags::empty(),
+        )
+        .unwrap();
+
+        // Enforcing a ruleset without privileges requires `no_new_privs`.
+        assert_eq!(
+            unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) },
+            0
+        );
+        restrict_self(&ruleset, LandlockRestrictSelfFlags::empty()).unwrap();
+
+        // Reading the directory is still allowed.
+        openat(
+            &dir,
+            ".",
+            OFlags::RDONLY | OFlags::DIRECTORY | OFlags::CLOEXEC,
+            Mode::empty(),
+        )
+        .unwrap();
+
+        // Creating a file inside it isn't.
+        assert_eq!(
+            openat(
+                &dir,
+                "new",
+                OFlags::WRONLY | OFlags::CREATE | OFlags::CLOEXEC,
+                Mode::RUSR | Mode::WUSR,
+            )
+            .unwrap_err(),
+            io::Errno::ACCESS
+        );
+    })
+    .join()
+    .unwrap();
+}
@@ -0,0 +1,32 @@
+use rustix::mm::MmapBuffer;
+
+#[test]
+fn test_mmap_buffer() {
+    let mut buffer = MmapBuffer::new(64 * 1024).unwrap();
+    assert_eq!(buffer.len(), 64 * 1024);
+    assert!(!buffer.is_empty());
+
+    // The buffer is page-aligned and starts out zeroed.
+    #[cfg(feature = "param")]
+    assert_eq!(buffer.as_ptr() as usize % rustix::param::page_size(), 0);
+    assert!(buffer.iter().all(|b| *b == 0));
+
+    for (i, b) in buffer.as_mut_slice().iter_mut().enumerate() {
+        *b = i as u8;
+    }
+    assert!(buffer.iter().enumerate().all(|(i, b)| *b == i as u8));
+    assert_eq!(buffer.as_mut_ptr() as *const u8, buffer.as_ptr());
+}
+
+#[test]
+fn test_mmap_buffer_locked() {
+    // Locking may fail with `EPERM` or `ENOMEM` without `CAP_IPC_LOCK`, if
+    // it exceeds `RLIMIT_MEMLOCK`.
+    let mut buffer = match MmapBuffer::new_locked(4096) {
+        Ok(buffer) => buffer,
+        Err(rustix::io::Errno::PERM) | Err(rustix::io::Errno::NOMEM) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    buffer[0] = 1;
+    assert_eq!(buffer[0], 1);
+}
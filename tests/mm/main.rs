@@ -4,6 +4,10 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod buffer;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod mempolicy;
 #[cfg(not(windows))]
 #[cfg(not(target_os = "wasi"))]
 mod mlock;
@@ -11,3 +15,5 @@ mod mlock;
 mod mmap;
 #[cfg(not(windows))]
 mod prot;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod shadow_stack;
@@ -0,0 +1,144 @@
+use rustix::io;
+use rustix::mm::{
+    get_mempolicy, migrate_pages, set_mempolicy, GetMempolicyFlags, MemPolicy, NodeMask,
+};
+
+// Kernels built without NUMA support don't have the mempolicy syscalls, and
+// container seccomp profiles commonly deny them, so the tests below treat
+// `NOSYS` and `PERM` as a reason to skip.
+
+#[test]
+fn test_nodemask() {
+    let mut nodemask = NodeMask::new();
+    assert!(nodemask.is_empty());
+    nodemask.set(0);
+    nodemask.set(NodeMask::MAX_NODE - 1);
+    assert!(nodemask.is_set(0));
+    assert!(!nodemask.is_set(1));
+    assert!(nodemask.is_set(NodeMask::MAX_NODE - 1));
+    assert_eq!(nodemask.count(), 2);
+    nodemask.unset(0);
+    assert_eq!(nodemask.count(), 1);
+    nodemask.clear();
+    assert!(nodemask.is_empty());
+}
+
+#[test]
+fn test_mempolicy() {
+    let (policy, nodemask) = match get_mempolicy(None, GetMempolicyFlags::empty()) {
+        Ok(result) => result,
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("get_mempolicy failed: {:?}", err),
+    };
+    assert_eq!(policy, MemPolicy::Default);
+    assert!(nodemask.is_empty());
+
+    // There's always at least one node we're allowed to use.
+    let (policy, allowed) = get_mempolicy(None, GetMempolicyFlags::MEMS_ALLOWED).unwrap();
+    assert_eq!(policy, MemPolicy::Default);
+    assert!(!allowed.is_empty());
+
+    // The policy is per-thread, so this doesn't affect other tests.
+    set_mempolicy(MemPolicy::Local, &NodeMask::new()).unwrap();
+    let (policy, nodemask) = get_mempolicy(None, GetMempolicyFlags::empty()).unwrap();
+    assert_eq!(policy, MemPolicy::Local);
+    assert!(nodemask.is_empty());
+
+    set_mempolicy(MemPolicy::Default, &NodeMask::new()).unwrap();
+    let (policy, _) = get_mempolicy(None, GetMempolicyFlags::empty()).unwrap();
+    assert_eq!(policy, MemPolicy::Default);
+}
+
+#[test]
+fn test_mbind() {
+    use rustix::mm::{mbind, mmap_anonymous, munmap, MapFlags, MbindFlags, ProtFlags};
+    use std::ptr::null_mut;
+
+    let len = 4 * 4096;
+    unsafe {
+        let addr = mmap_anonymous(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+
+        let mut nodemask = NodeMask::new();
+        nodemask.set(0);
+        match mbind(addr, len, MemPolicy::Bind, &nodemask, MbindFlags::MOVE) {
+            Ok(()) => {
+                let (policy, nodes) = get_mempolicy(Some(addr), GetMempolicyFlags::ADDR).unwrap();
+                assert_eq!(policy, MemPolicy::Bind);
+                assert_eq!(nodes, nodemask);
+            }
+            Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => {}
+            Err(err) => panic!("mbind failed: {:?}", err),
+        }
+
+        munmap(addr, len).unwrap();
+    }
+}
+
+#[cfg(feature = "process")]
+#[test]
+fn test_migrate_pages() {
+    use rustix::process::getpid;
+
+    let mut nodemask = NodeMask::new();
+    nodemask.set(0);
+    match migrate_pages(getpid(), &nodemask, &nodemask) {
+        Ok(not_moved) => assert_eq!(not_moved, 0),
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => {}
+        Err(err) => panic!("migrate_pages failed: {:?}", err),
+    }
+}
+
+#[cfg(all(feature = "param", feature = "process"))]
+#[test]
+fn test_move_pages() {
+    use rustix::mm::{mmap_anonymous, move_pages, munmap, MapFlags, MovePagesFlags, ProtFlags};
+    use rustix::process::getpid;
+    use std::ffi::c_void;
+    use std::ptr::null_mut;
+
+    let page_size = rustix::param::page_size();
+    let len = 3 * page_size;
+    unsafe {
+        let addr = mmap_anonymous(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::PRIVATE,
+        )
+        .unwrap();
+
+        // Touch each page so that it's allocated on some node.
+        let pages = (0..3)
+            .map(|i| {
+                let page = addr.cast::<u8>().add(i * page_size);
+                page.write(1);
+                page.cast::<c_void>() as *const c_void
+            })
+            .collect::<Vec<_>>();
+
+        match move_pages(getpid(), &pages, None, MovePagesFlags::empty()) {
+            Ok(status) => {
+                assert_eq!(status.len(), pages.len());
+                for node in status {
+                    assert!(node >= 0, "unexpected page status: {}", node);
+                    assert!((node as usize) < NodeMask::MAX_NODE);
+                }
+            }
+            Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => {}
+            Err(err) => panic!("move_pages failed: {:?}", err),
+        }
+
+        assert_eq!(
+            move_pages(getpid(), &pages, Some(&[0]), MovePagesFlags::empty()),
+            Err(io::Errno::INVAL)
+        );
+
+        munmap(addr, len).unwrap();
+    }
+}
@@ -0,0 +1,19 @@
+use rustix::io;
+use rustix::mm::{map_shadow_stack, munmap, MapShadowStackFlags};
+
+#[test]
+fn test_map_shadow_stack() {
+    let size = 4 * 4096;
+    unsafe {
+        let addr = match map_shadow_stack(0, size, MapShadowStackFlags::SET_TOKEN) {
+            Ok(addr) => addr,
+            // Shadow stacks need x86-64 CET, enabled for this thread, and
+            // Linux 6.6 or later.
+            Err(io::Errno::NOSYS) | Err(io::Errno::NOTSUP) => return,
+            Err(err) => panic!("map_shadow_stack failed: {:?}", err),
+        };
+        assert!(!addr.is_null());
+        assert_eq!(addr as usize % 4096, 0);
+        munmap(addr, size).unwrap();
+    }
+}
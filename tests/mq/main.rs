@@ -0,0 +1,112 @@
+//! Tests for [`rustix::mq`].
+
+#![cfg(feature = "mq")]
+#![cfg(any(target_os = "android", target_os = "linux"))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+use rustix::fs::{Mode, OFlags, Timespec};
+use rustix::io;
+use rustix::mq::{
+    mq_getattr, mq_open, mq_receive, mq_send, mq_setattr, mq_timedreceive, mq_timedsend, mq_unlink,
+    MqAttr,
+};
+
+fn queue_name(test: &str) -> String {
+    format!("/rustix-test-{}-{}", test, std::process::id())
+}
+
+#[test]
+fn test_mq_priority_order() {
+    let name = queue_name("priority");
+    let mq = match mq_open(
+        name.as_str(),
+        OFlags::RDWR | OFlags::CREATE | OFlags::EXCL | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+        Some(&MqAttr::new(4, 16)),
+    ) {
+        Ok(mq) => mq,
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    mq_unlink(name.as_str()).unwrap();
+
+    mq_send(&mq, b"low", 1).unwrap();
+    mq_send(&mq, b"high", 5).unwrap();
+    assert_eq!(mq_getattr(&mq).unwrap().cur_msgs, 2);
+
+    let mut buf = [0_u8; 16];
+    let (len, priority) = mq_receive(&mq, &mut buf).unwrap();
+    assert_eq!((&buf[..len], priority), (&b"high"[..], 5));
+    let (len, priority) = mq_receive(&mq, &mut buf).unwrap();
+    assert_eq!((&buf[..len], priority), (&b"low"[..], 1));
+
+    // A buffer shorter than the queue's message size is rejected, even if
+    // the message would fit.
+    mq_send(&mq, b"x", 0).unwrap();
+    let mut short = [0_u8; 8];
+    assert_eq!(mq_receive(&mq, &mut short), Err(io::Errno::MSGSIZE));
+    assert_eq!(mq_send(&mq, &[0; 17], 0), Err(io::Errno::MSGSIZE));
+}
+
+#[test]
+fn test_mq_attrs_and_timeouts() {
+    let name = queue_name("attrs");
+    let mq = match mq_open(
+        name.as_str(),
+        OFlags::RDWR | OFlags::CREATE | OFlags::EXCL | OFlags::CLOEXEC,
+        Mode::RUSR | Mode::WUSR,
+        Some(&MqAttr::new(1, 8)),
+    ) {
+        Ok(mq) => mq,
+        Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    let attr = mq_getattr(&mq).unwrap();
+    assert_eq!(attr, MqAttr::new(1, 8));
+
+    let mut nonblocking = attr;
+    nonblocking.flags = OFlags::NONBLOCK;
+    assert_eq!(mq_setattr(&mq, &nonblocking).unwrap(), attr);
+    assert_eq!(mq_getattr(&mq).unwrap().flags, OFlags::NONBLOCK);
+
+    let mut buf = [0_u8; 8];
+    assert_eq!(mq_receive(&mq, &mut buf), Err(io::Errno::AGAIN));
+    mq_send(&mq, b"full", 0).unwrap();
+    assert_eq!(mq_send(&mq, b"full", 0), Err(io::Errno::AGAIN));
+
+    mq_setattr(&mq, &attr).unwrap();
+
+    // A timeout in the past fails immediately, instead of blocking.
+    let past = Timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    assert_eq!(
+        mq_timedsend(&mq, b"full", 0, &past),
+        Err(io::Errno::TIMEDOUT)
+    );
+    let (len, _) = mq_timedreceive(&mq, &mut buf, &past).unwrap();
+    assert_eq!(&buf[..len], b"full");
+    assert_eq!(
+        mq_timedreceive(&mq, &mut buf, &past),
+        Err(io::Errno::TIMEDOUT)
+    );
+
+    // Reopening by name finds the same queue.
+    let reopened = mq_open(name.as_str(), OFlags::WRONLY, Mode::empty(), None).unwrap();
+    mq_send(&reopened, b"again", 2).unwrap();
+    assert_eq!(mq_receive(&mq, &mut buf), Ok((5, 2)));
+
+    mq_unlink(name.as_str()).unwrap();
+    assert_eq!(mq_unlink(name.as_str()), Err(io::Errno::NOENT));
+}
+
+#[test]
+fn test_mq_names() {
+    assert_eq!(mq_unlink("no-slash"), Err(io::Errno::INVAL));
+    assert_eq!(
+        mq_open("", OFlags::RDONLY, Mode::empty(), None).map(drop),
+        Err(io::Errno::INVAL)
+    );
+}
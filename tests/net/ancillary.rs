@@ -0,0 +1,117 @@
+use rustix::fd::{AsFd, AsRawFd, IntoRawFd};
+use rustix::io::{pipe, read, write, IoSlice, IoSliceMut};
+use rustix::net::sockopt::set_socket_passcred;
+use rustix::net::{
+    recvmsg, sendmsg, socketpair, AddressFamily, Protocol, RecvAncillaryMessage,
+    RecvAncillaryMessages, RecvFlags, SendAncillaryBuffer, SendAncillaryMessage, SendFlags,
+    SocketType, UnixCredentials,
+};
+use rustix::process::{getgid, getpid, getuid};
+
+fn current_credentials() -> UnixCredentials {
+    UnixCredentials {
+        pid: Some(getpid()),
+        uid: getuid(),
+        gid: getgid(),
+    }
+}
+
+#[test]
+fn test_encode_decode_in_order() {
+    let (reader, writer) = pipe().unwrap();
+    let creds = current_credentials();
+
+    let fds = [reader.as_fd(), writer.as_fd()];
+    let mut space = [0_u8; 128];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    let rights = SendAncillaryMessage::ScmRights(&fds);
+    let credentials = SendAncillaryMessage::ScmCredentials(creds);
+    assert!(control.push(rights));
+    assert!(control.push(credentials));
+    assert_eq!(
+        control.as_bytes().len(),
+        rights.space() + credentials.space()
+    );
+
+    // A buffer that's too small leaves the messages pushed so far intact.
+    let mut small = [0_u8; 8];
+    let mut too_small = SendAncillaryBuffer::new(&mut small);
+    assert!(!too_small.push(credentials));
+    assert!(too_small.as_bytes().is_empty());
+
+    // Drop the last message's trailing padding, which the kernel may omit.
+    // A `struct ucred` is three 32-bit IDs.
+    let header_space = SendAncillaryMessage::ScmRights(&[]).space();
+    let mut bytes = control.as_bytes().to_vec();
+    bytes.truncate(rights.space() + header_space + 12);
+
+    // The decoded descriptors alias the ones we still own, so give them back
+    // rather than closing them.
+    let mut messages = unsafe { RecvAncillaryMessages::new(&bytes) };
+    match messages.next() {
+        Some(RecvAncillaryMessage::ScmRights(received)) => {
+            let raw: Vec<_> = received.map(IntoRawFd::into_raw_fd).collect();
+            assert_eq!(raw, [reader.as_raw_fd(), writer.as_raw_fd()]);
+        }
+        other => panic!("unexpected message {:?}", other),
+    }
+    match messages.next() {
+        Some(RecvAncillaryMessage::ScmCredentials(received)) => assert_eq!(received, creds),
+        other => panic!("unexpected message {:?}", other),
+    }
+    assert!(messages.next().is_none());
+}
+
+#[test]
+fn test_sendmsg_recvmsg_rights_and_credentials() {
+    let (a, b) = socketpair(AddressFamily::UNIX, SocketType::DGRAM, Protocol::default()).unwrap();
+    set_socket_passcred(&b, true).unwrap();
+
+    let (reader, writer) = pipe().unwrap();
+    let creds = current_credentials();
+
+    let fds = [writer.as_fd()];
+    let mut space = [0_u8; 128];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    assert!(control.push(SendAncillaryMessage::ScmRights(&fds)));
+    assert!(control.push(SendAncillaryMessage::ScmCredentials(creds)));
+    let n = sendmsg(
+        &a,
+        &[IoSlice::new(b"he"), IoSlice::new(b"llo")],
+        control.as_bytes(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(n, 5);
+    drop(writer);
+
+    let mut buf = [0_u8; 16];
+    let mut control = [0_u8; 128];
+    let ret = recvmsg(
+        &b,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::CMSG_CLOEXEC,
+    )
+    .unwrap();
+    assert_eq!(&buf[..ret.bytes], b"hello");
+    assert!(!ret.flags.contains(RecvFlags::CTRUNC));
+
+    let mut got_creds = None;
+    let mut got_fds = Vec::new();
+    for msg in unsafe { RecvAncillaryMessages::new(&control[..ret.control_len]) } {
+        match msg {
+            RecvAncillaryMessage::ScmCredentials(received) => got_creds = Some(received),
+            RecvAncillaryMessage::ScmRights(received) => got_fds.extend(received),
+            other => panic!("unexpected message {:?}", other),
+        }
+    }
+    assert_eq!(got_creds, Some(creds));
+    assert_eq!(got_fds.len(), 1);
+
+    // The received descriptor is the write end of the pipe.
+    assert_eq!(write(&got_fds[0], b"x").unwrap(), 1);
+    let mut byte = [0_u8; 1];
+    assert_eq!(read(&reader, &mut byte).unwrap(), 1);
+    assert_eq!(&byte, b"x");
+}
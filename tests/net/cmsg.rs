@@ -0,0 +1,135 @@
+use rustix::io::IoSliceMut;
+use rustix::net::{
+    bind_v4, getsockname, recvmsg, sendto_any, socket, AddressFamily, ControlMessages, Protocol,
+    RecvFlags, SendFlags, SocketAddrAny, SocketAddrV4, SocketType,
+};
+use rustix::time::Timespec;
+use std::net::Ipv4Addr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Send a datagram to a UDP socket bound to loopback and receive it with
+/// `recvmsg`, returning its control messages.
+fn loopback_roundtrip<F>(setup: F) -> (Vec<u8>, usize)
+where
+    F: FnOnce(&rustix::fd::OwnedFd),
+{
+    let s = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(&s, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    setup(&s);
+    let addr = getsockname(&s).unwrap();
+    assert!(matches!(addr, SocketAddrAny::V4(_)));
+    sendto_any(&s, b"hello", SendFlags::empty(), &addr).unwrap();
+
+    let mut buf = [0_u8; 16];
+    let mut control = vec![0_u8; 256];
+    let ret = recvmsg(
+        &s,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buf[..ret.bytes], b"hello");
+    assert!(!ret.flags.contains(RecvFlags::CTRUNC));
+    (control, ret.control_len)
+}
+
+/// Check that `ts` is a nonzero time from within the last minute.
+fn assert_recent(ts: Timespec) {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    assert!(ts.tv_sec > 0);
+    assert!((0..1_000_000_000).contains(&ts.tv_nsec));
+    assert!(ts.tv_sec as u64 <= now.as_secs());
+    assert!(ts.tv_sec as u64 + 60 >= now.as_secs());
+}
+
+#[test]
+fn test_timestamp_ns() {
+    let (control, control_len) = loopback_roundtrip(|s| {
+        rustix::net::sockopt::set_socket_timestamp_ns(s, true).unwrap();
+    });
+
+    let timestamps = ControlMessages::new(&control[..control_len])
+        .filter_map(|msg| msg.timestamp_ns())
+        .collect::<Vec<_>>();
+    assert_eq!(timestamps.len(), 1);
+    assert_recent(timestamps[0]);
+}
+
+#[test]
+fn test_timestamping() {
+    use rustix::net::sockopt::TimestampingFlags;
+
+    let (control, control_len) = loopback_roundtrip(|s| {
+        rustix::net::sockopt::set_socket_timestamping(
+            s,
+            TimestampingFlags::RX_SOFTWARE | TimestampingFlags::SOFTWARE,
+        )
+        .unwrap();
+    });
+
+    let timestamps = ControlMessages::new(&control[..control_len])
+        .filter_map(|msg| msg.timestamping())
+        .collect::<Vec<_>>();
+    assert_eq!(timestamps.len(), 1);
+    assert_recent(timestamps[0][0]);
+}
+
+#[test]
+fn test_no_control_messages() {
+    let (control, control_len) = loopback_roundtrip(|_| {});
+    assert_eq!(control_len, 0);
+    assert_eq!(ControlMessages::new(&control[..control_len]).count(), 0);
+}
+
+#[test]
+fn test_zerocopy() {
+    use rustix::io::{poll, PollFd, PollFlags};
+    use rustix::net::{accept, connect_any, listen, recv, recv_zerocopy_completions, send};
+
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    listen(&listener, 1).unwrap();
+    let addr = getsockname(&listener).unwrap();
+
+    let s = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    // `SO_ZEROCOPY` was added in Linux 4.14.
+    match rustix::net::sockopt::set_socket_zerocopy(&s, true) {
+        Ok(()) => {}
+        Err(rustix::io::Errno::NOPROTOOPT) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    connect_any(&s, &addr).unwrap();
+    let accepted = accept(&listener).unwrap();
+
+    let buf = vec![0xa5_u8; 1 << 16];
+    let mut sent = 0;
+    while sent < buf.len() {
+        sent += send(&s, &buf[sent..], SendFlags::ZEROCOPY).unwrap();
+    }
+
+    // Drain the data on the receiving side.
+    let mut received = 0;
+    let mut rbuf = vec![0_u8; 1 << 16];
+    while received < sent {
+        let n = recv(&accepted, &mut rbuf, RecvFlags::empty()).unwrap();
+        assert!(n > 0);
+        received += n;
+    }
+
+    // Wait for the completions to arrive on the error queue.
+    let mut completions = Vec::new();
+    while completions.is_empty() {
+        let mut fds = [PollFd::new(&s, PollFlags::empty())];
+        assert_ne!(poll(&mut fds, 5000).unwrap(), 0, "no zerocopy completions");
+        match recv_zerocopy_completions(&s) {
+            Ok(iter) => completions.extend(iter),
+            Err(rustix::io::Errno::AGAIN) => continue,
+            Err(err) => panic!("{:?}", err),
+        }
+    }
+
+    // The sends are numbered from zero.
+    assert_eq!(completions[0].lo, 0);
+    assert!(completions.iter().all(|c| c.lo <= c.hi));
+}
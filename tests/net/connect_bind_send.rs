@@ -485,3 +485,41 @@ fn net_v6_acceptfrom() -> std::io::Result<()> {
 
     Ok(())
 }
+
+/// Probe the length of a UDP datagram before receiving it.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn net_v4_recv_peek_len() -> std::io::Result<()> {
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(localhost, 0);
+    let receiver =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default())?;
+    rustix::net::bind(&receiver, &addr).expect("bind");
+    let local_addr = rustix::net::getsockname(&receiver)?;
+
+    let sender = rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default())?;
+    let request = [0x5a_u8; 100];
+    let n = rustix::net::sendto_any(&sender, &request, SendFlags::empty(), &local_addr)
+        .expect("sendto");
+    assert_eq!(n, request.len());
+
+    // Probing doesn't consume the datagram, so it can be probed again.
+    assert_eq!(rustix::net::recv_peek_len(&receiver).expect("peek"), 100);
+    assert_eq!(rustix::net::recv_peek_len(&receiver).expect("peek"), 100);
+
+    let mut response = vec![0_u8; rustix::net::recv_peek_len(&receiver)?];
+    let n = rustix::net::recv(&receiver, &mut response, RecvFlags::empty()).expect("recv");
+    assert_eq!(&request[..], &response[..n]);
+
+    // The datagram is gone now.
+    assert_eq!(
+        rustix::net::recv(
+            &receiver,
+            &mut [],
+            RecvFlags::PEEK | RecvFlags::TRUNC | RecvFlags::DONTWAIT
+        ),
+        Err(rustix::io::Errno::AGAIN)
+    );
+
+    Ok(())
+}
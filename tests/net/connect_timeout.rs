@@ -0,0 +1,76 @@
+use rustix::fs::{fcntl_getfl, OFlags};
+use rustix::io::Errno;
+use rustix::net::{AddressFamily, IpAddr, Ipv4Addr, Protocol, SocketAddr, SocketType};
+use std::time::{Duration, Instant};
+
+/// Connect to a listening socket, and check that the socket is left in
+/// blocking mode.
+#[test]
+fn net_connect_timeout_connects() {
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(localhost, 0);
+    let listener =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::bind(&listener, &addr).expect("bind");
+    rustix::net::listen(&listener, 1).expect("listen");
+    let local_addr = rustix::net::getsockname(&listener).unwrap();
+
+    let sender =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::connect_timeout(&sender, &local_addr, Duration::from_secs(10))
+        .expect("connect_timeout");
+    assert!(!fcntl_getfl(&sender).unwrap().contains(OFlags::NONBLOCK));
+
+    let _accepted = rustix::net::accept(&listener).expect("accept");
+}
+
+/// Connect to a port nothing is listening on.
+#[test]
+fn net_connect_timeout_refused() {
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(localhost, 0);
+    // Bind a socket without listening on it to find an unused port.
+    let unused =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::bind(&unused, &addr).expect("bind");
+    let local_addr = rustix::net::getsockname(&unused).unwrap();
+
+    let sender =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    assert_eq!(
+        rustix::net::connect_timeout(&sender, &local_addr, Duration::from_secs(10)),
+        Err(Errno::CONNREFUSED)
+    );
+    assert!(!fcntl_getfl(&sender).unwrap().contains(OFlags::NONBLOCK));
+}
+
+/// Connect to a listener whose backlog is full, so the connection isn't
+/// completed.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn net_connect_timeout_times_out() {
+    let localhost = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let addr = SocketAddr::new(localhost, 0);
+    let listener =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::bind(&listener, &addr).expect("bind");
+    rustix::net::listen(&listener, 0).expect("listen");
+    let local_addr = rustix::net::getsockname(&listener).unwrap();
+
+    // Linux drops connection requests once the accept queue is full, and a
+    // backlog of zero leaves room for one connection.
+    let first =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::connect_any(&first, &local_addr).expect("connect");
+
+    let sender =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    let timeout = Duration::from_millis(200);
+    let start = Instant::now();
+    assert_eq!(
+        rustix::net::connect_timeout(&sender, &local_addr, timeout),
+        Err(Errno::TIMEDOUT)
+    );
+    assert!(start.elapsed() >= timeout);
+    assert!(!fcntl_getfl(&sender).unwrap().contains(OFlags::NONBLOCK));
+}
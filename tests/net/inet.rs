@@ -0,0 +1,138 @@
+//! Test numeric address parsing and formatting.
+
+use rustix::io;
+use rustix::net::{
+    format_ip, format_ipv4, format_ipv6, parse_ipv4, parse_ipv6, IpAddr, Ipv4Addr, Ipv6Addr,
+};
+
+#[test]
+fn test_inet_ipv4() {
+    for s in &[
+        "0.0.0.0",
+        "127.0.0.1",
+        "192.0.2.1",
+        "10.200.30.4",
+        "255.255.255.255",
+    ] {
+        let addr = parse_ipv4(s).unwrap();
+        assert_eq!(addr, s.parse::<Ipv4Addr>().unwrap());
+        assert_eq!(format_ipv4(&addr), *s);
+    }
+
+    for s in &[
+        "",
+        "1.2.3",
+        "1.2.3.4.5",
+        "1.2.3.256",
+        "1.2.3.04",
+        "1.2.3.-4",
+        "1.2..4",
+        "1.2.3.4 ",
+        "0x1.2.3.4",
+        "1234.2.3.4",
+    ] {
+        assert_eq!(parse_ipv4(s), Err(io::Errno::INVAL), "{:?}", s);
+    }
+}
+
+#[test]
+fn test_inet_ipv6() {
+    for s in &[
+        "::",
+        "::1",
+        "1::",
+        "2001:db8::1",
+        "2001:db8::1:0:0:1",
+        "2001:db8:0:1:1:1:1:1",
+        "2001:0:0:1::1",
+        "fe80::1:2:3:4",
+        "1:2:3:4:5:6:7:8",
+        "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+        "::ffff:1.2.3.4",
+        "::ffff:255.255.255.255",
+        "::ffff:0.0.0.0",
+        "64:ff9b::c000:201",
+    ] {
+        let (addr, zone) = parse_ipv6(s).unwrap();
+        assert_eq!(zone, None);
+        assert_eq!(addr, s.parse::<Ipv6Addr>().unwrap(), "{:?}", s);
+        assert_eq!(format_ipv6(&addr), *s);
+    }
+
+    // Inputs which aren't in canonical form.
+    for (s, canonical) in &[
+        ("0:0:0:0:0:0:0:0", "::"),
+        ("0:0:0:0:0:0:0:1", "::1"),
+        ("2001:DB8::0001", "2001:db8::1"),
+        ("2001:db8:0:0:1:0:0:1", "2001:db8::1:0:0:1"),
+        ("1:0:2:3:4:5:6:7", "1:0:2:3:4:5:6:7"),
+        ("1:2:3:4:5:6:7::", "1:2:3:4:5:6:7:0"),
+        ("::2:3:4:5:6:7:8", "0:2:3:4:5:6:7:8"),
+        ("1:0:0:2:0:0:3:4", "1::2:0:0:3:4"),
+        ("1:0:0:2:0:0:0:4", "1:0:0:2::4"),
+        ("::0:ffff:1.2.3.4", "::ffff:1.2.3.4"),
+        ("::1.2.3.4", "::102:304"),
+        ("1:2:3:4:5:6:1.2.3.4", "1:2:3:4:5:6:102:304"),
+        ("::ffff:102:304", "::ffff:1.2.3.4"),
+    ] {
+        let (addr, zone) = parse_ipv6(s).unwrap();
+        assert_eq!(zone, None);
+        assert_eq!(addr, s.parse::<Ipv6Addr>().unwrap(), "{:?}", s);
+        // Not compared with `to_string`, as older versions of std format
+        // IPv4-compatible addresses such as `::102:304` in dotted-decimal.
+        assert_eq!(format_ipv6(&addr), *canonical);
+    }
+
+    for s in &[
+        "",
+        ":",
+        ":::",
+        "1:2",
+        "1::2::3",
+        ":1::2",
+        "1::2:",
+        "1:2:3:4:5:6:7",
+        "1:2:3:4:5:6:7:8:9",
+        "1:2:3:4:5:6:7:8::",
+        "::1:2:3:4:5:6:7:8",
+        "12345::",
+        "g::",
+        "::1.2.3",
+        "::1.2.3.4:5",
+        "1.2.3.4::",
+        "1:2:3:4:5:6:7:1.2.3.4",
+        "::ffff:1.2.3.256",
+        "1::2%",
+    ] {
+        assert_eq!(parse_ipv6(s), Err(io::Errno::INVAL), "{:?}", s);
+    }
+}
+
+#[test]
+fn test_inet_ipv6_zone() {
+    let (addr, zone) = parse_ipv6("fe80::1%eth0").unwrap();
+    assert_eq!(addr, Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1));
+    assert_eq!(zone, Some("eth0"));
+    assert_eq!(
+        format!("{}%{}", format_ipv6(&addr), zone.unwrap()),
+        "fe80::1%eth0"
+    );
+
+    let (addr, zone) = parse_ipv6("fe80::abcd:1%2").unwrap();
+    assert_eq!(addr, Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0xabcd, 1));
+    assert_eq!(zone, Some("2"));
+
+    assert_eq!(parse_ipv6("fe80::1%"), Err(io::Errno::INVAL));
+    assert_eq!(parse_ipv6("fe80::g%eth0"), Err(io::Errno::INVAL));
+}
+
+#[test]
+fn test_inet_format_ip() {
+    let v4 = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+    let v6 = IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1));
+    assert_eq!(format_ip(&v4).as_str(), "192.0.2.1");
+    assert_eq!(format_ip(&v6).as_str(), "2001:db8::1");
+    assert_eq!(format_ip(&v6).to_string(), v6.to_string());
+    assert_eq!(format!("{:>13}", format_ip(&v4)), "    192.0.2.1");
+    assert_eq!(format!("{:?}", format_ip(&v4)), "\"192.0.2.1\"");
+}
@@ -7,13 +7,39 @@
 #![cfg_attr(core_c_str, feature(core_c_str))]
 
 mod addr;
+#[cfg(all(feature = "process", any(target_os = "android", target_os = "linux")))]
+mod ancillary;
+#[cfg(feature = "time")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod cmsg;
 mod connect_bind_send;
+#[cfg(all(feature = "fs", not(windows)))]
+mod connect_timeout;
+mod inet;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod netlink;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod packet;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod pktinfo;
 mod poll;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod sendmsg;
+mod shutdown;
+#[cfg(not(windows))]
+mod sockatmark;
+#[cfg(not(windows))]
+mod socketpair;
+mod sockname;
 mod sockopt;
 #[cfg(unix)]
 mod unix;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod unix_abstract;
 mod v4;
 mod v6;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod vsock;
 
 /// Windows requires us to call a setup function before using any of the
 /// socket APIs.
@@ -0,0 +1,44 @@
+use rustix::net::{
+    bind_netlink, getsockname, socket, AddressFamily, NetlinkFamily, SocketAddrAny,
+    SocketAddrNetlink, SocketAddrStorage, SocketType,
+};
+use std::mem::MaybeUninit;
+
+#[test]
+fn test_netlink_encode_roundtrip() {
+    let mut netlink = SocketAddrNetlink::new(1234, 0);
+    netlink.set_groups(0x5);
+    assert_eq!(netlink.pid(), 1234);
+    assert_eq!(netlink.groups(), 0x5);
+
+    let any = SocketAddrAny::from(netlink);
+    assert_eq!(any.address_family(), AddressFamily::NETLINK);
+
+    let mut storage = MaybeUninit::<SocketAddrStorage>::uninit();
+    let decoded = unsafe {
+        let len = any.write(storage.as_mut_ptr());
+        SocketAddrAny::read(storage.as_ptr(), len).unwrap()
+    };
+    assert_eq!(decoded, any);
+}
+
+#[test]
+fn test_netlink_route_bind() {
+    let s = socket(
+        AddressFamily::NETLINK,
+        SocketType::RAW,
+        NetlinkFamily::ROUTE.into(),
+    )
+    .unwrap();
+
+    // A pid of 0 asks the kernel to assign one.
+    bind_netlink(&s, &SocketAddrNetlink::new(0, 0)).unwrap();
+
+    match getsockname(&s).unwrap() {
+        SocketAddrAny::Netlink(netlink) => {
+            assert_ne!(netlink.pid(), 0);
+            assert_eq!(netlink.groups(), 0);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+}
@@ -0,0 +1,83 @@
+use rustix::io::Errno;
+use rustix::net::{
+    bind_link, getsockname, recvfrom, sendto_link, socket, AddressFamily, Protocol, RecvFlags,
+    SendFlags, SocketAddrAny, SocketAddrLink, SocketAddrStorage, SocketType,
+};
+use std::mem::MaybeUninit;
+use std::time::Duration;
+
+/// The IEEE "local experimental" ethertype.
+const ETH_P_LOCAL: u16 = 0x88b5;
+
+#[test]
+fn test_link_encode_roundtrip() {
+    let mut link = SocketAddrLink::new(ETH_P_LOCAL, 7);
+    link.set_hatype(1);
+    link.set_pkttype(3);
+    link.set_addr(&[0x02, 0, 0, 0, 0, 0x01]).unwrap();
+    assert_eq!(link.addr(), &[0x02, 0, 0, 0, 0, 0x01]);
+    assert_eq!(link.set_addr(&[0; 9]), Err(Errno::INVAL));
+
+    let any = SocketAddrAny::from(link);
+    assert_eq!(any.address_family(), AddressFamily::PACKET);
+
+    let mut storage = MaybeUninit::<SocketAddrStorage>::uninit();
+    let decoded = unsafe {
+        let len = any.write(storage.as_mut_ptr());
+        SocketAddrAny::read(storage.as_ptr(), len).unwrap()
+    };
+    assert_eq!(decoded, any);
+}
+
+#[test]
+fn test_packet_socket_lo() {
+    let s = match socket(
+        AddressFamily::PACKET,
+        SocketType::RAW,
+        Protocol::from_raw(ETH_P_LOCAL.to_be().into()),
+    ) {
+        Ok(s) => s,
+        Err(Errno::PERM) | Err(Errno::ACCESS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    rustix::net::sockopt::set_socket_recv_timeout(&s, Some(Duration::from_secs(5))).unwrap();
+
+    let ifindex = std::fs::read_to_string("/sys/class/net/lo/ifindex")
+        .unwrap()
+        .trim()
+        .parse::<u32>()
+        .unwrap();
+    bind_link(&s, &SocketAddrLink::new(ETH_P_LOCAL, ifindex)).unwrap();
+
+    match getsockname(&s).unwrap() {
+        SocketAddrAny::Link(link) => {
+            assert_eq!(link.protocol(), ETH_P_LOCAL);
+            assert_eq!(link.ifindex(), ifindex);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+
+    // An Ethernet frame with all-zero MAC addresses, as `lo` uses.
+    let mut frame = [0_u8; 20];
+    frame[12..14].copy_from_slice(&ETH_P_LOCAL.to_be_bytes());
+    frame[14..].copy_from_slice(b"rustix");
+    let nsent = sendto_link(
+        &s,
+        &frame,
+        SendFlags::empty(),
+        &SocketAddrLink::new(ETH_P_LOCAL, ifindex),
+    )
+    .unwrap();
+    assert_eq!(nsent, frame.len());
+
+    let mut buf = [0_u8; 64];
+    let (n, from) = recvfrom(&s, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], &frame);
+    match from {
+        Some(SocketAddrAny::Link(link)) => {
+            assert_eq!(link.protocol(), ETH_P_LOCAL);
+            assert_eq!(link.ifindex(), ifindex);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+}
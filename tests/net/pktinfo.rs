@@ -0,0 +1,98 @@
+use rustix::io::{IoSlice, IoSliceMut};
+use rustix::net::sockopt::{get_ip_pktinfo, set_ip_pktinfo};
+use rustix::net::{
+    bind_v4, connect_v4, getsockname, recvfrom, recvmsg, sendmsg, sendto_v4, socket, AddressFamily,
+    Ipv4PacketInfo, Protocol, RecvAncillaryMessage, RecvAncillaryMessages, RecvFlags,
+    SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketAddrAny, SocketAddrV4, SocketType,
+};
+use std::net::Ipv4Addr;
+
+/// Return the port a socket is bound to.
+fn local_port(fd: &rustix::fd::OwnedFd) -> u16 {
+    match getsockname(fd).unwrap() {
+        SocketAddrAny::V4(addr) => addr.port(),
+        other => panic!("unexpected address {:?}", other),
+    }
+}
+
+#[test]
+fn test_ip_pktinfo_recv() {
+    let server = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(&server, &SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)).unwrap();
+    assert!(!get_ip_pktinfo(&server).unwrap());
+    set_ip_pktinfo(&server, true).unwrap();
+    assert!(get_ip_pktinfo(&server).unwrap());
+    let port = local_port(&server);
+
+    let client = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    sendto_v4(
+        &client,
+        b"hello",
+        SendFlags::empty(),
+        &SocketAddrV4::new(Ipv4Addr::LOCALHOST, port),
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 16];
+    let mut control = [0_u8; 64];
+    let ret = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buf[..ret.bytes], b"hello");
+
+    let mut infos = Vec::new();
+    for msg in unsafe { RecvAncillaryMessages::new(&control[..ret.control_len]) } {
+        match msg {
+            RecvAncillaryMessage::Ipv4PacketInfo(info) => infos.push(info),
+            other => panic!("unexpected message {:?}", other),
+        }
+    }
+    assert_eq!(infos.len(), 1);
+    assert_ne!(infos[0].ifindex, 0);
+    assert_eq!(infos[0].local_addr, Ipv4Addr::LOCALHOST);
+    assert_eq!(infos[0].dest_addr, Ipv4Addr::LOCALHOST);
+}
+
+#[test]
+fn test_ip_pktinfo_send() {
+    let server = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(&server, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    let port = local_port(&server);
+
+    let client = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(&client, &SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)).unwrap();
+    connect_v4(&client, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, port)).unwrap();
+
+    // Pick the source address explicitly.
+    let mut space = [0_u8; 64];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    assert!(
+        control.push(SendAncillaryMessage::Ipv4PacketInfo(Ipv4PacketInfo {
+            ifindex: 0,
+            local_addr: Ipv4Addr::LOCALHOST,
+            dest_addr: Ipv4Addr::UNSPECIFIED,
+        }))
+    );
+    sendmsg(
+        &client,
+        &[IoSlice::new(b"hello")],
+        control.as_bytes(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 16];
+    let (n, from) = recvfrom(&server, &mut buf, RecvFlags::empty()).unwrap();
+    assert_eq!(&buf[..n], b"hello");
+    match from {
+        Some(SocketAddrAny::V4(from)) => {
+            assert_eq!(*from.ip(), Ipv4Addr::LOCALHOST);
+            assert_eq!(from.port(), local_port(&client));
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+}
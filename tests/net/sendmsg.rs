@@ -0,0 +1,109 @@
+use rustix::io::{IoSlice, IoSliceMut};
+use rustix::net::sockopt::set_ip_pktinfo;
+use rustix::net::{
+    accept, bind_v4, connect_any, getsockname, listen, recvmsg, sendmsg, sendmsg_addr, socket,
+    AddressFamily, Ipv4PacketInfo, Protocol, RecvAncillaryMessage, RecvAncillaryMessages,
+    RecvFlags, SendAncillaryBuffer, SendAncillaryMessage, SendFlags, SocketAddrAny, SocketAddrV4,
+    SocketType,
+};
+use std::net::Ipv4Addr;
+
+/// A connected TCP socket doesn't need an address to send to, and doesn't
+/// report one for the messages it receives.
+#[test]
+fn test_sendmsg_connected() {
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    listen(&listener, 1).unwrap();
+    let addr = getsockname(&listener).unwrap();
+
+    let client = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    connect_any(&client, &addr).unwrap();
+    let server = accept(&listener).unwrap();
+
+    let n = sendmsg(
+        &client,
+        &[IoSlice::new(b"he"), IoSlice::new(b"llo")],
+        &[],
+        SendFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(n, 5);
+
+    let mut buf = [0_u8; 5];
+    let ret = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut [],
+        RecvFlags::WAITALL,
+    )
+    .unwrap();
+    assert_eq!(&buf[..ret.bytes], b"hello");
+    assert_eq!(ret.address, None);
+}
+
+/// An unconnected UDP socket sends to an explicit address, and reports the
+/// address of each message it receives, along with its control messages.
+#[test]
+fn test_sendmsg_addr_unconnected() {
+    let server = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(&server, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    set_ip_pktinfo(&server, true).unwrap();
+    let server_addr = getsockname(&server).unwrap();
+
+    let client = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(&client, &SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 0)).unwrap();
+
+    // Pick the source address explicitly.
+    let mut space = [0_u8; 64];
+    let mut control = SendAncillaryBuffer::new(&mut space);
+    assert!(
+        control.push(SendAncillaryMessage::Ipv4PacketInfo(Ipv4PacketInfo {
+            ifindex: 0,
+            local_addr: Ipv4Addr::LOCALHOST,
+            dest_addr: Ipv4Addr::UNSPECIFIED,
+        }))
+    );
+    let n = sendmsg_addr(
+        &client,
+        &server_addr,
+        &[IoSlice::new(b"hello")],
+        control.as_bytes(),
+        SendFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(n, 5);
+
+    let mut buf = [0_u8; 16];
+    let mut control = [0_u8; 64];
+    let ret = recvmsg(
+        &server,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut control,
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(&buf[..ret.bytes], b"hello");
+
+    let client_port = match getsockname(&client).unwrap() {
+        SocketAddrAny::V4(addr) => addr.port(),
+        other => panic!("unexpected address {:?}", other),
+    };
+    assert_eq!(
+        ret.address,
+        Some(SocketAddrAny::V4(SocketAddrV4::new(
+            Ipv4Addr::LOCALHOST,
+            client_port
+        )))
+    );
+
+    let mut infos = Vec::new();
+    for msg in unsafe { RecvAncillaryMessages::new(&control[..ret.control_len]) } {
+        match msg {
+            RecvAncillaryMessage::Ipv4PacketInfo(info) => infos.push(info),
+            other => panic!("unexpected message {:?}", other),
+        }
+    }
+    assert_eq!(infos.len(), 1);
+    assert_eq!(infos[0].dest_addr, Ipv4Addr::LOCALHOST);
+}
@@ -0,0 +1,42 @@
+use rustix::io::Errno;
+use rustix::net::{
+    accept, bind_v4, connect_any, getsockname, listen, recv, send, shutdown, socket, AddressFamily,
+    Ipv4Addr, Protocol, RecvFlags, SendFlags, Shutdown, SocketAddrV4, SocketType,
+};
+
+#[test]
+fn test_shutdown_write() {
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    connect_any(&client, &getsockname(&listener).unwrap()).unwrap();
+    let server = accept(&listener).unwrap();
+
+    shutdown(&client, Shutdown::Write).unwrap();
+
+    // The peer sees end-of-file.
+    let mut buf = [0_u8; 4];
+    assert_eq!(recv(&server, &mut buf, RecvFlags::empty()).unwrap(), 0);
+
+    // Writing to the shut-down side fails. The Rust runtime ignores
+    // `SIGPIPE`, so this returns an error rather than killing the process.
+    #[cfg(not(windows))]
+    assert_eq!(send(&client, b"x", SendFlags::empty()), Err(Errno::PIPE));
+    #[cfg(windows)]
+    assert_eq!(
+        send(&client, b"x", SendFlags::empty()),
+        Err(Errno::SHUTDOWN)
+    );
+
+    // The other direction still works.
+    assert_eq!(send(&server, b"x", SendFlags::empty()).unwrap(), 1);
+    assert_eq!(recv(&client, &mut buf, RecvFlags::empty()).unwrap(), 1);
+}
+
+#[test]
+fn test_shutdown_not_connected() {
+    let s = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    assert_eq!(shutdown(&s, Shutdown::ReadWrite), Err(Errno::NOTCONN));
+}
@@ -0,0 +1,34 @@
+use rustix::io::{poll, PollFd, PollFlags};
+use rustix::net::{
+    accept, bind_v4, connect_any, getsockname, listen, recv, send, sockatmark, socket,
+    AddressFamily, Ipv4Addr, Protocol, RecvFlags, SendFlags, SocketAddrV4, SocketType,
+};
+
+#[test]
+fn test_sockatmark() {
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    connect_any(&client, &getsockname(&listener).unwrap()).unwrap();
+    let server = accept(&listener).unwrap();
+
+    assert_eq!(send(&client, b"ab", SendFlags::empty()).unwrap(), 2);
+    assert_eq!(send(&client, b"c", SendFlags::OOB).unwrap(), 1);
+
+    // Wait for the urgent data to arrive.
+    let mut fds = [PollFd::new(&server, PollFlags::PRI)];
+    assert_eq!(poll(&mut fds, 5000).unwrap(), 1);
+
+    // The normal data comes before the mark, and reads stop at the mark.
+    assert!(!sockatmark(&server).unwrap());
+    let mut buf = [0_u8; 16];
+    assert_eq!(recv(&server, &mut buf, RecvFlags::empty()).unwrap(), 2);
+    assert_eq!(&buf[..2], b"ab");
+    assert!(sockatmark(&server).unwrap());
+
+    // The urgent byte itself is read out of band.
+    assert_eq!(recv(&server, &mut buf, RecvFlags::OOB).unwrap(), 1);
+    assert_eq!(buf[0], b'c');
+}
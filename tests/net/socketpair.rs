@@ -0,0 +1,110 @@
+use rustix::io::{fcntl_getfd, read, write, Errno, FdFlags};
+use rustix::net::{socketpair, socketpair_with, AddressFamily, Protocol, SocketFlags, SocketType};
+
+#[test]
+fn test_socketpair() {
+    let (a, b) = socketpair(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    assert_eq!(write(&a, b"hi").unwrap(), 2);
+    let mut buf = [0_u8; 2];
+    assert_eq!(read(&b, &mut buf).unwrap(), 2);
+    assert_eq!(&buf, b"hi");
+}
+
+#[cfg(not(any(target_os = "haiku", target_os = "ios", target_os = "macos")))]
+#[test]
+fn test_socketpair_with() {
+    let (a, b) = socketpair_with(
+        AddressFamily::UNIX,
+        SocketType::STREAM,
+        SocketFlags::NONBLOCK | SocketFlags::CLOEXEC,
+        Protocol::default(),
+    )
+    .unwrap();
+
+    let mut buf = [0_u8; 4];
+    assert_eq!(read(&a, &mut buf), Err(Errno::AGAIN));
+    assert_eq!(read(&b, &mut buf), Err(Errno::AGAIN));
+
+    assert!(fcntl_getfd(&a).unwrap().contains(FdFlags::CLOEXEC));
+    assert!(fcntl_getfd(&b).unwrap().contains(FdFlags::CLOEXEC));
+}
+
+/// `SOCK_SEQPACKET` preserves message boundaries, like a datagram socket, on
+/// a connected pair.
+#[cfg(not(any(target_os = "haiku", target_os = "ios", target_os = "macos")))]
+#[test]
+fn test_socketpair_seqpacket() {
+    use rustix::net::{recv, send, RecvFlags, SendFlags};
+
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::SEQPACKET,
+        Protocol::default(),
+    )
+    .unwrap();
+
+    assert_eq!(send(&a, b"hello", SendFlags::empty()).unwrap(), 5);
+    assert_eq!(send(&a, b"world!", SendFlags::empty()).unwrap(), 6);
+
+    let mut buf = [0_u8; 16];
+    assert_eq!(recv(&b, &mut buf, RecvFlags::empty()).unwrap(), 5);
+    assert_eq!(&buf[..5], b"hello");
+    assert_eq!(recv(&b, &mut buf, RecvFlags::empty()).unwrap(), 6);
+    assert_eq!(&buf[..6], b"world!");
+
+    // A short read discards the rest of the message.
+    assert_eq!(send(&b, b"truncated", SendFlags::empty()).unwrap(), 9);
+    assert_eq!(send(&b, b"next", SendFlags::empty()).unwrap(), 4);
+    let mut small = [0_u8; 4];
+    assert_eq!(recv(&a, &mut small, RecvFlags::empty()).unwrap(), 4);
+    assert_eq!(&small, b"trun");
+    assert_eq!(recv(&a, &mut buf, RecvFlags::empty()).unwrap(), 4);
+    assert_eq!(&buf[..4], b"next");
+}
+
+/// On Linux, a short read of a `SOCK_SEQPACKET` message reports `MSG_TRUNC`,
+/// and passing `MSG_TRUNC` returns the message's full length.
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_socketpair_seqpacket_trunc() {
+    use rustix::net::{recv, recvmsg, send, RecvFlags, SendFlags};
+    use std::io::IoSliceMut;
+
+    let (a, b) = socketpair(
+        AddressFamily::UNIX,
+        SocketType::SEQPACKET,
+        Protocol::default(),
+    )
+    .unwrap();
+
+    assert_eq!(send(&a, b"truncated", SendFlags::empty()).unwrap(), 9);
+    assert_eq!(send(&a, b"whole", SendFlags::empty()).unwrap(), 5);
+    assert_eq!(send(&a, b"truncated", SendFlags::empty()).unwrap(), 9);
+
+    let mut small = [0_u8; 4];
+    let ret = recvmsg(
+        &b,
+        &mut [IoSliceMut::new(&mut small)],
+        &mut [],
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(ret.bytes, 4);
+    assert!(ret.flags.contains(RecvFlags::TRUNC));
+    assert_eq!(&small, b"trun");
+
+    let mut buf = [0_u8; 16];
+    let ret = recvmsg(
+        &b,
+        &mut [IoSliceMut::new(&mut buf)],
+        &mut [],
+        RecvFlags::empty(),
+    )
+    .unwrap();
+    assert_eq!(ret.bytes, 5);
+    assert!(!ret.flags.contains(RecvFlags::TRUNC));
+    assert_eq!(&buf[..5], b"whole");
+
+    assert_eq!(recv(&b, &mut small, RecvFlags::TRUNC).unwrap(), 9);
+    assert_eq!(&small, b"trun");
+}
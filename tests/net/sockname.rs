@@ -0,0 +1,64 @@
+//! Test that `getsockname` and `getpeername` decode the addresses the kernel
+//! returns.
+
+use rustix::net::{
+    accept, bind_v4, connect_any, getpeername, getsockname, listen, socket, AddressFamily,
+    Ipv4Addr, Protocol, SocketAddrAny, SocketAddrV4, SocketType,
+};
+
+#[test]
+fn test_getsockname_v4() {
+    let s = socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    bind_v4(&s, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+
+    match getsockname(&s).unwrap() {
+        SocketAddrAny::V4(v4) => {
+            assert_eq!(v4.ip(), &Ipv4Addr::LOCALHOST);
+            assert_ne!(v4.port(), 0);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+    assert_eq!(getpeername(&s), Err(rustix::io::Errno::NOTCONN));
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_getsockname_abstract_unix() {
+    use rustix::net::{bind_unix, SocketAddrUnix};
+
+    let name = format!("rustix-sockname-{}", std::process::id());
+    let s = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_unix(
+        &s,
+        &SocketAddrUnix::new_abstract_name(name.as_bytes()).unwrap(),
+    )
+    .unwrap();
+
+    match getsockname(&s).unwrap() {
+        SocketAddrAny::Unix(unix) => {
+            assert_eq!(unix.abstract_name(), Some(name.as_bytes()));
+            assert_eq!(unix.path(), None);
+        }
+        other => panic!("unexpected address {:?}", other),
+    }
+}
+
+#[test]
+fn test_getpeername_matches_getsockname() {
+    let listener = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_v4(&listener, &SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0)).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    connect_any(&client, &getsockname(&listener).unwrap()).unwrap();
+    let server = accept(&listener).unwrap();
+
+    assert_eq!(
+        getpeername(&client).unwrap(),
+        Some(getsockname(&server).unwrap())
+    );
+    assert_eq!(
+        getpeername(&server).unwrap(),
+        Some(getsockname(&client).unwrap())
+    );
+}
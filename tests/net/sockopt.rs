@@ -141,3 +141,447 @@ fn test_sockopts() {
     // Check that the nodelay flag is set.
     assert!(rustix::net::sockopt::get_tcp_nodelay(&s).unwrap());
 }
+
+#[cfg(not(any(windows, target_os = "illumos", target_os = "solaris")))]
+#[test]
+fn test_sockopts_reuseport() {
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    let first =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    assert!(!rustix::net::sockopt::get_socket_reuseport(&first).unwrap());
+    rustix::net::sockopt::set_socket_reuseport(&first, true).unwrap();
+    assert!(rustix::net::sockopt::get_socket_reuseport(&first).unwrap());
+
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+    rustix::net::bind(&first, &addr).unwrap();
+    let local_addr = rustix::net::getsockname(&first).unwrap();
+
+    // A second socket with `SO_REUSEPORT` can bind to the same port.
+    let second =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    rustix::net::sockopt::set_socket_reuseport(&second, true).unwrap();
+    rustix::net::bind_any(&second, &local_addr).unwrap();
+    assert_eq!(rustix::net::getsockname(&second).unwrap(), local_addr);
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sockopts_bindtodevice() {
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+
+    let mut buf = [0_u8; 16];
+    assert!(rustix::net::sockopt::get_socket_bindtodevice(&s, &mut buf)
+        .unwrap()
+        .as_bytes()
+        .is_empty());
+
+    // Binding to a device requires `CAP_NET_RAW`.
+    match rustix::net::sockopt::set_socket_bindtodevice(&s, Some(rustix::cstr!("lo"))) {
+        Ok(()) => {}
+        Err(rustix::io::Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_eq!(
+        rustix::net::sockopt::get_socket_bindtodevice(&s, &mut buf)
+            .unwrap()
+            .as_bytes(),
+        b"lo"
+    );
+
+    rustix::net::sockopt::set_socket_bindtodevice(&s, None).unwrap();
+    assert!(rustix::net::sockopt::get_socket_bindtodevice(&s, &mut buf)
+        .unwrap()
+        .as_bytes()
+        .is_empty());
+}
+
+// On Windows, a timed-out `recv` fails with `WSAETIMEDOUT` instead.
+#[cfg(not(windows))]
+#[test]
+fn test_sockopts_recv_timeout() {
+    use rustix::net::{AddressFamily, Protocol, RecvFlags, SocketType};
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use std::time::{Duration, Instant};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    rustix::net::bind(&s, &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+
+    assert!(rustix::net::sockopt::get_socket_recv_timeout(&s)
+        .unwrap()
+        .is_none());
+    assert!(rustix::net::sockopt::get_socket_send_timeout(&s)
+        .unwrap()
+        .is_none());
+
+    // Sub-microsecond timeouts are rounded up rather than disabling the
+    // timeout.
+    rustix::net::sockopt::set_socket_send_timeout(&s, Some(Duration::new(0, 1))).unwrap();
+    assert!(rustix::net::sockopt::get_socket_send_timeout(&s)
+        .unwrap()
+        .is_some());
+    rustix::net::sockopt::set_socket_send_timeout(&s, None).unwrap();
+    assert!(rustix::net::sockopt::get_socket_send_timeout(&s)
+        .unwrap()
+        .is_none());
+
+    let timeout = Duration::from_millis(50);
+    rustix::net::sockopt::set_socket_recv_timeout(&s, Some(timeout)).unwrap();
+    assert!(
+        rustix::net::sockopt::get_socket_recv_timeout(&s)
+            .unwrap()
+            .unwrap()
+            >= timeout
+    );
+
+    // Nothing is sent, so the `recv` times out.
+    let start = Instant::now();
+    let mut buf = [0_u8; 16];
+    assert_eq!(
+        rustix::net::recv(&s, &mut buf, RecvFlags::empty()),
+        Err(rustix::io::Errno::AGAIN)
+    );
+    assert!(start.elapsed() >= Duration::from_millis(40));
+}
+
+#[test]
+fn test_sockopts_tcp() {
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+    let listener =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::bind(
+        &listener,
+        &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+    )
+    .unwrap();
+    rustix::net::listen(&listener, 1).unwrap();
+    let local_addr = rustix::net::getsockname(&listener).unwrap();
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::connect_any(&s, &local_addr).unwrap();
+    let _accepted = rustix::net::accept(&listener).unwrap();
+
+    assert!(!rustix::net::sockopt::get_tcp_nodelay(&s).unwrap());
+    rustix::net::sockopt::set_tcp_nodelay(&s, true).unwrap();
+    assert!(rustix::net::sockopt::get_tcp_nodelay(&s).unwrap());
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+        assert!(!rustix::net::sockopt::get_tcp_cork(&s).unwrap());
+        rustix::net::sockopt::set_tcp_cork(&s, true).unwrap();
+        assert!(rustix::net::sockopt::get_tcp_cork(&s).unwrap());
+        rustix::net::sockopt::set_tcp_cork(&s, false).unwrap();
+        assert!(!rustix::net::sockopt::get_tcp_cork(&s).unwrap());
+
+        rustix::net::sockopt::set_tcp_quickack(&s, true).unwrap();
+        assert!(rustix::net::sockopt::get_tcp_quickack(&s).unwrap());
+    }
+
+    #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "linux",
+        target_os = "netbsd",
+    ))]
+    {
+        use std::time::Duration;
+
+        // Durations are rounded up to whole seconds.
+        rustix::net::sockopt::set_tcp_keepidle(&s, Duration::from_millis(1500)).unwrap();
+        assert_eq!(
+            rustix::net::sockopt::get_tcp_keepidle(&s).unwrap(),
+            Duration::from_secs(2)
+        );
+        rustix::net::sockopt::set_tcp_keepintvl(&s, Duration::from_secs(3)).unwrap();
+        assert_eq!(
+            rustix::net::sockopt::get_tcp_keepintvl(&s).unwrap(),
+            Duration::from_secs(3)
+        );
+        rustix::net::sockopt::set_tcp_keepcnt(&s, 4).unwrap();
+        assert_eq!(rustix::net::sockopt::get_tcp_keepcnt(&s).unwrap(), 4);
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_sockopts_mtu() {
+    use rustix::net::sockopt::IpMtuDiscover;
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+    let peer =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    rustix::net::bind(&peer, &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)).unwrap();
+    let peer_addr = rustix::net::getsockname(&peer).unwrap();
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    rustix::net::sockopt::set_ip_mtu_discover(&s, IpMtuDiscover::Do).unwrap();
+
+    // `IP_MTU` is only valid on connected sockets.
+    assert_eq!(
+        rustix::net::sockopt::get_ip_mtu(&s),
+        Err(rustix::io::Errno::NOTCONN)
+    );
+    rustix::net::connect_any(&s, &peer_addr).unwrap();
+    let mtu = rustix::net::sockopt::get_ip_mtu(&s).unwrap();
+    assert!(mtu >= 576, "implausible MTU {}", mtu);
+
+    // IPv6 loopback may be unavailable in some sandboxes.
+    let peer =
+        match rustix::net::socket(AddressFamily::INET6, SocketType::DGRAM, Protocol::default()) {
+            Ok(peer) => peer,
+            Err(rustix::io::Errno::AFNOSUPPORT) => return,
+            Err(err) => panic!("{:?}", err),
+        };
+    match rustix::net::bind(&peer, &SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 0)) {
+        Ok(()) => {}
+        Err(rustix::io::Errno::ADDRNOTAVAIL) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    let peer_addr = rustix::net::getsockname(&peer).unwrap();
+
+    let s =
+        rustix::net::socket(AddressFamily::INET6, SocketType::DGRAM, Protocol::default()).unwrap();
+    rustix::net::sockopt::set_ipv6_mtu_discover(&s, IpMtuDiscover::Want).unwrap();
+    assert_eq!(
+        rustix::net::sockopt::get_ipv6_mtu(&s),
+        Err(rustix::io::Errno::NOTCONN)
+    );
+    rustix::net::connect_any(&s, &peer_addr).unwrap();
+    let mtu = rustix::net::sockopt::get_ipv6_mtu(&s).unwrap();
+    assert!(mtu >= 1280, "implausible IPv6 MTU {}", mtu);
+}
+
+/// A failed nonblocking `connect` leaves its error pending on the socket.
+#[cfg(not(windows))]
+#[test]
+fn test_sockopts_socket_error() {
+    use rustix::io::{poll, Errno, PollFd, PollFlags};
+    use rustix::net::{AddressFamily, IpAddr, Ipv4Addr, Protocol, SocketAddr, SocketType};
+
+    // Bind a socket without listening on it to find an unused port.
+    let unused =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    rustix::net::bind(
+        &unused,
+        &SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+    )
+    .unwrap();
+    let addr = rustix::net::getsockname(&unused).unwrap();
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    assert_eq!(rustix::net::sockopt::get_socket_error(&s), Ok(Ok(())));
+
+    rustix::io::ioctl_fionbio(&s, true).unwrap();
+    match rustix::net::connect_any(&s, &addr) {
+        Err(Errno::INPROGRESS) => {
+            let mut fds = [PollFd::new(&s, PollFlags::OUT)];
+            assert_eq!(poll(&mut fds, -1).unwrap(), 1);
+            assert_eq!(
+                rustix::net::sockopt::get_socket_error(&s),
+                Ok(Err(Errno::CONNREFUSED))
+            );
+        }
+        // Some platforms report the refusal immediately.
+        Err(Errno::CONNREFUSED) => (),
+        other => panic!("unexpected result {:?}", other),
+    }
+
+    // Reading the error clears it.
+    assert_eq!(rustix::net::sockopt::get_socket_error(&s), Ok(Ok(())));
+}
+
+/// Set and get options through the raw interface, and compare them with the
+/// typed functions.
+#[cfg(not(windows))]
+#[test]
+fn test_sockopts_raw() {
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+
+    unsafe {
+        let get_reuseaddr = || {
+            rustix::net::sockopt::get_raw::<libc::c_int, _>(
+                &s,
+                libc::SOL_SOCKET as _,
+                libc::SO_REUSEADDR as _,
+            )
+            .unwrap()
+        };
+        assert_eq!(get_reuseaddr(), 0);
+
+        rustix::net::sockopt::set_raw::<libc::c_int, _>(
+            &s,
+            libc::SOL_SOCKET as _,
+            libc::SO_REUSEADDR as _,
+            &1,
+        )
+        .unwrap();
+        assert_ne!(get_reuseaddr(), 0);
+
+        rustix::net::sockopt::set_socket_reuseaddr(&s, false).unwrap();
+        assert_eq!(get_reuseaddr(), 0);
+
+        rustix::net::sockopt::set_raw_bytes(
+            &s,
+            libc::SOL_SOCKET as _,
+            libc::SO_REUSEADDR as _,
+            &(1 as libc::c_int).to_ne_bytes(),
+        )
+        .unwrap();
+        let mut buf = [0_u8; 8];
+        let len = rustix::net::sockopt::get_raw_bytes(
+            &s,
+            libc::SOL_SOCKET as _,
+            libc::SO_REUSEADDR as _,
+            &mut buf,
+        )
+        .unwrap();
+        assert_eq!(len, core::mem::size_of::<libc::c_int>());
+        assert_ne!(
+            &buf[..len],
+            &[0_u8; core::mem::size_of::<libc::c_int>()][..]
+        );
+
+        let type_ = rustix::net::sockopt::get_raw::<libc::c_int, _>(
+            &s,
+            libc::SOL_SOCKET as _,
+            libc::SO_TYPE as _,
+        )
+        .unwrap();
+        assert_eq!(
+            SocketType::from_raw(type_ as _),
+            rustix::net::sockopt::get_socket_type(&s).unwrap()
+        );
+
+        // A mismatched size is reported as an error.
+        assert_eq!(
+            rustix::net::sockopt::get_raw::<u64, _>(&s, libc::SOL_SOCKET as _, libc::SO_TYPE as _),
+            Err(rustix::io::Errno::INVAL)
+        );
+    }
+}
+
+/// Set the DSCP and ECN fields and the socket priority.
+#[cfg(not(windows))]
+#[test]
+fn test_sockopts_qos() {
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    // DSCP Expedited Forwarding, with the ECN-capable transport bits set.
+    let tos = 0xb8 | 0x01;
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::DGRAM, Protocol::default()).unwrap();
+    assert_eq!(rustix::net::sockopt::get_ip_tos(&s).unwrap(), 0);
+    rustix::net::sockopt::set_ip_tos(&s, tos).unwrap();
+    assert_eq!(rustix::net::sockopt::get_ip_tos(&s).unwrap(), tos);
+
+    // Linux manages the ECN bits of TCP sockets itself, and masks them off.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+        let s = rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default())
+            .unwrap();
+        rustix::net::sockopt::set_ip_tos(&s, tos).unwrap();
+        assert_eq!(rustix::net::sockopt::get_ip_tos(&s).unwrap(), 0xb8);
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+        // Setting `IP_TOS` also sets the priority, to 4 for this DSCP.
+        assert_eq!(rustix::net::sockopt::get_socket_priority(&s).unwrap(), 4);
+        rustix::net::sockopt::set_socket_priority(&s, 2).unwrap();
+        assert_eq!(rustix::net::sockopt::get_socket_priority(&s).unwrap(), 2);
+    }
+
+    // IPv6 may be unavailable in some sandboxes.
+    let s = match rustix::net::socket(AddressFamily::INET6, SocketType::DGRAM, Protocol::default())
+    {
+        Ok(s) => s,
+        Err(rustix::io::Errno::AFNOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    rustix::net::sockopt::set_ipv6_tclass(&s, u32::from(tos)).unwrap();
+    assert_eq!(
+        rustix::net::sockopt::get_ipv6_tclass(&s).unwrap(),
+        u32::from(tos)
+    );
+    assert_eq!(
+        rustix::net::sockopt::set_ipv6_tclass(&s, 256),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
+
+#[test]
+fn test_sockopts_linger() {
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+    use std::time::Duration;
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    assert_eq!(rustix::net::sockopt::get_socket_linger(&s), Ok(None));
+
+    rustix::net::sockopt::set_socket_linger(&s, Some(Duration::from_secs(5))).unwrap();
+    assert_eq!(
+        rustix::net::sockopt::get_socket_linger(&s),
+        Ok(Some(Duration::from_secs(5)))
+    );
+
+    // Fractional seconds are rounded up.
+    rustix::net::sockopt::set_socket_linger(&s, Some(Duration::from_millis(1500))).unwrap();
+    assert_eq!(
+        rustix::net::sockopt::get_socket_linger(&s),
+        Ok(Some(Duration::from_secs(2)))
+    );
+
+    rustix::net::sockopt::set_socket_linger(&s, None).unwrap();
+    assert_eq!(rustix::net::sockopt::get_socket_linger(&s), Ok(None));
+}
+
+#[test]
+fn test_sockopts_buffer_size() {
+    use rustix::net::{AddressFamily, Protocol, SocketType};
+
+    let s =
+        rustix::net::socket(AddressFamily::INET, SocketType::STREAM, Protocol::default()).unwrap();
+    assert_ne!(
+        rustix::net::sockopt::get_socket_recv_buffer_size(&s).unwrap(),
+        0
+    );
+    assert_ne!(
+        rustix::net::sockopt::get_socket_send_buffer_size(&s).unwrap(),
+        0
+    );
+
+    // Linux reports double the requested size, and other platforms report
+    // the size as requested.
+    let size = 64 * 1024;
+    rustix::net::sockopt::set_socket_recv_buffer_size(&s, size).unwrap();
+    assert!(rustix::net::sockopt::get_socket_recv_buffer_size(&s).unwrap() >= size);
+    rustix::net::sockopt::set_socket_send_buffer_size(&s, size).unwrap();
+    assert!(rustix::net::sockopt::get_socket_send_buffer_size(&s).unwrap() >= size);
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    match rustix::net::sockopt::set_socket_recv_buffer_size_force(&s, size) {
+        Ok(()) => assert_eq!(
+            rustix::net::sockopt::get_socket_recv_buffer_size(&s).unwrap(),
+            size * 2
+        ),
+        Err(rustix::io::Errno::PERM) => (),
+        Err(err) => panic!("{:?}", err),
+    }
+}
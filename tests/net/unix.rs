@@ -145,3 +145,49 @@ fn test_unix() {
     client.join().unwrap();
     server.join().unwrap();
 }
+
+/// `SOCK_SEQPACKET` preserves message boundaries on a pair from `accept`, as
+/// on one from `socketpair`.
+#[test]
+fn test_unix_seqpacket_accept() {
+    use rustix::net::{recv, send, RecvFlags, SendFlags};
+
+    let tmp = tempfile::tempdir().unwrap();
+    let addr = SocketAddrUnix::new(tmp.path().join("seqpacket")).unwrap();
+
+    let listener = socket(
+        AddressFamily::UNIX,
+        SocketType::SEQPACKET,
+        Protocol::default(),
+    )
+    .unwrap();
+    bind_unix(&listener, &addr).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(
+        AddressFamily::UNIX,
+        SocketType::SEQPACKET,
+        Protocol::default(),
+    )
+    .unwrap();
+    connect_unix(&client, &addr).unwrap();
+    let server = accept(&listener).unwrap();
+
+    assert_eq!(send(&client, b"hello", SendFlags::empty()).unwrap(), 5);
+    assert_eq!(send(&client, b"world!", SendFlags::empty()).unwrap(), 6);
+    assert_eq!(send(&server, b"truncated", SendFlags::empty()).unwrap(), 9);
+    assert_eq!(send(&server, b"next", SendFlags::empty()).unwrap(), 4);
+
+    let mut buf = [0_u8; 16];
+    assert_eq!(recv(&server, &mut buf, RecvFlags::empty()).unwrap(), 5);
+    assert_eq!(&buf[..5], b"hello");
+    assert_eq!(recv(&server, &mut buf, RecvFlags::empty()).unwrap(), 6);
+    assert_eq!(&buf[..6], b"world!");
+
+    // A short read discards the rest of the message.
+    let mut small = [0_u8; 4];
+    assert_eq!(recv(&client, &mut small, RecvFlags::empty()).unwrap(), 4);
+    assert_eq!(&small, b"trun");
+    assert_eq!(recv(&client, &mut buf, RecvFlags::empty()).unwrap(), 4);
+    assert_eq!(&buf[..4], b"next");
+}
@@ -0,0 +1,72 @@
+//! Test Linux's abstract-namespace Unix-domain socket addresses.
+
+use rustix::io::{read, write};
+use rustix::net::{
+    accept, bind_unix, connect_unix, getpeername, getsockname, listen, socket, AddressFamily,
+    Protocol, SocketAddrAny, SocketAddrStorage, SocketAddrUnix, SocketType,
+};
+use std::mem::MaybeUninit;
+
+#[test]
+fn test_abstract_encode_roundtrip() {
+    // Trailing NULs are part of an abstract name.
+    let orig = SocketAddrUnix::new_abstract_name(b"rustix\0\0").unwrap();
+    assert_eq!(orig.abstract_name(), Some(&b"rustix\0\0"[..]));
+    assert_eq!(orig.path(), None);
+
+    let mut storage = MaybeUninit::<SocketAddrStorage>::uninit();
+    let decoded = unsafe {
+        let len = SocketAddrAny::Unix(orig.clone()).write(storage.as_mut_ptr());
+        SocketAddrAny::read(storage.as_ptr(), len).unwrap()
+    };
+    assert_eq!(decoded, SocketAddrAny::Unix(orig));
+}
+
+#[test]
+fn test_abstract_bind_connect() {
+    let name = format!("rustix-abstract-{}", std::process::id());
+    let addr = SocketAddrUnix::new_abstract_name(name.as_bytes()).unwrap();
+
+    let listener = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    bind_unix(&listener, &addr).unwrap();
+    listen(&listener, 1).unwrap();
+
+    let client = socket(AddressFamily::UNIX, SocketType::STREAM, Protocol::default()).unwrap();
+    connect_unix(&client, &addr).unwrap();
+    let server = accept(&listener).unwrap();
+
+    assert_eq!(
+        getpeername(&client).unwrap(),
+        Some(SocketAddrAny::Unix(addr))
+    );
+
+    assert_eq!(write(&client, b"hi").unwrap(), 2);
+    let mut buf = [0_u8; 2];
+    assert_eq!(read(&server, &mut buf).unwrap(), 2);
+    assert_eq!(&buf, b"hi");
+}
+
+#[test]
+fn test_autobind() {
+    let unnamed = SocketAddrUnix::new_unnamed();
+    assert_eq!(unnamed.path(), None);
+    assert_eq!(unnamed.abstract_name(), None);
+
+    let s = socket(AddressFamily::UNIX, SocketType::DGRAM, Protocol::default()).unwrap();
+    assert_eq!(
+        getsockname(&s).unwrap(),
+        SocketAddrAny::Unix(SocketAddrUnix::new_unnamed())
+    );
+
+    // Binding to an empty name asks the kernel to pick an abstract name.
+    bind_unix(&s, &unnamed).unwrap();
+    let name = match getsockname(&s).unwrap() {
+        SocketAddrAny::Unix(unix) => unix.abstract_name().unwrap().to_vec(),
+        other => panic!("unexpected address {:?}", other),
+    };
+    assert!(!name.is_empty());
+
+    // The assigned name can be connected to like any other.
+    let client = socket(AddressFamily::UNIX, SocketType::DGRAM, Protocol::default()).unwrap();
+    connect_unix(&client, &SocketAddrUnix::new_abstract_name(&name).unwrap()).unwrap();
+}
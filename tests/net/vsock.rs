@@ -0,0 +1,71 @@
+use rustix::io::Errno;
+use rustix::net::{
+    accept, bind_vsock, connect_vsock, getsockname, listen, recv, send, socket, AddressFamily,
+    Protocol, RecvFlags, SendFlags, SocketAddrAny, SocketAddrStorage, SocketAddrVsock, SocketType,
+    VMADDR_CID_ANY, VMADDR_CID_LOCAL, VMADDR_PORT_ANY,
+};
+use std::mem::MaybeUninit;
+
+#[test]
+fn test_vsock_encode_roundtrip() {
+    let any = SocketAddrAny::from(SocketAddrVsock::new(VMADDR_CID_LOCAL, 1234));
+    assert_eq!(any.address_family(), AddressFamily::VSOCK);
+
+    let mut storage = MaybeUninit::<SocketAddrStorage>::uninit();
+    let decoded = unsafe {
+        let len = any.write(storage.as_mut_ptr());
+        SocketAddrAny::read(storage.as_ptr(), len).unwrap()
+    };
+    assert_eq!(decoded, any);
+}
+
+#[test]
+fn test_vsock_loopback() {
+    let listener = match socket(
+        AddressFamily::VSOCK,
+        SocketType::STREAM,
+        Protocol::default(),
+    ) {
+        Ok(s) => s,
+        // The kernel doesn't support `AF_VSOCK`.
+        Err(Errno::AFNOSUPPORT) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+    match bind_vsock(
+        &listener,
+        &SocketAddrVsock::new(VMADDR_CID_ANY, VMADDR_PORT_ANY),
+    ) {
+        Ok(()) => (),
+        // No vsock transport is loaded.
+        Err(Errno::ADDRNOTAVAIL) | Err(Errno::NODEV) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    listen(&listener, 1).unwrap();
+    let port = match getsockname(&listener).unwrap() {
+        SocketAddrAny::Vsock(vsock) => vsock.port(),
+        other => panic!("unexpected address {:?}", other),
+    };
+
+    let client = socket(
+        AddressFamily::VSOCK,
+        SocketType::STREAM,
+        Protocol::default(),
+    )
+    .unwrap();
+    match connect_vsock(&client, &SocketAddrVsock::new(VMADDR_CID_LOCAL, port)) {
+        Ok(()) => (),
+        // The `vsock_loopback` module isn't loaded, so the connection is
+        // routed to a transport that can't reach us.
+        Err(Errno::NODEV)
+        | Err(Errno::NETUNREACH)
+        | Err(Errno::CONNRESET)
+        | Err(Errno::TIMEDOUT) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    let server = accept(&listener).unwrap();
+
+    assert_eq!(send(&client, b"x", SendFlags::empty()).unwrap(), 1);
+    let mut buf = [0_u8; 1];
+    assert_eq!(recv(&server, &mut buf, RecvFlags::empty()).unwrap(), 1);
+    assert_eq!(&buf, b"x");
+}
@@ -39,3 +39,34 @@ fn test_linux_hwcap() {
         assert_eq!(hwcap2, unsafe { libc_getauxval(libc::AT_HWCAP2) } as usize);
     }
 }
+
+#[cfg(any(
+    all(target_os = "android", target_pointer_width = "64"),
+    target_os = "linux",
+))]
+#[test]
+fn test_auxv() {
+    use rustix::param::{auxv, AuxvKey};
+
+    assert_eq!(auxv(AuxvKey::Pagesz), Some(page_size()));
+    assert_eq!(
+        auxv(AuxvKey::Clktck),
+        Some(clock_ticks_per_second() as usize)
+    );
+
+    // The tests aren't run set-user-ID or with extra capabilities.
+    assert_eq!(auxv(AuxvKey::Secure), Some(0));
+
+    assert!(auxv(AuxvKey::Random).is_some());
+    assert!(auxv(AuxvKey::Execfn).is_some());
+
+    #[cfg(any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "powerpc64",
+        target_arch = "riscv64",
+    ))]
+    assert!(auxv(AuxvKey::Hwcap).is_some());
+}
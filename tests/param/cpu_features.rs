@@ -0,0 +1,41 @@
+use rustix::param::{cpu_features, Feature};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[test]
+fn test_cpu_features() {
+    let features = cpu_features();
+    #[cfg(target_arch = "x86_64")]
+    assert!(features.has(Feature::Sse2));
+
+    // AVX2 implies AVX.
+    if features.has(Feature::Avx2) {
+        assert!(features.has(Feature::Avx));
+    }
+
+    // Compare with std's own detection.
+    assert_eq!(
+        features.has(Feature::Sse42),
+        is_x86_feature_detected!("sse4.2")
+    );
+    assert_eq!(features.has(Feature::Aes), is_x86_feature_detected!("aes"));
+    assert_eq!(features.has(Feature::Avx), is_x86_feature_detected!("avx"));
+    assert_eq!(
+        features.has(Feature::Avx2),
+        is_x86_feature_detected!("avx2")
+    );
+}
+
+#[cfg(target_arch = "aarch64")]
+#[test]
+fn test_cpu_features() {
+    let features = cpu_features();
+    assert!(features.has(Feature::Fp));
+    assert!(features.has(Feature::Neon));
+}
+
+#[cfg(target_arch = "riscv64")]
+#[test]
+fn test_cpu_features() {
+    let features = cpu_features();
+    assert!(features.has(Feature::I));
+}
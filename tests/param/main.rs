@@ -12,3 +12,10 @@ mod weak;
 
 #[cfg(not(target_os = "wasi"))]
 mod auxv;
+#[cfg(any(
+    all(target_os = "android", target_pointer_width = "64"),
+    target_os = "linux",
+))]
+mod cpu_features;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod sysconf;
@@ -0,0 +1,22 @@
+use rustix::param::{configured_cpus, online_cpus, open_max};
+
+#[test]
+fn test_cpus() {
+    let online = online_cpus();
+    let configured = configured_cpus();
+    assert!(online >= 1);
+    assert!(online <= configured);
+
+    #[cfg(feature = "process")]
+    assert_eq!(
+        online,
+        rustix::process::sched_getaffinity(None).unwrap().count() as usize
+    );
+}
+
+#[test]
+fn test_open_max() {
+    assert_eq!(open_max(), unsafe {
+        libc::sysconf(libc::_SC_OPEN_MAX) as usize
+    });
+}
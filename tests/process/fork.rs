@@ -0,0 +1,14 @@
+use rustix::process::{fork, waitpid, Fork, WaitOptions};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_fork() {
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => unsafe { libc::_exit(42) },
+        Fork::Parent(pid) => {
+            let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(42));
+        }
+    }
+}
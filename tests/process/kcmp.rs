@@ -0,0 +1,83 @@
+use core::cmp::Ordering;
+use rustix::fd::AsRawFd;
+use rustix::io;
+use rustix::process::{getpid, kcmp, KcmpType};
+
+#[test]
+fn test_kcmp_file() {
+    let pid = getpid();
+    let (reader, writer) = io::pipe().unwrap();
+    let dup = io::dup(&reader).unwrap();
+
+    match kcmp(
+        pid,
+        pid,
+        KcmpType::File,
+        reader.as_raw_fd() as usize,
+        dup.as_raw_fd() as usize,
+    ) {
+        Ok(ordering) => assert_eq!(ordering, Ordering::Equal),
+        // The kernel was built without `CONFIG_KCMP`, or a sandbox forbids
+        // it.
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+
+    // The two ends of a pipe are different open file descriptions, and the
+    // ordering is antisymmetric.
+    let ordering = kcmp(
+        pid,
+        pid,
+        KcmpType::File,
+        reader.as_raw_fd() as usize,
+        writer.as_raw_fd() as usize,
+    )
+    .unwrap();
+    assert_ne!(ordering, Ordering::Equal);
+    assert_eq!(
+        kcmp(
+            pid,
+            pid,
+            KcmpType::File,
+            writer.as_raw_fd() as usize,
+            reader.as_raw_fd() as usize,
+        )
+        .unwrap(),
+        ordering.reverse()
+    );
+
+    // A process shares its address space with itself.
+    assert_eq!(kcmp(pid, pid, KcmpType::Vm, 0, 0).unwrap(), Ordering::Equal);
+
+    // Closed file descriptors can't be compared.
+    assert_eq!(
+        kcmp(pid, pid, KcmpType::File, reader.as_raw_fd() as usize, !0),
+        Err(io::Errno::BADF)
+    );
+}
+
+#[test]
+fn test_kcmp_epoll_tfd() {
+    use rustix::io::epoll::{epoll_add, epoll_create, CreateFlags, EventFlags};
+    use rustix::process::{kcmp_epoll_tfd, KcmpEpollSlot};
+
+    let pid = getpid();
+    let (reader, writer) = io::pipe().unwrap();
+    let epoll = epoll_create(CreateFlags::CLOEXEC).unwrap();
+    epoll_add(&epoll, &reader, 0, EventFlags::IN).unwrap();
+
+    let slot = KcmpEpollSlot {
+        efd: epoll.as_raw_fd(),
+        tfd: reader.as_raw_fd(),
+        toff: 0,
+    };
+    match kcmp_epoll_tfd(pid, pid, reader.as_raw_fd(), &slot) {
+        Ok(ordering) => assert_eq!(ordering, Ordering::Equal),
+        Err(io::Errno::NOSYS) | Err(io::Errno::PERM) => return,
+        Err(err) => panic!("{:?}", err),
+    }
+    assert_ne!(
+        kcmp_epoll_tfd(pid, pid, writer.as_raw_fd(), &slot).unwrap(),
+        Ordering::Equal
+    );
+}
@@ -0,0 +1,44 @@
+use rustix::process::{
+    fork, kill_process, killpg, test_kill_process, waitpid, Fork, Signal, WaitOptions,
+};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_test_kill_process() {
+    let pid = match unsafe { fork() }.unwrap() {
+        Fork::Child => loop {
+            unsafe { libc::pause() };
+        },
+        Fork::Parent(pid) => pid,
+    };
+
+    assert!(test_kill_process(pid).unwrap());
+    kill_process(pid, Signal::Kill).unwrap();
+
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.terminating_signal(), Some(Signal::Kill as _));
+    assert!(!test_kill_process(pid).unwrap());
+}
+
+#[test]
+#[serial]
+fn test_killpg() {
+    let pid = match unsafe { fork() }.unwrap() {
+        Fork::Child => unsafe {
+            libc::setpgid(0, 0);
+            loop {
+                libc::pause();
+            }
+        },
+        Fork::Parent(pid) => pid,
+    };
+
+    // Set the child's process group from the parent too, so that it's set
+    // before we signal it.
+    unsafe { libc::setpgid(pid.as_raw_nonzero().get() as _, 0) };
+    killpg(pid, Signal::Kill).unwrap();
+
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.terminating_signal(), Some(Signal::Kill as _));
+}
@@ -11,19 +11,43 @@
 mod weak;
 
 mod cpu_set;
+#[cfg(not(target_os = "wasi"))]
+mod fork;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have get[gpu]id.
 mod id;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+mod kcmp;
+#[cfg(not(target_os = "wasi"))]
+mod kill;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod membarrier;
+#[cfg(any(
+    all(feature = "fs", any(target_os = "android", target_os = "linux")),
+    target_os = "ios",
+    target_os = "macos",
+))]
+mod memory_usage;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod perf_event;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod pidfd;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod prctl;
 #[cfg(not(any(target_os = "fuchsia", target_os = "wasi")))] // WASI doesn't have [gs]etpriority.
 mod priority;
 #[cfg(target_os = "freebsd")]
 mod procctl;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod ptrace;
 #[cfg(not(any(target_os = "fuchsia", target_os = "redox", target_os = "wasi")))]
 mod rlimit;
 mod sched_yield;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod seccomp;
+#[cfg(not(target_os = "wasi"))]
+mod session;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod spawn;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have uname.
 mod uname;
 #[cfg(not(target_os = "wasi"))] // WASI doesn't have waitpid.
@@ -0,0 +1,26 @@
+use rustix::process::memory_usage;
+
+#[test]
+fn test_memory_usage() {
+    let before = memory_usage().unwrap();
+    assert!(before.resident > 0);
+    assert!(before.resident_peak >= before.resident);
+    assert!(before.virtual_size >= before.resident);
+
+    // Allocate and touch 32 MiB, so that it's all resident.
+    let size = 32 << 20;
+    let buf = vec![1_u8; size];
+    for i in (0..size).step_by(4096) {
+        unsafe { core::ptr::read_volatile(&buf[i]) };
+    }
+
+    let after = memory_usage().unwrap();
+    assert!(
+        after.resident >= before.resident + (size as u64) / 2,
+        "{:?} {:?}",
+        before,
+        after
+    );
+    assert!(after.resident_peak >= after.resident);
+    drop(buf);
+}
@@ -0,0 +1,47 @@
+use rustix::io;
+use rustix::process::{
+    perf_event_disable, perf_event_enable, perf_event_open, perf_event_reset, PerfEventAttr,
+    PerfEventAttrFlags, PerfEventFlags, PerfIocFlags, PerfSwId,
+};
+
+fn read_count(fd: &rustix::fd::OwnedFd) -> u64 {
+    let mut buf = [0_u8; 8];
+    assert_eq!(io::read(fd, &mut buf).unwrap(), 8);
+    u64::from_ne_bytes(buf)
+}
+
+#[test]
+fn test_perf_event_cpu_clock() {
+    let mut attr = PerfEventAttr::software(PerfSwId::CpuClock);
+    attr.flags(PerfEventAttrFlags::DISABLED | PerfEventAttrFlags::EXCLUDE_HV);
+    let event = match perf_event_open(&attr, None, None, None, PerfEventFlags::FD_CLOEXEC) {
+        Ok(event) => event,
+        // `perf_event_paranoid` forbids it, or perf is unsupported or
+        // filtered by a sandbox.
+        Err(io::Errno::ACCESS)
+        | Err(io::Errno::PERM)
+        | Err(io::Errno::NOENT)
+        | Err(io::Errno::NOSYS) => return,
+        Err(err) => panic!("{:?}", err),
+    };
+
+    // The event starts disabled, so it hasn't counted anything.
+    assert_eq!(read_count(&event), 0);
+
+    perf_event_reset(&event, PerfIocFlags::empty()).unwrap();
+    perf_event_enable(&event, PerfIocFlags::empty()).unwrap();
+    let mut x = 0_u64;
+    for i in 0..10_000_000_u64 {
+        x = x.wrapping_mul(31).wrapping_add(i);
+    }
+    assert_ne!(x, 1);
+    perf_event_disable(&event, PerfIocFlags::empty()).unwrap();
+
+    let count = read_count(&event);
+    assert!(count > 0);
+
+    // The count stays put while the event is disabled.
+    assert_eq!(read_count(&event), count);
+    perf_event_reset(&event, PerfIocFlags::empty()).unwrap();
+    assert_eq!(read_count(&event), 0);
+}
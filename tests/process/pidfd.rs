@@ -0,0 +1,33 @@
+use rustix::io;
+use rustix::process::{
+    kill_process, pidfd_open, process_mrelease, waitpid, PidfdFlags, ProcessMreleaseFlags, Signal,
+    Spawn, WaitOptions,
+};
+use serial_test::serial;
+
+// This must execute serially with the wait tests, so that `test_wait`
+// doesn't reap the child spawned here.
+
+#[test]
+#[serial]
+fn test_process_mrelease() {
+    let pid = Spawn::new("/bin/sleep").arg("10").spawn().unwrap();
+
+    let pidfd = match pidfd_open(pid, PidfdFlags::empty()) {
+        Ok(pidfd) => Some(pidfd),
+        Err(io::Errno::NOSYS) => None,
+        Err(err) => panic!("pidfd_open failed: {:?}", err),
+    };
+
+    if let Some(pidfd) = pidfd {
+        // The child is alive and not exiting, so there's nothing to release.
+        match process_mrelease(&pidfd, ProcessMreleaseFlags::empty()) {
+            Err(io::Errno::INVAL) | Err(io::Errno::NOSYS) => {}
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    kill_process(pid, Signal::Kill).unwrap();
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.terminating_signal(), Some(Signal::Kill as _));
+}
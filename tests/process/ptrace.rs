@@ -0,0 +1,100 @@
+use rustix::process::{fork, ptrace, waitpid, Fork, Signal, WaitOptions};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn test_ptrace_step() {
+    let path = b"/bin/true\0";
+    let argv = [path.as_ptr().cast::<libc::c_char>(), core::ptr::null()];
+
+    let pid = match unsafe { fork() }.unwrap() {
+        Fork::Child => unsafe {
+            // Only async-signal-safe operations are allowed in the child.
+            if ptrace::traceme().is_err() {
+                libc::_exit(1);
+            }
+            libc::execv(argv[0], argv.as_ptr());
+            libc::_exit(2)
+        },
+        Fork::Parent(pid) => pid,
+    };
+
+    // The `execv` stops the child with `SIGTRAP`, unless a sandbox forbids
+    // tracing, in which case it exits.
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    if !status.stopped() {
+        assert_eq!(status.exit_status(), Some(1));
+        return;
+    }
+    assert_eq!(status.stopping_signal(), Some(Signal::Trap as u32));
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv64",
+        target_arch = "x86",
+        target_arch = "x86_64",
+    ))]
+    {
+        let regs = ptrace::getregs(pid).unwrap();
+        let mut ip = regs.instruction_pointer();
+        assert_ne!(ip, 0);
+
+        // The program's code is readable through its tracer.
+        ptrace::peektext(pid, ip).unwrap();
+
+        for _ in 0..2 {
+            ptrace::step(pid, None).unwrap();
+            let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.stopping_signal(), Some(Signal::Trap as u32));
+
+            let next = ptrace::getregs(pid).unwrap().instruction_pointer();
+            assert_ne!(next, ip);
+            ip = next;
+        }
+
+        // Writing the registers back unchanged doesn't disturb the tracee.
+        let regs = ptrace::getregs(pid).unwrap();
+        ptrace::setregs(pid, &regs).unwrap();
+        assert_eq!(ptrace::getregs(pid).unwrap(), regs);
+    }
+
+    ptrace::cont(pid, None).unwrap();
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+/// A word for `test_ptrace_peektext` to read from the tracee, which has it at
+/// the same address as we do.
+static PEEKED: usize = 0x5eed_f00d;
+
+#[test]
+#[serial]
+fn test_ptrace_peektext() {
+    let pid = match unsafe { fork() }.unwrap() {
+        Fork::Child => unsafe {
+            // Only async-signal-safe operations are allowed in the child.
+            if ptrace::traceme().is_err() {
+                libc::_exit(1);
+            }
+            libc::raise(libc::SIGSTOP);
+            libc::_exit(0)
+        },
+        Fork::Parent(pid) => pid,
+    };
+
+    // A sandbox may forbid tracing, in which case the child exits.
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    if !status.stopped() {
+        assert_eq!(status.exit_status(), Some(1));
+        return;
+    }
+    assert_eq!(status.stopping_signal(), Some(Signal::Stop as u32));
+
+    let addr = &PEEKED as *const usize as usize;
+    assert_eq!(ptrace::peektext(pid, addr).unwrap(), 0x5eed_f00d);
+
+    ptrace::cont(pid, None).unwrap();
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
@@ -0,0 +1,110 @@
+use rustix::process::{getpid, seccomp_set_mode_filter, SeccompFilterFlags, SockFilter, SockFprog};
+
+#[test]
+fn test_seccomp_allow_all() {
+    // `BPF_RET | BPF_K`, returning `SECCOMP_RET_ALLOW`.
+    static FILTER: [SockFilter; 1] = [SockFilter {
+        code: 0x06,
+        jt: 0,
+        jf: 0,
+        k: 0x7fff_0000,
+    }];
+    let prog = SockFprog::new(&FILTER).unwrap();
+    assert_eq!(prog.filter(), &FILTER);
+
+    // Without `TSYNC`, the filter only applies to the calling thread, so
+    // install it in a new thread to leave the rest of the tests alone.
+    std::thread::spawn(move || {
+        // Installing a filter without privileges requires `no_new_privs`.
+        assert_eq!(
+            unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) },
+            0
+        );
+        seccomp_set_mode_filter(&prog, SeccompFilterFlags::empty()).unwrap();
+
+        assert_eq!(unsafe { libc::prctl(libc::PR_GET_SECCOMP) }, 2);
+        assert!(getpid().as_raw_nonzero().get() > 0);
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(
+        seccomp_set_mode_filter(&prog, SeccompFilterFlags::NEW_LISTENER),
+        Err(rustix::io::Errno::INVAL)
+    );
+}
+
+#[test]
+fn test_seccomp_notif() {
+    use rustix::process::{
+        seccomp_notif_id_valid, seccomp_notif_recv, seccomp_notif_send,
+        seccomp_set_mode_filter_listener, SeccompNotifResp, SeccompNotifRespFlags,
+    };
+
+    // Notify on `getpid`, and allow everything else.
+    static FILTER: [SockFilter; 4] = [
+        // `BPF_LD | BPF_W | BPF_ABS`, loading `seccomp_data.nr`.
+        SockFilter {
+            code: 0x20,
+            jt: 0,
+            jf: 0,
+            k: 0,
+        },
+        // `BPF_JMP | BPF_JEQ | BPF_K`
+        SockFilter {
+            code: 0x15,
+            jt: 0,
+            jf: 1,
+            k: libc::SYS_getpid as u32,
+        },
+        // `BPF_RET | BPF_K`, returning `SECCOMP_RET_USER_NOTIF`.
+        SockFilter {
+            code: 0x06,
+            jt: 0,
+            jf: 0,
+            k: 0x7fc0_0000,
+        },
+        // `BPF_RET | BPF_K`, returning `SECCOMP_RET_ALLOW`.
+        SockFilter {
+            code: 0x06,
+            jt: 0,
+            jf: 0,
+            k: 0x7fff_0000,
+        },
+    ];
+    let prog = SockFprog::new(&FILTER).unwrap();
+
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let target = std::thread::spawn(move || {
+        assert_eq!(
+            unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) },
+            0
+        );
+        let listener =
+            seccomp_set_mode_filter_listener(&prog, SeccompFilterFlags::empty()).unwrap();
+        sender.send(listener).unwrap();
+
+        // Make the syscall directly, in case libc caches the PID.
+        unsafe { libc::syscall(libc::SYS_getpid) }
+    });
+
+    // Act as the supervisor, and fake the result.
+    let listener = receiver.recv().unwrap();
+    let notif = seccomp_notif_recv(&listener).unwrap();
+    assert_eq!(notif.data.nr, libc::SYS_getpid as i32);
+    assert!(notif.pid.is_some());
+    assert!(seccomp_notif_id_valid(&listener, notif.id).unwrap());
+    seccomp_notif_send(
+        &listener,
+        &SeccompNotifResp {
+            id: notif.id,
+            val: 4242,
+            error: 0,
+            flags: SeccompNotifRespFlags::empty(),
+        },
+    )
+    .unwrap();
+
+    assert_eq!(target.join().unwrap(), 4242);
+    assert!(!seccomp_notif_id_valid(&listener, notif.id).unwrap());
+}
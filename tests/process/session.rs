@@ -0,0 +1,62 @@
+use rustix::io::{pipe, read, write};
+use rustix::process::{fork, getpgid, getpid, getsid, setpgid, setsid, waitpid, Fork, WaitOptions};
+use serial_test::serial;
+
+#[test]
+fn test_getsid() {
+    assert_eq!(getsid(None).unwrap(), getsid(Some(getpid())).unwrap());
+}
+
+#[test]
+#[serial]
+fn test_setsid() {
+    let (ready_reader, ready_writer) = pipe().unwrap();
+    let (done_reader, done_writer) = pipe().unwrap();
+
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => {
+            drop(ready_reader);
+            drop(done_writer);
+            let ok = match setsid() {
+                // The new session leader is also a group leader, so it can't
+                // create another session.
+                Ok(sid) => sid == getpid() && setsid() == Err(rustix::io::Errno::PERM),
+                Err(_) => false,
+            };
+            let _ = write(&ready_writer, &[ok as u8]);
+            // Wait for the parent to inspect us before exiting.
+            let _ = read(&done_reader, &mut [0_u8]);
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) }
+        }
+        Fork::Parent(child) => {
+            drop(ready_writer);
+            drop(done_reader);
+            let mut ok = [0_u8];
+            assert_eq!(read(&ready_reader, &mut ok).unwrap(), 1);
+            assert_eq!(ok, [1]);
+
+            assert_eq!(getsid(Some(child)).unwrap(), child);
+            assert_eq!(getpgid(Some(child)).unwrap(), child);
+            assert_ne!(getsid(None).unwrap(), child);
+
+            drop(done_writer);
+            let (_, status) = waitpid(Some(child), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(0));
+        }
+    }
+}
+
+#[test]
+#[serial]
+fn test_setpgid() {
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => {
+            let ok = setpgid(None, None).is_ok() && getpgid(None) == Ok(getpid());
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) }
+        }
+        Fork::Parent(child) => {
+            let (_, status) = waitpid(Some(child), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(0));
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use rustix::io;
+use rustix::process::{waitpid, Spawn, WaitOptions};
+use serial_test::serial;
+
+// These tests must execute serially with the wait tests, so that `test_wait`
+// doesn't reap the children spawned here.
+
+#[test]
+#[serial]
+fn test_spawn() {
+    let (reader, writer) = io::pipe().unwrap();
+    let pid = Spawn::new("/bin/echo")
+        .arg("hello")
+        .dup2(&writer, 1)
+        .spawn()
+        .unwrap();
+    drop(writer);
+
+    let mut buf = [0_u8; 16];
+    let mut len = 0;
+    loop {
+        let n = io::read(&reader, &mut buf[len..]).unwrap();
+        if n == 0 {
+            break;
+        }
+        len += n;
+    }
+    assert_eq!(&buf[..len], b"hello\n");
+
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+#[test]
+#[serial]
+fn test_spawn_pidfd() {
+    let (pid, _pidfd) = Spawn::new("/bin/true").spawn_pidfd().unwrap();
+
+    let (_, status) = waitpid(Some(pid), WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+#[test]
+#[serial]
+fn test_spawn_not_found() {
+    assert_eq!(
+        Spawn::new("/no/such/program").spawn(),
+        Err(io::Errno::NOENT)
+    );
+}
@@ -1,4 +1,4 @@
-use libc::{kill, SIGSTOP};
+use libc::{kill, SIGCONT, SIGKILL, SIGSTOP};
 use rustix::process;
 use serial_test::serial;
 use std::process::{Command, Stdio};
@@ -18,8 +18,61 @@ fn test_waitpid() {
     unsafe { kill(child.id() as _, SIGSTOP) };
 
     let pid = unsafe { process::Pid::from_raw(child.id() as _) };
-    let status = process::waitpid(pid, process::WaitOptions::UNTRACED)
+    let (_, status) = process::waitpid(pid, process::WaitOptions::UNTRACED)
         .expect("failed to wait")
         .unwrap();
     assert!(status.stopped());
 }
+
+#[test]
+#[serial]
+fn test_wait_status_kind() {
+    use process::{fork, Fork, WaitStatusKind};
+
+    // A normal exit.
+    let pid = match unsafe { fork() }.unwrap() {
+        Fork::Child => unsafe { libc::_exit(7) },
+        Fork::Parent(pid) => pid,
+    };
+    let (waited, status) = process::waitpid(Some(pid), process::WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert_eq!(waited, pid);
+    assert_eq!(status.kind(), WaitStatusKind::Exited(7));
+
+    let pid = match unsafe { fork() }.unwrap() {
+        Fork::Child => loop {
+            unsafe { libc::pause() };
+        },
+        Fork::Parent(pid) => pid,
+    };
+    let raw_pid = pid.as_raw_nonzero().get() as libc::pid_t;
+
+    // A stop, which `WUNTRACED` reports.
+    unsafe { kill(raw_pid, SIGSTOP) };
+    let (_, status) = process::waitpid(Some(pid), process::WaitOptions::UNTRACED)
+        .expect("failed to wait")
+        .unwrap();
+    assert_eq!(status.kind(), WaitStatusKind::Stopped(SIGSTOP as u32));
+
+    // A continue, which `WCONTINUED` reports.
+    unsafe { kill(raw_pid, SIGCONT) };
+    let (_, status) = process::waitpid(Some(pid), process::WaitOptions::CONTINUED)
+        .expect("failed to wait")
+        .unwrap();
+    assert_eq!(status.kind(), WaitStatusKind::Continued);
+
+    // A `SIGKILL`, which never dumps core.
+    unsafe { kill(raw_pid, SIGKILL) };
+    let (_, status) = process::waitpid(Some(pid), process::WaitOptions::empty())
+        .expect("failed to wait")
+        .unwrap();
+    assert_eq!(
+        status.kind(),
+        WaitStatusKind::Signaled {
+            signal: SIGKILL as u32,
+            core_dumped: false,
+        }
+    );
+    assert!(!status.core_dumped());
+}
@@ -2,6 +2,7 @@
 
 #[cfg(not(any(target_os = "fuchsia", target_os = "macos")))]
 use rustix::fs::{Mode, OFlags};
+use serial_test::serial;
 use tempfile::{tempdir, TempDir};
 
 #[allow(unused)]
@@ -13,6 +14,7 @@ fn tmpdir() -> TempDir {
 /// that makes this test fail.
 #[cfg(not(target_os = "macos"))]
 #[test]
+#[serial]
 fn test_changing_working_directory() {
     let tmpdir = tmpdir();
 
@@ -43,3 +45,62 @@ fn test_changing_working_directory() {
         "The cwd wasn't changed back to the its original position"
     );
 }
+
+#[cfg(not(any(target_os = "fuchsia", target_os = "macos")))]
+#[test]
+#[serial]
+fn test_working_dir_guard() {
+    let tmpdir = tmpdir();
+
+    let orig_cwd = rustix::process::getcwd(Vec::new()).expect("get the cwd");
+
+    {
+        let _guard = rustix::process::WorkingDirGuard::new().expect("save the cwd");
+        rustix::process::chdir(tmpdir.path()).expect("changing dir to the tmp");
+        let ch1_cwd = rustix::process::getcwd(Vec::new()).expect("get the cwd");
+        assert_eq!(
+            ch1_cwd.to_string_lossy(),
+            tmpdir.path().to_string_lossy(),
+            "The cwd is not the same as the tmpdir"
+        );
+    }
+
+    let ch2_cwd = rustix::process::getcwd(Vec::new()).expect("get the cwd");
+    assert_eq!(
+        orig_cwd, ch2_cwd,
+        "The guard didn't restore the original cwd"
+    );
+
+    // The guard also restores the cwd when unwinding from a panic.
+    let result = std::panic::catch_unwind(|| {
+        let _guard = rustix::process::WorkingDirGuard::new().expect("save the cwd");
+        rustix::process::chdir(tmpdir.path()).expect("changing dir to the tmp");
+        panic!("unwinding");
+    });
+    assert!(result.is_err());
+
+    let ch3_cwd = rustix::process::getcwd(Vec::new()).expect("get the cwd");
+    assert_eq!(
+        orig_cwd, ch3_cwd,
+        "The guard didn't restore the original cwd after a panic"
+    );
+}
+
+#[cfg(not(any(target_os = "fuchsia", target_os = "macos")))]
+#[test]
+#[serial]
+fn test_getcwd_reuse() {
+    let tmpdir = tmpdir();
+    let _guard = rustix::process::WorkingDirGuard::new().expect("save the cwd");
+
+    rustix::process::chdir(tmpdir.path()).expect("changing dir to the tmp");
+    let expected = tmpdir.path().canonicalize().unwrap();
+
+    // A buffer that's already big enough is used as-is.
+    let cwd = rustix::process::getcwd(Vec::with_capacity(4096)).expect("get the cwd");
+    assert_eq!(cwd.to_str().unwrap(), expected.to_str().unwrap());
+
+    // A buffer that's too small is grown.
+    let cwd = rustix::process::getcwd(vec![b'x'; 1]).expect("get the cwd");
+    assert_eq!(cwd.to_str().unwrap(), expected.to_str().unwrap());
+}
@@ -0,0 +1,28 @@
+use rustix::rand::{fill_fast, fill_secure};
+
+#[test]
+fn test_fill_secure() {
+    let mut buf = vec![0_u8; 1024 * 1024];
+    fill_secure(&mut buf).unwrap();
+
+    // The odds of any 4 KiB of random bytes being all zeros are
+    // vanishingly small, so this checks that every part was written.
+    for chunk in buf.chunks(4096) {
+        assert!(chunk.iter().any(|b| *b != 0));
+    }
+}
+
+#[test]
+fn test_fill_secure_empty() {
+    fill_secure(&mut []).unwrap();
+}
+
+#[test]
+fn test_fill_fast() {
+    let mut buf = vec![0_u8; 64 * 1024];
+    fill_fast(&mut buf).unwrap();
+
+    for chunk in buf.chunks(4096) {
+        assert!(chunk.iter().any(|b| *b != 0));
+    }
+}
@@ -1,7 +1,45 @@
-use rustix::rand::{getrandom, GetRandomFlags};
+use rustix::rand::{getrandom, vdso_getrandom, GetRandomFlags};
 
 #[test]
 fn test_getrandom() {
     let mut buf = [0_u8; 256];
     let _ = getrandom(&mut buf, GetRandomFlags::empty());
 }
+
+#[test]
+fn test_vdso_getrandom() {
+    fn fill_many() -> Vec<[u8; 32]> {
+        (0..256)
+            .map(|_| {
+                let mut buf = [0_u8; 32];
+                assert_eq!(
+                    vdso_getrandom(&mut buf, GetRandomFlags::empty()).unwrap(),
+                    buf.len()
+                );
+                buf
+            })
+            .collect()
+    }
+
+    // Each thread has its own state for the vDSO, and no two outputs should
+    // be the same.
+    let threads = (0..2)
+        .map(|_| std::thread::spawn(fill_many))
+        .collect::<Vec<_>>();
+    let mut all = threads
+        .into_iter()
+        .flat_map(|thread| thread.join().unwrap())
+        .collect::<Vec<_>>();
+    let len = all.len();
+    all.sort_unstable();
+    all.dedup();
+    assert_eq!(all.len(), len);
+
+    // Large buffers are filled completely too.
+    let mut buf = vec![0_u8; 1 << 20];
+    assert_eq!(
+        vdso_getrandom(&mut buf, GetRandomFlags::empty()).unwrap(),
+        buf.len()
+    );
+    assert!(buf.iter().any(|b| *b != 0));
+}
@@ -5,5 +5,7 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(not(target_os = "wasi"))]
+mod fill;
 #[cfg(any(linux_raw, all(libc, target_os = "linux")))]
 mod getrandom;
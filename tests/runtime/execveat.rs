@@ -0,0 +1,27 @@
+use rustix::cstr;
+use rustix::fs::{cwd, openat, Mode, OFlags};
+use rustix::process::{waitpid, Pid, WaitOptions};
+
+#[test]
+fn test_fexecve() {
+    let file = openat(
+        cwd(),
+        cstr!("/bin/true"),
+        OFlags::RDONLY | OFlags::CLOEXEC,
+        Mode::empty(),
+    )
+    .unwrap();
+
+    // Use libc's `fork`, which runs the `pthread_atfork` handlers, so that
+    // the allocation in `fexecve` in the child is safe.
+    let pid = unsafe { libc::fork() };
+    assert_ne!(pid, -1);
+    if pid == 0 {
+        let _ = rustix::runtime::fexecve(&file, &[cstr!("true")], &[]);
+        unsafe { libc::_exit(1) };
+    }
+
+    let pid = unsafe { Pid::from_raw(pid as _) };
+    let (_, status) = waitpid(pid, WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
@@ -0,0 +1,14 @@
+//! Tests for [`rustix::runtime`].
+
+#![cfg(feature = "runtime")]
+#![cfg(linux_raw)]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+#![cfg_attr(core_c_str, feature(core_c_str))]
+
+#[cfg(all(feature = "fs", feature = "process"))]
+mod execveat;
+#[cfg(all(feature = "process", feature = "thread"))]
+mod sigaction;
+mod sigaltstack;
+#[cfg(all(feature = "process", feature = "thread", feature = "time"))]
+mod sigwaitinfo;
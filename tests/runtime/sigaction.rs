@@ -0,0 +1,98 @@
+use rustix::io;
+use rustix::process::{getpid, waitpid, Pid, SigSet, Signal, WaitOptions};
+use rustix::runtime::{sigaction, SigHandler, Sigaction, SigactionFlags};
+use rustix::thread::{gettid, tgkill};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[test]
+fn test_sigaction_ignore_sigpipe() {
+    // Changing the `SIGPIPE` action would affect the other tests running in
+    // this process, so do it in a child.
+    let pid = unsafe { libc::fork() };
+    assert_ne!(pid, -1);
+    if pid == 0 {
+        unsafe { libc::_exit(ignore_sigpipe_in_child()) };
+    }
+
+    let pid = unsafe { Pid::from_raw(pid as _) };
+    let (_, status) = waitpid(pid, WaitOptions::empty()).unwrap().unwrap();
+    assert_eq!(status.exit_status(), Some(0));
+}
+
+/// Check that `SIGPIPE` can be ignored, returning which step failed, if any.
+///
+/// This runs in a `fork`ed child, so it sticks to async-signal-safe calls.
+fn ignore_sigpipe_in_child() -> i32 {
+    // The test harness already ignores `SIGPIPE`, so restore the default
+    // first, which would kill the process on a write to a closed pipe.
+    let default = Sigaction {
+        handler: SigHandler::Default,
+        mask: SigSet::new(),
+        flags: SigactionFlags::empty(),
+    };
+    if unsafe { sigaction(Signal::Pipe, Some(&default)) }.is_err() {
+        return 1;
+    }
+    match unsafe { sigaction(Signal::Pipe, None) } {
+        Ok(Sigaction {
+            handler: SigHandler::Default,
+            ..
+        }) => (),
+        _ => return 2,
+    }
+
+    let ignore = Sigaction {
+        handler: SigHandler::Ignore,
+        ..default
+    };
+    if unsafe { sigaction(Signal::Pipe, Some(&ignore)) }.is_err() {
+        return 3;
+    }
+    match unsafe { sigaction(Signal::Pipe, None) } {
+        Ok(Sigaction {
+            handler: SigHandler::Ignore,
+            ..
+        }) => (),
+        _ => return 4,
+    }
+
+    let (reader, writer) = match io::pipe() {
+        Ok(pipe) => pipe,
+        Err(_) => return 5,
+    };
+    drop(reader);
+    if io::write(&writer, b"x") != Err(io::Errno::PIPE) {
+        return 6;
+    }
+    0
+}
+
+static HANDLED: AtomicBool = AtomicBool::new(false);
+
+unsafe extern "C" fn handler(sig: i32) {
+    if sig == Signal::Usr2 as i32 {
+        HANDLED.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_sigaction_handler() {
+    let action = Sigaction {
+        handler: SigHandler::Handler(handler),
+        mask: SigSet::new(),
+        flags: SigactionFlags::RESTART,
+    };
+    let old = unsafe { sigaction(Signal::Usr2, Some(&action)) }.unwrap();
+
+    // Querying returns the action we just installed.
+    let current = unsafe { sigaction(Signal::Usr2, None) }.unwrap();
+    assert!(matches!(current.handler, SigHandler::Handler(_)));
+    assert!(current.flags.contains(SigactionFlags::RESTART));
+
+    // The handler runs and returns normally. Send the signal to this thread,
+    // so that no other thread, such as one in `sigwaitinfo`, takes it.
+    tgkill(getpid(), gettid(), Signal::Usr2).unwrap();
+    assert!(HANDLED.load(Ordering::SeqCst));
+
+    unsafe { sigaction(Signal::Usr2, Some(&old)) }.unwrap();
+}
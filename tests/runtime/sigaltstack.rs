@@ -0,0 +1,33 @@
+use rustix::runtime::{sigaltstack, Stack, StackFlags, SIGSTKSZ};
+
+#[test]
+fn test_sigaltstack() {
+    // Run on a fresh thread, so that the stack doesn't leak into other tests.
+    std::thread::spawn(|| {
+        let mut buf = vec![0_u8; SIGSTKSZ];
+        let stack = Stack {
+            sp: buf.as_mut_ptr().cast(),
+            flags: StackFlags::empty(),
+            size: buf.len(),
+        };
+        unsafe { sigaltstack(Some(&stack)) }.unwrap();
+
+        let current = unsafe { sigaltstack(None) }.unwrap();
+        assert_eq!(current.sp, stack.sp);
+        assert_eq!(current.size, stack.size);
+        assert!(!current.flags.contains(StackFlags::DISABLE));
+
+        let disable = Stack {
+            sp: core::ptr::null_mut(),
+            flags: StackFlags::DISABLE,
+            size: 0,
+        };
+        let old = unsafe { sigaltstack(Some(&disable)) }.unwrap();
+        assert_eq!(old.sp, stack.sp);
+
+        let current = unsafe { sigaltstack(None) }.unwrap();
+        assert!(current.flags.contains(StackFlags::DISABLE));
+    })
+    .join()
+    .unwrap();
+}
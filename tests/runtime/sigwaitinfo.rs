@@ -0,0 +1,86 @@
+use rustix::io;
+use rustix::process::{getpid, getuid, SigSet, Signal};
+use rustix::runtime::{sigtimedwait, sigwaitinfo};
+use rustix::thread::{gettid, sigprocmask, tgkill, SigmaskHow};
+use rustix::time::{
+    timer_create, timer_delete, timer_settime, ClockId, Itimerspec, Sigevent, TimerSettimeFlags,
+    Timespec,
+};
+
+#[test]
+fn test_sigwaitinfo() {
+    let mut usr1 = SigSet::new();
+    usr1.set(Signal::Usr1);
+    let old_mask = sigprocmask(SigmaskHow::Block, &usr1).unwrap();
+
+    // Direct the signal at this thread, which has it blocked, so that the
+    // test harness's other threads never see it.
+    let pid = getpid();
+    let tid = gettid();
+    std::thread::spawn(move || tgkill(pid, tid, Signal::Usr1).unwrap())
+        .join()
+        .unwrap();
+
+    let info = sigwaitinfo(&usr1).unwrap();
+    assert_eq!(info.signo(), Signal::Usr1 as i32);
+    assert_eq!(info.pid(), Some(pid));
+    assert_eq!(info.uid(), Some(getuid()));
+
+    sigprocmask(SigmaskHow::SetMask, &old_mask).unwrap();
+}
+
+/// A signal which wasn't sent by a process, such as one from a timer, has no
+/// sender.
+#[test]
+fn test_sigwaitinfo_no_sender() {
+    let mut usr2 = SigSet::new();
+    usr2.set(Signal::Usr2);
+    let old_mask = sigprocmask(SigmaskHow::Block, &usr2).unwrap();
+
+    let timer = timer_create(
+        ClockId::Monotonic,
+        Sigevent::ThreadId {
+            signal: Signal::Usr2 as i32,
+            value: 0,
+            tid: gettid(),
+        },
+    )
+    .unwrap();
+    let one_ms = Itimerspec {
+        it_interval: Timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        },
+        it_value: Timespec {
+            tv_sec: 0,
+            tv_nsec: 1_000_000,
+        },
+    };
+    timer_settime(timer, TimerSettimeFlags::empty(), &one_ms).unwrap();
+
+    let info = sigwaitinfo(&usr2).unwrap();
+    assert_eq!(info.code(), libc::SI_TIMER);
+    assert_eq!(info.pid(), None);
+    assert_eq!(info.uid(), None);
+
+    timer_delete(timer).unwrap();
+    sigprocmask(SigmaskHow::SetMask, &old_mask).unwrap();
+}
+
+#[test]
+fn test_sigtimedwait_timeout() {
+    let mut usr2 = SigSet::new();
+    usr2.set(Signal::Usr2);
+    let old_mask = sigprocmask(SigmaskHow::Block, &usr2).unwrap();
+
+    let timeout = Timespec {
+        tv_sec: 0,
+        tv_nsec: 1_000_000,
+    };
+    assert_eq!(
+        sigtimedwait(&usr2, Some(&timeout)).unwrap_err(),
+        io::Errno::AGAIN
+    );
+
+    sigprocmask(SigmaskHow::SetMask, &old_mask).unwrap();
+}
@@ -0,0 +1,105 @@
+//! Tests for [`rustix::shm`].
+
+#![cfg(feature = "shm")]
+#![cfg(any(
+    target_os = "android",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "ios",
+    target_os = "linux",
+    target_os = "macos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+))]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+use rustix::fs::{fstat, ftruncate, Mode, OFlags};
+use rustix::io;
+use rustix::shm::{shm_open, shm_unlink};
+
+fn shm_name(test: &str) -> String {
+    // Keep this short; some platforms limit shm names to 31 bytes.
+    format!("/rustix-{}-{}", test, std::process::id())
+}
+
+#[test]
+fn test_shm_open_unlink() {
+    let name = shm_name("open");
+    let fd = shm_open(
+        name.as_str(),
+        OFlags::RDWR | OFlags::CREATE | OFlags::EXCL,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    assert_eq!(fstat(&fd).unwrap().st_size, 0);
+    assert_eq!(
+        shm_open(
+            name.as_str(),
+            OFlags::RDWR | OFlags::CREATE | OFlags::EXCL,
+            Mode::RUSR | Mode::WUSR,
+        )
+        .map(drop),
+        Err(io::Errno::EXIST)
+    );
+
+    shm_unlink(name.as_str()).unwrap();
+    assert_eq!(
+        shm_open(name.as_str(), OFlags::RDWR, Mode::empty()).map(drop),
+        Err(io::Errno::NOENT)
+    );
+    assert_eq!(shm_unlink(name.as_str()), Err(io::Errno::NOENT));
+
+    // The object outlives its name.
+    ftruncate(&fd, 4096).unwrap();
+    assert_eq!(fstat(&fd).unwrap().st_size, 4096);
+}
+
+#[cfg(feature = "mm")]
+#[test]
+fn test_shm_shared_mappings() {
+    use rustix::mm::{mmap, munmap, MapFlags, ProtFlags};
+    use std::ptr::null_mut;
+
+    let name = shm_name("map");
+    let len = 4096;
+    let writer = shm_open(
+        name.as_str(),
+        OFlags::RDWR | OFlags::CREATE | OFlags::EXCL,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    ftruncate(&writer, len as u64).unwrap();
+    let reader = shm_open(name.as_str(), OFlags::RDONLY, Mode::empty()).unwrap();
+    shm_unlink(name.as_str()).unwrap();
+
+    unsafe {
+        let w = mmap(
+            null_mut(),
+            len,
+            ProtFlags::READ | ProtFlags::WRITE,
+            MapFlags::SHARED,
+            &writer,
+            0,
+        )
+        .unwrap();
+        let r = mmap(
+            null_mut(),
+            len,
+            ProtFlags::READ,
+            MapFlags::SHARED,
+            &reader,
+            0,
+        )
+        .unwrap();
+        assert_ne!(w, r);
+
+        let w = w.cast::<u64>();
+        let r = r.cast::<u64>();
+        assert_eq!(r.read_volatile(), 0);
+        w.add(7).write_volatile(0x0123_4567_89ab_cdef);
+        assert_eq!(r.add(7).read_volatile(), 0x0123_4567_89ab_cdef);
+
+        munmap(w.cast(), len).unwrap();
+        munmap(r.cast(), len).unwrap();
+    }
+}
@@ -0,0 +1,138 @@
+//! Tests for [`rustix::sysvipc`].
+
+#![cfg(feature = "sysvipc")]
+#![cfg(target_os = "linux")]
+#![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
+
+use rustix::fs::Mode;
+use rustix::io;
+use rustix::sysvipc::*;
+
+#[test]
+fn test_shm_attach_twice() {
+    let id = shmget(
+        IpcKey::PRIVATE,
+        4096,
+        ShmFlags::CREATE | ShmFlags::EXCL,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+
+    let a = shmat(id, ShmatFlags::empty()).unwrap().cast::<u64>();
+    let b = shmat(id, ShmatFlags::RDONLY).unwrap().cast::<u64>();
+    assert_ne!(a, b);
+
+    // Removal is deferred until the segment is detached.
+    shmctl_rmid(id).unwrap();
+
+    unsafe {
+        assert_eq!(b.read_volatile(), 0);
+        a.add(3).write_volatile(0xfeed_f00d);
+        assert_eq!(b.add(3).read_volatile(), 0xfeed_f00d);
+
+        shmdt(a.cast()).unwrap();
+        shmdt(b.cast()).unwrap();
+    }
+    assert_eq!(
+        shmat(id, ShmatFlags::empty()).map(drop),
+        Err(io::Errno::INVAL)
+    );
+}
+
+#[test]
+fn test_sem() {
+    let id = semget(
+        IpcKey::PRIVATE,
+        2,
+        IpcFlags::CREATE,
+        Mode::RUSR | Mode::WUSR,
+    )
+    .unwrap();
+    assert_eq!(semctl_getval(id, 0), Ok(0));
+
+    semctl_setval(id, 1, 3).unwrap();
+    semop(
+        id,
+        &[
+            Sembuf {
+                sem_num: 0,
+                sem_op: 2,
+                sem_flg: SemopFlags::empty(),
+            },
+            Sembuf {
+                sem_num: 1,
+                sem_op: -1,
+                sem_flg: SemopFlags::empty(),
+            },
+        ],
+    )
+    .unwrap();
+    assert_eq!(semctl_getval(id, 0), Ok(2));
+    assert_eq!(semctl_getval(id, 1), Ok(2));
+
+    // Operations are atomic, so a failed one applies none of them.
+    assert_eq!(
+        semop(
+            id,
+            &[
+                Sembuf {
+                    sem_num: 0,
+                    sem_op: -1,
+                    sem_flg: SemopFlags::empty(),
+                },
+                Sembuf {
+                    sem_num: 1,
+                    sem_op: -3,
+                    sem_flg: SemopFlags::NOWAIT,
+                },
+            ],
+        ),
+        Err(io::Errno::AGAIN)
+    );
+    assert_eq!(semctl_getval(id, 0), Ok(2));
+
+    semctl_rmid(id).unwrap();
+    assert_eq!(semctl_getval(id, 0), Err(io::Errno::INVAL));
+}
+
+#[test]
+fn test_msg() {
+    let id = msgget(IpcKey::PRIVATE, IpcFlags::CREATE, Mode::RUSR | Mode::WUSR).unwrap();
+
+    msgsnd(id, 2, b"second", MsgFlags::empty()).unwrap();
+    msgsnd(id, 1, b"first", MsgFlags::empty()).unwrap();
+    msgsnd(id, 3, b"", MsgFlags::empty()).unwrap();
+
+    let mut buf = [0_u8; 16];
+    assert_eq!(msgrcv(id, &mut buf, 1, MsgFlags::empty()), Ok((5, 1)));
+    assert_eq!(&buf[..5], b"first");
+
+    let mut short = [0_u8; 3];
+    assert_eq!(
+        msgrcv(id, &mut short, 0, MsgFlags::empty()),
+        Err(io::Errno::TOOBIG)
+    );
+    assert_eq!(msgrcv(id, &mut short, 0, MsgFlags::NOERROR), Ok((3, 2)));
+    assert_eq!(&short, b"sec");
+
+    assert_eq!(msgrcv(id, &mut buf, 0, MsgFlags::empty()), Ok((0, 3)));
+    assert_eq!(
+        msgrcv(id, &mut buf, 0, MsgFlags::NOWAIT),
+        Err(io::Errno::NOMSG)
+    );
+    assert_eq!(
+        msgsnd(id, 0, b"x", MsgFlags::empty()),
+        Err(io::Errno::INVAL)
+    );
+
+    msgctl_rmid(id).unwrap();
+}
+
+#[test]
+fn test_ftok() {
+    let a = ftok("Cargo.toml", 1).unwrap();
+    assert_eq!(ftok("Cargo.toml", 1), Ok(a));
+    assert_ne!(ftok("Cargo.toml", 2), Ok(a));
+    assert_eq!(a.as_raw() as u32 >> 24, 1);
+    assert_eq!(ftok("no-such-file", 1), Err(io::Errno::NOENT));
+}
@@ -6,6 +6,9 @@
 
 #[cfg(not(windows))]
 mod isatty;
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(feature = "process")]
+mod pgrp;
 #[cfg(not(any(windows, target_os = "fuchsia")))]
 #[cfg(feature = "procfs")]
 mod ttyname;
@@ -0,0 +1,68 @@
+use rustix::fd::{FromRawFd, OwnedFd};
+use rustix::io;
+use rustix::process::{getpgrp, getpid, setsid};
+use rustix::termios::{tcgetpgrp, tcgetsid, tcsetpgrp};
+use std::ffi::{CStr, CString};
+
+/// Open a new pty, returning its master side and the path of its slave side.
+fn openpty() -> (OwnedFd, CString) {
+    unsafe {
+        let fd = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY | libc::O_CLOEXEC);
+        assert!(fd >= 0);
+        let master = OwnedFd::from_raw_fd(fd);
+        assert_eq!(libc::grantpt(fd), 0);
+        assert_eq!(libc::unlockpt(fd), 0);
+        let mut buf = [0 as libc::c_char; 64];
+        assert_eq!(libc::ptsname_r(fd, buf.as_mut_ptr(), buf.len()), 0);
+        (master, CStr::from_ptr(buf.as_ptr()).to_owned())
+    }
+}
+
+#[test]
+fn test_not_controlling_terminal() {
+    let (master, _name) = openpty();
+    assert_eq!(tcgetsid(&master), Err(io::Errno::NOTTY));
+
+    let file = std::fs::File::open("Cargo.toml").unwrap();
+    assert_eq!(tcgetsid(&file), Err(io::Errno::NOTTY));
+    assert_eq!(tcgetpgrp(&file), Err(io::Errno::NOTTY));
+}
+
+/// Run `f` in a forked child in a new session, and return its exit status.
+fn in_new_session(f: impl FnOnce() -> bool) -> libc::c_int {
+    unsafe {
+        match libc::fork() {
+            -1 => panic!("fork failed"),
+            0 => {
+                let ok = setsid().is_ok() && f();
+                libc::_exit(if ok { 0 } else { 1 })
+            }
+            child => {
+                let mut status = 0;
+                assert_eq!(libc::waitpid(child, &mut status, 0), child);
+                status
+            }
+        }
+    }
+}
+
+#[test]
+fn test_tcsetpgrp_tcgetpgrp() {
+    let (_master, name) = openpty();
+
+    let status = in_new_session(|| {
+        // A session leader without a controlling terminal acquires the first
+        // terminal it opens without `O_NOCTTY`.
+        let slave = unsafe { libc::open(name.as_ptr(), libc::O_RDWR) };
+        if slave < 0 {
+            return false;
+        }
+        let slave = unsafe { OwnedFd::from_raw_fd(slave) };
+
+        tcgetsid(&slave) == Ok(getpid())
+            && tcsetpgrp(&slave, getpgrp()).is_ok()
+            && tcgetpgrp(&slave) == Ok(getpgrp())
+    });
+    assert!(libc::WIFEXITED(status));
+    assert_eq!(libc::WEXITSTATUS(status), 0);
+}
@@ -22,3 +22,40 @@ fn test_ttyname_not_tty() {
     let file = File::open("/dev/null").unwrap();
     assert_eq!(ttyname(&file, Vec::new()).unwrap_err(), io::Errno::NOTTY);
 }
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+#[test]
+fn test_ttyname_pty() {
+    use std::ffi::CStr;
+    use std::os::unix::fs::OpenOptionsExt;
+    use std::os::unix::io::FromRawFd;
+
+    let (master, name) = unsafe {
+        let fd = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY | libc::O_CLOEXEC);
+        assert!(fd >= 0);
+        let master = File::from_raw_fd(fd);
+        assert_eq!(libc::grantpt(fd), 0);
+        assert_eq!(libc::unlockpt(fd), 0);
+        let mut buf = [0 as libc::c_char; 64];
+        assert_eq!(libc::ptsname_r(fd, buf.as_mut_ptr(), buf.len()), 0);
+        let name = CStr::from_ptr(buf.as_ptr()).to_str().unwrap().to_owned();
+        (master, name)
+    };
+    assert!(name.starts_with("/dev/pts/"));
+
+    let slave = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .custom_flags(libc::O_NOCTTY)
+        .open(&name)
+        .unwrap();
+    assert!(isatty(&slave));
+    assert!(isatty(&master));
+    assert_eq!(ttyname(&slave, Vec::new()).unwrap().to_str().unwrap(), name);
+}
+
+#[test]
+fn test_ctermid() {
+    let name = rustix::termios::ctermid();
+    assert!(name.to_str().unwrap().starts_with("/dev/"));
+}
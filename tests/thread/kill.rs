@@ -0,0 +1,60 @@
+use rustix::process::{getpid, Pid, Signal};
+use rustix::thread::{gettid, kill_thread, tgkill};
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+use std::sync::{mpsc, Arc};
+
+static HANDLER_TID: AtomicI32 = AtomicI32::new(0);
+
+extern "C" fn handler(_sig: libc::c_int) {
+    let tid = unsafe { libc::syscall(libc::SYS_gettid) } as i32;
+    HANDLER_TID.store(tid, Ordering::SeqCst);
+}
+
+#[test]
+fn test_tgkill() {
+    unsafe {
+        let mut action: libc::sigaction = core::mem::zeroed();
+        action.sa_sigaction = handler as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        assert_eq!(
+            libc::sigaction(libc::SIGUSR1, &action, core::ptr::null_mut()),
+            0
+        );
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let done = Arc::new(AtomicBool::new(false));
+    let thread_done = done.clone();
+    let thread = std::thread::spawn(move || unsafe {
+        // Keep `SIGUSR1` blocked except while in `sigsuspend`, so that a
+        // signal sent between checking `done` and waiting stays pending
+        // instead of being lost.
+        let mut blocked: libc::sigset_t = core::mem::zeroed();
+        let mut unblocked: libc::sigset_t = core::mem::zeroed();
+        libc::sigemptyset(&mut blocked);
+        libc::sigaddset(&mut blocked, libc::SIGUSR1);
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_BLOCK, &blocked, &mut unblocked),
+            0
+        );
+        libc::sigdelset(&mut unblocked, libc::SIGUSR1);
+
+        tx.send(gettid()).unwrap();
+        while !thread_done.load(Ordering::SeqCst) {
+            libc::sigsuspend(&unblocked);
+        }
+    });
+    let tid = rx.recv().unwrap();
+
+    tgkill(getpid(), tid, Signal::Usr1).unwrap();
+    while HANDLER_TID.load(Ordering::SeqCst) == 0 {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+    assert_eq!(
+        unsafe { Pid::from_raw(HANDLER_TID.load(Ordering::SeqCst) as _) },
+        Some(tid)
+    );
+
+    done.store(true, Ordering::SeqCst);
+    kill_thread(tid, Signal::Usr1).unwrap();
+    thread.join().unwrap();
+}
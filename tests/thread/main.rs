@@ -7,9 +7,15 @@
 mod clocks;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod id;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod kill;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod libcap;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod prctl;
 #[cfg(any(target_os = "android", target_os = "linux"))]
 mod setns;
+#[cfg(feature = "process")]
+#[cfg(any(target_os = "android", target_os = "linux"))]
+mod sigmask;
@@ -0,0 +1,58 @@
+use rustix::process::{getpid, SigSet, Signal};
+use rustix::thread::{gettid, sigpending, sigprocmask, tgkill, SigmaskHow};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static DELIVERED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn handler(_sig: libc::c_int) {
+    DELIVERED.store(true, Ordering::SeqCst);
+}
+
+#[test]
+fn test_sigset() {
+    let mut set = SigSet::new();
+    assert!(!set.is_set(Signal::Usr2));
+    set.set(Signal::Usr2);
+    assert!(set.is_set(Signal::Usr2));
+    assert!(!set.is_set(Signal::Usr1));
+    set.unset(Signal::Usr2);
+    assert_eq!(set, SigSet::new());
+
+    let mut full = SigSet::full();
+    assert!(full.is_set(Signal::Usr1));
+    full.clear();
+    assert!(!full.is_set(Signal::Usr1));
+}
+
+#[test]
+fn test_sigprocmask() {
+    unsafe {
+        let mut action: libc::sigaction = core::mem::zeroed();
+        action.sa_sigaction = handler as extern "C" fn(libc::c_int) as libc::sighandler_t;
+        assert_eq!(
+            libc::sigaction(libc::SIGUSR2, &action, core::ptr::null_mut()),
+            0
+        );
+    }
+
+    // Run on a fresh thread, so that the mask doesn't leak into other tests.
+    std::thread::spawn(|| {
+        let mut set = SigSet::new();
+        set.set(Signal::Usr2);
+        let old = sigprocmask(SigmaskHow::Block, &set).unwrap();
+        assert!(!old.is_set(Signal::Usr2));
+
+        tgkill(getpid(), gettid(), Signal::Usr2).unwrap();
+        assert!(sigpending().unwrap().is_set(Signal::Usr2));
+        assert!(!DELIVERED.load(Ordering::SeqCst));
+
+        // Unblocking delivers the pending signal before `sigprocmask`
+        // returns.
+        let blocked = sigprocmask(SigmaskHow::SetMask, &old).unwrap();
+        assert!(blocked.is_set(Signal::Usr2));
+        assert!(DELIVERED.load(Ordering::SeqCst));
+        assert!(!sigpending().unwrap().is_set(Signal::Usr2));
+    })
+    .join()
+    .unwrap();
+}
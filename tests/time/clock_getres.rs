@@ -0,0 +1,26 @@
+use rustix::time::{clock_getres, ClockId};
+
+#[test]
+fn test_clock_getres() {
+    let res = clock_getres(ClockId::Monotonic);
+    assert!(res.tv_sec > 0 || res.tv_nsec > 0);
+
+    // Any reasonably modern system has a high-resolution monotonic clock.
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    {
+        assert_eq!(res.tv_sec, 0);
+        assert!(res.tv_nsec <= 1_000_000);
+    }
+}
+
+#[cfg(not(any(
+    target_os = "illumos",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "solaris",
+)))]
+#[test]
+fn test_clock_getres_process_cputime() {
+    let res = clock_getres(ClockId::ProcessCPUTime);
+    assert!(res.tv_sec > 0 || res.tv_nsec > 0);
+}
@@ -5,10 +5,15 @@
 #![cfg_attr(target_os = "wasi", feature(wasi_ext))]
 #![cfg_attr(io_lifetimes_use_std, feature(io_safety))]
 
+#[cfg(not(any(target_os = "redox", target_os = "wasi")))]
+mod clock_getres;
 mod dynamic_clocks;
 #[cfg(not(any(target_os = "redox", target_os = "wasi")))]
 mod monotonic;
 #[cfg(any(target_os = "android", target_os = "linux"))]
+#[cfg(all(feature = "process", feature = "thread"))]
+mod timer;
+#[cfg(any(target_os = "android", target_os = "linux"))]
 mod timerfd;
 mod timespec;
 mod y2038;
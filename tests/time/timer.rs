@@ -0,0 +1,125 @@
+use rustix::process::{fork, waitpid, Fork, SigSet, Signal, WaitOptions};
+use rustix::thread::{gettid, sigprocmask, SigmaskHow};
+use rustix::time::{
+    timer_create, timer_delete, timer_getoverrun, timer_gettime, timer_settime, ClockId,
+    Itimerspec, Sigevent, TimerSettimeFlags, Timespec,
+};
+
+/// Arm a timer for 30ms from now, without an interval.
+fn arm_30ms() -> Itimerspec {
+    Itimerspec {
+        it_interval: Timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        },
+        it_value: Timespec {
+            tv_sec: 0,
+            tv_nsec: 30_000_000,
+        },
+    }
+}
+
+/// Wait up to 5 seconds for a blocked `SIGALRM`, and return its `si_value`.
+fn wait_for_alarm() -> Option<isize> {
+    unsafe {
+        let mut set: libc::sigset_t = core::mem::zeroed();
+        libc::sigemptyset(&mut set);
+        libc::sigaddset(&mut set, libc::SIGALRM);
+        let mut info: libc::siginfo_t = core::mem::zeroed();
+        let timeout = libc::timespec {
+            tv_sec: 5,
+            tv_nsec: 0,
+        };
+        if libc::sigtimedwait(&set, &mut info, &timeout) != libc::SIGALRM {
+            return None;
+        }
+        Some(info.si_value().sival_ptr as isize)
+    }
+}
+
+#[test]
+fn test_timer_sigev_none() {
+    let timer = timer_create(ClockId::Monotonic, Sigevent::None).unwrap();
+
+    let set = Itimerspec {
+        it_interval: Timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        },
+        it_value: Timespec {
+            tv_sec: 10,
+            tv_nsec: 0,
+        },
+    };
+    let old = timer_settime(timer, TimerSettimeFlags::empty(), &set).unwrap();
+    assert_eq!(old.it_value.tv_sec, 0);
+    assert_eq!(old.it_value.tv_nsec, 0);
+
+    let current = timer_gettime(timer).unwrap();
+    assert!(current.it_value.tv_sec <= 10);
+    assert!(current.it_value.tv_sec > 0 || current.it_value.tv_nsec > 0);
+    assert_eq!(timer_getoverrun(timer).unwrap(), 0);
+
+    timer_delete(timer).unwrap();
+}
+
+/// Direct the signal at this thread, so that the test harness's other
+/// threads, which don't block `SIGALRM`, never see it.
+#[test]
+fn test_timer_sigev_thread_id() {
+    let mut alarm = SigSet::new();
+    alarm.set(Signal::Alarm);
+    let old_mask = sigprocmask(SigmaskHow::Block, &alarm).unwrap();
+
+    let timer = timer_create(
+        ClockId::Monotonic,
+        Sigevent::ThreadId {
+            signal: Signal::Alarm as i32,
+            value: 42,
+            tid: gettid(),
+        },
+    )
+    .unwrap();
+    timer_settime(timer, TimerSettimeFlags::empty(), &arm_30ms()).unwrap();
+
+    assert_eq!(wait_for_alarm(), Some(42));
+
+    timer_delete(timer).unwrap();
+    sigprocmask(SigmaskHow::SetMask, &old_mask).unwrap();
+}
+
+/// A process-directed signal could be delivered to any thread, so test
+/// `SIGEV_SIGNAL` in a single-threaded child process.
+#[test]
+fn test_timer_sigev_signal() {
+    match unsafe { fork() }.unwrap() {
+        Fork::Child => {
+            let ok = (|| {
+                let mut alarm = SigSet::new();
+                alarm.set(Signal::Alarm);
+                if sigprocmask(SigmaskHow::Block, &alarm).is_err() {
+                    return false;
+                }
+
+                let timer = match timer_create(
+                    ClockId::Monotonic,
+                    Sigevent::Signal {
+                        signal: Signal::Alarm as i32,
+                        value: 7,
+                    },
+                ) {
+                    Ok(timer) => timer,
+                    Err(_) => return false,
+                };
+                timer_settime(timer, TimerSettimeFlags::empty(), &arm_30ms()).is_ok()
+                    && wait_for_alarm() == Some(7)
+                    && timer_delete(timer).is_ok()
+            })();
+            unsafe { libc::_exit(if ok { 0 } else { 1 }) }
+        }
+        Fork::Parent(child) => {
+            let (_, status) = waitpid(Some(child), WaitOptions::empty()).unwrap().unwrap();
+            assert_eq!(status.exit_status(), Some(0));
+        }
+    }
+}